@@ -5,7 +5,12 @@
  * - https://satisfactory.wiki.gg/wiki/Template:DocsItems.json?action=edit
  *
  * The script expects them as `raw-recipes.json` and `raw-items.json` in the same directory. It
- * generates `src/gamedata/{items,recipes}.ts`.
+ * generates `src/gamedata/{items,recipes}.{ts,rs}`.
+ *
+ * Digests store recipes by their stable ID: the index in `recipe-ids.json`. That file is
+ * append-only: new recipes are appended by this script, and recipes that disappear from the game
+ * data must be listed in `recipe-migrations.json` with their successor. Items in smart splitter
+ * filters are stored the same way, by their index in the append-only `item-ids.json`.
  *
  * Run as `deno run --allow-read --allow-write data/gen.ts`.
  */
@@ -21,8 +26,15 @@ type Item = {
     id: OurItemId;
     name: string;
     description: string;
+    form: ItemForm;
+    stackSize: number;
+    energy: number;
+    sinkPoints: number;
 };
 
+const ITEM_FORMS = ["solid", "liquid", "gas"] as const;
+type ItemForm = typeof ITEM_FORMS[number];
+
 type Recipes = Record<RawRecipeId, Recipe>;
 type Recipe = {
     id: OurRecipeId,
@@ -30,6 +42,7 @@ type Recipe = {
     duration: number;
     producedIn: ProductionBuilding;
     alternative: boolean;
+    unlock: Unlock;
     powerRequirements?: [number, number];
     inputs: {
         item: OurItemId;
@@ -41,6 +54,41 @@ type Recipe = {
     }[],
 };
 
+/**
+ * Recipes that were removed or renamed, mapped to the recipe replacing them. `inputs` and
+ * `outputs` are the items of the removed recipe in order, as old plans connect those handles.
+ */
+type Migrations = Record<OurRecipeId, {
+    successor: OurRecipeId;
+    note: string;
+    inputs: OurItemId[];
+    outputs: OurItemId[];
+}>;
+
+type Unlock =
+    | { kind: "milestone"; tier: number }
+    | { kind: "mam"; tree: MamTree }
+    | { kind: "hard-drive" }
+    | { kind: "ficsmas" };
+
+type MamTree = typeof MAM_TREES[keyof typeof MAM_TREES];
+
+// Prefixes of MAM research schematics (`Research_<prefix>_...`) to our tree names. Some trees
+// use several prefixes.
+const MAM_TREES = {
+    "AO": "alien-organisms",
+    "ACarapace": "alien-organisms",
+    "AOrganisms": "alien-organisms",
+    "AOrgans": "alien-organisms",
+    "Alien": "alien-technology",
+    "Caterium": "caterium",
+    "Mycelia": "mycelia",
+    "Nutrients": "nutrients",
+    "PowerSlugs": "power-slugs",
+    "Quartz": "quartz",
+    "Sulfur": "sulfur",
+} as const;
+
 type ProductionBuilding = typeof BUILDING_MAPPING[keyof typeof BUILDING_MAPPING]
 
 // Buildings names inside the JSON are not that nice, so we map them to nicer names.
@@ -83,16 +131,37 @@ const main = async () => {
     const rawRecipes = await Deno.readTextFile('raw-recipes.json');
     const recipes = readRecipes(rawRecipes, items);
 
+    const registry = updateRegistry(
+        recipes,
+        JSON.parse(await Deno.readTextFile('recipe-ids.json')),
+        JSON.parse(await Deno.readTextFile('recipe-migrations.json')),
+    );
+    await Deno.writeTextFile('recipe-ids.json', JSON.stringify(registry.ids, null, 4) + "\n");
+
     // Remove items that are not used in any recipes.
+    const migrationItems = new Set(Object.values(registry.migrations)
+        .flatMap(m => [...m.inputs, ...m.outputs]));
     for (const [rawKey, item] of Object.entries(items)) {
         const isUsed = Object.values(recipes).some(recipe => {
             return recipe.inputs.some(input => input.item === item.id)
                 || recipe.outputs.some(output => output.item === item.id);
         });
-        if (!isUsed) {
+        if (!isUsed && !migrationItems.has(item.id)) {
             delete items[rawKey];
         }
     }
+    for (const id of migrationItems) {
+        if (!Object.values(items).some(item => item.id === id)) {
+            throw new Error(`Item '${id}' of a migrated recipe does not exist`);
+        }
+    }
+
+
+    const itemIds = updateItemRegistry(
+        items,
+        JSON.parse(await Deno.readTextFile('item-ids.json')),
+    );
+    await Deno.writeTextFile('item-ids.json', JSON.stringify(itemIds, null, 4) + "\n");
 
 
     // Check if icons for all parts are available
@@ -123,9 +192,9 @@ const main = async () => {
     await Deno.writeTextFile("../src/gamedata/items.ts", tsItems);
 
     console.log("Generating output Rust files...");
-    const rustRecipes = genRecipesRs(recipes);
+    const rustRecipes = genRecipesRs(recipes, Object.values(items), registry);
     await Deno.writeTextFile("../src/gamedata/recipes.rs", rustRecipes);
-    const rustItems = genItemsRs(Object.values(items).map(item => item.id), SOURCE_ITEMS);
+    const rustItems = genItemsRs(items, SOURCE_ITEMS, itemIds);
     await Deno.writeTextFile("../src/gamedata/items.rs", rustItems);
 };
 
@@ -145,10 +214,18 @@ const readItems = (rawText: string): Items => {
             continue;
         }
 
+        if (!ITEM_FORMS.includes(info.form)) {
+            throw new Error(`Unknown item form '${info.form}' for ${rawKey}`);
+        }
+
         out[rawKey] = {
             id: info.name.toLowerCase().replace(/ /g, '-'),
             name: info.name,
             description: info.description,
+            form: info.form,
+            stackSize: info.stackSize,
+            energy: info.energy,
+            sinkPoints: info.sinkPoints,
         };
     }
 
@@ -224,6 +301,7 @@ const readRecipes = (rawText: string, items: Items): Recipes => {
             duration: info.duration,
             producedIn: building,
             alternative: info.alternate,
+            unlock: readUnlock(rawKey, info.unlockedBy),
             ...info.minPower != null && info.maxPower != null && {
                 powerRequirements: [info.minPower, info.maxPower],
             },
@@ -235,6 +313,96 @@ const readRecipes = (rawText: string, items: Items): Recipes => {
     return recipes;
 };
 
+type Registry = { ids: OurRecipeId[]; migrations: Migrations };
+
+/**
+ * Checks that every registered recipe ID still exists or has a migration, and appends the IDs of
+ * new recipes. Existing entries are never changed.
+ */
+const updateRegistry = (recipes: Recipes, ids: OurRecipeId[], migrations: Migrations): Registry => {
+    const current = new Set(Object.values(recipes).map(r => r.id));
+    for (const [id, migration] of Object.entries(migrations)) {
+        if (!ids.includes(id)) {
+            throw new Error(`Migrated recipe '${id}' is not in 'recipe-ids.json'`);
+        }
+        if (!current.has(migration.successor)) {
+            throw new Error(`Successor '${migration.successor}' of '${id}' does not exist`);
+        }
+    }
+    for (const id of ids) {
+        if (!current.has(id) && !(id in migrations)) {
+            throw new Error(`Recipe '${id}' was removed, add it to 'recipe-migrations.json'`);
+        }
+    }
+
+    const out = [...ids];
+    for (const recipe of Object.values(recipes)) {
+        if (!out.includes(recipe.id)) {
+            out.push(recipe.id);
+        }
+    }
+    return { ids: out, migrations };
+};
+
+/**
+ * Appends the IDs of new items. Items that disappear from the game data keep their ID, digests
+ * using them just fail to load.
+ */
+const updateItemRegistry = (items: Items, ids: OurItemId[]): OurItemId[] => {
+    const out = [...ids];
+    for (const item of Object.values(items)) {
+        if (!out.includes(item.id)) {
+            out.push(item.id);
+        }
+    }
+    if (out.length > 256) {
+        throw new Error("More than 256 item IDs, but digests store them in 8 bits");
+    }
+    return out;
+};
+
+/**
+ * Determines how a recipe is unlocked from the list of schematics unlocking it. If there are
+ * multiple, the one that's usually available first wins: milestones (lowest tier first), then MAM
+ * research, then hard drives.
+ */
+const readUnlock = (rawKey: string, schematics: string[]): Unlock => {
+    const unlocks: Unlock[] = schematics.flatMap((schematic): Unlock[] => {
+        const milestone = schematic.match(/^Schematic_(\d+)-\d+_C$/);
+        if (milestone) {
+            return [{ kind: "milestone", tier: Number(milestone[1]) }];
+        }
+        if (/^Schematic_(Tutorial|StartingRecipes)/.test(schematic)) {
+            return [{ kind: "milestone", tier: 0 }];
+        }
+        if (schematic.startsWith("Schematic_Alternate_")) {
+            return [{ kind: "hard-drive" }];
+        }
+        if (schematic.startsWith("Research_XMas")) {
+            return [{ kind: "ficsmas" }];
+        }
+        const research = schematic.match(/^Research_([A-Za-z]+)_/);
+        if (research && research[1] in MAM_TREES) {
+            return [{ kind: "mam", tree: MAM_TREES[research[1] as keyof typeof MAM_TREES] }];
+        }
+        return [];
+    });
+
+    const rank = (u: Unlock): number => {
+        switch (u.kind) {
+            case "milestone": return u.tier;
+            case "mam": return 100;
+            case "hard-drive": return 200;
+            case "ficsmas": return 300;
+        }
+    };
+    unlocks.sort((a, b) => rank(a) - rank(b));
+    if (unlocks.length === 0) {
+        throw new Error(`Unknown unlock for ${rawKey}: ${schematics}`);
+    }
+    return unlocks[0];
+};
+
 
 // ------- Code generation ------------------------------------------------------------------------
 
@@ -250,11 +418,9 @@ const FILE_HEADER = `\
 // ----- Typescript code
 
 const stringifyObj = (key: string, obj: object & { id?: string }) => {
-    const out = { ...obj };
+    const out = { className: key, ...obj };
     delete out.id;
-    return JSON.stringify(out, null, 4)
-        .replace(/^{/, `{ // ${key}`)
-        .replaceAll("\n", "\n        ");
+    return JSON.stringify(out, null, 4).replaceAll("\n", "\n        ");
 };
 
 const genRecipesTs = (recipes: Recipes) => `\
@@ -283,13 +449,24 @@ const genItemsTs = (items: Items) => `\
 // ----- Rust code
 
 const toPascalCase = (str: string) => str.replace(/(?:^|-)([a-z0-9])/g, (_, c) => c.toUpperCase());
+const toRustFloat = (n: number) => Number.isInteger(n) ? `${n}.0` : `${n}`;
+const genUnlock = (unlock: Unlock) => {
+    switch (unlock.kind) {
+        case "milestone": return `Unlock::Milestone { tier: ${unlock.tier} }`;
+        case "mam": return `Unlock::Mam { tree: MamTree::${toPascalCase(unlock.tree)} }`;
+        case "hard-drive": return "Unlock::HardDrive";
+        case "ficsmas": return "Unlock::Ficsmas";
+    }
+};
+const genIoEntry = (entry: { item: OurItemId; amount: number }) =>
+    `io(ItemKind::${toPascalCase(entry.item)}, ${toRustFloat(entry.amount)})`;
 
-const genRecipesRs = (recipes: Recipes) => `\
+const genRecipesRs = (recipes: Recipes, items: Item[], registry: Registry) => `\
     ${FILE_HEADER}
 
-    use super::ItemKind;
+    use super::{Building, ItemKind, MamTree, Migration, Unlock};
 
-    #[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Deserialize, serde::Serialize)]
     #[repr(u16)]
     pub enum RecipeKind {${Object.values(recipes).map((recipe, idx) => `
         #[serde(rename = "${recipe.id}")]
@@ -310,44 +487,208 @@ const genRecipesRs = (recipes: Recipes) => `\
         pub fn info(self) -> &'static RecipeInfo {
             &RECIPES[self as u16 as usize]
         }
+
+        /// The class name used by the game, e.g. \`Recipe_IronPlate_C\`.
+        pub fn class_name(self) -> &'static str {
+            match self {${Object.entries(recipes).map(([key, recipe]) => `
+                Self::${toPascalCase(recipe.id)} => "${key}",`).join("")}
+            }
+        }
+
+        /// Looks up a recipe by the class name used by the game, e.g. \`Recipe_IronPlate_C\`.
+        pub fn from_class_name(name: &str) -> Option<Self> {
+            match name {${Object.entries(recipes).map(([key, recipe]) => `
+                "${key}" => Some(Self::${toPascalCase(recipe.id)}),`).join("")}
+                _ => None,
+            }
+        }
+
+        /// ID used in digests, see \`data/recipe-ids.json\`. Unlike the discriminant, it never
+        /// changes when the game data is regenerated.
+        pub fn stable_id(self) -> u16 {
+            match self {${Object.values(recipes).map(recipe => `
+                Self::${toPascalCase(recipe.id)} => ${registry.ids.indexOf(recipe.id)},`).join("")}
+            }
+        }
+
+        /// Inverse of \`stable_id\`. Returns \`None\` for unknown IDs and removed recipes, see
+        /// \`MIGRATIONS\` for those.
+        pub fn from_stable_id(id: u16) -> Option<Self> {
+            match id {${Object.values(recipes).map(recipe => `
+                ${registry.ids.indexOf(recipe.id)} => Some(Self::${toPascalCase(recipe.id)}),`).join("")}
+                _ => None,
+            }
+        }
     }
 
+    /// Recipes that were removed or renamed, see \`data/recipe-migrations.json\`.
+    pub(super) static MIGRATIONS: [Migration; ${Object.keys(registry.migrations).length}] = [${Object.entries(registry.migrations).map(([id, m]) => `
+        Migration {
+            stable_id: ${registry.ids.indexOf(id)},
+            old_id: "${id}",
+            successor: RecipeKind::${toPascalCase(m.successor)},
+            note: "${m.note}",
+            inputs: &[${m.inputs.map(id => `ItemKind::${toPascalCase(id)}`).join(", ")}],
+            outputs: &[${m.outputs.map(id => `ItemKind::${toPascalCase(id)}`).join(", ")}],
+        },`).join("")}
+    ];
+
     pub struct RecipeInfo {
-        #[allow(dead_code)]
         pub name: &'static str,
-        pub inputs: &'static [ItemKind],
-        pub outputs: &'static [ItemKind],
+        pub building: Building,
+        /// Crafting duration in seconds, at 100%.
+        pub duration: f64,
+        pub alternative: bool,
+        pub unlock: Unlock,
+        /// For recipes inside fluctuating power buildings, the min and max power in MW.
+        pub power_range: Option<(f64, f64)>,
+        pub inputs: &'static [IoEntry],
+        pub outputs: &'static [IoEntry],
     }
 
-    const RECIPES: [RecipeInfo; 278] = [${Object.values(recipes).map(recipe => `
+    pub struct IoEntry {
+        pub item: ItemKind,
+        /// Amount per craft. For fluids, this is in m³.
+        pub amount: f64,
+    }
+
+    const fn io(item: ItemKind, amount: f64) -> IoEntry {
+        IoEntry { item, amount }
+    }
+
+    static RECIPES: [RecipeInfo; 278] = [${Object.values(recipes).map(recipe => `
         RecipeInfo {
             name: "${recipe.name}",
-            inputs: &[${recipe.inputs.map(i => "ItemKind::" + toPascalCase(i.item)).join(", ")}],
-            outputs: &[${recipe.outputs.map(o => "ItemKind::" + toPascalCase(o.item)).join(", ")}],
+            building: Building::${toPascalCase(recipe.producedIn)},
+            duration: ${toRustFloat(recipe.duration)},
+            alternative: ${recipe.alternative},
+            unlock: ${genUnlock(recipe.unlock)},
+            power_range: ${recipe.powerRequirements
+                ? `Some((${recipe.powerRequirements.map(toRustFloat).join(", ")}))`
+                : "None"},
+            inputs: &[${recipe.inputs.map(genIoEntry).join(", ")}],
+            outputs: &[${recipe.outputs.map(genIoEntry).join(", ")}],
         },`).join("")}
     ];
+
+    impl ItemKind {
+        /// Returns all recipes that have this item as output.
+        pub fn produced_by(self) -> &'static [RecipeKind] {
+            PRODUCED_BY[self as u8 as usize]
+        }
+
+        /// Returns all recipes that have this item as input.
+        pub fn consumed_by(self) -> &'static [RecipeKind] {
+            CONSUMED_BY[self as u8 as usize]
+        }
+    }
+
+    const PRODUCED_BY: [&[RecipeKind]; ${items.length}] = [${items.map(item => `
+        &[${recipeIndex(recipes, r => r.outputs.some(o => o.item === item.id))}],`).join("")}
+    ];
+
+    const CONSUMED_BY: [&[RecipeKind]; ${items.length}] = [${items.map(item => `
+        &[${recipeIndex(recipes, r => r.inputs.some(i => i.item === item.id))}],`).join("")}
+    ];
 `.replaceAll(/^    /gm, "");
 
-const genItemsRs = (items: string[], sourceItems: string[]) => `\
+const recipeIndex = (recipes: Recipes, filter: (r: Recipe) => boolean) => Object.values(recipes)
+    .filter(filter)
+    .map(r => "RecipeKind::" + toPascalCase(r.id))
+    .join(", ");
+
+const genItemsRs = (items: Items, sourceItems: string[], ids: OurItemId[]) => `\
     ${FILE_HEADER}
 
     #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Deserialize, serde::Serialize)]
     #[repr(u8)]
-    pub enum ItemKind {${items.map((id, idx) => `
-        #[serde(rename = "${id}")]
-        ${toPascalCase(id)} = ${idx},`).join("")}
+    pub enum ItemKind {${Object.values(items).map((item, idx) => `
+        #[serde(rename = "${item.id}")]
+        ${toPascalCase(item.id)} = ${idx},`).join("")}
     }
 
     impl TryFrom<u8> for ItemKind {
         type Error = ();
         fn try_from(value: u8) -> Result<Self, Self::Error> {
-            match value {${items.map((id, idx) => `
-                ${idx} => Ok(Self::${toPascalCase(id)}),`).join("")}
+            match value {${Object.values(items).map((item, idx) => `
+                ${idx} => Ok(Self::${toPascalCase(item.id)}),`).join("")}
                 _ => Err(()),
             }
         }
     }
 
+    impl ItemKind {
+        pub fn info(self) -> &'static ItemInfo {
+            &ITEMS[self as u8 as usize]
+        }
+
+        /// The class name used by the game, e.g. \`Desc_IronPlate_C\`.
+        pub fn class_name(self) -> &'static str {
+            match self {${Object.entries(items).map(([key, item]) => `
+                Self::${toPascalCase(item.id)} => "${key}",`).join("")}
+            }
+        }
+
+        /// Looks up an item by the class name used by the game, e.g. \`Desc_IronPlate_C\`.
+        pub fn from_class_name(name: &str) -> Option<Self> {
+            match name {${Object.entries(items).map(([key, item]) => `
+                "${key}" => Some(Self::${toPascalCase(item.id)}),`).join("")}
+                _ => None,
+            }
+        }
+
+        /// ID used in digests, see \`data/item-ids.json\`. Unlike the discriminant, it never changes
+        /// when the game data is regenerated.
+        pub fn stable_id(self) -> u8 {
+            match self {${Object.values(items).map(item => `
+                Self::${toPascalCase(item.id)} => ${ids.indexOf(item.id)},`).join("")}
+            }
+        }
+
+        /// Inverse of \`stable_id\`. Returns \`None\` for unknown IDs and removed items.
+        pub fn from_stable_id(id: u8) -> Option<Self> {
+            match id {${Object.values(items).map(item => `
+                ${ids.indexOf(item.id)} => Some(Self::${toPascalCase(item.id)}),`).join("")}
+                _ => None,
+            }
+        }
+    }
+
+    pub struct ItemInfo {
+        pub name: &'static str,
+        pub form: ItemForm,
+        /// Maximum number of items per inventory slot. 0 for fluids.
+        pub stack_size: u32,
+        /// Energy in MJ when burned, per item or per m³ for fluids. 0 for non-fuels.
+        pub energy: u32,
+        /// Points awarded by the AWESOME Sink. 0 if the item cannot be sunk.
+        pub sink_points: u32,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum ItemForm {
+        Solid,
+        Liquid,
+        Gas,
+    }
+
+    impl ItemForm {
+        /// Whether this is transported via pipes, with rates in m³/min instead of items/min.
+        pub fn is_fluid(self) -> bool {
+            self != Self::Solid
+        }
+    }
+
+    const ITEMS: [ItemInfo; ${Object.keys(items).length}] = [${Object.values(items).map(item => `
+        ItemInfo {
+            name: "${item.name}",
+            form: ItemForm::${toPascalCase(item.form)},
+            stack_size: ${item.stackSize},
+            energy: ${item.energy},
+            sink_points: ${item.sinkPoints},
+        },`).join("")}
+    ];
+
     #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Deserialize, serde::Serialize)]
     #[repr(u8)]
     pub enum SourceItemKind {${sourceItems.map((id, idx) => `
@@ -8,6 +8,7 @@ import {
     ConnectionLineType,
     OnNodesChange,
     OnEdgesChange,
+    OnConnectEnd,
 } from '@xyflow/react';
 
 import '@xyflow/react/dist/style.css';
@@ -17,8 +18,8 @@ import { NewNodeMenu, NewNodeMenuPos } from './NewNodeMenu';
 import { useStore } from './store';
 import { useShallow } from 'zustand/shallow';
 import {
-    connectionToHandles, edgeIdToHandlePair, fromFlowNodeId, handlePairToEdgeId, toFlowHandleId,
-    toFlowNodeId, useEventListener,
+    connectionToHandles, edgeIdToHandlePair, fromFlowHandleId, fromFlowNodeId, handlePairToEdgeId,
+    toFlowHandleId, toFlowNodeId, useEventListener,
 } from './util';
 import { EDGE_TYPES, MainEdge } from './edges';
 import { Header } from './Header';
@@ -61,16 +62,31 @@ export default function App() {
     });
 
     // Handle right-clicks to open the new node menu
-    const [menuPos, setMenuPos] = useState<NewNodeMenuPos | null>(null);
+    const [menu, setMenu] = useState<{ pos: NewNodeMenuPos; from?: GraphHandle } | null>(null);
     const onPaneContextMenu = useCallback(
         (event: React.MouseEvent | MouseEvent) => {
             event.preventDefault();
             const pos = calcNewNodeMenuPos(event, ref.current!.getBoundingClientRect());
-            setMenuPos(pos);
+            setMenu({ pos });
         },
-        [setMenuPos],
+        [setMenu],
     );
-    const closeMenu = () => setMenuPos(null);
+    const closeMenu = () => setMenu(null);
+
+    // Dropping a new connection on the empty pane opens the menu to add a node connected to it.
+    const onConnectEnd: OnConnectEnd = (event, connection) => {
+        const { fromNode, fromHandle, toNode, isValid } = connection;
+        if (isValid || toNode || !fromNode || fromHandle?.id == null) {
+            return;
+        }
+        const from = new GraphHandle(fromFlowNodeId(fromNode.id), fromFlowHandleId(fromHandle.id));
+        if (graph.node(from.node).isHandleConnected(from.handle)) {
+            return;
+        }
+        const point = "changedTouches" in event ? event.changedTouches[0] : event;
+        const pos = calcNewNodeMenuPos(point, ref.current!.getBoundingClientRect());
+        setMenu({ pos, from });
+    };
 
 
     const onNodesChange: OnNodesChange<FlowNode> = (changes) => {
@@ -195,6 +211,7 @@ export default function App() {
                 const [source, target] = connectionToHandles(connection);
                 addEdge(source, target);
             }}
+            onConnectEnd={onConnectEnd}
             isValidConnection={connection => {
                 const [source, target] = connectionToHandles(connection);
                 return graph.isValidConnection(source, target);
@@ -205,7 +222,13 @@ export default function App() {
             <Background gap={25} variant={BackgroundVariant.Cross} />
             <MiniMap />
             <Controls />
-            {menuPos && <NewNodeMenu close={closeMenu} pos={menuPos} />}
+            {menu && <NewNodeMenu
+                // Remount when reopened elsewhere, so results are computed for the new handle.
+                key={`${menu.pos.mouse.x},${menu.pos.mouse.y}`}
+                close={closeMenu}
+                pos={menu.pos}
+                from={menu.from}
+            />}
         </ReactFlow>
     </>;
 }
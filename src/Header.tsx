@@ -1,4 +1,7 @@
-import { LuInfo, LuShare2 } from "react-icons/lu";
+import { LuInfo, LuPackage, LuShare2, LuTrophy, LuX } from "react-icons/lu";
+
+import { useSettings } from "./settings";
+import { useLoadWarnings } from "./store";
 
 
 export const Header = () => {
@@ -26,9 +29,46 @@ export const Header = () => {
         }}>
             <Menu />
         </nav>
+
+        <LoadWarnings />
     </>;
 };
 
+/** Shows what changed when loading the plan, e.g. recipes that were removed from the game. */
+const LoadWarnings = () => {
+    const { warnings, dismiss } = useLoadWarnings();
+    if (warnings.length === 0) {
+        return null;
+    }
+
+    return <div css={{
+        position: "absolute",
+        zIndex: 100,
+        top: 48,
+        left: "50%",
+        transform: "translateX(-50%)",
+        maxWidth: 600,
+        display: "flex",
+        gap: 8,
+        alignItems: "flex-start",
+        padding: "8px 12px",
+        borderRadius: 4,
+        background: "#f39c12",
+    }}>
+        <ul css={{ margin: 0, paddingLeft: 16 }}>
+            {warnings.map((warning, i) => <li key={i}>{warning}</li>)}
+        </ul>
+        <button title="Dismiss" onClick={dismiss} css={{
+            flexShrink: 0,
+            border: "none",
+            background: "none",
+            cursor: "pointer",
+        }}>
+            <LuX />
+        </button>
+    </div>;
+};
+
 const Menu = () => {
     return (
         <ul css={{
@@ -39,6 +79,8 @@ const Menu = () => {
             display: "flex",
             gap: 8,
         }}>
+            <ProgressionSelect />
+            <DataPacksButton />
             <ShareButton />
             <InfoButton />
         </ul>
@@ -50,7 +92,7 @@ type MenuEntryProps = {
     icon: JSX.Element
 } & JSX.IntrinsicElements["li"];
 
-const MenuEntry = ({ label, icon, ...rest }: MenuEntryProps) => (
+const MenuEntry = ({ label, icon, children, ...rest }: MenuEntryProps) => (
     <li {...rest} css={{
         height: "100%",
         display: "flex",
@@ -65,9 +107,66 @@ const MenuEntry = ({ label, icon, ...rest }: MenuEntryProps) => (
     }}>
         {icon}
         {label}
+        {children}
     </li>
 );
 
+/** Highest HUB tier in the game. */
+const MAX_TIER = 9;
+
+const ProgressionSelect = () => {
+    const { recipeFilter, setRecipeFilter } = useSettings();
+
+    return <MenuEntry label="Tier" icon={<LuTrophy />}>
+        <select
+            value={recipeFilter.maxTier ?? "all"}
+            onChange={e => {
+                const value = e.target.value;
+                setRecipeFilter({
+                    ...recipeFilter,
+                    maxTier: value === "all" ? undefined : Number(value),
+                });
+            }}
+        >
+            <option value="all">All</option>
+            {Array.from({ length: MAX_TIER + 1 }, (_, tier) => (
+                <option key={tier} value={tier}>{tier}</option>
+            ))}
+        </select>
+    </MenuEntry>;
+};
+
+/** Loads data packs from JSON files, each containing one pack or an array of packs. */
+const DataPacksButton = () => {
+    const { dataPacks, setDataPacks } = useSettings();
+
+    const load = async (files: FileList) => {
+        try {
+            const contents = await Promise.all([...files].map(file => file.text()));
+            setDataPacks(contents.flatMap(text => [JSON.parse(text)].flat()));
+        } catch (e) {
+            alert(`Failed to load data packs: ${e}`);
+        }
+    };
+
+    return <MenuEntry label={`Data packs (${dataPacks.length})`} icon={<LuPackage />}>
+        <label
+            title="Choose data pack files, in the order they are applied"
+            css={{ cursor: "pointer", textDecoration: "underline" }}
+        >
+            Load
+            <input
+                type="file"
+                accept=".json,application/json"
+                multiple
+                onChange={e => e.target.files && load(e.target.files)}
+                css={{ display: "none" }}
+            />
+        </label>
+        {dataPacks.length > 0 && <button onClick={() => setDataPacks([])}>Clear</button>}
+    </MenuEntry>;
+};
+
 const ShareButton = () => {
     return <MenuEntry label="Share" icon={<LuShare2 />} />;
 };
@@ -1,11 +1,15 @@
-import { useState } from "react";
+import { useMemo, useState } from "react";
 import { useReactFlow } from "@xyflow/react";
 import { useShallow } from "zustand/shallow";
 import { LuArrowRightFromLine } from "react-icons/lu";
 
-import { ITEMS, Recipe, recipeEntries, RecipeEntry, RecipeId } from "./gamedata";
+import {
+    allowedRecipes, ItemId, ITEMS, Recipe, recipeEntries, RecipeEntry, RecipeId, RECIPES,
+    recipesConsuming, recipesProducing, RESOURCE_ITEMS,
+} from "./gamedata";
 import { itemIcon, match, nodeColor, useEventListener } from "./util";
 import { useStore } from "./store";
+import { useSettings } from "./settings";
 import SplitterIcon from "./icons/splitter.svg?react";
 import MergerIcon from "./icons/merger.svg?react";
 import { GraphNode } from "./graph/node";
@@ -13,6 +17,7 @@ import { RecipeGraphNode } from "./graph/recipe";
 import { SplitterGraphNode } from "./graph/splitter";
 import { MergerGraphNode } from "./graph/merger";
 import { SourceGraphNode } from "./graph/source";
+import { GraphHandle } from "./graph";
 import { NEW_NODE_MENU_HEIGHT, NEW_NODE_MENU_WIDTH } from "./new-node-menu";
 
 
@@ -31,30 +36,68 @@ export type NewNodeMenuPos = {
 
 
 
+/** A handle a connection was dragged from and dropped on the empty pane. */
+type DraggedHandle = {
+    handle: GraphHandle;
+    /** Whether `handle` is an output, i.e. the new node has to take its item. */
+    output: boolean;
+    item: ItemId | undefined;
+};
+
 export type NewNodeMenuProps = {
     pos: NewNodeMenuPos;
+    /** If the menu was opened by dropping a connection, the handle it was dragged from. */
+    from?: GraphHandle;
     close: () => void;
 };
 
-export const NewNodeMenu = ({ pos, close }: NewNodeMenuProps) => {
-    const { addNodeInner } = useStore(useShallow(state => ({
+export const NewNodeMenu = ({ pos, from, close }: NewNodeMenuProps) => {
+    const { graph, addNodeInner, addConnectedNode } = useStore(useShallow(state => ({
+        graph: state.graph,
         addNodeInner: state.addNode,
+        addConnectedNode: state.addConnectedNode,
     })));
 
+    const recipeFilter = useSettings(state => state.recipeFilter);
+    const allowed = useMemo(() => new Set(allowedRecipes(recipeFilter)), [recipeFilter]);
+    const dragged: DraggedHandle | undefined = from && {
+        handle: from,
+        output: graph.node(from.node).outputs().includes(from.handle),
+        item: graph.handleItem(from),
+    };
+
     const { screenToFlowPosition } = useReactFlow();
     const [query, setQuery] = useState("");
-    const [results, setResults] = useState<RecipeEntry[]>(filterRecipes(query));
+    const [results, setResults] = useState<RecipeEntry[]>(
+        () => filterRecipes(query, allowed, dragged),
+    );
     const [selected, setSelected] = useState<RecipeId | null>(results[0]?.id ?? null);
 
+    const placeholder = dragged?.item
+        ? `Add recipe ${dragged.output ? "taking" : "making"} ${ITEMS[dragged.item].name}`
+        : "Add recipe";
+
     const nodePos = () => screenToFlowPosition(pos.mouse);
     const addNode = (n: GraphNode) => {
-        addNodeInner(n);
+        // Connect the first free handle of the new node that fits the dragged one.
+        const handle = dragged && (dragged.output ? n.inputs() : n.outputs()).find(h => (
+            !(n instanceof RecipeGraphNode) || n.entry(h)?.item === dragged.item
+        ));
+        if (dragged && handle !== undefined) {
+            addConnectedNode(n, handle, dragged.handle);
+        } else {
+            addNodeInner(n);
+        }
         close();
     };
     const addRecipe = (recipe: RecipeId) => addNode(new RecipeGraphNode(recipe, nodePos()));
     const addSplitter = () => addNode(new SplitterGraphNode(nodePos()));
     const addMerger = () => addNode(new MergerGraphNode(nodePos()));
-    const addSource = () => addNode(new SourceGraphNode("iron-ore", 60, nodePos()));
+    const addSource = () => {
+        // When dragged from an input taking a resource, the source provides that resource.
+        const item = RESOURCE_ITEMS.find(i => i === dragged?.item) ?? "iron-ore";
+        addNode(new SourceGraphNode(item, 60, nodePos()));
+    };
 
     // Keyboard control (arrow keys and enter).
     useEventListener("keydown", (e: KeyboardEvent) => {
@@ -135,13 +178,13 @@ export const NewNodeMenu = ({ pos, close }: NewNodeMenuProps) => {
             </div>
             <input
                 type="text"
-                placeholder="Add recipe"
+                placeholder={placeholder}
                 autoFocus
                 value={query}
                 onChange={(e) => {
                     const q = e.target.value;
                     setQuery(q)
-                    const newResults = filterRecipes(q);
+                    const newResults = filterRecipes(q, allowed, dragged);
                     setResults(newResults);
                     if (selected && !newResults.some(({ id }) => id === selected)) {
                         setSelected(newResults[0]?.id ?? null);
@@ -225,7 +268,11 @@ export const NewNodeMenu = ({ pos, close }: NewNodeMenuProps) => {
     )
 };
 
-const filterRecipes = (query: string): RecipeEntry[] => {
+const filterRecipes = (
+    query: string,
+    allowed: Set<RecipeId>,
+    dragged: DraggedHandle | undefined,
+): RecipeEntry[] => {
     // Sort recipes to roughly match "simpler recipes first".
     const sort = (arr: RecipeEntry[]) => arr.sort((a, b) => {
         const score = (r: Recipe): number => {
@@ -254,18 +301,44 @@ const filterRecipes = (query: string): RecipeEntry[] => {
         return score(a.info) - score(b.info);
     });
 
-    const nameMatches: RecipeEntry[] = [];
-    const outputMatches: RecipeEntry[] = [];
-
     const q = query.toLowerCase();
-    for (const recipe of recipeEntries()) {
-        if (recipe.info.name.toLowerCase().includes(q)) {
-            nameMatches.push(recipe);
-        } else if (recipe.info.outputs.some(o => ITEMS[o.item].name.toLowerCase().includes(q))) {
-            outputMatches.push(recipe);
-        }
+
+    // When dragged from a handle with known item, only recipes that fit it are offered. Recipes
+    // that aren't unlocked yet are never shown.
+    if (dragged?.item) {
+        const candidates = dragged.output
+            ? recipesConsuming(dragged.item)
+            : recipesProducing(dragged.item);
+        return sort(candidates
+            .filter(id => allowed.has(id) && RECIPES[id].name.toLowerCase().includes(q))
+            .map(id => ({ id, info: RECIPES[id] })));
     }
+
+    const nameMatches = recipeEntries()
+        .filter(r => allowed.has(r.id) && r.info.name.toLowerCase().includes(q));
+
+    // Recipes producing or consuming a matching item come from the index in the WASM module.
+    const seen = new Set(nameMatches.map(r => r.id));
+    const lookup = (recipesFor: (item: ItemId) => RecipeId[]): RecipeEntry[] => {
+        const out: RecipeEntry[] = [];
+        for (const [item, info] of Object.entries(ITEMS)) {
+            if (!info.name.toLowerCase().includes(q)) {
+                continue;
+            }
+            for (const id of recipesFor(item as ItemId)) {
+                if (allowed.has(id) && !seen.has(id)) {
+                    seen.add(id);
+                    out.push({ id, info: RECIPES[id] });
+                }
+            }
+        }
+        return out;
+    };
+    const outputMatches = lookup(recipesProducing);
+    const inputMatches = lookup(recipesConsuming);
+
     sort(nameMatches);
     sort(outputMatches);
-    return nameMatches.concat(outputMatches);
+    sort(inputMatches);
+    return nameMatches.concat(outputMatches, inputMatches);
 };
@@ -0,0 +1,126 @@
+//! Power shards and Somersloops needed by a plan.
+//!
+//! Overclocking needs one power shard per 50% step above 100% and Somersloops are rare, so a plan
+//! can easily need more of them than the player owns.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{gamedata::GameData, state::{Graph, NodeId}};
+
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Amplifiers {
+    pub power_shards: u32,
+    pub somersloops: u32,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct AmplifierUsage {
+    /// Amplifiers of all buildings of each recipe node, only for nodes that need any.
+    pub nodes: BTreeMap<NodeId, Amplifiers>,
+    pub total: Amplifiers,
+    /// Somersloops beyond the slots of a building, and amplifiers beyond the owned ones.
+    pub problems: Vec<String>,
+}
+
+/// Sums up the amplifiers needed for all recipe nodes in `graph`. If `owned` is given, a plan
+/// needing more than that is flagged in `problems`. Pack recipes are looked up in `data`.
+pub fn amplifier_usage(
+    graph: &Graph,
+    data: &GameData,
+    owned: Option<Amplifiers>,
+) -> AmplifierUsage {
+    let mut out = AmplifierUsage::default();
+    for (id, node) in graph.nodes.iter().enumerate() {
+        let Some(recipe) = node.recipe(data) else { continue };
+        let somersloops = recipe.somersloops;
+        let slots = recipe.building.map_or(0, |b| b.somersloop_slots());
+        if somersloops > slots {
+            out.problems.push(format!(
+                "node {id} ({}): {somersloops} Somersloops, but {} only has {slots} slots",
+                recipe.name,
+                recipe.building_name,
+            ));
+        }
+
+        let count = recipe.buildings_count.get();
+        let usage = Amplifiers {
+            power_shards: recipe.overclock.power_shards() * count,
+            somersloops: somersloops as u32 * count,
+        };
+        if usage != Amplifiers::default() {
+            out.total.power_shards += usage.power_shards;
+            out.total.somersloops += usage.somersloops;
+            out.nodes.insert(id as NodeId, usage);
+        }
+    }
+
+    if let Some(owned) = owned {
+        for (name, needed, owned) in [
+            ("power shards", out.total.power_shards, owned.power_shards),
+            ("Somersloops", out.total.somersloops, owned.somersloops),
+        ] {
+            if needed > owned {
+                out.problems.push(format!("needs {needed} {name}, but only {owned} are owned"));
+            }
+        }
+    }
+    out
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::{export::tests::example, state::{Node, Overclock}};
+    use super::*;
+
+    #[test]
+    fn power_shards() {
+        let shards = [0.5, 1.0, 1.0001, 1.5, 1.5001, 2.0, 2.5].map(|v| {
+            Overclock::try_from(v).unwrap().power_shards()
+        });
+        assert_eq!(shards, [0, 0, 1, 1, 2, 2, 3]);
+    }
+
+    #[test]
+    fn usage() {
+        let mut graph = example();
+        let data = GameData::builtin();
+        // Only the two smelters at 150% need one shard each.
+        let usage = amplifier_usage(&graph, &data, None);
+        let smelters = Amplifiers { power_shards: 2, somersloops: 0 };
+        assert_eq!(usage.nodes, BTreeMap::from([(1, smelters)]));
+
+        for node in &mut graph.nodes {
+            if let Node::Recipe { buildings_count, overclock, somersloops, .. } = node {
+                *buildings_count = 2.try_into().unwrap();
+                *overclock = Overclock(1_600_000);
+                *somersloops = 1;
+            }
+        }
+        let recipes = graph.nodes.iter()
+            .filter(|n| matches!(n, Node::Recipe { .. }))
+            .count() as u32;
+        let usage = amplifier_usage(&graph, &data, None);
+        assert_eq!(usage.nodes.len() as u32, recipes);
+        assert_eq!(usage.total, Amplifiers { power_shards: 4 * recipes, somersloops: 2 * recipes });
+        assert!(usage.problems.is_empty());
+
+        let owned = Amplifiers { power_shards: 4 * recipes, somersloops: 0 };
+        let usage = amplifier_usage(&graph, &data, Some(owned));
+        let problem = format!("needs {} Somersloops, but only 0 are owned", 2 * recipes);
+        assert_eq!(usage.problems, [problem]);
+
+        let Some(Node::Recipe { somersloops, .. }) = graph.nodes.iter_mut()
+            .find(|n| matches!(n, Node::Recipe { .. }))
+        else {
+            unreachable!()
+        };
+        *somersloops = 2;
+        let problems = amplifier_usage(&graph, &data, None).problems;
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].ends_with("2 Somersloops, but Smelter only has 1 slots"), "{problems:?}");
+    }
+}
@@ -0,0 +1,135 @@
+//! Bill of materials: everything needed to construct a plan.
+//!
+//! The plan does not store belt or pipe lengths, so their material cost cannot be known. Instead,
+//! connections are counted by the belt or pipe tier they need for their rate, as simulated by
+//! `flows`.
+
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+use crate::{
+    gamedata::{
+        BeltTier, Building, GameData, Ingredient, ItemKind, PipeTier, MERGER_COST,
+        PRIORITY_MERGER_COST, PROGRAMMABLE_SPLITTER_COST, SMART_SPLITTER_COST, SPLITTER_COST,
+    },
+    state::{Graph, Node},
+};
+use super::flows;
+
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct BillOfMaterials {
+    /// Number of production buildings.
+    pub buildings: BTreeMap<Building, u32>,
+    /// Number of buildings added by mods, by name. Their construction cost is unknown.
+    pub modded_buildings: BTreeMap<String, u32>,
+    pub mergers: u32,
+    pub splitters: u32,
+    pub priority_mergers: u32,
+    /// Smart splitters with at most one filter per output. Others need a programmable splitter.
+    pub smart_splitters: u32,
+    pub programmable_splitters: u32,
+    /// Number of solid connections per belt tier needed for their rate.
+    pub belts: BTreeMap<BeltTier, u32>,
+    /// Number of fluid connections per pipe tier needed for their rate.
+    pub pipes: BTreeMap<PipeTier, u32>,
+    /// Connections whose item is unknown or whose rate is too high for any single belt or pipe.
+    pub unsized_connections: u32,
+    /// Total construction items for all buildings, mergers and splitters.
+    pub items: BTreeMap<ItemKind, u32>,
+}
+
+/// Sums up the construction costs for all nodes in `graph`, with pack recipes looked up in `data`.
+pub fn bill_of_materials(graph: &Graph, data: &GameData) -> BillOfMaterials {
+    let mut out = BillOfMaterials::default();
+    let add = |cost: &[Ingredient], count: u32, items: &mut BTreeMap<ItemKind, u32>| {
+        for ingredient in cost {
+            *items.entry(ingredient.item).or_default() += ingredient.amount * count;
+        }
+    };
+
+    for node in &graph.nodes {
+        match node {
+            Node::Recipe { .. } | Node::PackRecipe { .. } => {
+                let Some(recipe) = node.recipe(data) else { continue };
+                let count = recipe.buildings_count.get();
+                match recipe.building {
+                    Some(building) => {
+                        *out.buildings.entry(building).or_default() += count;
+                        add(building.build_cost(), count, &mut out.items);
+                    }
+                    None => {
+                        let name = recipe.building_name.to_owned();
+                        *out.modded_buildings.entry(name).or_default() += count;
+                    }
+                }
+            }
+            Node::Merger { .. } => {
+                out.mergers += 1;
+                add(MERGER_COST, 1, &mut out.items);
+            }
+            Node::Splitter { .. } => {
+                out.splitters += 1;
+                add(SPLITTER_COST, 1, &mut out.items);
+            }
+            Node::PriorityMerger { .. } => {
+                out.priority_mergers += 1;
+                add(PRIORITY_MERGER_COST, 1, &mut out.items);
+            }
+            Node::SmartSplitter { filters, .. } if filters.iter().all(|f| f.len() <= 1) => {
+                out.smart_splitters += 1;
+                add(SMART_SPLITTER_COST, 1, &mut out.items);
+            }
+            Node::SmartSplitter { .. } => {
+                out.programmable_splitters += 1;
+                add(PROGRAMMABLE_SPLITTER_COST, 1, &mut out.items);
+            }
+            Node::Source { .. } => {}
+        }
+    }
+
+    let rates = flows(graph, data).edges;
+    for (edge, &rate) in graph.edges.iter().zip(&rates) {
+        let fluid = graph.edge_item(edge, data).map(|item| item.info().form.is_fluid());
+        match fluid {
+            Some(false) if BeltTier::for_rate(rate).is_some() => {
+                *out.belts.entry(BeltTier::for_rate(rate).unwrap()).or_default() += 1;
+            }
+            Some(true) if PipeTier::for_rate(rate).is_some() => {
+                *out.pipes.entry(PipeTier::for_rate(rate).unwrap()).or_default() += 1;
+            }
+            _ => out.unsized_connections += 1,
+        }
+    }
+
+    out
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::export::tests::example;
+    use super::*;
+
+    #[test]
+    fn example_plan() {
+        let bom = bill_of_materials(&example(), &GameData::builtin());
+        assert_eq!(bom.buildings[&Building::Smelter], 2);
+        assert_eq!(bom.buildings[&Building::Constructor], 5);
+        assert_eq!(bom.splitters, 1);
+        assert_eq!(bom.mergers, 0);
+
+        // 2 smelters, 5 constructors, 1 splitter
+        assert_eq!(bom.items[&ItemKind::IronRod], 10);
+        assert_eq!(bom.items[&ItemKind::Wire], 16);
+        assert_eq!(bom.items[&ItemKind::ReinforcedIronPlate], 10);
+        assert_eq!(bom.items[&ItemKind::Cable], 40 + 2);
+        assert_eq!(bom.items[&ItemKind::IronPlate], 2);
+
+        // Ore and ingots with 90/min need Mk2, the splitter outputs get 60/min and 30/min.
+        assert_eq!(bom.belts[&BeltTier::Mk2], 2);
+        assert_eq!(bom.belts[&BeltTier::Mk1], 2);
+        assert_eq!(bom.unsized_connections, 0);
+    }
+}
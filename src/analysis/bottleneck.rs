@@ -0,0 +1,391 @@
+//! What keeps the end products of a plan from being produced faster.
+//!
+//! Starting from the steady-state flows, these changes are tried on a copy of the plan:
+//!
+//! - raising a source that does not satisfy the demand,
+//! - adding one building to a recipe node running at full speed,
+//! - upgrading a belt or pipe running at capacity, or adding a second one at the highest tier,
+//! - replacing a splitter that starves one of its outputs with a smart splitter that serves that
+//!   output first and sends the rest to the others as overflow.
+//!
+//! Often a change only helps together with others, e.g. another building also needs more input.
+//! So if a change alone unlocks nothing, sources and connections that now limit are raised as
+//! well, for a few rounds. Every set of changes that increases the rate of an end product, i.e. a
+//! recipe output that is not connected to anything, is returned as suggestion.
+
+use std::num::NonZeroU32;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    export::fmt_number,
+    gamedata::{BeltTier, GameData, ItemKind, PipeTier},
+    state::{Edge, Graph, HandleId, Node, NodeId, SplitFilter, SplitRule},
+};
+use super::{flows_with_capacity, Flows};
+
+
+const EPSILON: f64 = 1e-6;
+
+/// How often sources and connections are raised after a change that alone unlocks nothing.
+const FOLLOW_UP_ROUNDS: usize = 3;
+
+/// Belt and pipe tier all connections of a plan are built with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ConveyorTiers {
+    pub belt: BeltTier,
+    pub pipe: PipeTier,
+}
+
+impl Default for ConveyorTiers {
+    fn default() -> Self {
+        Self { belt: BeltTier::Mk6, pipe: PipeTier::Mk2 }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Bottlenecks {
+    pub products: Vec<EndProduct>,
+    /// Changes unlocking more output, the largest total gain first.
+    pub suggestions: Vec<Suggestion>,
+    /// Whether the flow simulation of the plan settled. If not, all rates are approximations.
+    pub converged: bool,
+}
+
+/// An unconnected recipe output.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct EndProduct {
+    pub node: NodeId,
+    pub handle: HandleId,
+    /// `None` for items only known to data packs.
+    pub item: Option<ItemKind>,
+    pub name: String,
+    /// Items or m³ per minute currently produced.
+    pub rate: f64,
+    /// Index of the suggestion unlocking the most output of this product, `None` if there is none.
+    pub limited_by: Option<usize>,
+}
+
+/// A single change to a plan.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum Change {
+    SourceRate { node: NodeId, rate: u32 },
+    BuildingsCount { node: NodeId, count: u32 },
+    Belt { edge: usize, tier: BeltTier, count: u32 },
+    Pipe { edge: usize, tier: PipeTier, count: u32 },
+    SmartSplitter { node: NodeId },
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Suggestion {
+    /// The change that was tried, followed by the changes it needs to have any effect.
+    pub changes: Vec<Change>,
+    /// Change of the rate of each end product that is affected, by index into
+    /// `Bottlenecks::products`. Can be negative if other products lose some of their input.
+    pub gains: Vec<(usize, f64)>,
+    /// E.g. "add 1 Constructor at node 12 → +15/min Modular Frame".
+    pub description: String,
+}
+
+/// Finds the limiting factors of the end products of `graph`, assuming all connections are built
+/// with `tiers`. Pack recipes are looked up in `data`.
+pub fn bottlenecks(graph: &Graph, data: &GameData, tiers: ConveyorTiers) -> Bottlenecks {
+    let capacity = graph.edges.iter()
+        .map(|e| edge_capacity(graph, data, e, tiers, 1))
+        .collect::<Vec<_>>();
+    let current = flows_with_capacity(graph, data, &capacity);
+    let mut products = end_products(graph, data, &current);
+    let rates = |graph: &Graph, capacity: &[f64]| {
+        end_products(graph, data, &flows_with_capacity(graph, data, capacity))
+            .iter()
+            .zip(&products)
+            .map(|(after, before)| after.rate - before.rate)
+            .collect::<Vec<_>>()
+    };
+
+    let mut suggestions = Vec::<Suggestion>::new();
+    for change in candidates(graph, data, &current, &capacity, tiers, false) {
+        let mut changed = graph.clone();
+        let mut changed_capacity = capacity.clone();
+        apply(&mut changed, data, &mut changed_capacity, &change, tiers);
+        let mut changes = vec![change];
+        let mut diff = rates(&changed, &changed_capacity);
+
+        for _ in 0..FOLLOW_UP_ROUNDS {
+            if diff.iter().any(|&d| d > EPSILON) {
+                break;
+            }
+            let flows = flows_with_capacity(&changed, data, &changed_capacity);
+            let follow_ups = candidates(&changed, data, &flows, &changed_capacity, tiers, true);
+            if follow_ups.is_empty() {
+                break;
+            }
+            for change in follow_ups {
+                apply(&mut changed, data, &mut changed_capacity, &change, tiers);
+                changes.push(change);
+            }
+            diff = rates(&changed, &changed_capacity);
+        }
+
+        if !diff.iter().any(|&d| d > EPSILON)
+            || suggestions.iter().any(|s| same_changes(&s.changes, &changes))
+        {
+            continue;
+        }
+        let gains = diff.into_iter()
+            .enumerate()
+            .filter(|(_, d)| d.abs() > EPSILON)
+            .collect::<Vec<_>>();
+        let description = format!(
+            "{} → {}",
+            changes.iter().map(|c| describe(graph, data, c, tiers)).collect::<Vec<_>>().join(", "),
+            gains.iter()
+                .map(|&(i, gain)| {
+                    let sign = if gain > 0.0 { "+" } else { "" };
+                    format!("{sign}{}/min {}", fmt_number(gain), products[i].name)
+                })
+                .collect::<Vec<_>>()
+                .join(", "),
+        );
+        suggestions.push(Suggestion { changes, gains, description });
+    }
+
+    let total = |s: &Suggestion| s.gains.iter().map(|(_, gain)| gain).sum::<f64>();
+    suggestions.sort_by(|a, b| total(b).total_cmp(&total(a)));
+    for (i, product) in products.iter_mut().enumerate() {
+        product.limited_by = suggestions.iter()
+            .enumerate()
+            .filter_map(|(s, suggestion)| {
+                suggestion.gains.iter().find(|(p, _)| *p == i).map(|&(_, gain)| (s, gain))
+            })
+            .filter(|&(_, gain)| gain > 0.0)
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(s, _)| s);
+    }
+
+    Bottlenecks { products, suggestions, converged: current.converged }
+}
+
+/// Rate `count` connections of the tier in `tiers` can carry along `edge`. Edges with unknown
+/// items are assumed to be belts.
+fn edge_capacity(
+    graph: &Graph,
+    data: &GameData,
+    edge: &Edge,
+    tiers: ConveyorTiers,
+    count: u32,
+) -> f64 {
+    let fluid = graph.edge_item(edge, data).is_some_and(|item| item.info().form.is_fluid());
+    let rate = if fluid { tiers.pipe.max_rate() } else { tiers.belt.max_rate() };
+    rate * count as f64
+}
+
+fn end_products(graph: &Graph, data: &GameData, flows: &Flows) -> Vec<EndProduct> {
+    let mut out = vec![];
+    for (id, node) in graph.nodes.iter().enumerate() {
+        let Some(recipe) = node.recipe(data) else { continue };
+        let multiplier = recipe.crafts_per_minute_total() * recipe.amplification();
+        for (handle, output) in (4..).zip(recipe.outputs.iter()) {
+            let connected = graph.edges.iter()
+                .any(|e| e.source.node == id as NodeId && e.source.handle == handle);
+            if !connected {
+                out.push(EndProduct {
+                    node: id as NodeId,
+                    handle,
+                    item: output.item,
+                    name: output.name.to_owned(),
+                    rate: flows.utilization[id].unwrap_or(0.0) * output.amount * multiplier,
+                    limited_by: None,
+                });
+            }
+        }
+    }
+    out
+}
+
+/// Changes worth trying. With `supply_only`, only sources and connections are raised.
+fn candidates(
+    graph: &Graph,
+    data: &GameData,
+    flows: &Flows,
+    capacity: &[f64],
+    tiers: ConveyorTiers,
+    supply_only: bool,
+) -> Vec<Change> {
+    let outgoing = |id: usize| graph.edges.iter()
+        .enumerate()
+        .filter(move |(_, e)| e.source.node == id as NodeId)
+        .map(|(i, _)| i);
+    let starved = |e: usize| flows.edges[e] < flows.accept[e] - EPSILON;
+
+    let mut out = vec![];
+    for (id, node) in graph.nodes.iter().enumerate() {
+        let node_id = id as NodeId;
+        match node {
+            // The demand of the targets is what they accept beyond what they get.
+            Node::Source { rate, .. } => {
+                let missing = outgoing(id).map(|e| flows.accept[e] - flows.edges[e]).sum::<f64>();
+                if missing > EPSILON && missing.is_finite() {
+                    let rate = (*rate as f64 + missing - EPSILON).ceil() as u32;
+                    out.push(Change::SourceRate { node: node_id, rate });
+                }
+            }
+            // Only recipes running at full speed gain from more buildings.
+            Node::Recipe { buildings_count, .. } | Node::PackRecipe { buildings_count, .. }
+                if !supply_only && flows.utilization[id].is_some_and(|u| u > 1.0 - EPSILON) =>
+            {
+                let count = buildings_count.get() + 1;
+                out.push(Change::BuildingsCount { node: node_id, count });
+            }
+            Node::Splitter { .. }
+                if !supply_only && outgoing(id).count() > 1 && outgoing(id).any(starved) =>
+            {
+                out.push(Change::SmartSplitter { node: node_id });
+            }
+            _ => {}
+        }
+    }
+
+    for (i, edge) in graph.edges.iter().enumerate() {
+        if flows.edges[i] < capacity[i] - EPSILON {
+            continue;
+        }
+        let fluid = graph.edge_item(edge, data).is_some_and(|item| item.info().form.is_fluid());
+        let count = (capacity[i] / edge_capacity(graph, data, edge, tiers, 1)).round() as u32;
+        out.push(if fluid {
+            match PipeTier::ALL.into_iter().find(|&t| t > tiers.pipe && count == 1) {
+                Some(tier) => Change::Pipe { edge: i, tier, count },
+                None => Change::Pipe { edge: i, tier: tiers.pipe, count: count + 1 },
+            }
+        } else {
+            match BeltTier::ALL.into_iter().find(|&t| t > tiers.belt && count == 1) {
+                Some(tier) => Change::Belt { edge: i, tier, count },
+                None => Change::Belt { edge: i, tier: tiers.belt, count: count + 1 },
+            }
+        });
+    }
+    out
+}
+
+fn apply(
+    graph: &mut Graph,
+    data: &GameData,
+    capacity: &mut [f64],
+    change: &Change,
+    tiers: ConveyorTiers,
+) {
+    match *change {
+        Change::SourceRate { node, rate: new_rate } => {
+            if let Node::Source { rate, .. } = &mut graph.nodes[node as usize] {
+                *rate = new_rate;
+            }
+        }
+        Change::BuildingsCount { node, count } => {
+            if let Some((buildings_count, _)) = graph.nodes[node as usize].clock_mut() {
+                *buildings_count = NonZeroU32::new(count).unwrap();
+            }
+        }
+        Change::Belt { edge, tier, count } => {
+            let tiers = ConveyorTiers { belt: tier, ..tiers };
+            capacity[edge] = edge_capacity(graph, data, &graph.edges[edge], tiers, count);
+        }
+        Change::Pipe { edge, tier, count } => {
+            let tiers = ConveyorTiers { pipe: tier, ..tiers };
+            capacity[edge] = edge_capacity(graph, data, &graph.edges[edge], tiers, count);
+        }
+        Change::SmartSplitter { node } => {
+            // Starved outputs first, the others only get what is left.
+            let current = flows_with_capacity(graph, data, capacity);
+            let mut filters: [Vec<SplitFilter>; 3] = Default::default();
+            for (i, e) in graph.edges.iter().enumerate() {
+                if e.source.node == node {
+                    let starved = current.edges[i] < current.accept[i] - EPSILON;
+                    let rule = if starved { SplitRule::Any } else { SplitRule::Overflow };
+                    filters[e.source.handle as usize - 1] = vec![SplitFilter::Rule(rule)];
+                }
+            }
+            let pos = *graph.nodes[node as usize].pos();
+            graph.nodes[node as usize] = Node::SmartSplitter { pos, filters };
+        }
+    }
+}
+
+fn describe(graph: &Graph, data: &GameData, change: &Change, tiers: ConveyorTiers) -> String {
+    match *change {
+        Change::SourceRate { node, rate } => format!("raise source at node {node} to {rate}/min"),
+        Change::BuildingsCount { node, .. } => {
+            let recipe = graph.node(node).recipe(data);
+            let building = recipe.as_ref().map_or("building", |r| r.building_name);
+            format!("add 1 {building} at node {node}")
+        }
+        Change::Belt { edge, tier, count: 1 } if tier != tiers.belt => {
+            format!("upgrade edge {edge} to {tier:?} belt")
+        }
+        Change::Pipe { edge, tier, count: 1 } if tier != tiers.pipe => {
+            format!("upgrade edge {edge} to {tier:?} pipe")
+        }
+        Change::Belt { edge, tier, count } => format!("use {count} {tier:?} belts for edge {edge}"),
+        Change::Pipe { edge, tier, count } => format!("use {count} {tier:?} pipes for edge {edge}"),
+        Change::SmartSplitter { node } => {
+            format!("replace splitter at node {node} with a smart splitter using overflow")
+        }
+    }
+}
+
+/// Whether `a` and `b` contain the same changes, in any order.
+fn same_changes(a: &[Change], b: &[Change]) -> bool {
+    a.len() == b.len() && a.iter().all(|c| b.contains(c))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::export::tests::example;
+    use super::*;
+
+    fn suggestion<'a>(result: &'a Bottlenecks, first: &Change) -> &'a Suggestion {
+        result.suggestions.iter()
+            .find(|s| s.changes[0] == *first)
+            .unwrap_or_else(|| panic!("{first:?} not in {:#?}", result.suggestions))
+    }
+
+    #[test]
+    fn example_plan() {
+        // The smelters run at full speed on all 90 ore. Plates only get 60 of the 90 ingots they
+        // could take, rods get all 30 they need.
+        let result = bottlenecks(&example(), &GameData::builtin(), ConveyorTiers::default());
+        assert!(result.converged);
+        assert_eq!(result.products.len(), 2);
+        assert!(result.products.iter().all(|p| p.limited_by.is_some()));
+
+        // Another smelter only helps with more ore, as much as plates and rods can take.
+        let smelter = suggestion(&result, &Change::BuildingsCount { node: 1, count: 3 });
+        assert_eq!(smelter.changes[1..], [Change::SourceRate { node: 0, rate: 120 }]);
+        assert_eq!(
+            smelter.description,
+            "add 1 Smelter at node 1, raise source at node 0 to 120/min → +20/min Iron Plate",
+        );
+
+        // Another rod constructor takes ingots away from the plates.
+        let rods = result.suggestions.iter()
+            .find(|s| s.changes == [Change::BuildingsCount { node: 4, count: 3 }])
+            .unwrap();
+        assert!(rods.gains.iter().any(|&(_, gain)| gain < 0.0), "{:?}", rods.gains);
+    }
+
+    #[test]
+    fn belt_capacity() {
+        // Only 60 ore and ingots per minute fit on Mk1 belts, so plates get 30 ingots.
+        let tiers = ConveyorTiers { belt: BeltTier::Mk1, pipe: PipeTier::Mk1 };
+        let result = bottlenecks(&example(), &GameData::builtin(), tiers);
+        let rates = result.products.iter().map(|p| p.rate).collect::<Vec<_>>();
+        assert_eq!(rates, [20.0, 30.0]);
+
+        let belt = suggestion(&result, &Change::Belt { edge: 0, tier: BeltTier::Mk2, count: 1 });
+        assert_eq!(
+            belt.description,
+            "upgrade edge 0 to Mk2 belt, upgrade edge 1 to Mk2 belt → +20/min Iron Plate",
+        );
+    }
+}
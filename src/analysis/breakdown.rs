@@ -0,0 +1,271 @@
+//! Expands the recipe tree of an item down to raw resources.
+//!
+//! For each intermediate item, the recipe used to produce it is taken from a `RecipeSelection`.
+//! Costs are always calculated for producing 1 item/min (or 1 m³/min for fluids) of the target.
+//! Byproducts of multi-output recipes are not credited against anything; the full cost of a recipe
+//! is attributed to the item that caused it to be used, and byproducts are reported separately.
+
+use std::{collections::BTreeMap, fmt};
+
+use serde::{Deserialize, Serialize};
+
+use crate::gamedata::{Building, IoEntry, ItemKind, RecipeFilter, RecipeKind, SourceItemKind};
+
+
+/// Which recipe to use to produce a specific item. Items not explicitly configured use a default
+/// recipe, see `RecipeSelection::default_for`.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct RecipeSelection {
+    overrides: BTreeMap<ItemKind, RecipeKind>,
+    #[serde(skip)]
+    filter: RecipeFilter,
+}
+
+impl RecipeSelection {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Use `recipe` for producing `item`. The recipe should have `item` as one of its outputs.
+    pub fn set(&mut self, item: ItemKind, recipe: RecipeKind) {
+        self.overrides.insert(item, recipe);
+    }
+
+    /// Like `set`, but by value for chaining.
+    pub fn with(mut self, item: ItemKind, recipe: RecipeKind) -> Self {
+        self.set(item, recipe);
+        self
+    }
+
+    /// Only choose default recipes that are allowed by `filter`. Explicitly set recipes are not
+    /// affected.
+    pub fn restrict(mut self, filter: RecipeFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    pub fn filter(&self) -> &RecipeFilter {
+        &self.filter
+    }
+
+    /// Returns the recipe to produce `item` with, or `None` if there is no such recipe.
+    pub fn recipe_for(&self, item: ItemKind) -> Option<RecipeKind> {
+        self.overrides.get(&item).copied().or_else(|| self.default_for(item))
+    }
+
+    /// The recipe used for `item` if nothing else is configured.
+    ///
+    /// This is the first standard recipe that has `item` as its main output, falling back to
+    /// alternates if no standard recipe exists. Converter recipes and unpackaging recipes are never
+    /// chosen, as those are almost never what you want when planning from raw resources. Recipes
+    /// not allowed by the filter are skipped.
+    pub fn default_for(&self, item: ItemKind) -> Option<RecipeKind> {
+        let candidates = || item.produced_by().iter().copied().filter(|&r| {
+            let info = r.info();
+            let unpackage = info.building == Building::Packager && item.info().form.is_fluid();
+            info.outputs[0].item == item
+                && info.building != Building::Converter
+                && !unpackage
+                && self.filter.allows(r)
+        });
+
+        candidates().find(|r| !r.info().alternative).or_else(|| candidates().next())
+    }
+}
+
+/// Result of `breakdown`: what's needed to produce 1 unit/min of an item.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Breakdown {
+    /// Raw resources per minute.
+    pub raw: BTreeMap<SourceItemKind, f64>,
+    /// Items per minute that are neither raw resources nor producible with the given selection
+    /// (e.g. nitrogen gas, which comes from resource wells).
+    pub unresolved: BTreeMap<ItemKind, f64>,
+    /// Byproducts per minute that are produced along the way.
+    pub byproducts: BTreeMap<ItemKind, f64>,
+    /// Number of buildings (at 100% clock speed) per recipe.
+    pub recipes: BTreeMap<RecipeKind, f64>,
+    /// Total number of buildings at 100% clock speed, summed over all recipes.
+    pub buildings: f64,
+    /// Average power consumption in MW.
+    pub power: f64,
+}
+
+impl Breakdown {
+    /// Total raw resources per minute, summed over all kinds.
+    pub fn total_raw(&self) -> f64 {
+        self.raw.values().sum()
+    }
+
+    /// Total raw solid resources in items/min, summed over all kinds.
+    pub fn total_solids(&self) -> f64 {
+        self.raw.iter()
+            .filter(|(&source, _)| !ItemKind::from(source).info().form.is_fluid())
+            .fold(0.0, |total, (_, amount)| total + amount)
+    }
+
+    /// Total raw fluids in m³/min, summed over all kinds. Kept apart from solids, as the units
+    /// can't be compared: fluids come in much larger amounts, mostly of plentiful water.
+    pub fn total_fluids(&self) -> f64 {
+        self.raw.iter()
+            .filter(|(&source, _)| ItemKind::from(source).info().form.is_fluid())
+            .fold(0.0, |total, (_, amount)| total + amount)
+    }
+}
+
+/// Recipe selection leads to a cycle, e.g. packaging and unpackaging the same fluid. Contains the
+/// items along the cycle, starting and ending with the same item.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle(pub Vec<ItemKind>);
+
+impl fmt::Display for Cycle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "recipe cycle: ")?;
+        for (i, item) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, " → ")?;
+            }
+            write!(f, "{}", item.info().name)?;
+        }
+        Ok(())
+    }
+}
+
+/// Calculates the raw resource cost, building count and power for producing 1 unit/min of `item`,
+/// expanding the recipe tree according to `selection`.
+pub fn breakdown(item: ItemKind, selection: &RecipeSelection) -> Result<Breakdown, Cycle> {
+    let mut out = Breakdown::default();
+    let mut stack = Vec::new();
+    expand(item, 1.0, selection, &mut stack, &mut out)?;
+    Ok(out)
+}
+
+fn expand(
+    item: ItemKind,
+    rate: f64,
+    selection: &RecipeSelection,
+    stack: &mut Vec<ItemKind>,
+    out: &mut Breakdown,
+) -> Result<(), Cycle> {
+    if let Ok(source) = SourceItemKind::try_from(item) {
+        *out.raw.entry(source).or_default() += rate;
+        return Ok(());
+    }
+
+    let Some(recipe) = selection.recipe_for(item) else {
+        *out.unresolved.entry(item).or_default() += rate;
+        return Ok(());
+    };
+
+    if let Some(pos) = stack.iter().position(|&i| i == item) {
+        let mut cycle = stack[pos..].to_vec();
+        cycle.push(item);
+        return Err(Cycle(cycle));
+    }
+
+    // Some recipes consume a bit of their own output (e.g. catalysts), so we only consider the
+    // net amount produced per craft.
+    let info = recipe.info();
+    let amount_of = |entries: &[IoEntry]| entries.iter()
+        .filter(|e| e.item == item)
+        .map(|e| e.amount)
+        .sum::<f64>();
+    let net_per_craft = amount_of(info.outputs) - amount_of(info.inputs);
+    if net_per_craft <= 0.0 {
+        *out.unresolved.entry(item).or_default() += rate;
+        return Ok(());
+    }
+
+    let crafts = rate / net_per_craft;
+    let buildings = crafts / info.crafts_per_minute();
+    *out.recipes.entry(recipe).or_default() += buildings;
+    out.buildings += buildings;
+    out.power += buildings * info.average_power();
+
+    for output in info.outputs.iter().filter(|o| o.item != item) {
+        *out.byproducts.entry(output.item).or_default() += crafts * output.amount;
+    }
+
+    stack.push(item);
+    for input in info.inputs.iter().filter(|i| i.item != item) {
+        expand(input.item, crafts * input.amount, selection, stack, out)?;
+    }
+    stack.pop();
+
+    Ok(())
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    #[test]
+    fn reinforced_iron_plate() {
+        let b = breakdown(ItemKind::ReinforcedIronPlate, &RecipeSelection::new()).unwrap();
+
+        // 1 RIP/min needs 6 plates + 12 screws. Plates: 6 * 3/2 = 9 ingots. Screws: 12 / 4 = 3
+        // rods = 3 ingots. So 12 ingots and thus 12 iron ore.
+        assert_eq!(b.raw.len(), 1);
+        assert!(approx(b.raw[&SourceItemKind::IronOre], 12.0));
+        assert!(b.unresolved.is_empty());
+        assert!(b.byproducts.is_empty());
+
+        // 5 RIP/min per assembler, 20 plates/min per constructor, 40 screws/min, 15 rods/min and
+        // 30 ingots/min per smelter.
+        assert!(approx(b.recipes[&RecipeKind::ReinforcedIronPlate], 1.0 / 5.0));
+        assert!(approx(b.recipes[&RecipeKind::IronPlate], 6.0 / 20.0));
+        assert!(approx(b.recipes[&RecipeKind::Screw], 12.0 / 40.0));
+        assert!(approx(b.recipes[&RecipeKind::IronRod], 3.0 / 15.0));
+        assert!(approx(b.recipes[&RecipeKind::IronIngot], 12.0 / 30.0));
+        assert!(approx(b.power, 0.2 * 15.0 + (0.3 + 0.3 + 0.2) * 4.0 + 0.4 * 4.0));
+    }
+
+    #[test]
+    fn selection_overrides_default() {
+        let selection = RecipeSelection::new()
+            .with(ItemKind::IronIngot, RecipeKind::PureIronIngot);
+        let b = breakdown(ItemKind::IronIngot, &selection).unwrap();
+
+        // Pure Iron Ingot: 35 ore + 20 water -> 65 ingots
+        assert!(approx(b.raw[&SourceItemKind::IronOre], 35.0 / 65.0));
+        assert!(approx(b.raw[&SourceItemKind::Water], 20.0 / 65.0));
+        assert_eq!(b.recipes.keys().copied().collect::<Vec<_>>(), [RecipeKind::PureIronIngot]);
+    }
+
+    #[test]
+    fn byproducts_and_unresolved() {
+        let b = breakdown(ItemKind::Plastic, &RecipeSelection::new()).unwrap();
+        assert!(b.byproducts.contains_key(&ItemKind::HeavyOilResidue));
+        assert!(b.raw.contains_key(&SourceItemKind::CrudeOil));
+
+        let b = breakdown(ItemKind::NitrogenGas, &RecipeSelection::new()).unwrap();
+        assert!(approx(b.unresolved[&ItemKind::NitrogenGas], 1.0));
+    }
+
+    #[test]
+    fn filter_restricts_defaults() {
+        // Compacted coal only has an alternate recipe, which is a MAM research.
+        let selection = RecipeSelection::new();
+        assert_eq!(selection.recipe_for(ItemKind::CompactedCoal), Some(RecipeKind::CompactedCoal));
+
+        let filter = RecipeFilter { mam_trees: Some(Default::default()), ..Default::default() };
+        let selection = selection.restrict(filter);
+        assert_eq!(selection.recipe_for(ItemKind::CompactedCoal), None);
+    }
+
+    #[test]
+    fn detects_cycles() {
+        let selection = RecipeSelection::new()
+            .with(ItemKind::RocketFuel, RecipeKind::UnpackageRocketFuel);
+        let err = breakdown(ItemKind::RocketFuel, &selection).unwrap_err();
+        assert_eq!(err.0.first(), Some(&ItemKind::RocketFuel));
+        assert_eq!(err.0.last(), Some(&ItemKind::RocketFuel));
+        assert!(err.0.contains(&ItemKind::PackagedRocketFuel));
+    }
+}
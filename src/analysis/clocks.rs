@@ -0,0 +1,219 @@
+//! Clock speeds for hitting an exact output rate with as few buildings as possible.
+//!
+//! All calculations are done in `Overclock` units (1_000_000 × the clock), so every clock we
+//! return can be typed into the game as is.
+
+use std::num::NonZeroU32;
+
+use serde::Serialize;
+
+use crate::{
+    gamedata::{ItemKind, RecipeKind},
+    state::{Graph, Node, Overclock},
+};
+
+
+pub(super) const MIN_CLOCK: u32 = 10_000;
+const FULL_CLOCK: u32 = 1_000_000;
+const MAX_CLOCK: u32 = 2_500_000;
+
+/// A number of buildings, all running at the same clock speed.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ClockGroup {
+    pub count: u32,
+    pub overclock: Overclock,
+}
+
+/// One way to reach a target rate.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ClockOption {
+    pub groups: Vec<ClockGroup>,
+    /// Rate actually produced, which may be slightly above the target due to the limited
+    /// precision of clock speeds.
+    pub rate: f64,
+}
+
+impl ClockOption {
+    pub fn buildings(&self) -> u32 {
+        self.groups.iter().map(|g| g.count).sum()
+    }
+
+    /// Whether no building is overclocked, i.e. no power shards are needed.
+    pub fn is_underclocked(&self) -> bool {
+        self.groups.iter().all(|g| g.overclock.0 <= FULL_CLOCK)
+    }
+}
+
+/// Returns ways to produce `rate` of `item` per minute with `recipe`, fewest buildings first:
+///
+/// - all buildings at the same clock, with as few buildings as possible,
+/// - as many buildings as possible at 250% plus one at a lower clock,
+/// - the same two without overclocking.
+///
+/// Clocks are rounded up, so the rate is never below the target.
+pub fn clock_options(
+    recipe: RecipeKind,
+    item: ItemKind,
+    rate: f64,
+) -> Result<Vec<ClockOption>, String> {
+    let info = recipe.info();
+    let output = info.outputs.iter()
+        .find(|o| o.item == item)
+        .ok_or_else(|| format!("recipe '{}' does not produce '{}'", info.name, item.info().name))?;
+    if !(rate > 0.0 && rate.is_finite()) {
+        return Err(format!("invalid rate {rate}"));
+    }
+
+    let full_rate = output.amount * info.crafts_per_minute();
+    let total = total_clock(rate / full_rate);
+    let to_option = |groups: Vec<ClockGroup>| {
+        let units = groups.iter().map(|g| g.count as f64 * f64::from(g.overclock)).sum::<f64>();
+        ClockOption { groups, rate: units * full_rate }
+    };
+
+    let mut options = [MAX_CLOCK, FULL_CLOCK].into_iter()
+        .flat_map(|max| [uniform(total, max).map(|g| vec![g]), with_remainder(total, max)])
+        .flatten()
+        .map(to_option)
+        .collect::<Vec<_>>();
+    options.sort_by_key(|o| (o.buildings(), o.groups.len()));
+    options.dedup();
+    Ok(options)
+}
+
+/// Total clock in `Overclock` units needed for `clock` times the rate of one building at 100%.
+pub(super) fn total_clock(clock: f64) -> u64 {
+    let units = clock * FULL_CLOCK as f64;
+    // Avoid rounding up values like 2.0000000000000004 due to float inaccuracies.
+    if (units - units.round()).abs() < 1e-6 {
+        units.round() as u64
+    } else {
+        units.ceil() as u64
+    }
+}
+
+/// As few buildings as possible, all at the same clock of at most `max`.
+pub(super) fn uniform(total: u64, max: u32) -> Option<ClockGroup> {
+    let count = total.div_ceil(max as u64);
+    let clock = total.div_ceil(count).max(MIN_CLOCK as u64);
+    Some(ClockGroup { count: count.try_into().ok()?, overclock: Overclock(clock as u32) })
+}
+
+/// As many buildings as possible at `max` and one building for the rest. Not possible if the rest
+/// is below the minimum clock.
+fn with_remainder(total: u64, max: u32) -> Option<Vec<ClockGroup>> {
+    let full = (total / max as u64).try_into().ok()?;
+    let rest = (total % max as u64) as u32;
+    let mut groups = vec![];
+    if full > 0 {
+        groups.push(ClockGroup { count: full, overclock: Overclock(max) });
+    }
+    match rest {
+        0 => {}
+        MIN_CLOCK.. => groups.push(ClockGroup { count: 1, overclock: Overclock(rest) }),
+        _ => return None,
+    }
+    Some(groups)
+}
+
+/// Rewrites every recipe node to the fewest buildings running at a uniform clock of at most `max`
+/// while keeping its output rate. Returns a message for each node whose rate changed due to
+/// rounding.
+pub fn normalize_clocks(graph: &mut Graph, max: Overclock) -> Vec<String> {
+    let mut warnings = vec![];
+    for (id, node) in graph.nodes.iter_mut().enumerate() {
+        let Node::Recipe { recipe, buildings_count, overclock, .. } = node else { continue };
+        let total = buildings_count.get() as u64 * overclock.0 as u64;
+        let Some(group) = uniform(total, max.0.max(MIN_CLOCK)) else { continue };
+        let new_total = group.count as u64 * group.overclock.0 as u64;
+        if new_total != total {
+            warnings.push(format!(
+                "node {id} ({}): rate changed by {:+.4}%",
+                recipe.info().name,
+                (new_total as f64 / total as f64 - 1.0) * 100.0,
+            ));
+        }
+        *buildings_count = NonZeroU32::new(group.count).unwrap();
+        *overclock = group.overclock;
+    }
+    warnings
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn clocks(option: &ClockOption) -> Vec<(u32, f64)> {
+        option.groups.iter().map(|g| (g.count, f64::from(g.overclock))).collect()
+    }
+
+    #[test]
+    fn options() {
+        // One smelter makes 30 iron ingots/min at 100%.
+        let options = clock_options(RecipeKind::IronIngot, ItemKind::IronIngot, 100.0).unwrap();
+        assert_eq!(options.iter().map(clocks).collect::<Vec<_>>(), [
+            vec![(2, 1.666667)],
+            vec![(1, 2.5), (1, 0.833334)],
+            vec![(4, 0.833334)],
+            vec![(3, 1.0), (1, 0.333334)],
+        ]);
+        assert!(options.iter().all(|o| o.rate >= 100.0 && o.rate < 100.001));
+        assert!(!options[1].is_underclocked());
+        assert!(options[2].is_underclocked());
+
+        // Exact multiples give a single group.
+        let options = clock_options(RecipeKind::IronIngot, ItemKind::IronIngot, 60.0).unwrap();
+        assert_eq!(options.iter().map(clocks).collect::<Vec<_>>(), [
+            vec![(1, 2.0)],
+            vec![(2, 1.0)],
+        ]);
+        assert_eq!(options[0].rate, 60.0);
+    }
+
+    #[test]
+    fn options_errors() {
+        let plate = clock_options(RecipeKind::IronIngot, ItemKind::IronPlate, 10.0);
+        assert_eq!(plate, Err("recipe 'Iron Ingot' does not produce 'Iron Plate'".into()));
+        assert!(clock_options(RecipeKind::IronIngot, ItemKind::IronIngot, 0.0).is_err());
+
+        // A rest below 1% is not possible.
+        let options = clock_options(RecipeKind::IronIngot, ItemKind::IronIngot, 30.001).unwrap();
+        assert!(options.iter().all(|o| o.groups.iter().all(|g| g.overclock.0 >= MIN_CLOCK)));
+    }
+
+    #[test]
+    fn normalize() {
+        let mut graph = crate::export::tests::example();
+        let total = |n: &Node| match n {
+            Node::Recipe { buildings_count, overclock, .. } => {
+                buildings_count.get() as f64 * f64::from(*overclock)
+            }
+            _ => 0.0,
+        };
+        let before = graph.nodes.iter().map(total).collect::<Vec<_>>();
+
+        let warnings = normalize_clocks(&mut graph, Overclock(2_500_000));
+        assert!(warnings.is_empty());
+        for (node, before) in graph.nodes.iter().zip(before) {
+            if let Node::Recipe { buildings_count, .. } = node {
+                assert_eq!(total(node), before);
+                assert_eq!(buildings_count.get(), (before / 2.5).ceil() as u32);
+            }
+        }
+
+        let mut graph = Graph { nodes: vec![Node::Recipe {
+            pos: crate::state::Pos { x: 0, y: 0 },
+            recipe: RecipeKind::IronIngot,
+            buildings_count: NonZeroU32::new(1).unwrap(),
+            overclock: Overclock(2_000_002),
+            somersloops: 0,
+        }], edges: vec![] };
+        let warnings = normalize_clocks(&mut graph, Overclock(1_000_000));
+        assert_eq!(warnings, ["node 0 (Iron Ingot): rate changed by +0.0001%"]);
+        let Node::Recipe { buildings_count, overclock, .. } = &graph.nodes[0] else {
+            unreachable!()
+        };
+        assert_eq!((buildings_count.get(), *overclock), (3, Overclock(666_668)));
+    }
+}
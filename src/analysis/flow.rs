@@ -0,0 +1,435 @@
+//! Steady-state flow of items through a plan.
+//!
+//! Unlike the nominal rates of the recipes, this knows how much actually arrives, as it simulates
+//! what the game does:
+//!
+//! - Splitters divide their input evenly among their connected outputs. If one output cannot take
+//!   its share, the rest overflows to the others.
+//! - Smart splitters first divide among the outputs whose filters match the item, then give the
+//!   rest to overflow outputs. Only a single item per belt is modeled, mixed belts are treated as
+//!   matching all item filters.
+//! - Mergers sum up their inputs. If their output is backed up, each input gets an even share of
+//!   what can pass, or for priority mergers, the inputs with higher priority go first.
+//! - Recipe nodes run as fast as their most starved input and their most backed up output allow.
+//!   Pack recipes missing from the game data take everything and give nothing.
+//!
+//! Every edge is described by what its source offers and what its target accepts, and both are
+//! updated until nothing changes anymore, which also covers loops. Unconnected inputs of recipe
+//! nodes count as fully supplied and unconnected outputs of any node as unlimited, as plans are
+//! usually not closed.
+
+use serde::Serialize;
+
+use crate::{
+    gamedata::{GameData, ItemKind},
+    state::{Graph, Node, SplitFilter, SplitRule},
+};
+
+
+const MAX_ITERATIONS: usize = 10_000;
+const EPSILON: f64 = 1e-9;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Flows {
+    /// Items or m³ per minute flowing along each edge, in the order of `Graph::edges`.
+    pub edges: Vec<f64>,
+    /// For each node, the fraction (0 to 1) at which recipe nodes run. `None` for other nodes.
+    pub utilization: Vec<Option<f64>>,
+    /// Whether the simulation settled. If not, the rates are just the last approximation.
+    pub converged: bool,
+    /// Rate the target of each edge would take, which is more than it gets if it is starved.
+    #[serde(skip)]
+    pub(crate) accept: Vec<f64>,
+}
+
+/// Simulates the flow of items through `graph` until it reaches a steady state, with pack recipes
+/// looked up in `data`.
+pub fn flows(graph: &Graph, data: &GameData) -> Flows {
+    flows_with_capacity(graph, data, &vec![f64::INFINITY; graph.edges.len()])
+}
+
+/// Like `flows`, but no edge carries more than its `capacity`, e.g. the rate of its belt.
+pub fn flows_with_capacity(graph: &Graph, data: &GameData, capacity: &[f64]) -> Flows {
+    let num_edges = graph.edges.len();
+    let recipes = graph.nodes.iter().map(|n| n.recipe(data)).collect::<Vec<_>>();
+    let mut offer = vec![0.0_f64; num_edges];
+    let mut accept = capacity.to_vec();
+    let mut utilization = vec![None; graph.nodes.len()];
+
+    // Edge indices per node.
+    let mut incoming = vec![vec![]; graph.nodes.len()];
+    let mut outgoing = vec![vec![]; graph.nodes.len()];
+    for (i, e) in graph.edges.iter().enumerate() {
+        incoming[e.target.node as usize].push(i);
+        outgoing[e.source.node as usize].push(i);
+    }
+
+    let mut converged = false;
+    for _ in 0..MAX_ITERATIONS {
+        let flow = |e: usize| offer[e].min(accept[e]);
+        let mut new_offer = offer.clone();
+        let mut new_accept = accept.clone();
+
+        for (id, node) in graph.nodes.iter().enumerate() {
+            let (ins, outs) = (&incoming[id], &outgoing[id]);
+            let inflow = ins.iter().map(|&e| flow(e)).sum::<f64>();
+            let total_accept = match outs.is_empty() {
+                true => f64::INFINITY,
+                false => outs.iter().map(|&e| accept[e]).sum::<f64>(),
+            };
+
+            match node {
+                Node::Source { rate, .. } => {
+                    for &e in outs {
+                        new_offer[e] = *rate as f64;
+                    }
+                }
+                Node::Splitter { .. } => {
+                    let caps = outs.iter().map(|&e| accept[e]).collect::<Vec<_>>();
+                    for (&e, share) in outs.iter().zip(fill(inflow, &caps)) {
+                        new_offer[e] = share;
+                    }
+                    for &e in ins {
+                        new_accept[e] = total_accept;
+                    }
+                }
+                Node::Merger { .. } => {
+                    for &e in outs {
+                        new_offer[e] = inflow;
+                    }
+                    let caps = ins.iter().map(|&e| offer[e]).collect::<Vec<_>>();
+                    let shares = fill(total_accept, &caps);
+                    let rest = total_accept - shares.iter().sum::<f64>();
+                    for (&e, share) in ins.iter().zip(shares) {
+                        new_accept[e] = share + rest;
+                    }
+                }
+                Node::SmartSplitter { filters, .. } => {
+                    let item = ins.first().and_then(|&e| graph.edge_item(&graph.edges[e], data));
+                    let output_filters = |e: usize| {
+                        &filters[graph.edges[e].source.handle as usize - 1]
+                    };
+                    let is_match = |e: usize| {
+                        output_filters(e).iter().any(|&f| matches(f, item, filters))
+                    };
+                    let is_overflow = |e: usize| {
+                        output_filters(e).contains(&SplitFilter::Rule(SplitRule::Overflow))
+                    };
+
+                    // Outputs taking nothing, then matching outputs, then overflow outputs.
+                    let mut rest = inflow;
+                    let mut accepted = 0.0;
+                    for &e in outs {
+                        new_offer[e] = 0.0;
+                    }
+                    for group in [
+                        outs.iter().copied().filter(|&e| is_match(e)).collect::<Vec<_>>(),
+                        outs.iter().copied().filter(|&e| !is_match(e) && is_overflow(e)).collect(),
+                    ] {
+                        let caps = group.iter().map(|&e| accept[e]).collect::<Vec<_>>();
+                        for (&e, share) in group.iter().zip(fill(rest, &caps)) {
+                            new_offer[e] = share;
+                            rest -= share;
+                        }
+                        accepted += caps.iter().sum::<f64>();
+                    }
+                    for &e in ins {
+                        new_accept[e] = accepted;
+                    }
+                }
+                Node::PriorityMerger { priority, .. } => {
+                    for &e in outs {
+                        new_offer[e] = inflow;
+                    }
+                    let mut rest = total_accept;
+                    for handle in priority.handles() {
+                        let edge = ins.iter().find(|&&e| graph.edges[e].target.handle == handle);
+                        if let Some(&e) = edge {
+                            new_accept[e] = rest;
+                            rest -= offer[e].min(rest);
+                        }
+                    }
+                }
+                Node::Recipe { .. } | Node::PackRecipe { .. } => {
+                    let Some(recipe) = &recipes[id] else {
+                        for &e in outs {
+                            new_offer[e] = 0.0;
+                        }
+                        for &e in ins {
+                            new_accept[e] = f64::INFINITY;
+                        }
+                        continue;
+                    };
+                    let multiplier = recipe.crafts_per_minute_total();
+                    let demand = |handle: u8| recipe.inputs[handle as usize].amount * multiplier;
+                    let capacity = |handle: u8| recipe.outputs[handle as usize - 4].amount
+                        * multiplier
+                        * recipe.amplification();
+
+                    // Fraction at which each input is supplied and each output can get rid of
+                    // its items.
+                    let supplied = ins.iter()
+                        .map(|&e| (e, flow(e) / demand(graph.edges[e].target.handle)))
+                        .collect::<Vec<_>>();
+                    let out_limit = outs.iter()
+                        .map(|&e| accept[e] / capacity(graph.edges[e].source.handle))
+                        .fold(1.0, f64::min);
+
+                    let u = supplied.iter().map(|(_, s)| *s).fold(out_limit, f64::min);
+                    utilization[id] = Some(u);
+                    for &e in outs {
+                        new_offer[e] = u * capacity(graph.edges[e].source.handle);
+                    }
+                    for &e in ins {
+                        let others = supplied.iter()
+                            .filter(|(other, _)| *other != e)
+                            .map(|(_, s)| *s)
+                            .fold(out_limit, f64::min);
+                        new_accept[e] = others * demand(graph.edges[e].target.handle);
+                    }
+                }
+            }
+        }
+
+        for (accept, &capacity) in new_accept.iter_mut().zip(capacity) {
+            *accept = accept.min(capacity);
+        }
+
+        let changed = |old: &[f64], new: &[f64]| old.iter().zip(new).any(|(a, b)| {
+            !(a == b || (a - b).abs() < EPSILON)
+        });
+        let done = !changed(&offer, &new_offer) && !changed(&accept, &new_accept);
+        offer = new_offer;
+        accept = new_accept;
+        if done {
+            converged = true;
+            break;
+        }
+    }
+
+    Flows {
+        edges: offer.iter().zip(&accept).map(|(o, a)| o.min(*a)).collect(),
+        utilization,
+        converged,
+        accept,
+    }
+}
+
+/// Whether a smart splitter output with `filter` takes `item`, which is `None` if unknown.
+fn matches(filter: SplitFilter, item: Option<ItemKind>, all: &[Vec<SplitFilter>; 3]) -> bool {
+    match filter {
+        SplitFilter::Item(i) => item.is_none_or(|item| i == item),
+        SplitFilter::Rule(SplitRule::Any) => true,
+        SplitFilter::Rule(SplitRule::AnyUndefined) => item.is_none_or(|item| {
+            !all.iter().flatten().any(|&f| f == SplitFilter::Item(item))
+        }),
+        SplitFilter::Rule(SplitRule::Overflow) => false,
+    }
+}
+
+/// Divides `total` evenly among receivers that take at most `caps` each. What one cannot take is
+/// divided among the others.
+fn fill(total: f64, caps: &[f64]) -> Vec<f64> {
+    let mut order = (0..caps.len()).collect::<Vec<_>>();
+    order.sort_by(|&a, &b| caps[a].total_cmp(&caps[b]));
+
+    let mut out = vec![0.0; caps.len()];
+    let mut rest = total;
+    for (i, &idx) in order.iter().enumerate() {
+        let share = rest / (caps.len() - i) as f64;
+        out[idx] = caps[idx].min(share);
+        rest -= out[idx];
+    }
+    out
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        balancer::ratio_network,
+        export::tests::example,
+        state::{Edge, GraphHandle, Input, Pos},
+    };
+    use super::*;
+
+    fn graph(nodes: &str, edges: &str) -> Graph {
+        let json = format!(
+            r#"{{"state":{{"graph":{{"nodes":{nodes},"edges":{edges}}}}},"version":0}}"#,
+        );
+        serde_json::from_str::<Input>(&json).unwrap().state.graph
+    }
+
+    fn assert_rates(actual: &[f64], expected: &[f64]) {
+        assert_eq!(actual.len(), expected.len());
+        for (a, e) in actual.iter().zip(expected) {
+            assert!((a - e).abs() < 1e-6, "{actual:?} != {expected:?}");
+        }
+    }
+
+    #[test]
+    fn splitter_overflow() {
+        // 90 ingots are offered to plates (90/min) and rods (30/min). Rods are saturated, the
+        // rest overflows to plates.
+        let flows = flows(&example(), &GameData::builtin());
+        assert!(flows.converged);
+        assert_rates(&flows.edges, &[90.0, 90.0, 60.0, 30.0]);
+        assert_eq!(flows.utilization[0], None);
+        assert_rates(&flows.utilization.iter().flatten().copied().collect::<Vec<_>>(), &[
+            1.0,
+            2.0 / 3.0,
+            1.0,
+        ]);
+    }
+
+    #[test]
+    fn merger_and_backpressure() {
+        let sources = r#"
+            {"type":"source","pos":{"x":0,"y":0},"item":"iron-ore","rate":10},
+            {"type":"source","pos":{"x":0,"y":0},"item":"iron-ore","rate":40},
+            {"type":"merger","pos":{"x":0,"y":0}}"#;
+        let edges = r#"[
+            {"source":{"node":0,"handle":0},"target":{"node":2,"handle":0}},
+            {"source":{"node":1,"handle":0},"target":{"node":2,"handle":1}},
+            {"source":{"node":2,"handle":3},"target":{"node":3,"handle":0}}
+        ]"#;
+        let smelters = |count| format!(r#"[{sources}, {{
+            "type":"recipe","recipe":"iron-ingot","pos":{{"x":0,"y":0}},
+            "buildingsCount":{count},"overclock":1
+        }}]"#);
+
+        // Enough demand: the merger sums up.
+        let flows = flows(&graph(&smelters(2), edges), &GameData::builtin());
+        assert_rates(&flows.edges, &[10.0, 40.0, 50.0]);
+        assert_rates(&[flows.utilization[3].unwrap()], &[50.0 / 60.0]);
+
+        // Only 30/min are taken: the merger takes evenly from both inputs, as far as possible.
+        let flows = super::flows(&graph(&smelters(1), edges), &GameData::builtin());
+        assert_rates(&flows.edges, &[10.0, 20.0, 30.0]);
+        assert_rates(&[flows.utilization[3].unwrap()], &[1.0]);
+    }
+
+    #[test]
+    fn loop_back() {
+        // Fifths with one of six parts looped back: the tree receives 6/5 of the input.
+        let mut network = ratio_network(&[1, 1, 1, 1, 1]).unwrap();
+        let graph = &mut network.graph;
+        graph.nodes.push(Node::Source {
+            pos: Pos { x: 0, y: 0 },
+            item: serde_json::from_str(r#""iron-ore""#).unwrap(),
+            rate: 50,
+        });
+        let source = GraphHandle { node: graph.nodes.len() as u16 - 1, handle: 0 };
+        graph.edges.push(Edge { source, target: network.input });
+        // Unconnected splitter outputs get nothing, so each output needs a target.
+        for &output in &network.outputs {
+            graph.nodes.push(Node::Merger { pos: Pos { x: 0, y: 0 } });
+            let target = GraphHandle { node: graph.nodes.len() as u16 - 1, handle: 0 };
+            graph.edges.push(Edge { source: output, target });
+        }
+
+        let flows = flows(graph, &GameData::builtin());
+        assert!(flows.converged);
+        let rate_from = |handle: GraphHandle| {
+            let edge = graph.edges.iter()
+                .position(|e| e.source.node == handle.node && e.source.handle == handle.handle)
+                .unwrap();
+            flows.edges[edge]
+        };
+        assert_rates(&[rate_from(GraphHandle { node: network.input.node, handle: 3 })], &[60.0]);
+        for &output in &network.outputs {
+            assert_rates(&[rate_from(output)], &[10.0]);
+        }
+    }
+
+    #[test]
+    fn fill_caps() {
+        assert_rates(&fill(90.0, &[90.0, 30.0]), &[60.0, 30.0]);
+        assert_rates(&fill(90.0, &[f64::INFINITY, f64::INFINITY, 0.0]), &[45.0, 45.0, 0.0]);
+        assert_rates(&fill(10.0, &[30.0, 30.0]), &[5.0, 5.0]);
+        assert_rates(&fill(f64::INFINITY, &[3.0, 4.0]), &[3.0, 4.0]);
+    }
+
+    #[test]
+    fn smart_splitter_and_priority_merger() {
+        // 90 ore into a smart splitter: iron ore goes to output 1, which only takes 30 (a
+        // smelter), the rest to the overflow output 3. Output 2 only takes copper ore.
+        let nodes = r#"[
+            {"type":"source","pos":{"x":0,"y":0},"item":"iron-ore","rate":90},
+            {"type":"smartSplitter","pos":{"x":0,"y":0},
+                "filters":[["iron-ore"],["copper-ore"],["overflow"]]},
+            {"type":"recipe","recipe":"iron-ingot","pos":{"x":0,"y":0},
+                "buildingsCount":1,"overclock":1},
+            {"type":"merger","pos":{"x":0,"y":0}},
+            {"type":"merger","pos":{"x":0,"y":0}}
+        ]"#;
+        let edges = r#"[
+            {"source":{"node":0,"handle":0},"target":{"node":1,"handle":0}},
+            {"source":{"node":1,"handle":1},"target":{"node":2,"handle":0}},
+            {"source":{"node":1,"handle":2},"target":{"node":3,"handle":0}},
+            {"source":{"node":1,"handle":3},"target":{"node":4,"handle":0}}
+        ]"#;
+        let result = flows(&graph(nodes, edges), &GameData::builtin());
+        assert!(result.converged);
+        assert_rates(&result.edges, &[90.0, 30.0, 0.0, 60.0]);
+
+        // Two sources into a priority merger feeding one smelter (30/min): input 1 goes first.
+        let nodes = r#"[
+            {"type":"source","pos":{"x":0,"y":0},"item":"iron-ore","rate":20},
+            {"type":"source","pos":{"x":0,"y":0},"item":"iron-ore","rate":20},
+            {"type":"priorityMerger","pos":{"x":0,"y":0},"priority":[1,0,2]},
+            {"type":"recipe","recipe":"iron-ingot","pos":{"x":0,"y":0},
+                "buildingsCount":1,"overclock":1}
+        ]"#;
+        let edges = r#"[
+            {"source":{"node":0,"handle":0},"target":{"node":2,"handle":0}},
+            {"source":{"node":1,"handle":0},"target":{"node":2,"handle":1}},
+            {"source":{"node":2,"handle":3},"target":{"node":3,"handle":0}}
+        ]"#;
+        let result = flows(&graph(nodes, edges), &GameData::builtin());
+        assert!(result.converged);
+        assert_rates(&result.edges, &[10.0, 20.0, 30.0]);
+    }
+
+    #[test]
+    fn pack_recipe() {
+        let pack = serde_json::from_str(r#"{
+            "id": "gears",
+            "version": "1.0.0",
+            "items": [{"id": "iron-gear", "name": "Iron Gear", "form": "solid"}],
+            "recipes": [{
+                "id": "iron-gear",
+                "name": "Iron Gear",
+                "building": "constructor",
+                "duration": 2,
+                "inputs": [{"item": "iron-ore", "amount": 1}],
+                "outputs": [{"item": "iron-gear", "amount": 1}]
+            }]
+        }"#).unwrap();
+        let data = GameData::with_packs(&[pack]).unwrap();
+
+        // 20 ore into a constructor making 30 gears per minute from 30 ore.
+        let nodes = r#"[
+            {"type":"source","pos":{"x":0,"y":0},"item":"iron-ore","rate":20},
+            {"type":"packRecipe","recipe":"iron-gear","pos":{"x":0,"y":0},
+                "buildingsCount":1,"overclock":1},
+            {"type":"merger","pos":{"x":0,"y":0}}
+        ]"#;
+        let edges = r#"[
+            {"source":{"node":0,"handle":0},"target":{"node":1,"handle":0}},
+            {"source":{"node":1,"handle":4},"target":{"node":2,"handle":0}}
+        ]"#;
+        let g = graph(nodes, edges);
+        let result = flows(&g, &data);
+        assert!(result.converged);
+        assert_rates(&result.edges, &[20.0, 20.0]);
+        assert_rates(&[result.utilization[1].unwrap()], &[2.0 / 3.0]);
+        assert_eq!(g.edge_item(&g.edges[0], &data), Some(ItemKind::IronOre));
+        assert_eq!(g.edge_item(&g.edges[1], &data), None);
+
+        // Without the pack, the recipe is unknown and takes everything.
+        let result = flows(&g, &GameData::builtin());
+        assert_rates(&result.edges, &[20.0, 0.0]);
+        assert_eq!(result.utilization[1], None);
+    }
+}
@@ -0,0 +1,657 @@
+//! Whole building counts with clocks from a fixed set, e.g. only 100% or the 50% steps up to 250%.
+//!
+//! The rates needed are the same as for `rebalance`: nodes with end products (an unconnected
+//! output) keep at least their rate and take all their buildings can, every other node has to
+//! produce what is taken from it. In the game, a node with more buildings than needed simply runs
+//! a bit below its clock.
+//!
+//! `optimize_integer` searches for the plan with the fewest buildings or power shards with
+//! branch and bound over a linear program. The program has a whole number of buildings per recipe
+//! node and clock, so a node may be best off with buildings at different clocks, and the rates of
+//! the nodes and edges follow from the end products like in `rebalance`.
+//!
+//! `round_per_node` is the quick approximation also used as the starting point of the search:
+//! every recipe node is rounded up on its own, without looking at what its rounding costs
+//! upstream.
+
+use std::num::NonZeroU32;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    export::fmt_number,
+    gamedata::GameData,
+    state::{Graph, Node, NodeId, NodeRecipe, Overclock},
+};
+use super::{flows, lp, rebalance::rebalance_with, ClockGroup, Edit};
+
+
+/// Linear programs solved before the search gives up and returns the best plan found so far.
+const MAX_BRANCHES: usize = 2_000;
+/// Weights of the first and second thing to minimize, see `IntegerOptions::minimize`. The total
+/// clock comes last with a weight of 1.
+const PRIMARY: f64 = 1e6;
+const SECONDARY: f64 = 1e3;
+/// Distance to the next integer still counted as a whole number of buildings.
+const INTEGRALITY: f64 = 1e-6;
+/// Utilization still counted as balanced, as clocks are rounded up.
+const TOLERANCE: f64 = 1e-4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Minimize {
+    #[default]
+    Buildings,
+    PowerShards,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct IntegerOptions {
+    /// Clocks the buildings may run at.
+    pub clocks: Vec<Overclock>,
+    /// What to minimize first. Ties are broken by the other one, then by the least total clock,
+    /// i.e. the least unused capacity.
+    pub minimize: Minimize,
+}
+
+impl Default for IntegerOptions {
+    fn default() -> Self {
+        Self { clocks: vec![Overclock(1_000_000)], minimize: Minimize::Buildings }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct IntegerPlan {
+    /// Only nodes that change, in node order. Nodes with buildings at different clocks are left
+    /// unchanged, see `clocks`.
+    pub edits: Vec<Edit>,
+    /// Buildings by clock of every recipe node that is simulated, in node order. A node only has
+    /// one clock, so one with several groups has to be split up into one node per group.
+    pub clocks: Vec<NodeClocks>,
+    /// Whether every recipe node gets all it needs and can get rid of its products with the edits
+    /// applied and nodes with several clocks split up.
+    pub balanced: bool,
+    /// Whether no plan needs fewer buildings or power shards. Only `optimize_integer` can tell, if
+    /// its search finished.
+    pub optimal: bool,
+    pub warnings: Vec<String>,
+    /// Total buildings of all recipe nodes in the plan.
+    pub buildings: u32,
+    /// Total power shards of all recipe nodes in the plan.
+    pub power_shards: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct NodeClocks {
+    pub node: NodeId,
+    /// Highest clock first. Empty if the node is not needed.
+    pub groups: Vec<ClockGroup>,
+}
+
+/// Computes edits that keep the rate of the end products of `graph` with whole buildings at the
+/// clocks in `options` and the fewest buildings or power shards. Pack recipes are looked up in
+/// `data`.
+pub fn optimize_integer(
+    graph: &Graph,
+    data: &GameData,
+    options: &IntegerOptions,
+) -> Result<IntegerPlan, String> {
+    let quick = round_per_node(graph, data, options)?;
+    let model = Model::new(graph, data, options);
+
+    // The quick plan is the one to beat. It may not be valid if it left nodes unchanged.
+    let quick_counts = quick.clocks.iter()
+        .flat_map(|clocks| model.clocks.iter().map(|c| {
+            let group = clocks.groups.iter().find(|g| g.overclock == *c);
+            group.map_or(0.0, |g| g.count as f64)
+        }))
+        .collect::<Vec<_>>();
+    let quick_valid = quick.balanced
+        && quick.clocks.iter().flat_map(|c| &c.groups).all(|g| model.clocks.contains(&g.overclock));
+    let mut best_cost = match quick_valid {
+        true => model.cost(&quick_counts),
+        false => f64::INFINITY,
+    };
+    let mut best = None;
+
+    // Depth first, trying more buildings first, as that tends to stay feasible.
+    let mut stack = vec![(vec![0.0; model.num_counts()], vec![f64::INFINITY; model.num_counts()])];
+    let mut branches = 0;
+    while let Some((lower, upper)) = stack.pop() {
+        if branches == MAX_BRANCHES {
+            break;
+        }
+        branches += 1;
+        let Some(x) = model.solve(&lower, &upper, &model.objective) else { continue };
+        let cost = model.cost(&x);
+        if cost >= best_cost - 1e-6 {
+            continue;
+        }
+        let fractional = x[..model.num_counts()].iter()
+            .map(|v| (v - v.round()).abs())
+            .enumerate()
+            .filter(|(_, distance)| *distance > INTEGRALITY)
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(i, _)| i);
+        match fractional {
+            None => {
+                // The relaxation is only integral up to `INTEGRALITY`, so the cost is recomputed.
+                let counts = x[..model.num_counts()].iter().map(|v| v.round()).collect::<Vec<_>>();
+                let cost = model.cost(&counts);
+                if cost < best_cost - 1e-6 {
+                    best_cost = cost;
+                    best = Some(counts);
+                }
+            }
+            Some(i) => {
+                let mut down = upper.clone();
+                down[i] = x[i].floor();
+                let mut up = lower.clone();
+                up[i] = x[i].ceil();
+                stack.push((lower, down));
+                stack.push((up, upper));
+            }
+        }
+    }
+    let optimal = stack.is_empty();
+
+    let Some(counts) = best else {
+        let mut plan = quick;
+        plan.optimal = optimal && quick_valid;
+        if !plan.optimal {
+            plan.warnings.push("no better plan found, this one is rounded per node".into());
+        }
+        return Ok(plan);
+    };
+    let mut plan = model.plan(graph, data, &counts);
+    plan.optimal = optimal;
+    if !optimal {
+        plan.warnings.push("search stopped early, there may be a plan with fewer buildings".into());
+    }
+    Ok(plan)
+}
+
+/// Computes edits that keep the rate of the end products of `graph`, rounding every recipe node to
+/// whole buildings at one of the clocks in `options`. Pack recipes are looked up in `data`.
+///
+/// Among the combinations reaching the rate of a node, the one with the fewest buildings or power
+/// shards is chosen. Nodes with end products are rounded first, and the nodes upstream planned for
+/// what that takes. Other nodes don't pass their rounding on, upstream nodes are planned for what
+/// they actually need.
+pub fn round_per_node(
+    graph: &Graph,
+    data: &GameData,
+    options: &IntegerOptions,
+) -> Result<IntegerPlan, String> {
+    if options.clocks.is_empty() {
+        return Err("at least one clock is needed".into());
+    }
+
+    let result = rebalance_with(graph, data, |total, _, _| {
+        options.clocks.iter()
+            .filter_map(|&clock| {
+                let count = u32::try_from(total.div_ceil(clock.0 as u64)).ok()?;
+                let shards = count * clock.power_shards();
+                let unused = count as u64 * clock.0 as u64 - total;
+                let key = match options.minimize {
+                    Minimize::Buildings => (count, shards, unused),
+                    Minimize::PowerShards => (shards, count, unused),
+                };
+                Some((key, ClockGroup { count, overclock: clock }))
+            })
+            .min_by_key(|(key, _)| *key)
+            .map(|(_, group)| group)
+    });
+
+    let mut planned = graph.clone();
+    super::apply_edits(&mut planned, &result.edits);
+    let (buildings, power_shards) = totals(&planned);
+    let clocks = planned.nodes.iter()
+        .enumerate()
+        .filter(|(_, node)| node.recipe(data).is_some())
+        .filter_map(|(id, node)| {
+            let (count, overclock) = node.clock()?;
+            let groups = vec![ClockGroup { count: count.get(), overclock }];
+            Some(NodeClocks { node: id as NodeId, groups })
+        })
+        .collect();
+
+    Ok(IntegerPlan {
+        edits: result.edits,
+        clocks,
+        balanced: result.balanced,
+        optimal: false,
+        warnings: result.warnings,
+        buildings,
+        power_shards,
+    })
+}
+
+/// Total buildings and power shards of all recipe nodes of `graph`.
+fn totals(graph: &Graph) -> (u32, u32) {
+    graph.nodes.iter()
+        .filter_map(|node| node.clock())
+        .map(|(count, clock)| (count.get(), count.get() * clock.power_shards()))
+        .fold((0, 0), |(b, s), (nb, ns)| (b + nb, s + ns))
+}
+
+/// The linear program for a plan. The variables are the buildings per simulated recipe node and
+/// clock, then the rate of each of those nodes in buildings at 100%, then the demand per edge.
+struct Model<'a> {
+    /// Recipe nodes with a known recipe.
+    nodes: Vec<usize>,
+    clocks: &'a [Overclock],
+    num_vars: usize,
+    constraints: Vec<(Vec<f64>, f64)>,
+    /// Cost per variable, to be minimized.
+    objective: Vec<f64>,
+}
+
+impl<'a> Model<'a> {
+    fn new(graph: &Graph, data: &GameData, options: &'a IntegerOptions) -> Self {
+        let current = flows(graph, data);
+        let recipes = graph.nodes.iter().map(|n| n.recipe(data)).collect::<Vec<_>>();
+        let nodes = (0..graph.nodes.len()).filter(|&id| recipes[id].is_some()).collect::<Vec<_>>();
+        let clocks = &options.clocks[..];
+        let num_counts = nodes.len() * clocks.len();
+        let num_vars = num_counts + nodes.len() + graph.edges.len();
+        let count = |k: usize, c: usize| k * clocks.len() + c;
+        let rate = |k: usize| num_counts + k;
+        let demand = |e: usize| num_counts + nodes.len() + e;
+
+        let mut incoming = vec![vec![]; graph.nodes.len()];
+        let mut outgoing = vec![vec![]; graph.nodes.len()];
+        for (i, e) in graph.edges.iter().enumerate() {
+            incoming[e.target.node as usize].push(i);
+            outgoing[e.source.node as usize].push(i);
+        }
+
+        let mut constraints = vec![];
+        let mut row = |entries: &[(usize, f64)], bound: f64| {
+            let mut row = vec![0.0; num_vars];
+            for &(var, value) in entries {
+                row[var] += value;
+            }
+            constraints.push((row, bound));
+        };
+        for (k, &id) in nodes.iter().enumerate() {
+            let recipe = recipes[id].as_ref().unwrap();
+            let capacity = (0..clocks.len())
+                .map(|c| (count(k, c), f64::from(clocks[c])))
+                .collect::<Vec<_>>();
+            let negated = capacity.iter().map(|&(var, v)| (var, -v)).collect::<Vec<_>>();
+
+            // The buildings can run at the rate.
+            row(&[&negated[..], &[(rate(k), 1.0)]].concat(), 0.0);
+            if is_end(graph, recipe, &outgoing[id]) {
+                // End products keep at least their rate and take all the buildings can.
+                let total = recipe.buildings_count.get() as f64 * f64::from(recipe.overclock);
+                row(&[&capacity[..], &[(rate(k), -1.0)]].concat(), 0.0);
+                row(&[(rate(k), -1.0)], -total * (1.0 - 1e-9));
+            }
+
+            // Outputs make at least what is taken, inputs get what the rate needs.
+            let per_building = recipe.crafts_per_minute;
+            for (handle, output) in (4..).zip(&recipe.outputs) {
+                let edges = outgoing[id].iter()
+                    .filter(|&&e| graph.edges[e].source.handle == handle);
+                let made = output.amount * per_building * recipe.amplification();
+                let entries = edges.map(|&e| (demand(e), 1.0))
+                    .chain([(rate(k), -made)])
+                    .collect::<Vec<_>>();
+                if entries.len() > 1 {
+                    row(&entries, 0.0);
+                }
+            }
+            for &e in &incoming[id] {
+                let Some(input) = recipe.inputs.get(graph.edges[e].target.handle as usize) else {
+                    continue;
+                };
+                row(&[(rate(k), input.amount * per_building), (demand(e), -1.0)], 0.0);
+            }
+        }
+
+        for (id, node) in graph.nodes.iter().enumerate() {
+            let (ins, outs) = (&incoming[id], &outgoing[id]);
+            if !node.is_split_merge() {
+                continue;
+            }
+            // Like in `rebalance`: nothing connected keeps what currently goes in, splitters need
+            // what all outputs take and mergers take from their inputs in the current proportion.
+            let inflow = ins.iter().map(|&e| current.edges[e]).sum::<f64>();
+            for &e in ins {
+                if outs.is_empty() {
+                    row(&[(demand(e), -1.0)], -current.edges[e]);
+                    continue;
+                }
+                let share = match node.is_splitter() {
+                    true => 1.0,
+                    false if inflow > 1e-9 => current.edges[e] / inflow,
+                    false => 1.0 / ins.len() as f64,
+                };
+                let entries = outs.iter()
+                    .map(|&o| (demand(o), share))
+                    .chain([(demand(e), -1.0)])
+                    .collect::<Vec<_>>();
+                row(&entries, 0.0);
+            }
+        }
+
+        let weights = |clock: Overclock| match options.minimize {
+            Minimize::Buildings => PRIMARY + SECONDARY * clock.power_shards() as f64,
+            Minimize::PowerShards => PRIMARY * clock.power_shards() as f64 + SECONDARY,
+        };
+        let mut objective = vec![0.0; num_vars];
+        for k in 0..nodes.len() {
+            for (c, &clock) in clocks.iter().enumerate() {
+                objective[count(k, c)] = weights(clock) + f64::from(clock);
+            }
+        }
+
+        Self { nodes, clocks, num_vars, constraints, objective }
+    }
+
+    fn num_counts(&self) -> usize {
+        self.nodes.len() * self.clocks.len()
+    }
+
+    /// Cost of the buildings in `x`, of which only the counts are used.
+    fn cost(&self, x: &[f64]) -> f64 {
+        self.objective.iter().zip(x).map(|(c, x)| c * x).sum()
+    }
+
+    /// Minimizes `objective` with the building counts between `lower` and `upper`. Returns all
+    /// variables, or `None` if there is no solution.
+    fn solve(&self, lower: &[f64], upper: &[f64], objective: &[f64]) -> Option<Vec<f64>> {
+        if lower.iter().zip(upper).any(|(lo, hi)| lo > hi) {
+            return None;
+        }
+        // The counts are shifted by their lower bound, so that it becomes 0.
+        let shift = |row: &[f64]| row.iter().zip(lower).map(|(a, lo)| a * lo).sum::<f64>();
+        let mut constraints = self.constraints.iter()
+            .map(|(row, bound)| (row.clone(), bound - shift(row)))
+            .collect::<Vec<_>>();
+        for (var, (lo, hi)) in lower.iter().zip(upper).enumerate() {
+            if hi.is_finite() {
+                let mut row = vec![0.0; self.num_vars];
+                row[var] = 1.0;
+                constraints.push((row, hi - lo));
+            }
+        }
+        let problem = lp::Problem {
+            objective: objective.iter().map(|c| -c).collect(),
+            constraints,
+        };
+        let mut x = lp::maximize(&problem).ok()?.x;
+        for (x, lo) in x.iter_mut().zip(lower) {
+            *x += lo;
+        }
+        Some(x)
+    }
+
+    /// Turns the building `counts` into a plan for `graph`.
+    fn plan(&self, graph: &Graph, data: &GameData, counts: &[f64]) -> IntegerPlan {
+        // The least rates and demands for these buildings. The search only minimized the
+        // buildings, so its rates may be higher than needed.
+        let rates_objective = (0..self.num_vars)
+            .map(|var| if var < self.num_counts() { 0.0 } else { 1.0 })
+            .collect::<Vec<_>>();
+        let x = self.solve(counts, counts, &rates_objective)
+            .expect("counts found by the search must be feasible");
+        let rate = |k: usize| x[self.num_counts() + k];
+        let demand = |e: usize| x[self.num_counts() + self.nodes.len() + e];
+
+        let mut out = IntegerPlan {
+            edits: vec![],
+            clocks: vec![],
+            balanced: false,
+            optimal: false,
+            warnings: vec![],
+            buildings: 0,
+            power_shards: 0,
+        };
+        // Nodes with several clocks are simulated as the same number of buildings at their
+        // average clock.
+        let mut planned = graph.clone();
+        for (k, &id) in self.nodes.iter().enumerate() {
+            let node = &graph.nodes[id];
+            let name = node.recipe(data).unwrap().name.to_owned();
+            let mut groups = self.clocks.iter()
+                .enumerate()
+                .map(|(c, &overclock)| {
+                    ClockGroup { count: counts[k * self.clocks.len() + c] as u32, overclock }
+                })
+                .filter(|g| g.count > 0)
+                .collect::<Vec<_>>();
+            groups.sort_by_key(|g| std::cmp::Reverse(g.overclock.0));
+            let count = groups.iter().map(|g| g.count).sum::<u32>();
+            out.buildings += count;
+            out.power_shards += groups.iter()
+                .map(|g| g.count * g.overclock.power_shards())
+                .sum::<u32>();
+            let total = groups.iter().map(|g| g.count as u64 * g.overclock.0 as u64).sum::<u64>();
+            match &groups[..] {
+                [] => out.warnings.push(format!("node {id} ({name}) is not needed")),
+                [group] => {
+                    if node.clock() != NonZeroU32::new(group.count).map(|c| (c, group.overclock)) {
+                        out.edits.push(Edit::Recipe {
+                            node: id as NodeId,
+                            buildings_count: NonZeroU32::new(group.count).unwrap(),
+                            overclock: group.overclock,
+                        });
+                    }
+                }
+                groups => out.warnings.push(format!(
+                    "node {id} ({name}) needs {}, split it into one node per clock",
+                    groups.iter()
+                        .map(|g| {
+                            let percent = fmt_number(f64::from(g.overclock) * 100.0);
+                            format!("{}× {percent}%", g.count)
+                        })
+                        .collect::<Vec<_>>()
+                        .join(" and "),
+                )),
+            }
+            if let (Some((buildings_count, overclock)), Some(count)) =
+                (planned.nodes[id].clock_mut(), NonZeroU32::new(count))
+            {
+                *buildings_count = count;
+                *overclock = Overclock(total.div_ceil(count.get() as u64) as u32);
+            }
+            out.clocks.push(NodeClocks { node: id as NodeId, groups });
+        }
+
+        // Recipe nodes that are not simulated keep their buildings.
+        for (id, node) in graph.nodes.iter().enumerate() {
+            match node {
+                Node::Source { rate, .. } => {
+                    let mut taken = graph.edges.iter()
+                        .enumerate()
+                        .filter(|(_, e)| e.source.node as usize == id)
+                        .map(|(e, _)| demand(e))
+                        .peekable();
+                    if taken.peek().is_none() {
+                        continue;
+                    }
+                    let new_rate = (taken.sum::<f64>() - TOLERANCE).ceil().max(0.0) as u32;
+                    if new_rate != *rate {
+                        out.edits.push(Edit::Source { node: id as NodeId, rate: new_rate });
+                    }
+                }
+                _ if !self.nodes.contains(&id) => {
+                    if let Some((count, clock)) = node.clock() {
+                        out.buildings += count.get();
+                        out.power_shards += count.get() * clock.power_shards();
+                    }
+                }
+                _ => {}
+            }
+        }
+        out.edits.sort_by_key(|edit| match *edit {
+            Edit::Recipe { node, .. } | Edit::Source { node, .. } => node,
+        });
+        super::apply_edits(&mut planned, &out.edits);
+
+        // Every recipe node has to run at least at the rate needed.
+        let after = flows(&planned, data);
+        out.balanced = after.converged && self.nodes.iter().enumerate().all(|(k, &id)| {
+            let Some((count, clock)) = planned.nodes[id].clock() else { return true };
+            let total = count.get() as f64 * f64::from(clock);
+            after.utilization[id].unwrap_or(0.0) * total > rate(k) * (1.0 - TOLERANCE)
+        });
+        out
+    }
+}
+
+/// Whether a node with `recipe` has an unconnected output, given its `outgoing` edges.
+fn is_end(graph: &Graph, recipe: &NodeRecipe, outgoing: &[usize]) -> bool {
+    (4..).take(recipe.outputs.len())
+        .any(|h| !outgoing.iter().any(|&e| graph.edges[e].source.handle == h))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::{export::tests::example, state::Node};
+    use super::*;
+
+    fn clocks(values: &[f64]) -> Vec<Overclock> {
+        values.iter().map(|&v| Overclock::try_from(v).unwrap()).collect()
+    }
+
+    /// Buildings and clock of every recipe node of `graph` after applying `plan`.
+    fn recipes_of(mut graph: Graph, plan: &IntegerPlan) -> Vec<(u32, Overclock)> {
+        super::super::apply_edits(&mut graph, &plan.edits);
+        graph.nodes.iter()
+            .filter_map(|node| match node {
+                Node::Recipe { buildings_count, overclock, .. } => {
+                    Some((buildings_count.get(), *overclock))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn recipes(plan: &IntegerPlan) -> Vec<(u32, Overclock)> {
+        recipes_of(example(), plan)
+    }
+
+    #[test]
+    fn example_plan() {
+        // Smelters need 4 buildings at 100%, plates 3 and rods 2.
+        let options = IntegerOptions::default();
+        let plan = round_per_node(&example(), &GameData::builtin(), &options).unwrap();
+        assert!(plan.balanced);
+        let full = Overclock(1_000_000);
+        assert_eq!(recipes(&plan), [(4, full), (3, full), (2, full)]);
+        assert_eq!((plan.buildings, plan.power_shards), (9, 0));
+
+        let steps = IntegerOptions { clocks: clocks(&[1.0, 1.5, 2.0, 2.5]), ..Default::default() };
+        let plan = round_per_node(&example(), &GameData::builtin(), &steps).unwrap();
+        assert_eq!(recipes(&plan), [
+            (2, Overclock(2_000_000)),
+            (2, Overclock(1_500_000)),
+            (1, Overclock(2_000_000)),
+        ]);
+        assert_eq!((plan.buildings, plan.power_shards), (5, 8));
+
+        let shards = IntegerOptions { minimize: Minimize::PowerShards, ..steps };
+        let plan = round_per_node(&example(), &GameData::builtin(), &shards).unwrap();
+        assert_eq!((plan.buildings, plan.power_shards), (9, 0));
+    }
+
+    #[test]
+    fn rounded_up() {
+        // Plates and rods produce more at 250%, so they get all ingots they can take: 150 for 2
+        // plate constructors and 37.5 for one rod constructor. 3 smelters can make 225.
+        let options = IntegerOptions { clocks: clocks(&[2.5]), ..Default::default() };
+        let plan = round_per_node(&example(), &GameData::builtin(), &options).unwrap();
+        assert!(plan.balanced, "{plan:?}");
+        let max = Overclock(2_500_000);
+        assert_eq!(recipes(&plan), [(3, max), (2, max), (1, max)]);
+        assert!(plan.edits.contains(&Edit::Source { node: 0, rate: 188 }));
+
+        let options = IntegerOptions { clocks: vec![], ..Default::default() };
+        assert!(round_per_node(&example(), &GameData::builtin(), &options).is_err());
+    }
+
+    #[test]
+    fn rounding_is_not_passed_upstream() {
+        // Ore -> ingots -> rods -> 2 screw constructors, which take 20 rods/min.
+        let json = r#"{"state":{"graph":{
+            "nodes": [
+                {"type":"source","pos":{"x":0,"y":0},"item":"iron-ore","rate":60},
+                {"type":"recipe","recipe":"iron-ingot","pos":{"x":100,"y":0},
+                    "buildingsCount":2,"overclock":1},
+                {"type":"recipe","recipe":"iron-rod","pos":{"x":400,"y":0},
+                    "buildingsCount":2,"overclock":1},
+                {"type":"recipe","recipe":"screw","pos":{"x":700,"y":0},
+                    "buildingsCount":2,"overclock":1}
+            ],
+            "edges": [
+                {"source":{"node":0,"handle":0},"target":{"node":1,"handle":0}},
+                {"source":{"node":1,"handle":4},"target":{"node":2,"handle":0}},
+                {"source":{"node":2,"handle":4},"target":{"node":3,"handle":0}}
+            ]
+        }},"version":0}"#;
+        let graph = serde_json::from_str::<crate::state::Input>(json).unwrap().state.graph;
+
+        // The rods are rounded up from 1.33 to 2 constructors, which could take 30 ingots/min,
+        // but the smelter is planned for the 20 ingots/min actually used.
+        let options = IntegerOptions::default();
+        let plan = round_per_node(&graph, &GameData::builtin(), &options).unwrap();
+        assert!(plan.balanced, "{plan:?}");
+        let full = Overclock(1_000_000);
+        assert_eq!(recipes_of(graph.clone(), &plan), [(1, full), (2, full), (2, full)]);
+        assert!(plan.edits.contains(&Edit::Source { node: 0, rate: 20 }));
+
+        // At 150%, the 80 screws/min round up to 2 constructors, which take 30 rods/min, and that
+        // is passed upstream. The rods round up from 1.33 to 2 constructors, which could take 45
+        // ingots/min, but 1 smelter for the 30 used is enough.
+        let options = IntegerOptions { clocks: clocks(&[1.5]), ..Default::default() };
+        let plan = round_per_node(&graph, &GameData::builtin(), &options).unwrap();
+        assert!(plan.balanced, "{plan:?}");
+        let clock = Overclock(1_500_000);
+        assert_eq!(recipes_of(graph, &plan), [(1, clock), (2, clock), (2, clock)]);
+        assert!(plan.edits.contains(&Edit::Source { node: 0, rate: 30 }));
+    }
+
+    #[test]
+    fn optimized() {
+        let data = GameData::builtin();
+        let plan = optimize_integer(&example(), &data, &IntegerOptions::default()).unwrap();
+        assert!(plan.balanced && plan.optimal, "{plan:?}");
+        assert_eq!((plan.buildings, plan.power_shards), (9, 0));
+
+        // Rounded per node, 3 plate constructors are needed at 50% and 250%, so 2 at 250% are
+        // rounded up to 60 plates/min and all upstream nodes are planned for them. 1 constructor
+        // at 250% and 1 at 50% make exactly 30.
+        let options = IntegerOptions { clocks: clocks(&[0.5, 2.5]), ..Default::default() };
+        let quick = round_per_node(&example(), &data, &options).unwrap();
+        assert_eq!((quick.buildings, quick.power_shards), (6, 18));
+        let plan = optimize_integer(&example(), &data, &options).unwrap();
+        assert!(plan.balanced && plan.optimal, "{plan:?}");
+        assert_eq!((plan.buildings, plan.power_shards), (5, 12));
+        let (max, min) = (Overclock(2_500_000), Overclock(500_000));
+        assert_eq!(plan.clocks[1], NodeClocks {
+            node: 3,
+            groups: vec![
+                ClockGroup { count: 1, overclock: max },
+                ClockGroup { count: 1, overclock: min },
+            ],
+        });
+        assert_eq!(plan.warnings.len(), 1, "{plan:?}");
+        assert_eq!(recipes(&plan), [(2, max), (3, Overclock(1_000_000)), (1, max)]);
+        assert!(plan.edits.contains(&Edit::Source { node: 0, rate: 128 }));
+
+        let steps = IntegerOptions { clocks: clocks(&[1.0, 1.5, 2.0, 2.5]), ..Default::default() };
+        let plan = optimize_integer(&example(), &data, &steps).unwrap();
+        assert!(plan.balanced && plan.optimal, "{plan:?}");
+        assert_eq!((plan.buildings, plan.power_shards), (5, 8));
+        let shards = IntegerOptions { minimize: Minimize::PowerShards, ..steps };
+        let plan = optimize_integer(&example(), &data, &shards).unwrap();
+        assert!(plan.balanced && plan.optimal, "{plan:?}");
+        assert_eq!((plan.buildings, plan.power_shards), (9, 0));
+    }
+}
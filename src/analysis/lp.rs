@@ -0,0 +1,221 @@
+//! A small dense simplex solver for the linear programs of the planning analyses.
+//!
+//! Problems have the form "maximize `c·x` subject to `A·x <= b`, `x >= 0`". Most of ours have
+//! `b >= 0`, as producing nothing never needs anything, so `x = 0` is a feasible start. Otherwise
+//! a first phase finds a feasible start, e.g. for lower bounds on building counts.
+
+const EPSILON: f64 = 1e-9;
+/// Added to the bounds against degeneracy, see `maximize`.
+const PERTURBATION: f64 = 1e-7;
+/// Remaining violation, relative to the largest bound, still counted as feasible.
+const FEASIBILITY: f64 = 1e-6;
+/// Gives up instead of running for ever if rounding errors make the simplex cycle.
+const MAX_PIVOTS: usize = 50_000;
+
+pub(super) struct Problem {
+    pub objective: Vec<f64>,
+    /// Rows of `A` with their bound `b`.
+    pub constraints: Vec<(Vec<f64>, f64)>,
+}
+
+#[derive(Debug)]
+pub(super) struct Solution {
+    pub x: Vec<f64>,
+    /// Increase of the optimal value per unit of each constraint bound (shadow prices).
+    pub duals: Vec<f64>,
+}
+
+/// Solves `problem` with the simplex method. Fails if the objective is unbounded or if no `x`
+/// satisfies the constraints.
+pub(super) fn maximize(problem: &Problem) -> Result<Solution, String> {
+    let n = problem.objective.len();
+    let m = problem.constraints.len();
+    // With a negative bound, an auxiliary variable is needed for the first phase.
+    let infeasible_start = problem.constraints.iter().any(|(_, bound)| *bound < 0.0);
+    let aux = n + m;
+    let width = n + m + 1 + infeasible_start as usize;
+    let rhs = width - 1;
+
+    // Tableau with the slack variables after the original ones and the bound in the last column.
+    // The last row is the objective, negated. Most bounds are 0 (intermediate items), which makes
+    // the problem highly degenerate and the simplex stall, so every bound is raised by a slightly
+    // different tiny amount. The exact solution for the final basis is computed at the end.
+    let mut t = vec![0.0; (m + 1) * width];
+    for (i, (row, bound)) in problem.constraints.iter().enumerate() {
+        assert!(bound.is_finite() && row.len() == n, "invalid constraint");
+        t[i * width..i * width + n].copy_from_slice(row);
+        t[i * width + n + i] = 1.0;
+        if infeasible_start {
+            t[i * width + aux] = -1.0;
+        }
+        t[i * width + rhs] = bound + PERTURBATION * (1.0 + i as f64 / m as f64);
+    }
+    let mut basis = (n..n + m).collect::<Vec<_>>();
+
+    if infeasible_start {
+        // First maximize `-aux` subject to `A·x - aux <= b`. Bringing `aux` into the basis in the
+        // row with the lowest bound makes all bounds non-negative. If `aux` can get down to 0, the
+        // final basis is a feasible start for the actual problem.
+        t[m * width + aux] = 1.0;
+        let lowest = (0..m)
+            .min_by(|&a, &b| t[a * width + rhs].total_cmp(&t[b * width + rhs]))
+            .unwrap();
+        pivot(&mut t, width, m, lowest, aux);
+        basis[lowest] = aux;
+        simplex(&mut t, width, m, &mut basis)?;
+
+        let scale = problem.constraints.iter().map(|(_, b)| b.abs()).fold(1.0, f64::max);
+        if t[m * width + rhs] < -FEASIBILITY * scale {
+            return Err("infeasible".into());
+        }
+        // `aux` is 0 now, but may still be basic. Then any other variable can take its place.
+        if let Some(row) = basis.iter().position(|&var| var == aux) {
+            if let Some(col) = (0..aux).find(|&j| t[row * width + j].abs() > EPSILON) {
+                pivot(&mut t, width, m, row, col);
+                basis[row] = col;
+            }
+        }
+        for i in 0..=m {
+            t[i * width + aux] = 0.0;
+        }
+
+        // The objective row in terms of the current basis.
+        t[m * width..].fill(0.0);
+        for (j, c) in problem.objective.iter().enumerate() {
+            t[m * width + j] = -c;
+        }
+        for (i, &var) in basis.iter().enumerate() {
+            let c = problem.objective.get(var).copied().unwrap_or(0.0);
+            if c != 0.0 {
+                for j in 0..width {
+                    t[m * width + j] += c * t[i * width + j];
+                }
+            }
+        }
+    } else {
+        for (j, c) in problem.objective.iter().enumerate() {
+            t[m * width + j] = -c;
+        }
+    }
+
+    simplex(&mut t, width, m, &mut basis)?;
+    Ok(solution(problem, &t, &basis, width))
+}
+
+/// Pivots until the objective row of the tableau `t` has no negative entries left.
+fn simplex(t: &mut [f64], width: usize, m: usize, basis: &mut [usize]) -> Result<(), String> {
+    for _ in 0..MAX_PIVOTS {
+        let objective = &t[m * width..m * width + width - 1];
+        let entering = objective.iter()
+            .enumerate()
+            .filter(|(_, &v)| v < -EPSILON)
+            .min_by(|a, b| a.1.total_cmp(b.1))
+            .map(|(j, _)| j);
+        let Some(entering) = entering else {
+            return Ok(());
+        };
+
+        let leaving = (0..m)
+            .filter(|&i| t[i * width + entering] > EPSILON)
+            .map(|i| (i, t[i * width + width - 1].max(0.0) / t[i * width + entering]))
+            .min_by(|a, b| a.1.total_cmp(&b.1).then(basis[a.0].cmp(&basis[b.0])));
+        let Some((leaving, _)) = leaving else {
+            return Err("unbounded".into());
+        };
+
+        pivot(t, width, m, leaving, entering);
+        basis[leaving] = entering;
+    }
+    Err("too many iterations".into())
+}
+
+/// Makes `col` a unit column with the 1 in `row`.
+fn pivot(t: &mut [f64], width: usize, m: usize, row: usize, col: usize) {
+    let pivot = t[row * width + col];
+    for v in &mut t[row * width..(row + 1) * width] {
+        *v /= pivot;
+    }
+    let pivot_row = t[row * width..(row + 1) * width].to_vec();
+    for i in (0..=m).filter(|&i| i != row) {
+        let factor = t[i * width + col];
+        if factor.abs() > 0.0 {
+            for (v, p) in t[i * width..(i + 1) * width].iter_mut().zip(&pivot_row) {
+                *v -= factor * p;
+            }
+        }
+    }
+}
+
+/// Reads the solution for the unperturbed bounds from the final tableau. The slack columns hold
+/// the inverse of the basis, so the basic variables are that applied to the original bounds.
+fn solution(problem: &Problem, t: &[f64], basis: &[usize], width: usize) -> Solution {
+    let n = problem.objective.len();
+    let m = problem.constraints.len();
+
+    let mut x = vec![0.0; n];
+    for (i, &var) in basis.iter().enumerate() {
+        if var < n {
+            let inverse = &t[i * width + n..i * width + n + m];
+            let value = inverse.iter()
+                .zip(&problem.constraints)
+                .map(|(a, (_, bound))| a * bound)
+                .sum::<f64>();
+            x[var] = value.max(0.0);
+        }
+    }
+    Solution {
+        x,
+        duals: (0..m).map(|i| t[m * width + n + i]).collect(),
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn small_problems() {
+        // max 3a + 2b, a + b <= 4, a + 3b <= 6, a <= 3
+        let solution = maximize(&Problem {
+            objective: vec![3.0, 2.0],
+            constraints: vec![
+                (vec![1.0, 1.0], 4.0),
+                (vec![1.0, 3.0], 6.0),
+                (vec![1.0, 0.0], 3.0),
+            ],
+        }).unwrap();
+        assert!((solution.x[0] - 3.0).abs() < 1e-9 && (solution.x[1] - 1.0).abs() < 1e-9);
+        // One more of the first bound gives one more b, worth 2. The second one is not tight.
+        assert!((solution.duals[0] - 2.0).abs() < 1e-9);
+        assert!(solution.duals[1].abs() < 1e-9);
+
+        let unbounded = maximize(&Problem {
+            objective: vec![1.0, 1.0],
+            constraints: vec![(vec![1.0, -1.0], 1.0)],
+        });
+        assert!(unbounded.is_err());
+    }
+
+    #[test]
+    fn lower_bounds() {
+        // min a + b, a + 2b >= 4, 3a + b >= 6
+        let solution = maximize(&Problem {
+            objective: vec![-1.0, -1.0],
+            constraints: vec![
+                (vec![-1.0, -2.0], -4.0),
+                (vec![-3.0, -1.0], -6.0),
+            ],
+        }).unwrap();
+        assert!((solution.x[0] - 1.6).abs() < 1e-9 && (solution.x[1] - 1.2).abs() < 1e-9);
+        // Needing one less in the first row saves 0.4.
+        assert!((solution.duals[0] - 0.4).abs() < 1e-9, "{solution:?}");
+
+        // a <= 1, a >= 2
+        let infeasible = maximize(&Problem {
+            objective: vec![1.0],
+            constraints: vec![(vec![1.0], 1.0), (vec![-1.0], -2.0)],
+        });
+        assert_eq!(infeasible.err().unwrap(), "infeasible");
+    }
+}
@@ -0,0 +1,207 @@
+//! The most of an item that can be made from a fixed set of resources, e.g. the nodes and wells
+//! that have been claimed.
+//!
+//! Unlike `breakdown`, which expands a fixed recipe per item, this chooses among all allowed
+//! recipes and may mix several for the same item, as a linear program: each recipe runs in some
+//! number of buildings, every intermediate item has to be produced at least as fast as it is
+//! consumed, and raw resources are limited to the budget.
+
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+use crate::gamedata::{IoEntry, ItemKind, RecipeFilter, RecipeKind, SourceItemKind};
+use super::lp::{self, Problem};
+
+
+/// Cost per building added to the objective, so that among equally good solutions the one with
+/// the fewest buildings wins, instead of e.g. packaging and unpackaging fluids for nothing.
+pub(super) const BUILDING_PENALTY: f64 = 1e-6;
+const EPSILON: f64 = 1e-9;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MaxOutput {
+    pub item: ItemKind,
+    /// Highest possible rate per minute.
+    pub rate: f64,
+    /// Number of buildings (at 100% clock speed) per recipe.
+    pub recipes: BTreeMap<RecipeKind, f64>,
+    /// Raw resources used per minute.
+    pub raw: BTreeMap<SourceItemKind, f64>,
+    /// Items per minute produced beyond what is consumed.
+    pub byproducts: BTreeMap<ItemKind, f64>,
+    /// Raw resources of the budget that are used up, with the additional output per additional
+    /// 1/min of each. The one limiting the output the most comes first.
+    pub binding: Vec<(SourceItemKind, f64)>,
+}
+
+/// All allowed recipes that may be involved in producing an item, and all items they use.
+pub(super) struct RecipeModel {
+    pub recipes: Vec<RecipeKind>,
+    pub items: Vec<ItemKind>,
+}
+
+impl RecipeModel {
+    pub fn new(target: ItemKind, filter: &RecipeFilter) -> Result<Self, String> {
+        if SourceItemKind::try_from(target).is_ok() {
+            return Err(format!("'{}' is a raw resource", target.info().name));
+        }
+
+        let mut recipes = vec![];
+        let mut items = vec![target];
+        let mut i = 0;
+        while let Some(&item) = items.get(i) {
+            i += 1;
+            if SourceItemKind::try_from(item).is_ok() {
+                continue;
+            }
+            for &recipe in item.produced_by() {
+                if recipes.contains(&recipe) || !filter.allows(recipe) {
+                    continue;
+                }
+                recipes.push(recipe);
+                let info = recipe.info();
+                for entry in info.inputs.iter().chain(info.outputs) {
+                    if !items.contains(&entry.item) {
+                        items.push(entry.item);
+                    }
+                }
+            }
+        }
+        if recipes.is_empty() {
+            return Err(format!("no allowed recipe produces '{}'", target.info().name));
+        }
+        Ok(Self { recipes, items })
+    }
+
+    /// Net amount of `item` per minute made by one building at 100% of each recipe.
+    pub fn net(&self, item: ItemKind) -> Vec<f64> {
+        self.recipes.iter()
+            .map(|recipe| {
+                let info = recipe.info();
+                let amount = |entries: &[IoEntry]| entries.iter()
+                    .filter(|e| e.item == item)
+                    .map(|e| e.amount)
+                    .sum::<f64>();
+                (amount(info.outputs) - amount(info.inputs)) * info.crafts_per_minute()
+            })
+            .collect()
+    }
+
+    /// Constraints for every item but `target`: intermediate items have to be produced at least
+    /// as fast as they are consumed, raw resources are limited by `budget`.
+    pub fn item_constraints(
+        &self,
+        target: ItemKind,
+        budget: impl Fn(SourceItemKind) -> f64,
+    ) -> Vec<(Vec<f64>, f64)> {
+        self.items.iter()
+            .filter(|&&item| item != target)
+            .map(|&item| {
+                let consumed = self.net(item).into_iter().map(|v| -v).collect();
+                let bound = SourceItemKind::try_from(item).map_or(0.0, &budget);
+                (consumed, bound)
+            })
+            .collect()
+    }
+}
+
+/// Calculates the highest rate of `item` that can be produced from the raw resources in `budget`
+/// (per minute), using any recipes allowed by `filter`.
+pub fn max_output(
+    item: ItemKind,
+    budget: &BTreeMap<SourceItemKind, f64>,
+    filter: &RecipeFilter,
+) -> Result<MaxOutput, String> {
+    let model = RecipeModel::new(item, filter)?;
+    let produced = model.net(item);
+    let budget_of = |source| budget.get(&source).copied().unwrap_or(0.0).max(0.0);
+    let problem = Problem {
+        objective: produced.iter().map(|p| p - BUILDING_PENALTY).collect(),
+        constraints: model.item_constraints(item, budget_of),
+    };
+    let solution = lp::maximize(&problem)
+        .map_err(|e| format!("cannot maximize '{}': {e}", item.info().name))?;
+
+    let mut out = MaxOutput {
+        item,
+        rate: produced.iter().zip(&solution.x).map(|(p, x)| p * x).sum(),
+        recipes: BTreeMap::new(),
+        raw: BTreeMap::new(),
+        byproducts: BTreeMap::new(),
+        binding: vec![],
+    };
+    for (&recipe, &buildings) in model.recipes.iter().zip(&solution.x) {
+        if buildings > EPSILON {
+            out.recipes.insert(recipe, buildings);
+        }
+    }
+
+    let others = model.items.iter().filter(|&&i| i != item);
+    for (&other, &dual) in others.zip(&solution.duals) {
+        let net = model.net(other).iter().zip(&solution.x).map(|(n, x)| n * x).sum::<f64>();
+        match SourceItemKind::try_from(other) {
+            Ok(source) => {
+                if -net > EPSILON {
+                    out.raw.insert(source, -net);
+                }
+                let available = budget_of(source);
+                if dual > EPSILON && available > 0.0 && available + net < EPSILON {
+                    out.binding.push((source, dual));
+                }
+            }
+            Err(()) if net > EPSILON => {
+                out.byproducts.insert(other, net);
+            }
+            Err(()) => {}
+        }
+    }
+    out.binding.sort_by(|a, b| b.1.total_cmp(&a.1));
+    Ok(out)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use super::*;
+
+    fn approx(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-4
+    }
+
+    #[test]
+    fn iron_plates() {
+        let no_alternates = RecipeFilter { alternates: Some(BTreeSet::new()), ..Default::default() };
+        let budget = BTreeMap::from([(SourceItemKind::IronOre, 60.0)]);
+        let result = max_output(ItemKind::IronPlate, &budget, &no_alternates).unwrap();
+        // 60 ore → 60 ingots → 40 plates.
+        assert!(approx(result.rate, 40.0), "{result:?}");
+        assert!(approx(result.recipes[&RecipeKind::IronIngot], 2.0));
+        assert!(approx(result.raw[&SourceItemKind::IronOre], 60.0));
+        assert_eq!(result.binding.len(), 1);
+        assert_eq!(result.binding[0].0, SourceItemKind::IronOre);
+        assert!(approx(result.binding[0].1, 2.0 / 3.0));
+
+        // With water, alternates get more ingots out of the ore. Water is not used up.
+        let budget = BTreeMap::from([
+            (SourceItemKind::IronOre, 60.0),
+            (SourceItemKind::Water, 10_000.0),
+        ]);
+        let result = max_output(ItemKind::IronPlate, &budget, &RecipeFilter::default()).unwrap();
+        assert!(result.rate > 40.0 + 1e-4, "{result:?}");
+        assert!(result.binding.iter().all(|(source, _)| *source != SourceItemKind::Water));
+        assert_eq!(result.binding[0].0, SourceItemKind::IronOre);
+    }
+
+    #[test]
+    fn errors() {
+        let budget = BTreeMap::new();
+        let filter = RecipeFilter::default();
+        assert!(max_output(ItemKind::IronOre, &budget, &filter).is_err());
+        let nothing = max_output(ItemKind::IronPlate, &budget, &filter).unwrap();
+        assert!(nothing.rate.abs() < 1e-9);
+        assert!(nothing.recipes.is_empty());
+    }
+}
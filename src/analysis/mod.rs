@@ -0,0 +1,31 @@
+//! Analyses on top of the game data and plans.
+
+mod amplifiers;
+mod bom;
+mod bottleneck;
+mod breakdown;
+mod clocks;
+mod compare;
+mod flow;
+mod integer;
+mod lp;
+mod max_output;
+mod pareto;
+mod rebalance;
+
+
+pub use self::{
+    amplifiers::{amplifier_usage, AmplifierUsage, Amplifiers},
+    bom::{bill_of_materials, BillOfMaterials},
+    bottleneck::{bottlenecks, Bottlenecks, Change, ConveyorTiers, EndProduct, Suggestion},
+    breakdown::{breakdown, Breakdown, Cycle, RecipeSelection},
+    clocks::{clock_options, normalize_clocks, ClockGroup, ClockOption},
+    compare::{compare_recipes, ComparisonRow, RankBy, RecipeComparison},
+    flow::{flows, flows_with_capacity, Flows},
+    integer::{
+        optimize_integer, round_per_node, IntegerOptions, IntegerPlan, Minimize, NodeClocks,
+    },
+    max_output::{max_output, MaxOutput},
+    pareto::{pareto_frontier, ParetoFrontier},
+    rebalance::{apply_edits, rebalance, Edit, Rebalance, RebalanceOptions},
+};
@@ -0,0 +1,240 @@
+//! The trade-offs between raw resources, power and buildings when producing an item.
+//!
+//! Each axis gets a weight and the recipe mix with the lowest weighted cost is found with the same
+//! linear program as `max_output`: the most of the item that can be made for a weighted cost of 1.
+//! Going through a grid of weights finds the plans on the convex part of the frontier. Plans that
+//! lie in a dent between two others are not found, but a mix of those two is at least as good.
+//!
+//! All raw resources except water count on the raw axis. Water is plentiful and comes in much
+//! larger amounts, so counting it would make water-using alternates look expensive; it is free
+//! here and only shows up in the `raw` of each plan. Crude oil and nitrogen are as scarce as ores
+//! and count in m³ like one item each.
+
+use serde::Serialize;
+
+use crate::gamedata::{ItemKind, RecipeFilter, SourceItemKind};
+use super::{
+    lp::{self, Problem},
+    max_output::{RecipeModel, BUILDING_PENALTY},
+    Breakdown,
+};
+
+
+/// Number of steps per weight between 0 and 1.
+const WEIGHT_STEPS: usize = 10;
+/// Relative difference below which two costs count as equal.
+const TOLERANCE: f64 = 1e-6;
+const EPSILON: f64 = 1e-9;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ParetoFrontier {
+    pub item: ItemKind,
+    /// Cost of producing 1 unit/min of the item, for every plan that is not worse than another
+    /// one in all of raw resources, power and buildings. Sorted by raw resources without water.
+    pub plans: Vec<Breakdown>,
+}
+
+/// Cost of one building per minute of each recipe in `model`, on the three axes.
+struct Costs {
+    raw: Vec<f64>,
+    power: Vec<f64>,
+    buildings: Vec<f64>,
+}
+
+impl Costs {
+    fn new(model: &RecipeModel) -> Self {
+        let mut raw = vec![0.0; model.recipes.len()];
+        let scarce = |i| SourceItemKind::try_from(i).is_ok_and(|s| s != SourceItemKind::Water);
+        for &item in model.items.iter().filter(|&&i| scarce(i)) {
+            for (r, net) in raw.iter_mut().zip(model.net(item)) {
+                *r -= net;
+            }
+        }
+        Self {
+            raw,
+            // Power plants would pay for the rest of the chain, so they only count as free.
+            power: model.recipes.iter().map(|r| r.info().average_power().max(0.0)).collect(),
+            buildings: vec![1.0; model.recipes.len()],
+        }
+    }
+
+    /// Total of each axis for the buildings per recipe in `x`.
+    fn totals(&self, x: &[f64]) -> [f64; 3] {
+        [&self.raw, &self.power, &self.buildings]
+            .map(|costs| costs.iter().zip(x).map(|(c, x)| c * x).sum())
+    }
+}
+
+/// Finds the plans for producing `item` with the recipes allowed by `filter` that trade raw
+/// resources, power and buildings against each other.
+pub fn pareto_frontier(item: ItemKind, filter: &RecipeFilter) -> Result<ParetoFrontier, String> {
+    let model = RecipeModel::new(item, filter)?;
+    let produced = model.net(item);
+    let costs = Costs::new(&model);
+
+    // Intermediate items have to be produced at least as fast as they are consumed. Raw resources
+    // can't be produced beyond their use, or converting them back and forth could make them free.
+    let mut constraints = model.items.iter()
+        .filter(|&&i| i != item)
+        .map(|&other| {
+            let net = model.net(other);
+            match SourceItemKind::try_from(other) {
+                Ok(_) => (net, 0.0),
+                Err(()) => (net.into_iter().map(|v| -v).collect(), 0.0),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    // Solves for the weights, returning the buildings per recipe for 1/min of `item`.
+    let mut solve = |weights: [f64; 3]| {
+        let weighted = (0..model.recipes.len())
+            .map(|r| {
+                weights[0] * costs.raw[r] + weights[1] * costs.power[r]
+                    + weights[2] * costs.buildings[r]
+            })
+            .collect();
+        constraints.push((weighted, 1.0));
+        let solution = lp::maximize(&Problem {
+            objective: produced.iter().map(|p| p - BUILDING_PENALTY).collect(),
+            constraints: constraints.clone(),
+        });
+        constraints.pop();
+
+        let x = solution.ok()?.x;
+        let rate = produced.iter().zip(&x).map(|(p, x)| p * x).sum::<f64>();
+        (rate > EPSILON).then(|| x.into_iter().map(|x| x / rate).collect::<Vec<_>>())
+    };
+
+    // The axes have very different units, so the weights are relative to the lowest possible cost
+    // on each.
+    let mut scale = [1.0; 3];
+    for (axis, s) in scale.iter_mut().enumerate() {
+        let mut weights = [0.0; 3];
+        weights[axis] = 1.0;
+        if let Some(x) = solve(weights) {
+            let lowest = costs.totals(&x)[axis];
+            if lowest > EPSILON {
+                *s = lowest;
+            }
+        }
+    }
+
+    let mut plans: Vec<([f64; 3], Vec<f64>)> = vec![];
+    for a in 0..=WEIGHT_STEPS {
+        for b in 0..=WEIGHT_STEPS - a {
+            let c = WEIGHT_STEPS - a - b;
+            let weights = [a, b, c].map(|w| w as f64 / WEIGHT_STEPS as f64);
+            let Some(x) = solve([0, 1, 2].map(|i| weights[i] / scale[i])) else { continue };
+            let totals = costs.totals(&x);
+            if !plans.iter().any(|(other, _)| equal(other, &totals)) {
+                plans.push((totals, x));
+            }
+        }
+    }
+    if plans.is_empty() {
+        return Err(format!("'{}' can't be produced", item.info().name));
+    }
+
+    let mut frontier = plans.iter()
+        .filter(|(totals, _)| !plans.iter().any(|(other, _)| dominates(other, totals)))
+        .collect::<Vec<_>>();
+    frontier.sort_by(|(a, _), (b, _)| a[0].total_cmp(&b[0]));
+    let plans = frontier.into_iter()
+        .map(|(_, x)| breakdown(&model, item, x))
+        .collect();
+    Ok(ParetoFrontier { item, plans })
+}
+
+fn equal(a: &[f64; 3], b: &[f64; 3]) -> bool {
+    a.iter().zip(b).all(|(a, b)| (a - b).abs() <= TOLERANCE * a.abs().max(b.abs()).max(1.0))
+}
+
+/// Whether `a` is at most `b` on every axis and less on at least one.
+fn dominates(a: &[f64; 3], b: &[f64; 3]) -> bool {
+    let slack = |a: f64, b: f64| TOLERANCE * a.abs().max(b.abs()).max(1.0);
+    a.iter().zip(b).all(|(&a, &b)| a <= b + slack(a, b))
+        && a.iter().zip(b).any(|(&a, &b)| a < b - slack(a, b))
+}
+
+fn breakdown(model: &RecipeModel, target: ItemKind, x: &[f64]) -> Breakdown {
+    let mut out = Breakdown::default();
+    for (&recipe, &buildings) in model.recipes.iter().zip(x) {
+        if buildings > EPSILON {
+            out.recipes.insert(recipe, buildings);
+            out.buildings += buildings;
+            out.power += buildings * recipe.info().average_power();
+        }
+    }
+    for &item in model.items.iter().filter(|&&i| i != target) {
+        let net = model.net(item).iter().zip(x).map(|(n, x)| n * x).sum::<f64>();
+        match SourceItemKind::try_from(item) {
+            Ok(source) if -net > EPSILON => {
+                out.raw.insert(source, -net);
+            }
+            Err(()) if net > EPSILON => {
+                out.byproducts.insert(item, net);
+            }
+            _ => {}
+        }
+    }
+    out
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use crate::gamedata::RecipeKind;
+    use super::*;
+
+    #[test]
+    fn iron_plates() {
+        let no_alternates =
+            RecipeFilter { alternates: Some(BTreeSet::new()), ..Default::default() };
+        let frontier = pareto_frontier(ItemKind::IronPlate, &no_alternates).unwrap();
+        assert_eq!(frontier.plans.len(), 1);
+        let plan = &frontier.plans[0];
+        assert!((plan.raw[&SourceItemKind::IronOre] - 1.5).abs() < 1e-6, "{plan:?}");
+        assert!((plan.buildings - (1.5 / 30.0 + 1.0 / 20.0)).abs() < 1e-6);
+
+        // Alternates trade fewer resources for more power and buildings.
+        let frontier = pareto_frontier(ItemKind::IronPlate, &RecipeFilter::default()).unwrap();
+        let plans = &frontier.plans;
+        assert!(plans.len() > 1, "{plans:?}");
+        let least_raw = &plans[0];
+        let least_power = plans.iter().min_by(|a, b| a.power.total_cmp(&b.power)).unwrap();
+        assert!(least_raw.total_solids() < least_power.total_solids());
+        assert!(least_raw.power > least_power.power);
+    }
+
+    #[test]
+    fn water_is_not_raw() {
+        // Pure Iron Ingot needs 0.54 ore and 0.31 water per ingot. Counting the water would put it
+        // behind Leached Iron Ingot (0.5 ore, 0.1 sulfur and 0.1 water), but water doesn't count.
+        let frontier = pareto_frontier(ItemKind::IronIngot, &RecipeFilter::default()).unwrap();
+        let plans = &frontier.plans;
+        let pure = &plans[0];
+        assert_eq!(pure.recipes.keys().collect::<Vec<_>>(), [&RecipeKind::PureIronIngot]);
+        assert!((pure.total_solids() - 35.0 / 65.0).abs() < 1e-6, "{pure:?}");
+        assert!((pure.total_fluids() - 20.0 / 65.0).abs() < 1e-6, "{pure:?}");
+        assert!(plans[1].recipes.contains_key(&RecipeKind::LeachedIronIngot), "{plans:?}");
+        assert!(plans[1].total_raw() < pure.total_raw());
+    }
+
+    #[test]
+    fn crude_oil_is_raw() {
+        // The Plastic recipe needs 1.5 oil per plastic and no solids. If oil were free, it would
+        // beat the recycling loops on every axis and be the only plan.
+        let frontier = pareto_frontier(ItemKind::Plastic, &RecipeFilter::default()).unwrap();
+        let plans = &frontier.plans;
+        assert!(plans.len() > 1, "{plans:?}");
+        let oil = |plan: &Breakdown| plan.raw[&SourceItemKind::CrudeOil];
+        assert!(oil(&plans[0]) < 0.5, "{plans:?}");
+        // Sorted by oil, even though the recycling loops use more water.
+        assert!(plans.windows(2).all(|w| oil(&w[0]) <= oil(&w[1])), "{plans:?}");
+        let last = plans.last().unwrap();
+        assert_eq!(last.recipes.keys().collect::<Vec<_>>(), [&RecipeKind::Plastic]);
+        assert!((oil(last) - 1.5).abs() < 1e-6, "{last:?}");
+    }
+}
@@ -0,0 +1,360 @@
+//! Edits making a plan balanced, so that no input is starved and no output backs up.
+//!
+//! Recipe nodes producing end products (i.e. with an unconnected output) keep their rate. From
+//! there, the demand is propagated upstream: splitters need what all their outputs take, mergers
+//! take from their inputs in the proportion they currently do, and every other recipe node has to
+//! produce what is taken from it. Loops are handled by repeating this until nothing changes.
+//! Finally, the rate of every recipe node is turned into buildings and clocks, and sources are set
+//! to what is taken from them.
+
+use std::num::NonZeroU32;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    export::fmt_number,
+    gamedata::GameData,
+    state::{Graph, Node, NodeId, Overclock},
+};
+use super::{
+    clocks::{total_clock, uniform, MIN_CLOCK},
+    flows, ClockGroup,
+};
+
+
+const MAX_ITERATIONS: usize = 10_000;
+const EPSILON: f64 = 1e-9;
+/// Utilization still counted as balanced, as clocks are rounded up.
+const TOLERANCE: f64 = 1e-4;
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[serde(default)]
+pub struct RebalanceOptions {
+    /// Change the clock of the existing buildings where possible, instead of keeping the clock and
+    /// changing the number of buildings.
+    pub prefer_clocks: bool,
+    /// Highest clock to use when changing clocks.
+    pub max_clock: Overclock,
+}
+
+impl Default for RebalanceOptions {
+    fn default() -> Self {
+        Self { prefer_clocks: false, max_clock: Overclock(2_500_000) }
+    }
+}
+
+/// A change of a single node, using the same fields as `Node`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum Edit {
+    #[serde(rename_all = "camelCase")]
+    Recipe { node: NodeId, buildings_count: NonZeroU32, overclock: Overclock },
+    Source { node: NodeId, rate: u32 },
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Rebalance {
+    /// Only nodes that change, in node order.
+    pub edits: Vec<Edit>,
+    /// Whether every recipe node gets all it needs and can get rid of its products with the edits
+    /// applied. Byproducts that are produced in excess can prevent this, see `warnings`.
+    pub balanced: bool,
+    pub warnings: Vec<String>,
+}
+
+/// Computes the edits needed to balance `graph` while keeping the rate of its end products, with
+/// pack recipes looked up in `data`.
+pub fn rebalance(graph: &Graph, data: &GameData, options: RebalanceOptions) -> Rebalance {
+    rebalance_with(graph, data, |total, count, clock| clocks_for(total, count, clock, options))
+}
+
+/// Like `rebalance`, but buildings and clocks for a total clock in `Overclock` units are chosen by
+/// `choose`, given the current number of buildings and clock. The result may be more than the
+/// total.
+pub(super) fn rebalance_with(
+    graph: &Graph,
+    data: &GameData,
+    choose: impl Fn(u64, NonZeroU32, Overclock) -> Option<ClockGroup>,
+) -> Rebalance {
+    let mut out = Rebalance::default();
+    let current = flows(graph, data);
+    let recipes = graph.nodes.iter().map(|n| n.recipe(data)).collect::<Vec<_>>();
+    let mut incoming = vec![vec![]; graph.nodes.len()];
+    let mut outgoing = vec![vec![]; graph.nodes.len()];
+    for (i, e) in graph.edges.iter().enumerate() {
+        incoming[e.target.node as usize].push(i);
+        outgoing[e.source.node as usize].push(i);
+    }
+
+    let is_end = |id: usize| match &recipes[id] {
+        Some(recipe) => (4..).take(recipe.outputs.len()).any(|h| {
+            !outgoing[id].iter().any(|&e| graph.edges[e].source.handle == h)
+        }),
+        None => false,
+    };
+    // Nodes with end products take all their buildings can, as nothing downstream limits them.
+    // So they are rounded first and the rest is planned for their rounded rate.
+    let end_groups = recipes.iter()
+        .enumerate()
+        .map(|(id, recipe)| match recipe {
+            Some(recipe) if is_end(id) => {
+                let total = recipe.buildings_count.get() as u64 * recipe.overclock.0 as u64;
+                choose(total, recipe.buildings_count, recipe.overclock)
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    // Rate of each recipe node, in buildings at 100%.
+    let mut rates = recipes.iter()
+        .zip(&end_groups)
+        .map(|(recipe, group)| match (recipe, group) {
+            (_, Some(group)) => group.count as f64 * f64::from(group.overclock),
+            (Some(recipe), None) => {
+                recipe.buildings_count.get() as f64 * f64::from(recipe.overclock)
+            }
+            _ => 0.0,
+        })
+        .collect::<Vec<_>>();
+
+    let mut demand = current.edges.clone();
+    let mut converged = false;
+    for _ in 0..MAX_ITERATIONS {
+        let mut new_demand = demand.clone();
+        let mut new_rates = rates.clone();
+        for (id, node) in graph.nodes.iter().enumerate() {
+            let (ins, outs) = (&incoming[id], &outgoing[id]);
+            // Nodes with nothing connected keep what currently goes in.
+            let taken = match outs.is_empty() {
+                true => ins.iter().map(|&e| current.edges[e]).sum::<f64>(),
+                false => outs.iter().map(|&e| demand[e]).sum::<f64>(),
+            };
+
+            match node {
+                Node::Source { .. } => {}
+                Node::Splitter { .. } | Node::SmartSplitter { .. } => {
+                    for &e in ins {
+                        new_demand[e] = taken;
+                    }
+                }
+                Node::Merger { .. } | Node::PriorityMerger { .. } => {
+                    let inflow = ins.iter().map(|&e| current.edges[e]).sum::<f64>();
+                    for &e in ins {
+                        new_demand[e] = match inflow > EPSILON {
+                            true => taken * current.edges[e] / inflow,
+                            false => taken / ins.len() as f64,
+                        };
+                    }
+                }
+                Node::Recipe { .. } | Node::PackRecipe { .. } => {
+                    let Some(recipe) = &recipes[id] else { continue };
+                    let per_building = recipe.crafts_per_minute;
+                    if !is_end(id) {
+                        let amplified = per_building * recipe.amplification();
+                        new_rates[id] = outs.iter()
+                            .map(|&e| {
+                                let handle = graph.edges[e].source.handle;
+                                demand[e] / (recipe.outputs[handle as usize - 4].amount * amplified)
+                            })
+                            .fold(0.0, f64::max);
+                    }
+                    for &e in ins {
+                        let handle = graph.edges[e].target.handle as usize;
+                        new_demand[e] =
+                            new_rates[id] * recipe.inputs[handle].amount * per_building;
+                    }
+                }
+            }
+        }
+
+        let changed = |old: &[f64], new: &[f64]| old.iter().zip(new).any(|(a, b)| {
+            (a - b).abs() > EPSILON * a.abs().max(1.0)
+        });
+        let done = !changed(&demand, &new_demand) && !changed(&rates, &new_rates);
+        demand = new_demand;
+        rates = new_rates;
+        if done {
+            converged = true;
+            break;
+        }
+    }
+    if !converged {
+        let warning = "demand did not settle, a loop probably needs more than it returns";
+        out.warnings.push(warning.into());
+    }
+
+    let mut balanced = graph.clone();
+    for (id, node) in balanced.nodes.iter_mut().enumerate() {
+        let node_id = id as NodeId;
+        match (node, &recipes[id]) {
+            (node, Some(recipe)) => {
+                let Some((buildings_count, overclock)) = node.clock_mut() else { continue };
+                let name = recipe.name;
+                if rates[id] < EPSILON {
+                    out.warnings.push(format!("node {id} ({name}) is not needed"));
+                    continue;
+                }
+                let total = total_clock(rates[id]);
+                let group = end_groups[id].clone()
+                    .or_else(|| choose(total, *buildings_count, *overclock));
+                let Some(group) = group else {
+                    out.warnings.push(format!("node {id} ({name}) needs too many buildings"));
+                    continue;
+                };
+                let count = NonZeroU32::new(group.count).unwrap();
+                if (count, group.overclock) != (*buildings_count, *overclock) {
+                    *buildings_count = count;
+                    *overclock = group.overclock;
+                    out.edits.push(Edit::Recipe {
+                        node: node_id,
+                        buildings_count: count,
+                        overclock: group.overclock,
+                    });
+                }
+            }
+            (Node::Source { rate, .. }, None) if !outgoing[id].is_empty() => {
+                let taken = outgoing[id].iter().map(|&e| demand[e]).sum::<f64>();
+                let new_rate = (taken - TOLERANCE).ceil().max(0.0) as u32;
+                if new_rate != *rate {
+                    *rate = new_rate;
+                    out.edits.push(Edit::Source { node: node_id, rate: new_rate });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // Outputs that produce more than is taken from them cannot be fixed by changing rates.
+    for (id, recipe) in recipes.iter().enumerate() {
+        let Some(recipe) = recipe else { continue };
+        let multiplier = rates[id] * recipe.crafts_per_minute * recipe.amplification();
+        for (handle, output) in (4..).zip(recipe.outputs.iter()) {
+            let edges = outgoing[id].iter().filter(|&&e| graph.edges[e].source.handle == handle);
+            let taken = edges.clone().map(|&e| demand[e]).sum::<f64>();
+            let excess = output.amount * multiplier - taken;
+            if edges.count() > 0 && excess > TOLERANCE {
+                out.warnings.push(format!(
+                    "node {id} ({}): {} {}/min more than taken",
+                    recipe.name,
+                    fmt_number(excess),
+                    output.name,
+                ));
+            }
+        }
+    }
+
+    // Every recipe node has to run at least at the rate needed.
+    let after = flows(&balanced, data);
+    out.balanced = converged
+        && after.converged
+        && balanced.nodes.iter().enumerate().all(|(id, node)| match node.recipe(data) {
+            Some(recipe) => {
+                let total = recipe.buildings_count.get() as f64 * f64::from(recipe.overclock);
+                after.utilization[id].unwrap_or(0.0) * total > rates[id] * (1.0 - TOLERANCE)
+            }
+            None => true,
+        });
+    out
+}
+
+/// Applies `edits` to `graph`, ignoring edits for nodes of another type.
+pub fn apply_edits(graph: &mut Graph, edits: &[Edit]) {
+    for edit in edits {
+        match *edit {
+            Edit::Recipe { node, buildings_count: new_count, overclock: new_clock } => {
+                if let Some((buildings_count, overclock)) = graph.nodes[node as usize].clock_mut() {
+                    *buildings_count = new_count;
+                    *overclock = new_clock;
+                }
+            }
+            Edit::Source { node, rate: new_rate } => {
+                if let Node::Source { rate, .. } = &mut graph.nodes[node as usize] {
+                    *rate = new_rate;
+                }
+            }
+        }
+    }
+}
+
+/// Buildings and clock for a total clock of at least `total` `Overclock` units, changing either the
+/// clock or the number of buildings of the current configuration. When keeping the clock, the
+/// buildings may have more capacity than needed.
+fn clocks_for(
+    total: u64,
+    count: NonZeroU32,
+    clock: Overclock,
+    options: RebalanceOptions,
+) -> Option<ClockGroup> {
+    if options.prefer_clocks {
+        let per_building = total.div_ceil(count.get() as u64);
+        if (MIN_CLOCK as u64..=options.max_clock.0 as u64).contains(&per_building) {
+            let overclock = Overclock(per_building as u32);
+            return Some(ClockGroup { count: count.get(), overclock });
+        }
+        uniform(total, options.max_clock.0.max(MIN_CLOCK))
+    } else {
+        let count = total.div_ceil(clock.0.max(MIN_CLOCK) as u64);
+        Some(ClockGroup { count: count.try_into().ok()?, overclock: clock })
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::export::tests::example;
+    use super::*;
+
+    #[test]
+    fn example_plan() {
+        // Plates (90 ingots/min) and rods (30) need 120 ingots, i.e. 4 smelters at 100%. Keeping
+        // the clock of 150%, that takes 3 smelters.
+        let graph = example();
+        let result = rebalance(&graph, &GameData::builtin(), RebalanceOptions::default());
+        assert!(result.balanced, "{result:?}");
+        assert!(result.warnings.is_empty());
+        assert_eq!(result.edits, [
+            Edit::Source { node: 0, rate: 120 },
+            Edit::Recipe {
+                node: 1,
+                buildings_count: NonZeroU32::new(3).unwrap(),
+                overclock: Overclock(1_500_000),
+            },
+        ]);
+        let mut balanced = graph.clone();
+        apply_edits(&mut balanced, &result.edits);
+        let again = rebalance(&balanced, &GameData::builtin(), RebalanceOptions::default());
+        assert!(again.edits.is_empty());
+
+        let options = RebalanceOptions { prefer_clocks: true, ..Default::default() };
+        let result = rebalance(&graph, &GameData::builtin(), options);
+        assert!(result.balanced);
+        assert_eq!(result.edits[1], Edit::Recipe {
+            node: 1,
+            buildings_count: NonZeroU32::new(2).unwrap(),
+            overclock: Overclock(2_000_000),
+        });
+
+        // Applying the edits again changes nothing.
+        let mut balanced = graph.clone();
+        apply_edits(&mut balanced, &result.edits);
+        let again = rebalance(&balanced, &GameData::builtin(), options);
+        assert!(again.edits.is_empty(), "{:?}", again.edits);
+        assert!(again.balanced);
+    }
+
+    #[test]
+    fn clock_limits() {
+        let two = NonZeroU32::new(2).unwrap();
+        // 4 buildings at 100% do not fit into 2 buildings at up to 150%.
+        let options = RebalanceOptions { prefer_clocks: true, max_clock: Overclock(1_500_000) };
+        let group = clocks_for(4_000_000, two, Overclock(1_000_000), options);
+        assert_eq!(group, Some(ClockGroup { count: 3, overclock: Overclock(1_333_334) }));
+
+        // Without preferring clocks, the clock is kept.
+        let options = RebalanceOptions::default();
+        let group = clocks_for(500_000, two, Overclock(1_000_000), options);
+        assert_eq!(group, Some(ClockGroup { count: 1, overclock: Overclock(1_000_000) }));
+        let group = clocks_for(4_000_000, two, Overclock(1_500_000), options);
+        assert_eq!(group, Some(ClockGroup { count: 3, overclock: Overclock(1_500_000) }));
+    }
+}
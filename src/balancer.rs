@@ -0,0 +1,276 @@
+//! Networks of splitters and mergers that divide one input in a given ratio.
+//!
+//! A splitter divides its input evenly among its connected outputs, so a tree of splitters using 2
+//! or 3 outputs each divides the input into N equal parts, where N is a product of 2s and 3s. For
+//! a ratio with a total of S parts, we take the smallest such N ≥ S, route the right number of
+//! parts to every output and loop the remaining N - S parts back to a merger in front of the tree.
+//! In steady state, the tree then receives N / S times the input, so every output gets exactly its
+//! share of the input.
+//!
+//! Subtrees whose parts all go to the same place are not built, and parts going to the same place
+//! are combined by mergers.
+
+use serde::Serialize;
+
+use crate::{
+    layout,
+    state::{Edge, Graph, GraphHandle, HandleId, Node, NodeId, Pos},
+};
+
+
+/// Networks with more parts get too large to be useful.
+const MAX_PARTS: u64 = 1024;
+
+/// A subgraph dividing its input in a given ratio.
+#[derive(Serialize)]
+pub struct RatioNetwork {
+    pub graph: Graph,
+    /// Handle the input has to be connected to.
+    pub input: GraphHandle,
+    /// Handle each output leaves the network at, in the order of the ratios.
+    pub outputs: Vec<GraphHandle>,
+}
+
+/// Converts rates (or any positive numbers) into the smallest integer ratio, taking 4 decimal
+/// digits into account.
+pub fn ratios_from_rates(rates: &[f64]) -> Result<Vec<u32>, String> {
+    let scaled = rates.iter()
+        .map(|&rate| {
+            let scaled = (rate * 10_000.0).round();
+            if !(1.0..u32::MAX as f64).contains(&scaled) {
+                return Err(format!("invalid rate {rate}"));
+            }
+            Ok(scaled as u32)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let divisor = scaled.iter().fold(0, |a, &b| gcd(a, b)).max(1);
+    Ok(scaled.into_iter().map(|v| v / divisor).collect())
+}
+
+/// Builds a network dividing one input into outputs with the given `ratios`, e.g. `[1, 2]` for
+/// one third and two thirds. All nodes are positioned.
+pub fn ratio_network(ratios: &[u32]) -> Result<RatioNetwork, String> {
+    if ratios.len() < 2 {
+        return Err("at least two outputs are needed".into());
+    }
+    if ratios.contains(&0) {
+        return Err("ratios must be positive".into());
+    }
+    let divisor = ratios.iter().fold(0, |a, &b| gcd(a, b));
+    let parts = ratios.iter().map(|&r| (r / divisor) as u64).collect::<Vec<_>>();
+    let total = parts.iter().sum::<u64>();
+    if total > MAX_PARTS {
+        return Err(format!("ratio has {total} parts, at most {MAX_PARTS} are supported"));
+    }
+
+    // Destination of every part: the index of the output, or `ratios.len()` for the loop.
+    let factors = factors_for(total);
+    let num_parts = factors.iter().product::<u64>();
+    let looped = ratios.len();
+    let dests = parts.iter()
+        .enumerate()
+        .flat_map(|(i, &n)| std::iter::repeat_n(i, n as usize))
+        .chain(std::iter::repeat_n(looped, (num_parts - total) as usize))
+        .collect::<Vec<_>>();
+
+    let mut b = Builder {
+        graph: Graph { nodes: vec![], edges: vec![] },
+        feeds: vec![vec![]; looped + 1],
+    };
+    let Part::Splitter(root) = b.split(&dests, &factors) else {
+        unreachable!("at least two different destinations");
+    };
+
+    let mut feeds = std::mem::take(&mut b.feeds);
+    let loops = feeds.pop().unwrap();
+    let outputs = feeds.into_iter().map(|f| b.merge(f, 1)[0]).collect();
+    let input = if loops.is_empty() {
+        GraphHandle { node: root, handle: 0 }
+    } else {
+        let entry = b.add(Node::Merger { pos: Pos { x: 0, y: 0 } });
+        b.connect(GraphHandle { node: entry, handle: 3 }, GraphHandle { node: root, handle: 0 });
+        for (loop_back, handle) in b.merge(loops, 2).into_iter().zip(1..) {
+            b.connect(loop_back, GraphHandle { node: entry, handle });
+        }
+        GraphHandle { node: entry, handle: 0 }
+    };
+
+    let mut graph = b.graph;
+    layout::auto_layout(&mut graph);
+    Ok(RatioNetwork { graph, input, outputs })
+}
+
+/// Factors (3s first, then 2s) of the smallest product of 2s and 3s that is at least `total`.
+fn factors_for(total: u64) -> Vec<u64> {
+    let (threes, twos) = (0..)
+        .map_while(|threes| {
+            let power = 3u64.pow(threes);
+            (power < total * 3).then(|| {
+                let twos = total.div_ceil(power).next_power_of_two().ilog2();
+                (threes, twos)
+            })
+        })
+        .min_by_key(|&(threes, twos)| 3u64.pow(threes) << twos)
+        .unwrap();
+    let mut factors = vec![3; threes as usize];
+    factors.resize((threes + twos) as usize, 2);
+    factors
+}
+
+fn gcd(a: u32, b: u32) -> u32 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+enum Part {
+    Splitter(NodeId),
+    /// All parts go to the same destination, no splitter needed.
+    Dest(usize),
+}
+
+struct Builder {
+    graph: Graph,
+    /// Handles feeding each destination.
+    feeds: Vec<Vec<GraphHandle>>,
+}
+
+impl Builder {
+    fn add(&mut self, node: Node) -> NodeId {
+        self.graph.nodes.push(node);
+        (self.graph.nodes.len() - 1) as NodeId
+    }
+
+    fn connect(&mut self, source: GraphHandle, target: GraphHandle) {
+        self.graph.edges.push(Edge { source, target });
+    }
+
+    /// Builds the splitter tree dividing its input into the parts given by `dests`.
+    fn split(&mut self, dests: &[usize], factors: &[u64]) -> Part {
+        if dests.iter().all(|&d| d == dests[0]) {
+            return Part::Dest(dests[0]);
+        }
+
+        let (&factor, rest) = factors.split_first().expect("more factors for more parts");
+        let splitter = self.add(Node::Splitter { pos: Pos { x: 0, y: 0 } });
+        let handles: &[HandleId] = if factor == 2 { &[1, 3] } else { &[1, 2, 3] };
+        for (chunk, &handle) in dests.chunks(dests.len() / factor as usize).zip(handles) {
+            let source = GraphHandle { node: splitter, handle };
+            match self.split(chunk, rest) {
+                Part::Splitter(child) => {
+                    self.connect(source, GraphHandle { node: child, handle: 0 });
+                }
+                Part::Dest(dest) => self.feeds[dest].push(source),
+            }
+        }
+        Part::Splitter(splitter)
+    }
+
+    /// Combines `feeds` with mergers until at most `max` handles are left.
+    fn merge(&mut self, mut feeds: Vec<GraphHandle>, max: usize) -> Vec<GraphHandle> {
+        while feeds.len() > max {
+            let n = (feeds.len() - max + 1).min(3);
+            let merger = self.add(Node::Merger { pos: Pos { x: 0, y: 0 } });
+            for (feed, handle) in feeds.drain(..n).collect::<Vec<_>>().into_iter().zip(0..) {
+                self.connect(feed, GraphHandle { node: merger, handle });
+            }
+            feeds.push(GraphHandle { node: merger, handle: 3 });
+        }
+        feeds
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Rates leaving the outputs of `network` for an input of 1, by iterating until the flows
+    /// converge.
+    fn simulate(network: &RatioNetwork) -> Vec<f64> {
+        let graph = &network.graph;
+        let mut rates = vec![0.0; graph.edges.len()];
+        let inflow = |node: NodeId, rates: &[f64]| {
+            let external = (network.input.node == node) as u8 as f64;
+            let internal = graph.edges.iter()
+                .zip(rates)
+                .filter(|(e, _)| e.target.node == node)
+                .map(|(_, r)| r)
+                .sum::<f64>();
+            external + internal
+        };
+        let outflow = |handle: GraphHandle, rates: &[f64]| {
+            let outputs = graph.edges.iter()
+                .filter(|e| e.source.node == handle.node)
+                .count()
+                + network.outputs.iter().filter(|o| o.node == handle.node).count();
+            inflow(handle.node, rates) / outputs as f64
+        };
+        for _ in 0..2000 {
+            rates = graph.edges.iter().map(|e| outflow(e.source, &rates)).collect();
+        }
+        network.outputs.iter().map(|&o| outflow(o, &rates)).collect()
+    }
+
+    fn check(ratios: &[u32]) -> RatioNetwork {
+        let network = ratio_network(ratios).unwrap();
+        let total = ratios.iter().sum::<u32>() as f64;
+        for (rate, &ratio) in simulate(&network).iter().zip(ratios) {
+            assert!((rate - ratio as f64 / total).abs() < 1e-9, "{ratios:?}: {rate}");
+        }
+        for edge in &network.graph.edges {
+            let (source, target) = (&network.graph.nodes[edge.source.node as usize], edge.target);
+            assert!(match source {
+                Node::Splitter { .. } => (1..=3).contains(&edge.source.handle),
+                _ => edge.source.handle == 3,
+            });
+            assert!(match network.graph.node(target.node) {
+                Node::Merger { .. } => target.handle <= 2,
+                _ => target.handle == 0,
+            });
+        }
+        network
+    }
+
+    #[test]
+    fn networks() {
+        let even = check(&[1, 1]);
+        assert_eq!(even.graph.nodes.len(), 1);
+        let outputs = even.outputs.iter().map(|o| (o.node, o.handle)).collect::<Vec<_>>();
+        assert_eq!(outputs, [(0, 1), (0, 3)]);
+
+        // One splitter, two outputs merged.
+        let third = check(&[1, 2]);
+        assert_eq!(third.graph.nodes.len(), 2);
+
+        // 8 parts without loop, 5 parts in 6 with one looped back.
+        let eighths = check(&[3, 5]);
+        assert!(eighths.graph.node(eighths.input.node).is_splitter());
+        let fifths = check(&[1, 1, 1, 1, 1]);
+        assert!(fifths.graph.node(fifths.input.node).is_split_merge());
+        assert!(!fifths.graph.node(fifths.input.node).is_splitter());
+
+        check(&[2, 4, 6]);
+        check(&[7, 11, 13]);
+        check(&[1, 100]);
+    }
+
+    #[test]
+    fn factors() {
+        assert_eq!(factors_for(2), [2]);
+        assert_eq!(factors_for(5), [3, 2]);
+        assert_eq!(factors_for(7), [2, 2, 2]);
+        assert_eq!(factors_for(17), [3, 3, 2]);
+        assert_eq!(factors_for(13), [2, 2, 2, 2]);
+        assert_eq!(factors_for(1024), [2; 10]);
+    }
+
+    #[test]
+    fn errors_and_rates() {
+        assert!(ratio_network(&[1]).is_err());
+        assert!(ratio_network(&[1, 0]).is_err());
+        assert!(ratio_network(&[1, 2000]).is_err());
+
+        assert_eq!(ratios_from_rates(&[7.5, 15.0]), Ok(vec![1, 2]));
+        assert_eq!(ratios_from_rates(&[0.3333, 0.6667]), Ok(vec![3333, 6667]));
+        assert!(ratios_from_rates(&[1.0, -2.0]).is_err());
+    }
+}
@@ -20,6 +20,11 @@
 //! - Edges
 //!     - num edges: 8 or 16 bits
 //!     - edges
+//! - Extensions (optional, each byte aligned): 8 bit tag followed by its payload. Digests without
+//!   extensions end after the edges, so older digests stay valid.
+//!     - 1: data packs: num packs, then ID and version of each
+//!     - 2: Somersloops: num recipe nodes with any, then node ID and count of each
+//!     - 3: pack recipes: num pack recipe nodes, then node ID and recipe ID of each
 //!
 //! Here is an unordered list of possible improvements:
 //! - Node positions: here is still redundancy. One could use sub-bit encoding, but that requires to
@@ -35,11 +40,18 @@
 
 use std::{cmp::{max, min}, collections::BTreeMap, num::NonZero, ops::{Add, Not, Shl, Shr, Sub}};
 
-use crate::{gamedata::{ItemKind, RecipeKind, SourceItemKind}, state::{self, HandleId, NodeId}};
+use crate::{
+    gamedata::{self, ItemKind, Migration, PackRef, RecipeKind, SourceItemKind},
+    state::{self, HandleId, NodeId},
+};
 
 
 const MIN_POS_BITS: u8 = 4;
 
+const EXT_DATA_PACKS: u8 = 1;
+const EXT_SOMERSLOOPS: u8 = 2;
+const EXT_PACK_RECIPES: u8 = 3;
+
 pub fn encode(state: &state::Input) -> Vec<u8> {
     let mut buf = BitBuf::new();
     buf.write_u8(state.version as u8);
@@ -47,7 +59,7 @@ pub fn encode(state: &state::Input) -> Vec<u8> {
 
     // ----- Write nodes ------------------------------
     buf.write_len(g.nodes.len());
-    if g.nodes.len() > 0 {
+    if !g.nodes.is_empty() {
         // ----- Write positions
 
         // Translate all nodes such that the node with the lowest x has x = 0, and same for y.
@@ -88,7 +100,13 @@ pub fn encode(state: &state::Input) -> Vec<u8> {
                     write_recipe_kind(&mut buf, recipe);
                     write_overclock(&mut buf, overclock);
                     write_building_count(&mut buf, buildings_count);
-                    // TODO: power amplification
+                    // Somersloops are stored in an extension, as they are rare.
+                }
+                state::Node::PackRecipe { buildings_count, overclock, .. } => {
+                    buf.write_bits(6, 3); // Tag
+                    write_overclock(&mut buf, overclock);
+                    write_building_count(&mut buf, buildings_count);
+                    // The recipe ID is a string, which is stored in an extension.
                 }
                 state::Node::Merger { .. } => buf.write_bits(1, 3), // Tag
                 state::Node::Splitter { .. } => buf.write_bits(2, 3), // Tag
@@ -97,6 +115,19 @@ pub fn encode(state: &state::Input) -> Vec<u8> {
                     write_source_item_kind(&mut buf, item);
                     write_source_rate(&mut buf, rate);
                 }
+                state::Node::SmartSplitter { ref filters, .. } => {
+                    buf.write_bits(4, 3); // Tag
+                    for output in filters {
+                        buf.write_len(output.len());
+                        for &filter in output {
+                            write_split_filter(&mut buf, filter);
+                        }
+                    }
+                }
+                state::Node::PriorityMerger { priority, .. } => {
+                    buf.write_bits(5, 3); // Tag
+                    write_input_priority(&mut buf, priority);
+                }
             }
         }
     }
@@ -105,23 +136,83 @@ pub fn encode(state: &state::Input) -> Vec<u8> {
     // ----- Write edges ------------------------------
     buf.finish_byte();
     buf.write_len(g.edges.len());
-    if g.edges.len() > 0 {
-        let mut coder = EdgeCoder::new(&g.nodes);
+    if !g.edges.is_empty() {
+        let mut coder = EdgeCoder::new(&g.nodes, &[]);
         coder.encode(&mut buf, g);
     }
 
+
+    // ----- Write extensions -------------------------
+    if !state.data_packs.is_empty() {
+        buf.finish_byte();
+        buf.write_u8(EXT_DATA_PACKS);
+        buf.write_len(state.data_packs.len());
+        for pack in &state.data_packs {
+            buf.write_str(&pack.id);
+            buf.write_str(&pack.version);
+        }
+    }
+    let somersloops = g.nodes.iter()
+        .enumerate()
+        .filter_map(|(id, n)| match *n {
+            state::Node::Recipe { somersloops, .. }
+            | state::Node::PackRecipe { somersloops, .. } if somersloops > 0 => {
+                Some((id, somersloops))
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    if !somersloops.is_empty() {
+        buf.finish_byte();
+        buf.write_u8(EXT_SOMERSLOOPS);
+        buf.write_len(somersloops.len());
+        for (id, count) in somersloops {
+            buf.write_len(id);
+            buf.write_u8(count);
+        }
+    }
+    let pack_recipes = g.nodes.iter()
+        .enumerate()
+        .filter_map(|(id, n)| match n {
+            state::Node::PackRecipe { recipe, .. } => Some((id, recipe)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    if !pack_recipes.is_empty() {
+        buf.finish_byte();
+        buf.write_u8(EXT_PACK_RECIPES);
+        buf.write_len(pack_recipes.len());
+        for (id, recipe) in pack_recipes {
+            buf.write_len(id);
+            buf.write_str(recipe);
+        }
+    }
+
     buf.buf
 }
 
-pub fn decode(data: &[u8]) -> Result<state::Input, String> {
+/// Decodes a digest. Also returns warnings, e.g. about recipes that were replaced because they no
+/// longer exist in the game.
+pub fn decode(data: &[u8]) -> Result<(state::Input, Vec<String>), String> {
+    decode_with(data, gamedata::resolve_stable_id)
+}
+
+/// Like `decode`, with `resolve` instead of `gamedata::resolve_stable_id` for recipe IDs.
+fn decode_with<'m>(
+    data: &[u8],
+    resolve: impl Fn(u16) -> Result<(RecipeKind, Option<&'m Migration>), String>,
+) -> Result<(state::Input, Vec<String>), String> {
     // Also check out `encode` for more explanation!
 
     let mut buf = BitReader::new(data);
+    let mut warnings = Vec::new();
     let version = buf.read_u8();
 
     // ----- Read nodes ------------------------------
     let num_nodes = buf.read_len();
     let mut nodes = Vec::with_capacity(num_nodes);
+    // For each node, the migration of its recipe if that was removed from the game.
+    let mut migrations = Vec::with_capacity(num_nodes);
     if num_nodes > 0 {
         // ----- Read positions
         let bits_x = buf.read_bits(4) as u8 + MIN_POS_BITS;
@@ -134,15 +225,20 @@ pub fn decode(data: &[u8]) -> Result<state::Input, String> {
         }
 
         // ----- Read node payload
-        for i in 0..num_nodes {
-            let pos = positions[i];
+        for pos in positions {
+            let mut migration = None;
             let node = match buf.read_bits(3) {
-                0 => state::Node::Recipe {
-                    pos,
-                    recipe: read_recipe_kind(&mut buf),
-                    overclock: read_overclock(&mut buf),
-                    buildings_count: read_building_count(&mut buf),
-                },
+                0 => {
+                    let (recipe, m) = read_recipe_kind(&mut buf, &resolve)?;
+                    migration = m;
+                    state::Node::Recipe {
+                        pos,
+                        recipe,
+                        overclock: read_overclock(&mut buf),
+                        buildings_count: read_building_count(&mut buf),
+                        somersloops: 0,
+                    }
+                }
                 1 => state::Node::Merger { pos },
                 2 => state::Node::Splitter { pos },
                 3 => state::Node::Source {
@@ -150,9 +246,27 @@ pub fn decode(data: &[u8]) -> Result<state::Input, String> {
                     item: read_source_item_kind(&mut buf),
                     rate: read_source_rate(&mut buf),
                 },
+                4 => {
+                    let mut output = || -> Result<_, String> {
+                        (0..buf.read_len()).map(|_| read_split_filter(&mut buf)).collect()
+                    };
+                    let filters = [output()?, output()?, output()?];
+                    state::Node::SmartSplitter { pos, filters }
+                }
+                5 => state::Node::PriorityMerger { pos, priority: read_input_priority(&mut buf)? },
+                // The recipe is filled in from its extension.
+                6 => state::Node::PackRecipe {
+                    pos,
+                    recipe: String::new(),
+                    overclock: read_overclock(&mut buf),
+                    buildings_count: read_building_count(&mut buf),
+                    somersloops: 0,
+                },
                 _ => Err("invalid node tag")?,
             };
 
+            warnings.extend(migration.map(Migration::warning));
+            migrations.push(migration);
             nodes.push(node);
         }
     }
@@ -162,16 +276,83 @@ pub fn decode(data: &[u8]) -> Result<state::Input, String> {
     let num_edges = buf.read_len();
     let mut edges = Vec::new();
     if num_edges > 0 {
-        let mut coder = EdgeCoder::new(&nodes);
+        let mut coder = EdgeCoder::new(&nodes, &migrations);
         edges = coder.decode(&mut buf, num_edges, &nodes);
     }
 
-    Ok(state::Input {
+    // Edges were encoded with the handles of removed recipes, move them to the successors'.
+    edges.retain_mut(|e| [&mut e.source, &mut e.target].into_iter().all(|h| {
+        let Some(m) = migrations[h.node as usize] else { return true };
+        match m.handle(h.handle) {
+            Ok(handle) => {
+                h.handle = handle;
+                true
+            }
+            Err(warning) => {
+                warnings.push(warning);
+                false
+            }
+        }
+    }));
+
+    // ----- Read extensions -------------------------
+    let mut data_packs = Vec::new();
+    loop {
+        buf.finish_byte();
+        if buf.is_at_end() {
+            break;
+        }
+        match buf.read_u8() {
+            EXT_DATA_PACKS => {
+                for _ in 0..buf.read_len() {
+                    let id = buf.read_str()?;
+                    let version = buf.read_str()?;
+                    data_packs.push(PackRef { id, version });
+                }
+            }
+            EXT_SOMERSLOOPS => {
+                for _ in 0..buf.read_len() {
+                    let id = buf.read_len();
+                    let count = buf.read_u8();
+                    match nodes.get_mut(id) {
+                        Some(state::Node::Recipe { recipe, .. })
+                            if count > recipe.info().building.somersloop_slots() =>
+                        {
+                            Err(format!("too many Somersloops for node {id}"))?
+                        }
+                        // The building of pack recipes is only known from the data packs.
+                        Some(state::Node::Recipe { somersloops, .. })
+                        | Some(state::Node::PackRecipe { somersloops, .. }) => *somersloops = count,
+                        _ => Err("Somersloops for invalid node")?,
+                    }
+                }
+            }
+            EXT_PACK_RECIPES => {
+                for _ in 0..buf.read_len() {
+                    let id = buf.read_len();
+                    match nodes.get_mut(id) {
+                        Some(state::Node::PackRecipe { recipe, .. }) => *recipe = buf.read_str()?,
+                        _ => Err("pack recipe for invalid node")?,
+                    }
+                }
+            }
+            tag => Err(format!("unknown digest extension {tag}"))?,
+        }
+    }
+    let missing = nodes.iter()
+        .any(|n| matches!(n, state::Node::PackRecipe { recipe, .. } if recipe.is_empty()));
+    if missing {
+        Err("pack recipe node without recipe")?;
+    }
+
+    let input = state::Input {
+        data_packs,
         version: version as u32,
         state: state::State {
             graph: state::Graph { nodes, edges },
         },
-    })
+    };
+    Ok((input, warnings))
 }
 
 fn required_bits_for(count: u64) -> u8 {
@@ -196,12 +377,17 @@ fn write_recipe_kind(buf: &mut BitBuf, v: RecipeKind) {
     // would help here, even if we sort the recipe IDs by usage frequency, as the IDs
     // are not _that_ biased towards low values. It's just intuition, I did not
     // test it.
-    buf.write_bits((v as u16).into(), 9);
+    //
+    // We store the stable ID and not the discriminant, as the latter can change whenever the game
+    // data is regenerated.
+    buf.write_bits(v.stable_id().into(), 9);
 }
 
-fn read_recipe_kind(buf: &mut BitReader) -> RecipeKind {
-    let id = buf.read_bits(9) as u16;
-    RecipeKind::try_from(id).unwrap()
+fn read_recipe_kind<'m>(
+    buf: &mut BitReader,
+    resolve: impl Fn(u16) -> Result<(RecipeKind, Option<&'m Migration>), String>,
+) -> Result<(RecipeKind, Option<&'m Migration>), String> {
+    resolve(buf.read_bits(9) as u16)
 }
 
 fn write_overclock(buf: &mut BitBuf, v: state::Overclock) {
@@ -298,6 +484,43 @@ fn read_source_item_kind(buf: &mut BitReader) -> SourceItemKind {
     SourceItemKind::try_from(id).unwrap()
 }
 
+fn write_split_filter(buf: &mut BitBuf, v: state::SplitFilter) {
+    // 2 bits for the kind, followed by the item if it's an item filter. Like recipes, items are
+    // stored by their stable ID, of which there are at most 256 (see `data/gen.ts`).
+    match v {
+        state::SplitFilter::Item(item) => {
+            buf.write_bits(0, 2);
+            buf.write_u8(item.stable_id());
+        }
+        state::SplitFilter::Rule(state::SplitRule::Any) => buf.write_bits(1, 2),
+        state::SplitFilter::Rule(state::SplitRule::AnyUndefined) => buf.write_bits(2, 2),
+        state::SplitFilter::Rule(state::SplitRule::Overflow) => buf.write_bits(3, 2),
+    }
+}
+
+fn read_split_filter(buf: &mut BitReader) -> Result<state::SplitFilter, String> {
+    Ok(match buf.read_bits(2) {
+        0 => {
+            let item = ItemKind::from_stable_id(buf.read_u8()).ok_or("invalid item in filter")?;
+            state::SplitFilter::Item(item)
+        }
+        1 => state::SplitFilter::Rule(state::SplitRule::Any),
+        2 => state::SplitFilter::Rule(state::SplitRule::AnyUndefined),
+        _ => state::SplitFilter::Rule(state::SplitRule::Overflow),
+    })
+}
+
+fn write_input_priority(buf: &mut BitBuf, v: state::InputPriority) {
+    // There are only 6 possible orders.
+    let idx = state::InputPriority::ALL.iter().position(|&p| p == v).unwrap();
+    buf.write_bits(idx as u64, 3);
+}
+
+fn read_input_priority(buf: &mut BitReader) -> Result<state::InputPriority, String> {
+    let idx = buf.read_bits(3) as usize;
+    state::InputPriority::ALL.get(idx).copied().ok_or_else(|| "invalid merger priority".into())
+}
+
 fn write_source_rate(buf: &mut BitBuf, v: u32) {
     match v {
         // We use four bits for common cases, while making sure that the two first bits are
@@ -403,9 +626,18 @@ impl EdgeCoderEntry {
     }
 }
 
+/// Whether the inputs of `node` are not tied to an item, as far as the digest can tell. Edges
+/// to those are encoded like edges to splitters and mergers.
+fn takes_any_item(node: &state::Node) -> bool {
+    node.is_split_merge() || matches!(node, state::Node::PackRecipe { .. })
+}
+
 impl EdgeCoder {
     /// Creates a new edge coder. This just creates two lists of all input/output handles.
-    fn new(nodes: &[state::Node]) -> Self {
+    ///
+    /// When decoding, `migrations` has the migration for each node whose recipe was removed from
+    /// the game, as the digest was encoded with the handles of the removed recipe.
+    fn new(nodes: &[state::Node], migrations: &[Option<&Migration>]) -> Self {
         let mut inputs = Vec::with_capacity(nodes.len());
         let mut outputs = Vec::with_capacity(nodes.len());
         let e = |node, handle, item| EdgeCoderEntry { node, handle, item, used: false };
@@ -413,20 +645,33 @@ impl EdgeCoder {
             let node_id = node_id as NodeId;
             match *node {
                 state::Node::Recipe { recipe, .. } => {
-                    for (i, input_item) in recipe.info().inputs.iter().enumerate() {
-                        inputs.push(e(node_id, i as HandleId, Some(*input_item)));
+                    let items = |io: &[gamedata::IoEntry]| io.iter().map(|e| e.item).collect();
+                    let (recipe_inputs, recipe_outputs): (Vec<_>, Vec<_>) =
+                        match migrations.get(node_id as usize).copied().flatten() {
+                            Some(m) => (m.inputs.to_vec(), m.outputs.to_vec()),
+                            None => (items(recipe.info().inputs), items(recipe.info().outputs)),
+                        };
+                    for (i, item) in recipe_inputs.into_iter().enumerate() {
+                        inputs.push(e(node_id, i as HandleId, Some(item)));
                     }
-                    for (i, output_item) in recipe.info().outputs.iter().enumerate() {
-                        outputs.push(e(node_id, i as HandleId + 4, Some(*output_item)));
+                    for (i, item) in recipe_outputs.into_iter().enumerate() {
+                        outputs.push(e(node_id, i as HandleId + 4, Some(item)));
                     }
                 }
-                state::Node::Merger { .. } => {
+                // Without the data packs, any handle could be used.
+                state::Node::PackRecipe { .. } => {
+                    for i in 0..4 {
+                        inputs.push(e(node_id, i, None));
+                        outputs.push(e(node_id, i + 4, None));
+                    }
+                }
+                state::Node::Merger { .. } | state::Node::PriorityMerger { .. } => {
                     inputs.push(e(node_id, 0, None));
                     inputs.push(e(node_id, 1, None));
                     inputs.push(e(node_id, 2, None));
                     outputs.push(e(node_id, 3, None));
                 }
-                state::Node::Splitter { .. } => {
+                state::Node::Splitter { .. } | state::Node::SmartSplitter { .. } => {
                     inputs.push(e(node_id, 0, None));
                     outputs.push(e(node_id, 1, None));
                     outputs.push(e(node_id, 2, None));
@@ -452,7 +697,7 @@ impl EdgeCoder {
             let mut edges = graph.edges.clone();
             edges.sort_by_key(|e| {
                 let source_split_merge = graph.node(e.source.node).is_split_merge();
-                let target_split_merge = graph.node(e.target.node).is_split_merge();
+                let target_split_merge = takes_any_item(graph.node(e.target.node));
                 (!target_split_merge, !source_split_merge, e.target.node)
             });
             edges
@@ -460,7 +705,7 @@ impl EdgeCoder {
 
         // Encode how many edges target a splitter/merger
         let num_split_merge_target = edges.iter()
-            .take_while(|e| graph.node(e.target.node).is_split_merge())
+            .take_while(|e| takes_any_item(graph.node(e.target.node)))
             .count();
         let mut coder = SubBitEncoder::new();
         coder.encode(buf, num_split_merge_target as u32, edges.len() as u32);
@@ -556,7 +801,7 @@ impl EdgeCoder {
         // Prepare some data for the iterator. We need to know the number of splitter/merger inputs
         // as well as the number of all other inputs.
         let num_split_merges_total = self.inputs.iter()
-            .filter(|input| nodes[input.node as usize].is_split_merge())
+            .filter(|input| takes_any_item(&nodes[input.node as usize]))
             .count();
         let mut num_other_total = (self.inputs.len() - num_split_merges_total) as u32;
 
@@ -568,7 +813,7 @@ impl EdgeCoder {
             let Some(item) = input.item else {
                 continue;
             };
-            if nodes[input.node as usize].is_split_merge() {
+            if takes_any_item(&nodes[input.node as usize]) {
                 continue;
             }
 
@@ -629,7 +874,8 @@ impl<'a> EdgeTargetInfo<'a> {
         inputs.iter()
             .enumerate()
             .filter(|(_, e)| {
-                let correct_node_type = self.nodes[e.node as usize].is_split_merge() == self.targets_split_merge;
+                let takes_any = takes_any_item(&self.nodes[e.node as usize]);
+                let correct_node_type = takes_any == self.targets_split_merge;
                 let item_matches = match (e.item, self.expected_item) {
                     (Some(a), Some(b)) => a == b,
                     _ => true,
@@ -759,12 +1005,12 @@ impl BitBuf {
     }
 
     fn reserve(&mut self, bits: usize) {
-        let bytes = (self.pos + bits + 7) / 8;
+        let bytes = (self.pos + bits).div_ceil(8);
         self.buf.resize(bytes, 0);
     }
 
     fn finish_byte(&mut self) {
-        self.pos = (self.pos + 7) / 8 * 8;
+        self.pos = self.pos.div_ceil(8) * 8;
     }
 
     fn write_u8(&mut self, value: u8) {
@@ -783,6 +1029,14 @@ impl BitBuf {
         }
     }
 
+    /// Writes the length (see `write_len`) followed by the UTF-8 bytes.
+    fn write_str(&mut self, s: &str) {
+        self.write_len(s.len());
+        for b in s.bytes() {
+            self.write_u8(b);
+        }
+    }
+
     /// Writes the `count` least significant bits from `v` into `self`, start with the most
     /// significant bit of the those.
     fn write_bits(&mut self, v: u64, mut count: u8) {
@@ -825,7 +1079,7 @@ impl<'a> BitReader<'a> {
     }
 
     fn finish_byte(&mut self) {
-        self.pos = (self.pos + 7) / 8 * 8;
+        self.pos = self.pos.div_ceil(8) * 8;
     }
 
     /// Write the length of nodes/edges as varint (either 1 or 2 bytes). Can encode only up to
@@ -838,6 +1092,16 @@ impl<'a> BitReader<'a> {
         }
     }
 
+    fn read_str(&mut self) -> Result<String, String> {
+        let bytes = (0..self.read_len()).map(|_| self.read_u8()).collect();
+        String::from_utf8(bytes).map_err(|_| "invalid UTF-8 in digest".into())
+    }
+
+    /// Whether all bytes have been read.
+    fn is_at_end(&self) -> bool {
+        self.pos >= self.buf.len() * 8
+    }
+
     /// Writes `count` bits from `v` and returns them in the LSBits of the result.
     fn read_bits(&mut self, mut count: u8) -> u64 {
         let mut out = 0;
@@ -872,13 +1136,12 @@ trait BitNum: Copy
 {
     const ZERO: Self;
     const ONE: Self;
-    const EIGHT: Self;
     const BIT_WIDTH: Self;
 
     /// Returns `Self` with the `n` least significant bits set to 1.
     fn bitmask_lsb(n: Self) -> Self {
         if n == Self::BIT_WIDTH {
-            return !Self::ZERO;
+            !Self::ZERO
         } else {
             (Self::ONE << n) - Self::ONE
         }
@@ -892,19 +1155,16 @@ trait BitNum: Copy
 impl BitNum for u8 {
     const ZERO: Self = 0;
     const ONE: Self = 1;
-    const EIGHT: Self = 8;
     const BIT_WIDTH: Self = 8;
 }
 impl BitNum for u32 {
     const ZERO: Self = 0;
     const ONE: Self = 1;
-    const EIGHT: Self = 8;
     const BIT_WIDTH: Self = 32;
 }
 impl BitNum for u64 {
     const ZERO: Self = 0;
     const ONE: Self = 1;
-    const EIGHT: Self = 8;
     const BIT_WIDTH: Self = 64;
 }
 
@@ -936,6 +1196,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::unusual_byte_groupings)] // Grouped by the writes.
     fn bitbuf() {
         let mut buf = BitBuf::new();
         buf.write_bits(0b11010, 5);
@@ -1009,6 +1270,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::zero_prefixed_literal)] // Percent and fraction, e.g. `050_0000` for 50%.
     fn overclock() {
         let test = |v| test_roundtrip(state::Overclock(v), write_overclock, read_overclock);
 
@@ -1127,4 +1389,135 @@ mod tests {
         test(vec![(35, p20), (34, p20), (33, p20), (32, p20), (7, 244)]);
         test(vec![(35, p16), (34, p16), (33, p16), (32, p16), (7, 244)]);
     }
+
+    #[test]
+    fn data_packs_extension() {
+        let json = r#"{"state":{"graph":{
+            "nodes": [
+                {"type":"source","pos":{"x":0,"y":0},"item":"iron-ore","rate":90},
+                {"type":"recipe","recipe":"iron-ingot","pos":{"x":100,"y":0},"buildingsCount":3,"overclock":1}
+            ],
+            "edges": [{"source":{"node":0,"handle":0},"target":{"node":1,"handle":0}}]
+        }},"version":0}"#;
+        let mut input = serde_json::from_str::<state::Input>(json).unwrap();
+        let plain = encode(&input);
+        assert!(decode(&plain).unwrap().0.data_packs.is_empty());
+
+        input.data_packs = vec![
+            PackRef { id: "refined-power".into(), version: "3.1.0".into() },
+            PackRef { id: "experimental".into(), version: "1.1-ü".into() },
+        ];
+        let digest = encode(&input);
+        assert!(digest.starts_with(&plain));
+        let (decoded, warnings) = decode(&digest).unwrap();
+        assert!(warnings.is_empty());
+        assert_eq!(decoded.data_packs, input.data_packs);
+        assert_eq!(decoded.state.graph.edges.len(), 1);
+
+        let mut unknown = plain.clone();
+        unknown.push(200);
+        assert_eq!(decode(&unknown).err().unwrap(), "unknown digest extension 200");
+
+        let state::Node::Recipe { somersloops, .. } = &mut input.state.graph.nodes[1] else {
+            unreachable!()
+        };
+        *somersloops = 1;
+        let digest = encode(&input);
+        let (decoded, _) = decode(&digest).unwrap();
+        assert!(matches!(decoded.state.graph.nodes[1], state::Node::Recipe { somersloops: 1, .. }));
+        assert_eq!(decoded.data_packs, input.data_packs);
+
+        // Smelters only have one slot.
+        let state::Node::Recipe { somersloops, .. } = &mut input.state.graph.nodes[1] else {
+            unreachable!()
+        };
+        *somersloops = 2;
+        let digest = encode(&input);
+        assert_eq!(decode(&digest).err().unwrap(), "too many Somersloops for node 1");
+    }
+
+    #[test]
+    fn smart_splitter_and_priority_merger() {
+        let json = r#"{"state":{"graph":{
+            "nodes": [
+                {"type":"source","pos":{"x":0,"y":0},"item":"iron-ore","rate":90},
+                {"type":"smartSplitter","pos":{"x":100,"y":0},
+                    "filters":[["iron-ore","copper-ore"],[],["overflow"]]},
+                {"type":"priorityMerger","pos":{"x":200,"y":0},"priority":[2,0,1]},
+                {"type":"smartSplitter","pos":{"x":300,"y":0},
+                    "filters":[["any"],["anyUndefined"],["iron-plate"]]},
+                {"type":"recipe","recipe":"iron-ingot","pos":{"x":400,"y":0},"buildingsCount":1,"overclock":1}
+            ],
+            "edges": [
+                {"source":{"node":0,"handle":0},"target":{"node":1,"handle":0}},
+                {"source":{"node":1,"handle":1},"target":{"node":2,"handle":2}},
+                {"source":{"node":1,"handle":3},"target":{"node":2,"handle":0}},
+                {"source":{"node":2,"handle":3},"target":{"node":3,"handle":0}},
+                {"source":{"node":3,"handle":1},"target":{"node":4,"handle":0}}
+            ]
+        }},"version":0}"#;
+        let input = serde_json::from_str::<state::Input>(json).unwrap();
+        let (decoded, warnings) = decode(&encode(&input)).unwrap();
+        assert!(warnings.is_empty());
+        assert_eq!(
+            serde_json::to_value(&decoded.state.graph.nodes).unwrap(),
+            serde_json::to_value(&input.state.graph.nodes).unwrap(),
+        );
+        // Edges are not kept in order.
+        let edges = |graph: &state::Graph| {
+            let mut edges = graph.edges.iter()
+                .map(|e| (e.source.node, e.source.handle, e.target.node, e.target.handle))
+                .collect::<Vec<_>>();
+            edges.sort();
+            edges
+        };
+        assert_eq!(edges(&decoded.state.graph), edges(&input.state.graph));
+    }
+
+    #[test]
+    fn migrated_recipe() {
+        // Iron Alloy Ingot takes iron ore and copper ore. Pretend it was replaced by Iron Ingot.
+        let json = r#"{"state":{"graph":{
+            "nodes": [
+                {"type":"source","pos":{"x":0,"y":0},"item":"iron-ore","rate":90},
+                {"type":"source","pos":{"x":0,"y":100},"item":"copper-ore","rate":30},
+                {"type":"recipe","recipe":"iron-alloy-ingot","pos":{"x":100,"y":0},"buildingsCount":1,"overclock":1},
+                {"type":"merger","pos":{"x":200,"y":0}}
+            ],
+            "edges": [
+                {"source":{"node":0,"handle":0},"target":{"node":2,"handle":0}},
+                {"source":{"node":1,"handle":0},"target":{"node":2,"handle":1}},
+                {"source":{"node":2,"handle":4},"target":{"node":3,"handle":1}}
+            ]
+        }},"version":0}"#;
+        let input = serde_json::from_str::<state::Input>(json).unwrap();
+        let digest = encode(&input);
+
+        let migration = Migration {
+            stable_id: RecipeKind::IronAlloyIngot.stable_id(),
+            old_id: "iron-alloy-ingot",
+            successor: RecipeKind::IronIngot,
+            note: "removed",
+            inputs: &[ItemKind::IronOre, ItemKind::CopperOre],
+            outputs: &[ItemKind::IronIngot],
+        };
+        let (decoded, warnings) = decode_with(&digest, |id| match id == migration.stable_id {
+            true => Ok((migration.successor, Some(&migration))),
+            false => gamedata::resolve_stable_id(id),
+        }).unwrap();
+        assert!(matches!(
+            decoded.state.graph.nodes[2],
+            state::Node::Recipe { recipe: RecipeKind::IronIngot, .. },
+        ));
+        let mut edges = decoded.state.graph.edges.iter()
+            .map(|e| (e.source.node, e.source.handle, e.target.node, e.target.handle))
+            .collect::<Vec<_>>();
+        edges.sort();
+        assert_eq!(edges, [(0, 0, 2, 0), (2, 4, 3, 1)]);
+        assert_eq!(warnings, [
+            migration.warning(),
+            "a connection of recipe 'iron-alloy-ingot' was removed, as 'Iron Ingot' has no input \
+                'Copper Ore'".into(),
+        ]);
+    }
 }
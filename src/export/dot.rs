@@ -0,0 +1,72 @@
+//! Export as Graphviz DOT.
+
+use std::fmt::Write;
+
+use crate::{analysis::flows, gamedata::GameData, state::{Graph, Node}};
+use super::{edge_label, node_label};
+
+
+/// Renders the graph as Graphviz `digraph` flowing from left to right.
+pub fn to_dot(graph: &Graph, data: &GameData) -> String {
+    let mut out = String::new();
+    out.push_str("digraph plan {\n");
+    out.push_str("    rankdir=LR;\n");
+    out.push_str("    node [fontname=\"Helvetica\", fontsize=10];\n");
+    out.push_str("    edge [fontname=\"Helvetica\", fontsize=9];\n");
+    out.push('\n');
+
+    for (i, node) in graph.nodes.iter().enumerate() {
+        let shape = match node {
+            Node::Recipe { .. } | Node::PackRecipe { .. } => "shape=box, style=rounded",
+            Node::Source { .. } => "shape=ellipse, color=\"#27ae60\"",
+            _ => "shape=circle, fontsize=8",
+        };
+        let label = escape(&node_label(node, data));
+        writeln!(out, "    n{i} [{shape}, label=\"{label}\"];").unwrap();
+    }
+    if !graph.edges.is_empty() {
+        out.push('\n');
+    }
+    let rates = flows(graph, data).edges;
+    for (edge, &rate) in graph.edges.iter().zip(&rates) {
+        let label = edge_label(graph, data, edge, rate);
+        write!(out, "    n{} -> n{}", edge.source.node, edge.target.node).unwrap();
+        if !label.is_empty() {
+            write!(out, " [label=\"{}\"]", escape(&label)).unwrap();
+        }
+        out.push_str(";\n");
+    }
+
+    out.push_str("}\n");
+    out
+}
+
+/// Joins the lines and escapes them for use in a double quoted DOT string.
+fn escape(lines: &[String]) -> String {
+    lines.iter()
+        .map(|line| line.replace('\\', "\\\\").replace('"', "\\\""))
+        .collect::<Vec<_>>()
+        .join("\\n")
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::export::tests::example;
+    use super::*;
+
+    #[test]
+    fn example_plan() {
+        let dot = to_dot(&example(), &GameData::builtin());
+        assert!(dot.starts_with("digraph plan {\n    rankdir=LR;\n"));
+        assert!(dot.contains(
+            r#"n1 [shape=box, style=rounded, label="Iron Ingot\n2× Smelter @ 150%"];"#
+        ));
+        assert!(dot.contains(r#"n2 [shape=circle, fontsize=8, label="Splitter"];"#));
+        assert!(dot.contains(r#"n1 -> n2 [label="Iron Ingot\n90/min"];"#));
+        // The rods take 30/min, the rest overflows to the plates.
+        assert!(dot.contains(r#"n2 -> n3 [label="Iron Ingot\n60/min"];"#));
+        assert!(dot.contains(r#"n2 -> n4 [label="Iron Ingot\n30/min"];"#));
+        assert!(dot.ends_with("}\n"));
+    }
+}
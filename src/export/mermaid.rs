@@ -0,0 +1,60 @@
+//! Export as Mermaid flowchart.
+
+use std::fmt::Write;
+
+use crate::{analysis::flows, gamedata::GameData, state::{Graph, Node}};
+use super::{edge_label, node_label};
+
+
+/// Renders the graph as Mermaid flowchart flowing from left to right.
+pub fn to_mermaid(graph: &Graph, data: &GameData) -> String {
+    let mut out = String::new();
+    out.push_str("flowchart LR\n");
+
+    for (i, node) in graph.nodes.iter().enumerate() {
+        let label = escape(&node_label(node, data));
+        let (open, close) = match node {
+            Node::Recipe { .. } | Node::PackRecipe { .. } => ("[", "]"),
+            Node::Source { .. } => ("([", "])"),
+            _ => ("((", "))"),
+        };
+        writeln!(out, "    n{i}{open}\"{label}\"{close}").unwrap();
+    }
+    let rates = flows(graph, data).edges;
+    for (edge, &rate) in graph.edges.iter().zip(&rates) {
+        let label = edge_label(graph, data, edge, rate);
+        write!(out, "    n{} -->", edge.source.node).unwrap();
+        if !label.is_empty() {
+            write!(out, "|\"{}\"|", escape(&label)).unwrap();
+        }
+        writeln!(out, " n{}", edge.target.node).unwrap();
+    }
+
+    out
+}
+
+/// Joins the lines and escapes them for use in a double quoted Mermaid label.
+fn escape(lines: &[String]) -> String {
+    lines.iter()
+        .map(|line| line.replace('"', "#quot;"))
+        .collect::<Vec<_>>()
+        .join("<br/>")
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::export::tests::example;
+    use super::*;
+
+    #[test]
+    fn example_plan() {
+        let mermaid = to_mermaid(&example(), &GameData::builtin());
+        assert!(mermaid.starts_with("flowchart LR\n"));
+        assert!(mermaid.contains("    n0([\"Iron Ore<br/>90/min\"])\n"));
+        assert!(mermaid.contains("    n1[\"Iron Ingot<br/>2× Smelter @ 150%\"]\n"));
+        assert!(mermaid.contains("    n2((\"Splitter\"))\n"));
+        assert!(mermaid.contains("    n1 -->|\"Iron Ingot<br/>90/min\"| n2\n"));
+        assert!(mermaid.contains("    n2 -->|\"Iron Ingot<br/>30/min\"| n4\n"));
+    }
+}
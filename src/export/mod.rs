@@ -0,0 +1,133 @@
+//! Exporting a plan to text formats that can be embedded in documents.
+
+mod dot;
+mod mermaid;
+mod summary;
+mod svg;
+
+pub use self::{
+    dot::to_dot,
+    mermaid::to_mermaid,
+    summary::{to_csv as summary_csv, to_markdown as summary_markdown},
+    svg::to_svg,
+};
+
+use crate::{
+    gamedata::{GameData, ItemKind},
+    state::{Edge, Graph, Node, Overclock, SplitFilter, SplitRule},
+};
+
+
+/// Lines of the label for a node, with pack recipes looked up in `data`.
+fn node_label(node: &Node, data: &GameData) -> Vec<String> {
+    match node {
+        Node::Recipe { .. } | Node::PackRecipe { .. } => {
+            let (name, mut second, overclock) = match (node.recipe(data), node) {
+                (Some(recipe), _) => (
+                    recipe.name.to_owned(),
+                    format!("{}× {}", recipe.buildings_count, recipe.building_name),
+                    recipe.overclock,
+                ),
+                // The name and building of a pack recipe are only known from its data pack.
+                (None, Node::PackRecipe { recipe, buildings_count, overclock, .. }) => {
+                    (recipe.clone(), format!("{buildings_count}×"), *overclock)
+                }
+                (None, _) => unreachable!("built-in recipes are always known"),
+            };
+            if overclock != Overclock(1_000_000) {
+                second += &format!(" @ {}%", fmt_number(f64::from(overclock) * 100.0));
+            }
+            vec![name, second]
+        }
+        Node::Source { item, rate, .. } => {
+            let item = ItemKind::from(*item);
+            vec![item.info().name.to_owned(), format!("{rate}/min")]
+        }
+        Node::Merger { .. } => vec!["Merger".into()],
+        Node::Splitter { .. } => vec!["Splitter".into()],
+        Node::SmartSplitter { filters, .. } => {
+            let outputs = filters.iter().zip(1..).filter(|(f, _)| !f.is_empty()).map(|(f, i)| {
+                let names = f.iter().map(|&f| filter_name(f)).collect::<Vec<_>>();
+                format!("{i}: {}", names.join(", "))
+            });
+            ["Smart Splitter".into()].into_iter().chain(outputs).collect()
+        }
+        Node::PriorityMerger { priority, .. } => {
+            let order = priority.handles().map(|h| h.to_string()).join(" > ");
+            vec!["Priority Merger".into(), order]
+        }
+    }
+}
+
+fn filter_name(filter: SplitFilter) -> &'static str {
+    match filter {
+        SplitFilter::Item(item) => item.info().name,
+        SplitFilter::Rule(SplitRule::Any) => "Any",
+        SplitFilter::Rule(SplitRule::AnyUndefined) => "Any undefined",
+        SplitFilter::Rule(SplitRule::Overflow) => "Overflow",
+    }
+}
+
+/// Lines of the label for an edge: the item, if known, and the `rate` from `analysis::flows`.
+fn edge_label(graph: &Graph, data: &GameData, edge: &Edge, rate: f64) -> Vec<String> {
+    let item = graph.edge_item(edge, data).map(|item| item.info().name.to_owned());
+    item.into_iter().chain([format!("{}/min", fmt_number(rate))]).collect()
+}
+
+/// Formats with at most 3 decimal places, without trailing zeros.
+pub(crate) fn fmt_number(v: f64) -> String {
+    let s = format!("{v:.3}");
+    let s = s.trim_end_matches('0').trim_end_matches('.');
+    if s == "-0" { "0".into() } else { s.into() }
+}
+
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use crate::{analysis::flows, state::{Graph, Input}};
+    use super::*;
+
+    /// Iron ore -> 2 smelters @ 150% -> splitter -> plates and rods.
+    pub(crate) fn example() -> Graph {
+        let json = r#"{"state":{"graph":{
+            "nodes": [
+                {"type":"source","pos":{"x":0,"y":0},"item":"iron-ore","rate":90},
+                {"type":"recipe","recipe":"iron-ingot","pos":{"x":100,"y":0},"buildingsCount":2,"overclock":1.5},
+                {"type":"splitter","pos":{"x":400,"y":0}},
+                {"type":"recipe","recipe":"iron-plate","pos":{"x":500,"y":0},"buildingsCount":3,"overclock":1},
+                {"type":"recipe","recipe":"iron-rod","pos":{"x":500,"y":200},"buildingsCount":2,"overclock":1}
+            ],
+            "edges": [
+                {"source":{"node":0,"handle":0},"target":{"node":1,"handle":0}},
+                {"source":{"node":1,"handle":4},"target":{"node":2,"handle":0}},
+                {"source":{"node":2,"handle":1},"target":{"node":3,"handle":0}},
+                {"source":{"node":2,"handle":3},"target":{"node":4,"handle":0}}
+            ]
+        }},"version":0}"#;
+        serde_json::from_str::<Input>(json).unwrap().state.graph
+    }
+
+    /// `example` with a Somersloop in each iron plate constructor, doubling their output.
+    pub(crate) fn amplified() -> Graph {
+        let mut graph = example();
+        let Node::Recipe { somersloops, .. } = &mut graph.nodes[3] else { unreachable!() };
+        *somersloops = 1;
+        graph
+    }
+
+    #[test]
+    fn labels() {
+        let g = example();
+        let data = GameData::builtin();
+        assert_eq!(node_label(&g.nodes[1], &data), ["Iron Ingot", "2× Smelter @ 150%"]);
+        assert_eq!(node_label(&g.nodes[3], &data), ["Iron Plate", "3× Constructor"]);
+        assert_eq!(node_label(&g.nodes[0], &data), ["Iron Ore", "90/min"]);
+        let rates = flows(&g, &data).edges;
+        assert_eq!(edge_label(&g, &data, &g.edges[1], rates[1]), ["Iron Ingot", "90/min"]);
+        // The rods take less than half, the rest overflows to the plates.
+        assert_eq!(edge_label(&g, &data, &g.edges[2], rates[2]), ["Iron Ingot", "60/min"]);
+        assert_eq!(edge_label(&g, &data, &g.edges[3], rates[3]), ["Iron Ingot", "30/min"]);
+        assert_eq!(fmt_number(12.5), "12.5");
+        assert_eq!(fmt_number(1.0 / 3.0), "0.333");
+    }
+}
@@ -0,0 +1,286 @@
+//! Tabular production summary of a plan as CSV or Markdown.
+//!
+//! The summary has three sections: one row per recipe node, the net balance per item and the
+//! raw resource totals. In CSV, sections are separated by an empty line and each starts with
+//! its own header row.
+
+use std::{collections::BTreeMap, fmt::Write};
+
+use crate::{
+    gamedata::{GameData, ItemKind, SourceItemKind},
+    state::{Graph, Node, NodeIo},
+};
+use super::fmt_number;
+
+
+struct Summary {
+    recipes: Vec<RecipeRow>,
+    /// Produced and consumed per minute for each item.
+    items: BTreeMap<ItemKind, (f64, f64)>,
+    /// Same as `items` for items only known to data packs, by name.
+    pack_items: BTreeMap<String, (f64, f64)>,
+    /// Supplied by source nodes and required by recipes per minute for each raw resource.
+    raw: BTreeMap<SourceItemKind, (f64, f64)>,
+}
+
+struct Section {
+    title: &'static str,
+    header: Vec<&'static str>,
+    rows: Vec<Vec<String>>,
+}
+
+struct RecipeRow {
+    node: usize,
+    name: String,
+    building: String,
+    count: u32,
+    overclock: f64,
+    inputs: Vec<(String, f64)>,
+    outputs: Vec<(String, f64)>,
+}
+
+impl Summary {
+    fn new(graph: &Graph, data: &GameData) -> Self {
+        let mut recipes = Vec::new();
+        let mut items = BTreeMap::<ItemKind, (f64, f64)>::new();
+        let mut pack_items = BTreeMap::<String, (f64, f64)>::new();
+        let mut raw = BTreeMap::<SourceItemKind, (f64, f64)>::new();
+
+        for (i, node) in graph.nodes.iter().enumerate() {
+            match node {
+                Node::Recipe { .. } | Node::PackRecipe { .. } => {
+                    let Some(recipe) = node.recipe(data) else {
+                        // Keep the node visible even if its data pack is not loaded.
+                        let Node::PackRecipe { recipe, buildings_count, overclock, .. } = node
+                        else { unreachable!() };
+                        recipes.push(RecipeRow {
+                            node: i,
+                            name: format!("{recipe} (data pack not loaded)"),
+                            building: String::new(),
+                            count: buildings_count.get(),
+                            overclock: f64::from(*overclock),
+                            inputs: Vec::new(),
+                            outputs: Vec::new(),
+                        });
+                        continue;
+                    };
+                    let factor = recipe.crafts_per_minute_total();
+                    let amplified = factor * recipe.amplification();
+
+                    for e in &recipe.inputs {
+                        let rate = e.amount * factor;
+                        balance(&mut items, &mut pack_items, e).1 += rate;
+                        let source = e.item.and_then(|i| SourceItemKind::try_from(i).ok());
+                        if let Some(source) = source {
+                            raw.entry(source).or_default().1 += rate;
+                        }
+                    }
+                    for e in &recipe.outputs {
+                        balance(&mut items, &mut pack_items, e).0 += e.amount * amplified;
+                    }
+
+                    let io = |entries: &[NodeIo], factor: f64| entries.iter()
+                        .map(|e| (e.name.to_owned(), e.amount * factor))
+                        .collect();
+                    recipes.push(RecipeRow {
+                        node: i,
+                        name: recipe.name.to_owned(),
+                        building: recipe.building_name.to_owned(),
+                        count: recipe.buildings_count.get(),
+                        overclock: f64::from(recipe.overclock),
+                        inputs: io(&recipe.inputs, factor),
+                        outputs: io(&recipe.outputs, amplified),
+                    });
+                }
+                Node::Source { item, rate, .. } => {
+                    items.entry((*item).into()).or_default().0 += *rate as f64;
+                    raw.entry(*item).or_default().0 += *rate as f64;
+                }
+                _ => {}
+            }
+        }
+
+        Self { recipes, items, pack_items, raw }
+    }
+
+    fn sections(&self) -> [Section; 3] {
+        let io = |entries: &[(String, f64)]| entries.iter()
+            .map(|(name, rate)| format!("{} {name}", fmt_number(*rate)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let recipes = self.recipes.iter()
+            .map(|r| vec![
+                r.node.to_string(),
+                r.name.clone(),
+                r.building.clone(),
+                r.count.to_string(),
+                format!("{}%", fmt_number(r.overclock * 100.0)),
+                io(&r.inputs),
+                io(&r.outputs),
+            ])
+            .collect();
+        let items = self.items.iter()
+            .map(|(item, &balance)| (item.info().name, balance))
+            .chain(self.pack_items.iter().map(|(name, &balance)| (name.as_str(), balance)))
+            .map(|(name, (produced, consumed))| vec![
+                name.to_owned(),
+                fmt_number(produced),
+                fmt_number(consumed),
+                fmt_number(produced - consumed),
+            ])
+            .collect();
+        let raw = self.raw.iter()
+            .map(|(&item, &(supplied, required))| vec![
+                ItemKind::from(item).info().name.to_owned(),
+                fmt_number(supplied),
+                fmt_number(required),
+            ])
+            .collect();
+
+        [
+            Section {
+                title: "Recipes",
+                header: vec![
+                    "Node", "Recipe", "Building", "Count", "Clock", "Inputs/min", "Outputs/min",
+                ],
+                rows: recipes,
+            },
+            Section {
+                title: "Item balance",
+                header: vec!["Item", "Produced/min", "Consumed/min", "Surplus/min"],
+                rows: items,
+            },
+            Section {
+                title: "Raw resources",
+                header: vec!["Resource", "Supplied/min", "Required/min"],
+                rows: raw,
+            },
+        ]
+    }
+}
+
+/// Balance entry of an item, by kind or, for items only known to data packs, by name.
+fn balance<'a>(
+    items: &'a mut BTreeMap<ItemKind, (f64, f64)>,
+    pack_items: &'a mut BTreeMap<String, (f64, f64)>,
+    io: &NodeIo,
+) -> &'a mut (f64, f64) {
+    match io.item {
+        Some(item) => items.entry(item).or_default(),
+        None => pack_items.entry(io.name.to_owned()).or_default(),
+    }
+}
+
+/// Renders the production summary as CSV.
+pub fn to_csv(graph: &Graph, data: &GameData) -> String {
+    let cell = |s: &str| if s.contains([',', '"', '\n']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_owned()
+    };
+
+    let mut out = String::new();
+    for (i, section) in Summary::new(graph, data).sections().into_iter().enumerate() {
+        if i > 0 {
+            out.push('\n');
+        }
+        let header = section.header.into_iter().map(|s| s.to_owned()).collect();
+        for row in std::iter::once(header).chain(section.rows) {
+            let line = row.iter().map(|s| cell(s)).collect::<Vec<_>>().join(",");
+            writeln!(out, "{line}").unwrap();
+        }
+    }
+    out
+}
+
+/// Renders the production summary as GitHub-flavored Markdown with one table per section.
+pub fn to_markdown(graph: &Graph, data: &GameData) -> String {
+    let cell = |s: &str| s.replace('|', "\\|");
+
+    let mut out = String::new();
+    for (i, section) in Summary::new(graph, data).sections().into_iter().enumerate() {
+        if i > 0 {
+            out.push('\n');
+        }
+        writeln!(out, "## {}\n", section.title).unwrap();
+        writeln!(out, "| {} |", section.header.join(" | ")).unwrap();
+        // Rates and counts are right-aligned.
+        let align = section.header.iter()
+            .map(|h| if h.ends_with("/min") || *h == "Count" { "--:" } else { "---" })
+            .collect::<Vec<_>>();
+        writeln!(out, "|{}|", align.join("|")).unwrap();
+        for row in section.rows {
+            let row = row.iter().map(|s| cell(s)).collect::<Vec<_>>();
+            writeln!(out, "| {} |", row.join(" | ")).unwrap();
+        }
+    }
+    out
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::export::tests::{amplified, example};
+    use super::*;
+
+    #[test]
+    fn csv() {
+        let csv = to_csv(&example(), &GameData::builtin());
+        let sections = csv.split("\n\n").collect::<Vec<_>>();
+        assert_eq!(sections.len(), 3);
+        assert_eq!(sections[0].lines().collect::<Vec<_>>(), [
+            "Node,Recipe,Building,Count,Clock,Inputs/min,Outputs/min",
+            "1,Iron Ingot,Smelter,2,150%,90 Iron Ore,90 Iron Ingot",
+            "3,Iron Plate,Constructor,3,100%,90 Iron Ingot,60 Iron Plate",
+            "4,Iron Rod,Constructor,2,100%,30 Iron Ingot,30 Iron Rod",
+        ]);
+        assert!(sections[1].contains("\nIron Ingot,90,120,-30\n"));
+        assert_eq!(sections[2], "Resource,Supplied/min,Required/min\nIron Ore,90,90\n");
+    }
+
+    #[test]
+    fn markdown() {
+        let md = to_markdown(&example(), &GameData::builtin());
+        assert!(md.starts_with("## Recipes\n\n| Node | Recipe |"));
+        assert!(md.contains("| 1 | Iron Ingot | Smelter | 2 | 150% | 90 Iron Ore | 90 Iron Ingot |"));
+        assert!(md.contains("## Item balance\n\n| Item | Produced/min | Consumed/min | Surplus/min |\n|---|--:|--:|--:|\n"));
+        assert!(md.contains("| Iron Plate | 60 | 0 | 60 |"));
+    }
+
+    #[test]
+    fn somersloops() {
+        let csv = to_csv(&amplified(), &GameData::builtin());
+        assert!(csv.contains("\n3,Iron Plate,Constructor,3,100%,90 Iron Ingot,120 Iron Plate\n"));
+        assert!(csv.contains("\nIron Plate,120,0,120\n"));
+    }
+
+    #[test]
+    fn pack_recipe() {
+        let pack = serde_json::from_str(r#"{
+            "id": "gears",
+            "version": "1.0.0",
+            "items": [{"id": "iron-gear", "name": "Iron Gear", "form": "solid"}],
+            "recipes": [{
+                "id": "iron-gear",
+                "name": "Iron Gear",
+                "building": "constructor",
+                "duration": 2,
+                "inputs": [{"item": "iron-ore", "amount": 1}],
+                "outputs": [{"item": "iron-gear", "amount": 1}]
+            }]
+        }"#).unwrap();
+        let data = GameData::with_packs(&[pack]).unwrap();
+        let graph = serde_json::from_str::<Graph>(r#"{"nodes": [
+            {"type":"packRecipe","recipe":"iron-gear","pos":{"x":0,"y":0},
+                "buildingsCount":2,"overclock":1}
+        ], "edges": []}"#).unwrap();
+
+        let csv = to_csv(&graph, &data);
+        assert!(csv.contains("\n0,Iron Gear,Constructor,2,100%,60 Iron Ore,60 Iron Gear\n"));
+        assert!(csv.contains("\nIron Gear,60,0,60\n"));
+        assert!(csv.ends_with("\nIron Ore,0,60\n"));
+
+        let csv = to_csv(&graph, &GameData::builtin());
+        assert!(csv.contains("\n0,iron-gear (data pack not loaded),,2,100%,,\n"));
+    }
+}
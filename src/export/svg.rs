@@ -0,0 +1,338 @@
+//! Render a plan as standalone SVG image, mimicking the look of the editor.
+//!
+//! Geometry mirrors the React components in `src/nodes`: node positions are the top left corner,
+//! recipe inputs are on the left and outputs on the right (one 25px row per item below a 56px
+//! header), mergers take inputs from top/left/bottom and splitters output to top/right/bottom.
+
+use std::fmt::Write;
+
+use crate::{
+    analysis::flows,
+    gamedata::GameData,
+    state::{Edge, Graph, GraphHandle, Node, Overclock},
+};
+use super::fmt_number;
+
+
+const RECIPE_WIDTH: f64 = 250.0;
+const RECIPE_HEADER: f64 = 56.0;
+const RECIPE_ROW: f64 = 25.0;
+const COMBINER_SIZE: f64 = 25.0;
+const SOURCE_RADIUS: f64 = 24.5;
+
+/// Margin around the bounding box of all nodes, leaving space for rate labels.
+const MARGIN: f64 = 100.0;
+
+const STYLE: &str = "\
+    text { font-family: 'Hubot Sans', Helvetica, Arial, sans-serif; font-size: 10px; }
+    .node { fill: white; stroke: #777; stroke-width: 1; }
+    .edge { fill: none; stroke: #999; stroke-width: 1.5; }
+    .handle { fill: white; stroke: #777; stroke-width: 2; }
+    .rate { font-weight: bold; }
+    .name { font-weight: bold; font-size: 12px; }
+    .label { paint-order: stroke; stroke: white; stroke-width: 3px; }";
+
+#[derive(Clone, Copy)]
+enum Side { Left, Top, Right, Bottom }
+
+impl Side {
+    fn direction(self) -> (f64, f64) {
+        match self {
+            Side::Left => (-1.0, 0.0),
+            Side::Top => (0.0, -1.0),
+            Side::Right => (1.0, 0.0),
+            Side::Bottom => (0.0, 1.0),
+        }
+    }
+}
+
+/// Renders the graph as SVG document, with pack recipes looked up in `data`.
+pub fn to_svg(graph: &Graph, data: &GameData) -> String {
+    let (min_x, min_y, max_x, max_y) = graph.nodes.iter()
+        .map(|n| {
+            let (x, y) = (n.pos().x as f64, n.pos().y as f64);
+            let (w, h) = size(n, data);
+            (x, y, x + w, y + h)
+        })
+        .reduce(|a, b| (a.0.min(b.0), a.1.min(b.1), a.2.max(b.2), a.3.max(b.3)))
+        .unwrap_or_default();
+    let (x, y) = (min_x - MARGIN, min_y - MARGIN);
+    let (w, h) = (max_x - min_x + 2.0 * MARGIN, max_y - min_y + 2.0 * MARGIN);
+
+    let mut out = String::new();
+    writeln!(
+        out,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{} {} {} {}" width="{}" height="{}">"#,
+        n(x), n(y), n(w), n(h), n(w), n(h),
+    ).unwrap();
+    writeln!(out, "<style>\n{STYLE}\n</style>").unwrap();
+    writeln!(out, r#"<rect x="{}" y="{}" width="{}" height="{}" fill="white"/>"#, n(x), n(y), n(w), n(h))
+        .unwrap();
+
+    let rates = flows(graph, data).edges;
+    for (edge, &rate) in graph.edges.iter().zip(&rates) {
+        render_edge(&mut out, graph, edge, rate);
+    }
+    for (id, node) in graph.nodes.iter().enumerate() {
+        render_node(&mut out, graph, data, &rates, id as u16, node);
+    }
+
+    out.push_str("</svg>\n");
+    out
+}
+
+/// Size of the node's main body.
+fn size(node: &Node, data: &GameData) -> (f64, f64) {
+    match (node, node.recipe(data)) {
+        (_, Some(recipe)) => {
+            let rows = recipe.inputs.len().max(recipe.outputs.len()) as f64;
+            (RECIPE_WIDTH, RECIPE_HEADER + rows * RECIPE_ROW)
+        }
+        // Recipes have at most 4 rows.
+        (Node::PackRecipe { .. }, None) => (RECIPE_WIDTH, RECIPE_HEADER + 4.0 * RECIPE_ROW),
+        _ => (COMBINER_SIZE, COMBINER_SIZE),
+    }
+}
+
+/// Absolute position of a handle and the side of the node it is on.
+fn handle_pos(node: &Node, handle: u8) -> ((f64, f64), Side) {
+    let (x, y) = (node.pos().x as f64, node.pos().y as f64);
+    let mid = COMBINER_SIZE / 2.0;
+    match node {
+        Node::Recipe { .. } | Node::PackRecipe { .. } => {
+            let (idx, side, hx) = match handle {
+                0..=3 => (handle, Side::Left, x - 2.5),
+                _ => (handle - 4, Side::Right, x + RECIPE_WIDTH + 2.5),
+            };
+            ((hx, y + RECIPE_HEADER + idx as f64 * RECIPE_ROW + 11.5), side)
+        }
+        Node::Merger { .. } | Node::PriorityMerger { .. } => match handle {
+            0 => ((x + mid, y), Side::Top),
+            1 => ((x, y + mid), Side::Left),
+            2 => ((x + mid, y + COMBINER_SIZE), Side::Bottom),
+            _ => ((x + COMBINER_SIZE, y + mid), Side::Right),
+        },
+        Node::Splitter { .. } | Node::SmartSplitter { .. } => match handle {
+            0 => ((x, y + mid), Side::Left),
+            1 => ((x + mid, y), Side::Top),
+            2 => ((x + COMBINER_SIZE, y + mid), Side::Right),
+            _ => ((x + mid, y + COMBINER_SIZE), Side::Bottom),
+        },
+        Node::Source { .. } => ((x + 27.0, y + mid), Side::Right),
+    }
+}
+
+fn render_edge(out: &mut String, graph: &Graph, edge: &Edge, rate: f64) {
+    let endpoint = |h: GraphHandle| handle_pos(graph.node(h.node), h.handle);
+    let ((sx, sy), s_side) = endpoint(edge.source);
+    let ((tx, ty), t_side) = endpoint(edge.target);
+
+    // Cubic bezier leaving and entering the handles perpendicular to the node border.
+    let reach = ((tx - sx).abs().max((ty - sy).abs()) / 2.0).max(25.0);
+    let control = |(x, y): (f64, f64), side: Side| {
+        let (dx, dy) = side.direction();
+        (x + dx * reach, y + dy * reach)
+    };
+    let (c1x, c1y) = control((sx, sy), s_side);
+    let (c2x, c2y) = control((tx, ty), t_side);
+    writeln!(
+        out,
+        r#"<path class="edge" d="M {} {} C {} {}, {} {}, {} {}"/>"#,
+        n(sx), n(sy), n(c1x), n(c1y), n(c2x), n(c2y), n(tx), n(ty),
+    ).unwrap();
+
+    // Midpoint of the bezier curve.
+    let mx = (sx + 3.0 * c1x + 3.0 * c2x + tx) / 8.0;
+    let my = (sy + 3.0 * c1y + 3.0 * c2y + ty) / 8.0;
+    writeln!(
+        out,
+        r#"<text class="rate label" x="{}" y="{}" text-anchor="middle" dy="-4">{}</text>"#,
+        n(mx), n(my), fmt_number(rate),
+    ).unwrap();
+}
+
+/// Renders a node, `rates` being the flow along each edge of `graph`.
+fn render_node(
+    out: &mut String,
+    graph: &Graph,
+    data: &GameData,
+    rates: &[f64],
+    id: u16,
+    node: &Node,
+) {
+    let (x, y) = (node.pos().x as f64, node.pos().y as f64);
+    match (node, node.recipe(data)) {
+        (_, Some(recipe)) => {
+            let (w, h) = size(node, data);
+            let cx = x + w / 2.0;
+            writeln!(
+                out,
+                r#"<rect class="node" x="{}" y="{}" width="{w}" height="{}" rx="4"/>"#,
+                n(x), n(y), n(h),
+            ).unwrap();
+            let mut header = format!("{}× {}", recipe.buildings_count, recipe.building_name);
+            if recipe.overclock != Overclock(1_000_000) {
+                header += &format!(" @ {}%", fmt_number(f64::from(recipe.overclock) * 100.0));
+            }
+            text(out, cx, y + 18.0, "middle", "", &header);
+            text(out, cx, y + 40.0, "middle", "name", recipe.name);
+            writeln!(
+                out,
+                r##"<line x1="{}" y1="{}" x2="{}" y2="{}" stroke="#e0e0e0"/>"##,
+                n(x), n(y + RECIPE_HEADER - 5.0), n(x + w), n(y + RECIPE_HEADER - 5.0),
+            ).unwrap();
+
+            let multiplier = recipe.buildings_count.get() as f64 * f64::from(recipe.overclock);
+            let amplified = multiplier * recipe.amplification();
+            let entries = recipe.inputs.iter().enumerate().map(|(i, e)| (i as u8, e))
+                .chain(recipe.outputs.iter().enumerate().map(|(i, e)| (i as u8 + 4, e)));
+            for (handle, entry) in entries {
+                let ((hx, hy), _) = handle_pos(node, handle);
+                let rate = entry.amount * recipe.crafts_per_minute;
+                let total = fmt_number(rate * if handle < 4 { multiplier } else { amplified });
+                let label = format!("{} {}", fmt_number(rate), entry.name);
+                if handle < 4 {
+                    text(out, x + 10.0, hy + 3.5, "start", "", &label);
+                    text(out, x - 15.0, hy + 3.5, "end", "rate label", &total);
+                } else {
+                    text(out, x + w - 10.0, hy + 3.5, "end", "", &label);
+                    text(out, x + w + 15.0, hy + 3.5, "start", "rate label", &total);
+                }
+                render_handle(out, hx, hy);
+            }
+        }
+        // Only the ID and the connected handles are known without the data pack.
+        (Node::PackRecipe { recipe, buildings_count, overclock, .. }, None) => {
+            let (w, h) = size(node, data);
+            writeln!(
+                out,
+                r#"<rect class="node" x="{}" y="{}" width="{w}" height="{}" rx="4"/>"#,
+                n(x), n(y), n(h),
+            ).unwrap();
+            let mut header = format!("{buildings_count}×");
+            if *overclock != Overclock(1_000_000) {
+                header += &format!(" @ {}%", fmt_number(f64::from(*overclock) * 100.0));
+            }
+            text(out, x + w / 2.0, y + 18.0, "middle", "", &header);
+            text(out, x + w / 2.0, y + 40.0, "middle", "name", recipe);
+            let handles = graph.edges.iter()
+                .flat_map(|e| [e.source, e.target])
+                .filter(|h| h.node == id);
+            for handle in handles {
+                let ((hx, hy), _) = handle_pos(node, handle.handle);
+                render_handle(out, hx, hy);
+            }
+        }
+        (Node::Source { item, rate, .. }, None) => {
+            let mid = COMBINER_SIZE / 2.0;
+            writeln!(
+                out,
+                r##"<circle class="node" cx="{}" cy="{}" r="{SOURCE_RADIUS}" stroke="#27ae60" stroke-width="2"/>"##,
+                n(x + mid), n(y + mid),
+            ).unwrap();
+            let name = crate::gamedata::ItemKind::from(*item).info().name;
+            text(out, x + mid, y + mid + 5.0, "middle", "", &abbreviate(name));
+            text(out, x + mid, y + COMBINER_SIZE + 26.0, "middle", "", name);
+            text(out, x + COMBINER_SIZE + 15.0, y + mid + 3.5, "start", "rate label", &rate.to_string());
+            let ((hx, hy), _) = handle_pos(node, 0);
+            render_handle(out, hx, hy);
+        }
+        _ => {
+            let (fill, symbol) = match node {
+                Node::Merger { .. } => ("#faebdd", "⇉"),
+                Node::PriorityMerger { .. } => ("#faebdd", "⇛"),
+                Node::SmartSplitter { .. } => ("#e3efff", "⇻"),
+                _ => ("#e3efff", "⇶"),
+            };
+            writeln!(
+                out,
+                r#"<rect x="{}" y="{}" width="29" height="29" rx="12" fill="{fill}"/>"#,
+                n(x - 2.0), n(y - 2.0),
+            ).unwrap();
+            text(out, x + COMBINER_SIZE / 2.0, y + 17.0, "middle", "name", symbol);
+            for handle in 0..4 {
+                let ((hx, hy), _) = handle_pos(node, handle);
+                render_handle(out, hx, hy);
+            }
+
+            // Mergers show their total input next to them, like in the editor.
+            if !node.is_splitter() {
+                let inputs = graph.edges.iter()
+                    .zip(rates)
+                    .filter(|(e, _)| e.target.node == id)
+                    .map(|(_, &rate)| rate)
+                    .collect::<Vec<_>>();
+                let outputs = graph.edges.iter().filter(|e| e.source.node == id).count();
+                if !(inputs.len() == 1 && outputs == 1) {
+                    let total = inputs.iter().fold(0.0, |total, rate| total + rate);
+                    let (tx, ty) = (x + COMBINER_SIZE + 7.0, y + COMBINER_SIZE / 2.0 + 3.5);
+                    text(out, tx, ty, "start", "rate label", &fmt_number(total));
+                }
+            }
+        }
+    }
+}
+
+fn render_handle(out: &mut String, x: f64, y: f64) {
+    writeln!(out, r#"<circle class="handle" cx="{}" cy="{}" r="4"/>"#, n(x), n(y)).unwrap();
+}
+
+fn text(out: &mut String, x: f64, y: f64, anchor: &str, class: &str, content: &str) {
+    let class = if class.is_empty() { String::new() } else { format!(r#" class="{class}""#) };
+    writeln!(
+        out,
+        r#"<text{class} x="{}" y="{}" text-anchor="{anchor}">{}</text>"#,
+        n(x), n(y), escape(content),
+    ).unwrap();
+}
+
+/// Initials of the item name, shown in place of the item icon.
+fn abbreviate(name: &str) -> String {
+    name.split_whitespace().filter_map(|w| w.chars().next()).take(3).collect()
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+fn n(v: f64) -> String {
+    fmt_number(v)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::export::tests::{amplified, example};
+    use super::*;
+
+    #[test]
+    fn example_plan() {
+        let svg = to_svg(&example(), &GameData::builtin());
+        assert!(svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="-100 -100 950 481""#));
+        assert!(svg.ends_with("</svg>\n"));
+        assert_eq!(svg.matches(r#"<path class="edge""#).count(), 4);
+
+        // Smelter output (handle 4) to splitter input (handle 0).
+        assert!(svg.contains(r#"d="M 352.5 67.5 C 380 67.5, 372.5 12.5, 400 12.5""#));
+        assert!(svg.contains(">2× Smelter @ 150%</text>"));
+        assert!(svg.contains(r#"text-anchor="start">90</text>"#));
+        // Splitter outputs are labeled with what they get.
+        assert_eq!(svg.matches(r#"text-anchor="middle" dy="-4">"#).count(), 4);
+        assert!(svg.contains(r#"text-anchor="middle" dy="-4">60</text>"#));
+    }
+
+    #[test]
+    fn somersloops() {
+        let rate = |total| format!(r#"text-anchor="start">{total}</text>"#);
+        assert!(to_svg(&example(), &GameData::builtin()).contains(&rate(60)));
+        let svg = to_svg(&amplified(), &GameData::builtin());
+        assert!(svg.contains(&rate(120)) && !svg.contains(&rate(60)));
+        // Inputs are not amplified.
+        assert!(svg.contains(r#"text-anchor="end">90</text>"#));
+    }
+
+    #[test]
+    fn escapes_text() {
+        assert_eq!(escape(r#"<a & "b">"#), "&lt;a &amp; &quot;b&quot;&gt;");
+    }
+}
@@ -0,0 +1,125 @@
+use super::RecipeInfo;
+
+
+/// All buildings that can run recipes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Building {
+    Smelter,
+    Foundry,
+    Constructor,
+    Assembler,
+    Manufacturer,
+    Refinery,
+    Packager,
+    Blender,
+    NuclearReactor,
+    ParticleAccelerator,
+    Converter,
+    QuantumEncoder,
+}
+
+impl Building {
+    pub const ALL: [Self; 12] = [
+        Self::Smelter,
+        Self::Foundry,
+        Self::Constructor,
+        Self::Assembler,
+        Self::Manufacturer,
+        Self::Refinery,
+        Self::Packager,
+        Self::Blender,
+        Self::NuclearReactor,
+        Self::ParticleAccelerator,
+        Self::Converter,
+        Self::QuantumEncoder,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Smelter => "Smelter",
+            Self::Foundry => "Foundry",
+            Self::Constructor => "Constructor",
+            Self::Assembler => "Assembler",
+            Self::Manufacturer => "Manufacturer",
+            Self::Refinery => "Refinery",
+            Self::Packager => "Packager",
+            Self::Blender => "Blender",
+            Self::NuclearReactor => "Nuclear Power Plant",
+            Self::ParticleAccelerator => "Particle Accelerator",
+            Self::Converter => "Converter",
+            Self::QuantumEncoder => "Quantum Encoder",
+        }
+    }
+
+    /// Class name of the buildable, as used in save files.
+    pub fn class_name(self) -> &'static str {
+        match self {
+            Self::Smelter => "Build_SmelterMk1_C",
+            Self::Foundry => "Build_FoundryMk1_C",
+            Self::Constructor => "Build_ConstructorMk1_C",
+            Self::Assembler => "Build_AssemblerMk1_C",
+            Self::Manufacturer => "Build_ManufacturerMk1_C",
+            Self::Refinery => "Build_OilRefinery_C",
+            Self::Packager => "Build_Packager_C",
+            Self::Blender => "Build_Blender_C",
+            Self::NuclearReactor => "Build_GeneratorNuclear_C",
+            Self::ParticleAccelerator => "Build_HadronCollider_C",
+            Self::Converter => "Build_Converter_C",
+            Self::QuantumEncoder => "Build_QuantumEncoder_C",
+        }
+    }
+
+    pub fn from_class_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|b| b.class_name() == name)
+    }
+
+    /// Number of Somersloops that can be inserted to amplify production. Each one adds
+    /// `1 / slots` to the output, so a full building produces twice as much.
+    pub fn somersloop_slots(self) -> u8 {
+        match self {
+            Self::Smelter | Self::Constructor => 1,
+            Self::Foundry | Self::Assembler | Self::Refinery | Self::Converter => 2,
+            Self::Manufacturer
+                | Self::Blender
+                | Self::ParticleAccelerator
+                | Self::QuantumEncoder => 4,
+            Self::Packager | Self::NuclearReactor => 0,
+        }
+    }
+
+    /// Power consumption in MW at 100% clock speed. Negative values mean the building produces
+    /// power. Buildings with fluctuating power usage return 0 here, as their usage depends on the
+    /// recipe (see `RecipeInfo::power_range`).
+    pub fn base_power(self) -> f64 {
+        match self {
+            Self::Smelter => 4.0,
+            Self::Foundry => 16.0,
+            Self::Constructor => 4.0,
+            Self::Assembler => 15.0,
+            Self::Manufacturer => 55.0,
+            Self::Refinery => 30.0,
+            Self::Packager => 10.0,
+            Self::Blender => 75.0,
+            Self::NuclearReactor => -2500.0,
+            Self::ParticleAccelerator => 0.0,
+            Self::Converter => 0.0,
+            Self::QuantumEncoder => 0.0,
+        }
+    }
+}
+
+impl RecipeInfo {
+    /// Average power consumption in MW of one building running this recipe at 100%.
+    pub fn average_power(&self) -> f64 {
+        match self.power_range {
+            Some((min, max)) => (min + max) / 2.0,
+            None => self.building.base_power(),
+        }
+    }
+
+    /// Craft cycles per minute of one building at 100%.
+    pub fn crafts_per_minute(&self) -> f64 {
+        60.0 / self.duration
+    }
+}
@@ -0,0 +1,157 @@
+use serde::{Deserialize, Serialize};
+
+use super::{Building, ItemKind};
+
+
+/// An item and how many of it are needed to construct something.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Ingredient {
+    pub item: ItemKind,
+    pub amount: u32,
+}
+
+macro_rules! cost {
+    ($($item:ident: $amount:literal),* $(,)?) => {
+        &[$(Ingredient { item: ItemKind::$item, amount: $amount }),*]
+    };
+}
+
+impl Building {
+    /// Items needed to construct one building.
+    pub fn build_cost(self) -> &'static [Ingredient] {
+        match self {
+            Self::Smelter => cost![IronRod: 5, Wire: 8],
+            Self::Foundry => cost![ModularFrame: 10, Rotor: 10, Concrete: 20],
+            Self::Constructor => cost![ReinforcedIronPlate: 2, Cable: 8],
+            Self::Assembler => cost![ReinforcedIronPlate: 8, Rotor: 4, Cable: 10],
+            Self::Manufacturer => cost![
+                Motor: 5,
+                HeavyModularFrame: 10,
+                Cable: 50,
+                Plastic: 20,
+            ],
+            Self::Refinery => cost![
+                Motor: 10,
+                EncasedIndustrialBeam: 10,
+                SteelPipe: 30,
+                CopperSheet: 20,
+            ],
+            Self::Packager => cost![SteelBeam: 20, Rubber: 10, Plastic: 10],
+            Self::Blender => cost![
+                Motor: 20,
+                HeavyModularFrame: 10,
+                AluminumCasing: 50,
+                RadioControlUnit: 5,
+            ],
+            Self::NuclearReactor => cost![
+                Concrete: 250,
+                HeavyModularFrame: 100,
+                Supercomputer: 50,
+                Cable: 100,
+                AlcladAluminumSheet: 100,
+            ],
+            Self::ParticleAccelerator => cost![
+                RadioControlUnit: 25,
+                ElectromagneticControlRod: 100,
+                Supercomputer: 10,
+                CoolingSystem: 50,
+                FusedModularFrame: 20,
+                TurboMotor: 10,
+            ],
+            Self::Converter => cost![
+                FusedModularFrame: 10,
+                CoolingSystem: 25,
+                RadioControlUnit: 50,
+                SamFluctuator: 100,
+            ],
+            Self::QuantumEncoder => cost![
+                TurboMotor: 20,
+                Supercomputer: 20,
+                CoolingSystem: 50,
+                TimeCrystal: 50,
+                FicsiteTrigon: 100,
+            ],
+        }
+    }
+}
+
+/// Items needed to construct one conveyor merger.
+pub const MERGER_COST: &[Ingredient] = cost![IronPlate: 2, IronRod: 2];
+
+/// Items needed to construct one conveyor splitter.
+pub const SPLITTER_COST: &[Ingredient] = cost![IronPlate: 2, Cable: 2];
+
+/// Items needed to construct one smart splitter.
+pub const SMART_SPLITTER_COST: &[Ingredient] = cost![
+    ReinforcedIronPlate: 2,
+    Rotor: 2,
+    AiLimiter: 1,
+];
+
+/// Items needed to construct one programmable splitter.
+pub const PROGRAMMABLE_SPLITTER_COST: &[Ingredient] = cost![
+    Supercomputer: 1,
+    HeavyModularFrame: 1,
+    Motor: 1,
+];
+
+/// Items needed to construct one priority merger.
+pub const PRIORITY_MERGER_COST: &[Ingredient] = cost![CircuitBoard: 2, Rotor: 2];
+
+/// Conveyor belt tiers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum BeltTier {
+    Mk1,
+    Mk2,
+    Mk3,
+    Mk4,
+    Mk5,
+    Mk6,
+}
+
+impl BeltTier {
+    pub const ALL: [Self; 6] = [Self::Mk1, Self::Mk2, Self::Mk3, Self::Mk4, Self::Mk5, Self::Mk6];
+
+    /// Items per minute this belt can transport.
+    pub fn max_rate(self) -> f64 {
+        match self {
+            Self::Mk1 => 60.0,
+            Self::Mk2 => 120.0,
+            Self::Mk3 => 270.0,
+            Self::Mk4 => 480.0,
+            Self::Mk5 => 780.0,
+            Self::Mk6 => 1200.0,
+        }
+    }
+
+    /// The lowest tier that can transport `rate` items per minute, or `None` if none can.
+    pub fn for_rate(rate: f64) -> Option<Self> {
+        Self::ALL.into_iter().find(|t| rate <= t.max_rate())
+    }
+}
+
+/// Pipeline tiers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum PipeTier {
+    Mk1,
+    Mk2,
+}
+
+impl PipeTier {
+    pub const ALL: [Self; 2] = [Self::Mk1, Self::Mk2];
+
+    /// m³ per minute this pipeline can transport.
+    pub fn max_rate(self) -> f64 {
+        match self {
+            Self::Mk1 => 300.0,
+            Self::Mk2 => 600.0,
+        }
+    }
+
+    /// The lowest tier that can transport `rate` m³ per minute, or `None` if none can.
+    pub fn for_rate(rate: f64) -> Option<Self> {
+        Self::ALL.into_iter().find(|t| rate <= t.max_rate())
+    }
+}
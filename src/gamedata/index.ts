@@ -1,5 +1,6 @@
 import { ITEMS as ITEMS_, RESOURCE_ITEMS as RESOURCE_ITEMS_ } from "./items";
 import { RECIPES as RECIPES_ } from "./recipes";
+import { allowed_recipes, load_data_packs, recipe_index } from "../../pkg/satisfactory_planner";
 
 export const ITEMS: Record<ItemId, Item> = ITEMS_;
 export const RECIPES: Record<RecipeId, Recipe> = RECIPES_;
@@ -7,12 +8,24 @@ export const RESOURCE_ITEMS = RESOURCE_ITEMS_ satisfies readonly ItemId[];
 
 export type ItemId = keyof typeof ITEMS_;
 export type Item = {
+    /** Class name used by the game and other tools, e.g. `Desc_IronPlate_C`. */
+    className: string;
     /** Human-readable name of the item. */
     name: string;
     /** Human-readable description of the item. */
     description: string;
+    /** Whether this is a solid item or a fluid (liquid or gas). */
+    form: ItemForm;
+    /** Maximum number of items per inventory slot. 0 for fluids. */
+    stackSize: number;
+    /** Energy in MJ when burned, per item or per m³ for fluids. 0 for non-fuels. */
+    energy: number;
+    /** Points awarded by the AWESOME Sink. 0 if the item cannot be sunk. */
+    sinkPoints: number;
 };
 
+export type ItemForm = "solid" | "liquid" | "gas";
+
 export type RecipeId = keyof typeof RECIPES_;
 export type RecipeEntry = {
     id: RecipeId;
@@ -23,8 +36,86 @@ export const recipeEntries = (): RecipeEntry[] => (
     Object.entries(RECIPES).map(([id, info]) => ({ id: id as RecipeId, info }))
 );
 
+type RecipeIndex = {
+    producers: Partial<Record<ItemId, RecipeId[]>>;
+    consumers: Partial<Record<ItemId, RecipeId[]>>;
+};
+
+// Fetched from the WASM module once, on first use.
+let recipeIndex: RecipeIndex | null = null;
+const getRecipeIndex = (): RecipeIndex => recipeIndex ??= JSON.parse(recipe_index());
+
+/** All recipes that have `item` as output. Uses the precomputed index from the WASM module. */
+export const recipesProducing = (item: ItemId): RecipeId[] => (
+    getRecipeIndex().producers[item] ?? []
+);
+
+/** All recipes that have `item` as input. Uses the precomputed index from the WASM module. */
+export const recipesConsuming = (item: ItemId): RecipeId[] => (
+    getRecipeIndex().consumers[item] ?? []
+);
+
+/** Name of a built-in item or one added by the loaded data packs. */
+export const itemName = (id: ItemId): string => (
+    ITEMS[id]?.name ?? packItemNames.get(id) ?? id
+);
+
+/** What recipe nodes need to know about their recipe, also available for pack recipes. */
+export type RecipeLike = Pick<Recipe, "name" | "duration" | "inputs" | "outputs">;
+
+/** `gamedata::GameData` as returned by `load_data_packs`, only the parts used here. */
+type GameDataJson = {
+    items: { id: string; name: string }[];
+    recipes: {
+        id: string;
+        name: string;
+        duration: number;
+        inputs: { item: string; amount: number }[];
+        outputs: { item: string; amount: number }[];
+    }[];
+};
+
+// Set by `loadDataPacks`. Items of packs may not be built-in, but are still keyed by `ItemId` so
+// that pack recipes can be shown like built-in ones.
+let packRecipes = new Map<string, RecipeLike>();
+let packItemNames = new Map<string, string>();
+let packsGeneration = 0;
+
+/**
+ * Applies data packs (see `src/gamedata/packs.rs` for the format) in order on top of the built-in
+ * game data. From then on, pack recipe nodes show their recipe and the WASM module simulates them
+ * in all analyses. Throws if a pack is invalid, keeping the previously loaded ones.
+ */
+export const loadDataPacks = (packs: unknown[]): void => {
+    const data: GameDataJson = JSON.parse(load_data_packs(JSON.stringify(packs)));
+    const entries = (io: { item: string; amount: number }[]): IoEntry[] => (
+        io.map(({ item, amount }) => ({ item: item as ItemId, amount }))
+    );
+    packItemNames = new Map(data.items.map(item => [item.id, item.name]));
+    packRecipes = new Map(data.recipes.map(recipe => [recipe.id, {
+        name: recipe.name,
+        duration: recipe.duration,
+        inputs: entries(recipe.inputs),
+        outputs: entries(recipe.outputs),
+    }]));
+    packsGeneration++;
+};
+
+/** Changes every time data packs are loaded, to invalidate anything computed from them. */
+export const dataPacksGeneration = (): number => packsGeneration;
+
+/** The recipe with the given ID from the loaded data packs, if any. */
+export const packRecipe = (id: string): RecipeLike | undefined => packRecipes.get(id);
+
+/** All recipes available with the given progression. */
+export const allowedRecipes = (filter: RecipeFilter): RecipeId[] => (
+    JSON.parse(allowed_recipes(JSON.stringify(filter)))
+);
+
 /** Information about a recipe. */
 export type Recipe = {
+    /** Class name used by the game and other tools, e.g. `Recipe_IronPlate_C`. */
+    className: string;
     /** Human-readable name of the recipe. */
     name: string;
     /** Crafting duration in seconds, at 100%. */
@@ -33,6 +124,8 @@ export type Recipe = {
     producedIn: ProductionBuilding;
     /** Whether this is an alternative recipe. */
     alternative: boolean;
+    /** How this recipe is unlocked in the game. */
+    unlock: Unlock;
     /** For recipes inside fluctuating power buildings, the min and max power requirement. */
     powerRequirements?: [number, number];
     /** Inputs for the recipe. */
@@ -46,6 +139,39 @@ export type IoEntry = {
     amount: number;
 };
 
+export type Unlock =
+    | { kind: "milestone"; tier: number }
+    | { kind: "mam"; tree: MamTree }
+    | { kind: "hard-drive" }
+    | { kind: "ficsmas" }
+    ;
+
+export type MamTree =
+    | "alien-organisms"
+    | "alien-technology"
+    | "caterium"
+    | "mycelia"
+    | "nutrients"
+    | "power-slugs"
+    | "quartz"
+    | "sulfur"
+    ;
+
+/**
+ * Restricts recipes to those available at some point of the progression. Omitted fields mean "no
+ * restriction".
+ */
+export type RecipeFilter = {
+    /** Highest tier with all milestones unlocked. */
+    maxTier?: number;
+    /** MAM trees that have been researched. */
+    mamTrees?: MamTree[];
+    /** Alternate recipes unlocked via hard drives. */
+    alternates?: RecipeId[];
+    /** Whether FICSMAS recipes are available. */
+    ficsmas?: boolean;
+};
+
 /** All buildings that can run recipes. */
 type ProductionBuilding =
     | "smelter"
@@ -475,6 +475,1739 @@ impl TryFrom<u8> for ItemKind {
     }
 }
 
+impl ItemKind {
+    pub fn info(self) -> &'static ItemInfo {
+        &ITEMS[self as u8 as usize]
+    }
+
+    /// The class name used by the game, e.g. `Desc_IronPlate_C`.
+    pub fn class_name(self) -> &'static str {
+        match self {
+            Self::UraniumWaste => "Desc_NuclearWaste_C",
+            Self::PlutoniumWaste => "Desc_PlutoniumWaste_C",
+            Self::Concrete => "Desc_Cement_C",
+            Self::Silica => "Desc_Silica_C",
+            Self::IronPlate => "Desc_IronPlate_C",
+            Self::SteelBeam => "Desc_SteelPlate_C",
+            Self::AluminumIngot => "Desc_AluminumIngot_C",
+            Self::Battery => "Desc_Battery_C",
+            Self::PackagedFuel => "Desc_Fuel_C",
+            Self::PackagedIonizedFuel => "Desc_PackagedIonizedFuel_C",
+            Self::PackagedRocketFuel => "Desc_PackagedRocketFuel_C",
+            Self::PackagedTurbofuel => "Desc_TurboFuel_C",
+            Self::IodineInfusedFilter => "Desc_HazmatFilter_C",
+            Self::CompactedCoal => "Desc_CompactedCoal_C",
+            Self::PackagedHeavyOilResidue => "Desc_PackagedOilResidue_C",
+            Self::PetroleumCoke => "Desc_PetroleumCoke_C",
+            Self::PackagedOil => "Desc_PackagedOil_C",
+            Self::IronRod => "Desc_IronRod_C",
+            Self::Wire => "Desc_Wire_C",
+            Self::Cable => "Desc_Cable_C",
+            Self::IronIngot => "Desc_IronIngot_C",
+            Self::ReinforcedIronPlate => "Desc_IronPlateReinforced_C",
+            Self::Rotor => "Desc_Rotor_C",
+            Self::Fuel => "Desc_LiquidFuel_C",
+            Self::RocketFuel => "Desc_RocketFuel_C",
+            Self::CopperSheet => "Desc_CopperSheet_C",
+            Self::ModularFrame => "Desc_ModularFrame_C",
+            Self::Screw => "Desc_IronScrew_C",
+            Self::NitricAcid => "Desc_NitricAcid_C",
+            Self::Turbofuel => "Desc_LiquidTurboFuel_C",
+            Self::EmptyFluidTank => "Desc_GasTank_C",
+            Self::CrystalOscillator => "Desc_CrystalOscillator_C",
+            Self::Motor => "Desc_Motor_C",
+            Self::DarkMatterCrystal => "Desc_DarkMatter_C",
+            Self::IonizedFuel => "Desc_IonizedFuel_C",
+            Self::Supercomputer => "Desc_ComputerSuper_C",
+            Self::CoolingSystem => "Desc_CoolingSystem_C",
+            Self::FicsiteTrigon => "Desc_FicsiteMesh_C",
+            Self::TurboMotor => "Desc_MotorLightweight_C",
+            Self::TimeCrystal => "Desc_TimeCrystal_C",
+            Self::DarkMatterResidue => "Desc_DarkEnergy_C",
+            Self::ReanimatedSam => "Desc_SAMIngot_C",
+            Self::ExcitedPhotonicMatter => "Desc_QuantumEnergy_C",
+            Self::Diamonds => "Desc_Diamond_C",
+            Self::AlcladAluminumSheet => "Desc_AluminumPlate_C",
+            Self::SuperpositionOscillator => "Desc_QuantumOscillator_C",
+            Self::NeuralQuantumProcessor => "Desc_TemporalProcessor_C",
+            Self::AiExpansionServer => "Desc_SpaceElevatorPart_12_C",
+            Self::MagneticFieldGenerator => "Desc_SpaceElevatorPart_6_C",
+            Self::SamFluctuator => "Desc_SAMFluctuator_C",
+            Self::SteelPipe => "Desc_SteelPipe_C",
+            Self::FusedModularFrame => "Desc_ModularFrameFused_C",
+            Self::RadioControlUnit => "Desc_ModularFrameLightweight_C",
+            Self::FicsiteIngot => "Desc_FicsiteIngot_C",
+            Self::BiochemicalSculptor => "Desc_SpaceElevatorPart_10_C",
+            Self::AssemblyDirectorSystem => "Desc_SpaceElevatorPart_7_C",
+            Self::CateriumIngot => "Desc_GoldIngot_C",
+            Self::EmptyCanister => "Desc_FluidCanister_C",
+            Self::CircuitBoard => "Desc_CircuitBoard_C",
+            Self::Plastic => "Desc_Plastic_C",
+            Self::EncasedIndustrialBeam => "Desc_SteelPlateReinforced_C",
+            Self::Rubber => "Desc_Rubber_C",
+            Self::PolymerResin => "Desc_PolymerResin_C",
+            Self::HeavyOilResidue => "Desc_HeavyOilResidue_C",
+            Self::QuartzCrystal => "Desc_QuartzCrystal_C",
+            Self::SteelIngot => "Desc_SteelIngot_C",
+            Self::VersatileFramework => "Desc_SpaceElevatorPart_2_C",
+            Self::PackagedWater => "Desc_PackagedWater_C",
+            Self::CopperIngot => "Desc_CopperIngot_C",
+            Self::AluminumScrap => "Desc_AluminumScrap_C",
+            Self::AluminumCasing => "Desc_AluminumCasing_C",
+            Self::AluminaSolution => "Desc_AluminaSolution_C",
+            Self::Computer => "Desc_Computer_C",
+            Self::HeavyModularFrame => "Desc_ModularFrameHeavy_C",
+            Self::SmartPlating => "Desc_SpaceElevatorPart_1_C",
+            Self::HighSpeedConnector => "Desc_HighSpeedConnector_C",
+            Self::AutomatedWiring => "Desc_SpaceElevatorPart_3_C",
+            Self::Stator => "Desc_Stator_C",
+            Self::AiLimiter => "Desc_CircuitBoardHighSpeed_C",
+            Self::Quickwire => "Desc_HighSpeedWire_C",
+            Self::ModularEngine => "Desc_SpaceElevatorPart_4_C",
+            Self::AdaptiveControlUnit => "Desc_SpaceElevatorPart_5_C",
+            Self::PressureConversionCube => "Desc_PressureConversionCube_C",
+            Self::EncasedPlutoniumCell => "Desc_PlutoniumCell_C",
+            Self::PlutoniumPellet => "Desc_PlutoniumPellet_C",
+            Self::NonFissileUranium => "Desc_NonFissibleUranium_C",
+            Self::SulfuricAcid => "Desc_SulfuricAcid_C",
+            Self::CopperPowder => "Desc_CopperDust_C",
+            Self::HeatSink => "Desc_AluminumPlateReinforced_C",
+            Self::ElectromagneticControlRod => "Desc_ElectromagneticControlRod_C",
+            Self::NuclearPasta => "Desc_SpaceElevatorPart_9_C",
+            Self::EncasedUraniumCell => "Desc_UraniumCell_C",
+            Self::DissolvedSilica => "Desc_DissolvedSilica_C",
+            Self::ThermalPropulsionRocket => "Desc_SpaceElevatorPart_8_C",
+            Self::BlackPowder => "Desc_Gunpowder_C",
+            Self::Ficsonium => "Desc_Ficsonium_C",
+            Self::SingularityCell => "Desc_SingularityCell_C",
+            Self::BallisticWarpDrive => "Desc_SpaceElevatorPart_11_C",
+            Self::GasFilter => "Desc_Filter_C",
+            Self::AlienProtein => "Desc_AlienProtein_C",
+            Self::BluePowerSlug => "Desc_Crystal_C",
+            Self::AlienDnaCapsule => "Desc_AlienDNACapsule_C",
+            Self::PurplePowerSlug => "Desc_Crystal_mk3_C",
+            Self::YellowPowerSlug => "Desc_Crystal_mk2_C",
+            Self::SmokelessPowder => "Desc_GunpowderMK2_C",
+            Self::UraniumFuelRod => "Desc_NuclearFuelRod_C",
+            Self::PlutoniumFuelRod => "Desc_PlutoniumFuelRod_C",
+            Self::FicsoniumFuelRod => "Desc_FicsoniumFuelRod_C",
+            Self::ExplosiveRebar => "Desc_Rebar_Explosive_C",
+            Self::StunRebar => "Desc_Rebar_Stunshot_C",
+            Self::HomingRifleAmmo => "Desc_CartridgeSmartProjectile_C",
+            Self::ClusterNobelisk => "Desc_NobeliskCluster_C",
+            Self::Nobelisk => "Desc_NobeliskExplosive_C",
+            Self::GasNobelisk => "Desc_NobeliskGas_C",
+            Self::NukeNobelisk => "Desc_NobeliskNuke_C",
+            Self::PulseNobelisk => "Desc_NobeliskShockwave_C",
+            Self::IronRebar => "Desc_SpikedRebar_C",
+            Self::PortableMiner => "BP_ItemDescriptorPortableMiner_C",
+            Self::ShatterRebar => "Desc_Rebar_Spreadshot_C",
+            Self::TurboRifleAmmo => "Desc_CartridgeChaos_C",
+            Self::RifleAmmo => "Desc_CartridgeStandard_C",
+            Self::IronOre => "Desc_OreIron_C",
+            Self::Coal => "Desc_Coal_C",
+            Self::Water => "Desc_Water_C",
+            Self::NitrogenGas => "Desc_NitrogenGas_C",
+            Self::Sulfur => "Desc_Sulfur_C",
+            Self::Sam => "Desc_SAM_C",
+            Self::Bauxite => "Desc_OreBauxite_C",
+            Self::CateriumOre => "Desc_OreGold_C",
+            Self::CopperOre => "Desc_OreCopper_C",
+            Self::RawQuartz => "Desc_RawQuartz_C",
+            Self::Limestone => "Desc_Stone_C",
+            Self::Uranium => "Desc_OreUranium_C",
+            Self::CrudeOil => "Desc_LiquidOil_C",
+            Self::SolidBiofuel => "Desc_Biofuel_C",
+            Self::PackagedLiquidBiofuel => "Desc_PackagedBiofuel_C",
+            Self::Biomass => "Desc_GenericBiomass_C",
+            Self::Leaves => "Desc_Leaves_C",
+            Self::Mycelia => "Desc_Mycelia_C",
+            Self::Wood => "Desc_Wood_C",
+            Self::LiquidBiofuel => "Desc_LiquidBiofuel_C",
+            Self::PackagedAluminaSolution => "Desc_PackagedAlumina_C",
+            Self::PackagedNitrogenGas => "Desc_PackagedNitrogenGas_C",
+            Self::PackagedNitricAcid => "Desc_PackagedNitricAcid_C",
+            Self::PackagedSulfuricAcid => "Desc_PackagedSulfuricAcid_C",
+            Self::Fabric => "Desc_Fabric_C",
+            Self::HogRemains => "Desc_HogParts_C",
+            Self::SpitterRemains => "Desc_SpitterParts_C",
+            Self::StingerRemains => "Desc_StingerParts_C",
+            Self::HatcherRemains => "Desc_HatcherParts_C",
+            Self::PowerShard => "Desc_CrystalShard_C",
+            Self::AlienPowerMatrix => "Desc_AlienPowerFuel_C",
+        }
+    }
+
+    /// Looks up an item by the class name used by the game, e.g. `Desc_IronPlate_C`.
+    pub fn from_class_name(name: &str) -> Option<Self> {
+        match name {
+            "Desc_NuclearWaste_C" => Some(Self::UraniumWaste),
+            "Desc_PlutoniumWaste_C" => Some(Self::PlutoniumWaste),
+            "Desc_Cement_C" => Some(Self::Concrete),
+            "Desc_Silica_C" => Some(Self::Silica),
+            "Desc_IronPlate_C" => Some(Self::IronPlate),
+            "Desc_SteelPlate_C" => Some(Self::SteelBeam),
+            "Desc_AluminumIngot_C" => Some(Self::AluminumIngot),
+            "Desc_Battery_C" => Some(Self::Battery),
+            "Desc_Fuel_C" => Some(Self::PackagedFuel),
+            "Desc_PackagedIonizedFuel_C" => Some(Self::PackagedIonizedFuel),
+            "Desc_PackagedRocketFuel_C" => Some(Self::PackagedRocketFuel),
+            "Desc_TurboFuel_C" => Some(Self::PackagedTurbofuel),
+            "Desc_HazmatFilter_C" => Some(Self::IodineInfusedFilter),
+            "Desc_CompactedCoal_C" => Some(Self::CompactedCoal),
+            "Desc_PackagedOilResidue_C" => Some(Self::PackagedHeavyOilResidue),
+            "Desc_PetroleumCoke_C" => Some(Self::PetroleumCoke),
+            "Desc_PackagedOil_C" => Some(Self::PackagedOil),
+            "Desc_IronRod_C" => Some(Self::IronRod),
+            "Desc_Wire_C" => Some(Self::Wire),
+            "Desc_Cable_C" => Some(Self::Cable),
+            "Desc_IronIngot_C" => Some(Self::IronIngot),
+            "Desc_IronPlateReinforced_C" => Some(Self::ReinforcedIronPlate),
+            "Desc_Rotor_C" => Some(Self::Rotor),
+            "Desc_LiquidFuel_C" => Some(Self::Fuel),
+            "Desc_RocketFuel_C" => Some(Self::RocketFuel),
+            "Desc_CopperSheet_C" => Some(Self::CopperSheet),
+            "Desc_ModularFrame_C" => Some(Self::ModularFrame),
+            "Desc_IronScrew_C" => Some(Self::Screw),
+            "Desc_NitricAcid_C" => Some(Self::NitricAcid),
+            "Desc_LiquidTurboFuel_C" => Some(Self::Turbofuel),
+            "Desc_GasTank_C" => Some(Self::EmptyFluidTank),
+            "Desc_CrystalOscillator_C" => Some(Self::CrystalOscillator),
+            "Desc_Motor_C" => Some(Self::Motor),
+            "Desc_DarkMatter_C" => Some(Self::DarkMatterCrystal),
+            "Desc_IonizedFuel_C" => Some(Self::IonizedFuel),
+            "Desc_ComputerSuper_C" => Some(Self::Supercomputer),
+            "Desc_CoolingSystem_C" => Some(Self::CoolingSystem),
+            "Desc_FicsiteMesh_C" => Some(Self::FicsiteTrigon),
+            "Desc_MotorLightweight_C" => Some(Self::TurboMotor),
+            "Desc_TimeCrystal_C" => Some(Self::TimeCrystal),
+            "Desc_DarkEnergy_C" => Some(Self::DarkMatterResidue),
+            "Desc_SAMIngot_C" => Some(Self::ReanimatedSam),
+            "Desc_QuantumEnergy_C" => Some(Self::ExcitedPhotonicMatter),
+            "Desc_Diamond_C" => Some(Self::Diamonds),
+            "Desc_AluminumPlate_C" => Some(Self::AlcladAluminumSheet),
+            "Desc_QuantumOscillator_C" => Some(Self::SuperpositionOscillator),
+            "Desc_TemporalProcessor_C" => Some(Self::NeuralQuantumProcessor),
+            "Desc_SpaceElevatorPart_12_C" => Some(Self::AiExpansionServer),
+            "Desc_SpaceElevatorPart_6_C" => Some(Self::MagneticFieldGenerator),
+            "Desc_SAMFluctuator_C" => Some(Self::SamFluctuator),
+            "Desc_SteelPipe_C" => Some(Self::SteelPipe),
+            "Desc_ModularFrameFused_C" => Some(Self::FusedModularFrame),
+            "Desc_ModularFrameLightweight_C" => Some(Self::RadioControlUnit),
+            "Desc_FicsiteIngot_C" => Some(Self::FicsiteIngot),
+            "Desc_SpaceElevatorPart_10_C" => Some(Self::BiochemicalSculptor),
+            "Desc_SpaceElevatorPart_7_C" => Some(Self::AssemblyDirectorSystem),
+            "Desc_GoldIngot_C" => Some(Self::CateriumIngot),
+            "Desc_FluidCanister_C" => Some(Self::EmptyCanister),
+            "Desc_CircuitBoard_C" => Some(Self::CircuitBoard),
+            "Desc_Plastic_C" => Some(Self::Plastic),
+            "Desc_SteelPlateReinforced_C" => Some(Self::EncasedIndustrialBeam),
+            "Desc_Rubber_C" => Some(Self::Rubber),
+            "Desc_PolymerResin_C" => Some(Self::PolymerResin),
+            "Desc_HeavyOilResidue_C" => Some(Self::HeavyOilResidue),
+            "Desc_QuartzCrystal_C" => Some(Self::QuartzCrystal),
+            "Desc_SteelIngot_C" => Some(Self::SteelIngot),
+            "Desc_SpaceElevatorPart_2_C" => Some(Self::VersatileFramework),
+            "Desc_PackagedWater_C" => Some(Self::PackagedWater),
+            "Desc_CopperIngot_C" => Some(Self::CopperIngot),
+            "Desc_AluminumScrap_C" => Some(Self::AluminumScrap),
+            "Desc_AluminumCasing_C" => Some(Self::AluminumCasing),
+            "Desc_AluminaSolution_C" => Some(Self::AluminaSolution),
+            "Desc_Computer_C" => Some(Self::Computer),
+            "Desc_ModularFrameHeavy_C" => Some(Self::HeavyModularFrame),
+            "Desc_SpaceElevatorPart_1_C" => Some(Self::SmartPlating),
+            "Desc_HighSpeedConnector_C" => Some(Self::HighSpeedConnector),
+            "Desc_SpaceElevatorPart_3_C" => Some(Self::AutomatedWiring),
+            "Desc_Stator_C" => Some(Self::Stator),
+            "Desc_CircuitBoardHighSpeed_C" => Some(Self::AiLimiter),
+            "Desc_HighSpeedWire_C" => Some(Self::Quickwire),
+            "Desc_SpaceElevatorPart_4_C" => Some(Self::ModularEngine),
+            "Desc_SpaceElevatorPart_5_C" => Some(Self::AdaptiveControlUnit),
+            "Desc_PressureConversionCube_C" => Some(Self::PressureConversionCube),
+            "Desc_PlutoniumCell_C" => Some(Self::EncasedPlutoniumCell),
+            "Desc_PlutoniumPellet_C" => Some(Self::PlutoniumPellet),
+            "Desc_NonFissibleUranium_C" => Some(Self::NonFissileUranium),
+            "Desc_SulfuricAcid_C" => Some(Self::SulfuricAcid),
+            "Desc_CopperDust_C" => Some(Self::CopperPowder),
+            "Desc_AluminumPlateReinforced_C" => Some(Self::HeatSink),
+            "Desc_ElectromagneticControlRod_C" => Some(Self::ElectromagneticControlRod),
+            "Desc_SpaceElevatorPart_9_C" => Some(Self::NuclearPasta),
+            "Desc_UraniumCell_C" => Some(Self::EncasedUraniumCell),
+            "Desc_DissolvedSilica_C" => Some(Self::DissolvedSilica),
+            "Desc_SpaceElevatorPart_8_C" => Some(Self::ThermalPropulsionRocket),
+            "Desc_Gunpowder_C" => Some(Self::BlackPowder),
+            "Desc_Ficsonium_C" => Some(Self::Ficsonium),
+            "Desc_SingularityCell_C" => Some(Self::SingularityCell),
+            "Desc_SpaceElevatorPart_11_C" => Some(Self::BallisticWarpDrive),
+            "Desc_Filter_C" => Some(Self::GasFilter),
+            "Desc_AlienProtein_C" => Some(Self::AlienProtein),
+            "Desc_Crystal_C" => Some(Self::BluePowerSlug),
+            "Desc_AlienDNACapsule_C" => Some(Self::AlienDnaCapsule),
+            "Desc_Crystal_mk3_C" => Some(Self::PurplePowerSlug),
+            "Desc_Crystal_mk2_C" => Some(Self::YellowPowerSlug),
+            "Desc_GunpowderMK2_C" => Some(Self::SmokelessPowder),
+            "Desc_NuclearFuelRod_C" => Some(Self::UraniumFuelRod),
+            "Desc_PlutoniumFuelRod_C" => Some(Self::PlutoniumFuelRod),
+            "Desc_FicsoniumFuelRod_C" => Some(Self::FicsoniumFuelRod),
+            "Desc_Rebar_Explosive_C" => Some(Self::ExplosiveRebar),
+            "Desc_Rebar_Stunshot_C" => Some(Self::StunRebar),
+            "Desc_CartridgeSmartProjectile_C" => Some(Self::HomingRifleAmmo),
+            "Desc_NobeliskCluster_C" => Some(Self::ClusterNobelisk),
+            "Desc_NobeliskExplosive_C" => Some(Self::Nobelisk),
+            "Desc_NobeliskGas_C" => Some(Self::GasNobelisk),
+            "Desc_NobeliskNuke_C" => Some(Self::NukeNobelisk),
+            "Desc_NobeliskShockwave_C" => Some(Self::PulseNobelisk),
+            "Desc_SpikedRebar_C" => Some(Self::IronRebar),
+            "BP_ItemDescriptorPortableMiner_C" => Some(Self::PortableMiner),
+            "Desc_Rebar_Spreadshot_C" => Some(Self::ShatterRebar),
+            "Desc_CartridgeChaos_C" => Some(Self::TurboRifleAmmo),
+            "Desc_CartridgeStandard_C" => Some(Self::RifleAmmo),
+            "Desc_OreIron_C" => Some(Self::IronOre),
+            "Desc_Coal_C" => Some(Self::Coal),
+            "Desc_Water_C" => Some(Self::Water),
+            "Desc_NitrogenGas_C" => Some(Self::NitrogenGas),
+            "Desc_Sulfur_C" => Some(Self::Sulfur),
+            "Desc_SAM_C" => Some(Self::Sam),
+            "Desc_OreBauxite_C" => Some(Self::Bauxite),
+            "Desc_OreGold_C" => Some(Self::CateriumOre),
+            "Desc_OreCopper_C" => Some(Self::CopperOre),
+            "Desc_RawQuartz_C" => Some(Self::RawQuartz),
+            "Desc_Stone_C" => Some(Self::Limestone),
+            "Desc_OreUranium_C" => Some(Self::Uranium),
+            "Desc_LiquidOil_C" => Some(Self::CrudeOil),
+            "Desc_Biofuel_C" => Some(Self::SolidBiofuel),
+            "Desc_PackagedBiofuel_C" => Some(Self::PackagedLiquidBiofuel),
+            "Desc_GenericBiomass_C" => Some(Self::Biomass),
+            "Desc_Leaves_C" => Some(Self::Leaves),
+            "Desc_Mycelia_C" => Some(Self::Mycelia),
+            "Desc_Wood_C" => Some(Self::Wood),
+            "Desc_LiquidBiofuel_C" => Some(Self::LiquidBiofuel),
+            "Desc_PackagedAlumina_C" => Some(Self::PackagedAluminaSolution),
+            "Desc_PackagedNitrogenGas_C" => Some(Self::PackagedNitrogenGas),
+            "Desc_PackagedNitricAcid_C" => Some(Self::PackagedNitricAcid),
+            "Desc_PackagedSulfuricAcid_C" => Some(Self::PackagedSulfuricAcid),
+            "Desc_Fabric_C" => Some(Self::Fabric),
+            "Desc_HogParts_C" => Some(Self::HogRemains),
+            "Desc_SpitterParts_C" => Some(Self::SpitterRemains),
+            "Desc_StingerParts_C" => Some(Self::StingerRemains),
+            "Desc_HatcherParts_C" => Some(Self::HatcherRemains),
+            "Desc_CrystalShard_C" => Some(Self::PowerShard),
+            "Desc_AlienPowerFuel_C" => Some(Self::AlienPowerMatrix),
+            _ => None,
+        }
+    }
+
+    /// ID used in digests, see `data/item-ids.json`. Unlike the discriminant, it never changes
+    /// when the game data is regenerated.
+    pub fn stable_id(self) -> u8 {
+        match self {
+            Self::UraniumWaste => 0,
+            Self::PlutoniumWaste => 1,
+            Self::Concrete => 2,
+            Self::Silica => 3,
+            Self::IronPlate => 4,
+            Self::SteelBeam => 5,
+            Self::AluminumIngot => 6,
+            Self::Battery => 7,
+            Self::PackagedFuel => 8,
+            Self::PackagedIonizedFuel => 9,
+            Self::PackagedRocketFuel => 10,
+            Self::PackagedTurbofuel => 11,
+            Self::IodineInfusedFilter => 12,
+            Self::CompactedCoal => 13,
+            Self::PackagedHeavyOilResidue => 14,
+            Self::PetroleumCoke => 15,
+            Self::PackagedOil => 16,
+            Self::IronRod => 17,
+            Self::Wire => 18,
+            Self::Cable => 19,
+            Self::IronIngot => 20,
+            Self::ReinforcedIronPlate => 21,
+            Self::Rotor => 22,
+            Self::Fuel => 23,
+            Self::RocketFuel => 24,
+            Self::CopperSheet => 25,
+            Self::ModularFrame => 26,
+            Self::Screw => 27,
+            Self::NitricAcid => 28,
+            Self::Turbofuel => 29,
+            Self::EmptyFluidTank => 30,
+            Self::CrystalOscillator => 31,
+            Self::Motor => 32,
+            Self::DarkMatterCrystal => 33,
+            Self::IonizedFuel => 34,
+            Self::Supercomputer => 35,
+            Self::CoolingSystem => 36,
+            Self::FicsiteTrigon => 37,
+            Self::TurboMotor => 38,
+            Self::TimeCrystal => 39,
+            Self::DarkMatterResidue => 40,
+            Self::ReanimatedSam => 41,
+            Self::ExcitedPhotonicMatter => 42,
+            Self::Diamonds => 43,
+            Self::AlcladAluminumSheet => 44,
+            Self::SuperpositionOscillator => 45,
+            Self::NeuralQuantumProcessor => 46,
+            Self::AiExpansionServer => 47,
+            Self::MagneticFieldGenerator => 48,
+            Self::SamFluctuator => 49,
+            Self::SteelPipe => 50,
+            Self::FusedModularFrame => 51,
+            Self::RadioControlUnit => 52,
+            Self::FicsiteIngot => 53,
+            Self::BiochemicalSculptor => 54,
+            Self::AssemblyDirectorSystem => 55,
+            Self::CateriumIngot => 56,
+            Self::EmptyCanister => 57,
+            Self::CircuitBoard => 58,
+            Self::Plastic => 59,
+            Self::EncasedIndustrialBeam => 60,
+            Self::Rubber => 61,
+            Self::PolymerResin => 62,
+            Self::HeavyOilResidue => 63,
+            Self::QuartzCrystal => 64,
+            Self::SteelIngot => 65,
+            Self::VersatileFramework => 66,
+            Self::PackagedWater => 67,
+            Self::CopperIngot => 68,
+            Self::AluminumScrap => 69,
+            Self::AluminumCasing => 70,
+            Self::AluminaSolution => 71,
+            Self::Computer => 72,
+            Self::HeavyModularFrame => 73,
+            Self::SmartPlating => 74,
+            Self::HighSpeedConnector => 75,
+            Self::AutomatedWiring => 76,
+            Self::Stator => 77,
+            Self::AiLimiter => 78,
+            Self::Quickwire => 79,
+            Self::ModularEngine => 80,
+            Self::AdaptiveControlUnit => 81,
+            Self::PressureConversionCube => 82,
+            Self::EncasedPlutoniumCell => 83,
+            Self::PlutoniumPellet => 84,
+            Self::NonFissileUranium => 85,
+            Self::SulfuricAcid => 86,
+            Self::CopperPowder => 87,
+            Self::HeatSink => 88,
+            Self::ElectromagneticControlRod => 89,
+            Self::NuclearPasta => 90,
+            Self::EncasedUraniumCell => 91,
+            Self::DissolvedSilica => 92,
+            Self::ThermalPropulsionRocket => 93,
+            Self::BlackPowder => 94,
+            Self::Ficsonium => 95,
+            Self::SingularityCell => 96,
+            Self::BallisticWarpDrive => 97,
+            Self::GasFilter => 98,
+            Self::AlienProtein => 99,
+            Self::BluePowerSlug => 100,
+            Self::AlienDnaCapsule => 101,
+            Self::PurplePowerSlug => 102,
+            Self::YellowPowerSlug => 103,
+            Self::SmokelessPowder => 104,
+            Self::UraniumFuelRod => 105,
+            Self::PlutoniumFuelRod => 106,
+            Self::FicsoniumFuelRod => 107,
+            Self::ExplosiveRebar => 108,
+            Self::StunRebar => 109,
+            Self::HomingRifleAmmo => 110,
+            Self::ClusterNobelisk => 111,
+            Self::Nobelisk => 112,
+            Self::GasNobelisk => 113,
+            Self::NukeNobelisk => 114,
+            Self::PulseNobelisk => 115,
+            Self::IronRebar => 116,
+            Self::PortableMiner => 117,
+            Self::ShatterRebar => 118,
+            Self::TurboRifleAmmo => 119,
+            Self::RifleAmmo => 120,
+            Self::IronOre => 121,
+            Self::Coal => 122,
+            Self::Water => 123,
+            Self::NitrogenGas => 124,
+            Self::Sulfur => 125,
+            Self::Sam => 126,
+            Self::Bauxite => 127,
+            Self::CateriumOre => 128,
+            Self::CopperOre => 129,
+            Self::RawQuartz => 130,
+            Self::Limestone => 131,
+            Self::Uranium => 132,
+            Self::CrudeOil => 133,
+            Self::SolidBiofuel => 134,
+            Self::PackagedLiquidBiofuel => 135,
+            Self::Biomass => 136,
+            Self::Leaves => 137,
+            Self::Mycelia => 138,
+            Self::Wood => 139,
+            Self::LiquidBiofuel => 140,
+            Self::PackagedAluminaSolution => 141,
+            Self::PackagedNitrogenGas => 142,
+            Self::PackagedNitricAcid => 143,
+            Self::PackagedSulfuricAcid => 144,
+            Self::Fabric => 145,
+            Self::HogRemains => 146,
+            Self::SpitterRemains => 147,
+            Self::StingerRemains => 148,
+            Self::HatcherRemains => 149,
+            Self::PowerShard => 150,
+            Self::AlienPowerMatrix => 151,
+        }
+    }
+
+    /// Inverse of `stable_id`. Returns `None` for unknown IDs and removed items.
+    pub fn from_stable_id(id: u8) -> Option<Self> {
+        match id {
+            0 => Some(Self::UraniumWaste),
+            1 => Some(Self::PlutoniumWaste),
+            2 => Some(Self::Concrete),
+            3 => Some(Self::Silica),
+            4 => Some(Self::IronPlate),
+            5 => Some(Self::SteelBeam),
+            6 => Some(Self::AluminumIngot),
+            7 => Some(Self::Battery),
+            8 => Some(Self::PackagedFuel),
+            9 => Some(Self::PackagedIonizedFuel),
+            10 => Some(Self::PackagedRocketFuel),
+            11 => Some(Self::PackagedTurbofuel),
+            12 => Some(Self::IodineInfusedFilter),
+            13 => Some(Self::CompactedCoal),
+            14 => Some(Self::PackagedHeavyOilResidue),
+            15 => Some(Self::PetroleumCoke),
+            16 => Some(Self::PackagedOil),
+            17 => Some(Self::IronRod),
+            18 => Some(Self::Wire),
+            19 => Some(Self::Cable),
+            20 => Some(Self::IronIngot),
+            21 => Some(Self::ReinforcedIronPlate),
+            22 => Some(Self::Rotor),
+            23 => Some(Self::Fuel),
+            24 => Some(Self::RocketFuel),
+            25 => Some(Self::CopperSheet),
+            26 => Some(Self::ModularFrame),
+            27 => Some(Self::Screw),
+            28 => Some(Self::NitricAcid),
+            29 => Some(Self::Turbofuel),
+            30 => Some(Self::EmptyFluidTank),
+            31 => Some(Self::CrystalOscillator),
+            32 => Some(Self::Motor),
+            33 => Some(Self::DarkMatterCrystal),
+            34 => Some(Self::IonizedFuel),
+            35 => Some(Self::Supercomputer),
+            36 => Some(Self::CoolingSystem),
+            37 => Some(Self::FicsiteTrigon),
+            38 => Some(Self::TurboMotor),
+            39 => Some(Self::TimeCrystal),
+            40 => Some(Self::DarkMatterResidue),
+            41 => Some(Self::ReanimatedSam),
+            42 => Some(Self::ExcitedPhotonicMatter),
+            43 => Some(Self::Diamonds),
+            44 => Some(Self::AlcladAluminumSheet),
+            45 => Some(Self::SuperpositionOscillator),
+            46 => Some(Self::NeuralQuantumProcessor),
+            47 => Some(Self::AiExpansionServer),
+            48 => Some(Self::MagneticFieldGenerator),
+            49 => Some(Self::SamFluctuator),
+            50 => Some(Self::SteelPipe),
+            51 => Some(Self::FusedModularFrame),
+            52 => Some(Self::RadioControlUnit),
+            53 => Some(Self::FicsiteIngot),
+            54 => Some(Self::BiochemicalSculptor),
+            55 => Some(Self::AssemblyDirectorSystem),
+            56 => Some(Self::CateriumIngot),
+            57 => Some(Self::EmptyCanister),
+            58 => Some(Self::CircuitBoard),
+            59 => Some(Self::Plastic),
+            60 => Some(Self::EncasedIndustrialBeam),
+            61 => Some(Self::Rubber),
+            62 => Some(Self::PolymerResin),
+            63 => Some(Self::HeavyOilResidue),
+            64 => Some(Self::QuartzCrystal),
+            65 => Some(Self::SteelIngot),
+            66 => Some(Self::VersatileFramework),
+            67 => Some(Self::PackagedWater),
+            68 => Some(Self::CopperIngot),
+            69 => Some(Self::AluminumScrap),
+            70 => Some(Self::AluminumCasing),
+            71 => Some(Self::AluminaSolution),
+            72 => Some(Self::Computer),
+            73 => Some(Self::HeavyModularFrame),
+            74 => Some(Self::SmartPlating),
+            75 => Some(Self::HighSpeedConnector),
+            76 => Some(Self::AutomatedWiring),
+            77 => Some(Self::Stator),
+            78 => Some(Self::AiLimiter),
+            79 => Some(Self::Quickwire),
+            80 => Some(Self::ModularEngine),
+            81 => Some(Self::AdaptiveControlUnit),
+            82 => Some(Self::PressureConversionCube),
+            83 => Some(Self::EncasedPlutoniumCell),
+            84 => Some(Self::PlutoniumPellet),
+            85 => Some(Self::NonFissileUranium),
+            86 => Some(Self::SulfuricAcid),
+            87 => Some(Self::CopperPowder),
+            88 => Some(Self::HeatSink),
+            89 => Some(Self::ElectromagneticControlRod),
+            90 => Some(Self::NuclearPasta),
+            91 => Some(Self::EncasedUraniumCell),
+            92 => Some(Self::DissolvedSilica),
+            93 => Some(Self::ThermalPropulsionRocket),
+            94 => Some(Self::BlackPowder),
+            95 => Some(Self::Ficsonium),
+            96 => Some(Self::SingularityCell),
+            97 => Some(Self::BallisticWarpDrive),
+            98 => Some(Self::GasFilter),
+            99 => Some(Self::AlienProtein),
+            100 => Some(Self::BluePowerSlug),
+            101 => Some(Self::AlienDnaCapsule),
+            102 => Some(Self::PurplePowerSlug),
+            103 => Some(Self::YellowPowerSlug),
+            104 => Some(Self::SmokelessPowder),
+            105 => Some(Self::UraniumFuelRod),
+            106 => Some(Self::PlutoniumFuelRod),
+            107 => Some(Self::FicsoniumFuelRod),
+            108 => Some(Self::ExplosiveRebar),
+            109 => Some(Self::StunRebar),
+            110 => Some(Self::HomingRifleAmmo),
+            111 => Some(Self::ClusterNobelisk),
+            112 => Some(Self::Nobelisk),
+            113 => Some(Self::GasNobelisk),
+            114 => Some(Self::NukeNobelisk),
+            115 => Some(Self::PulseNobelisk),
+            116 => Some(Self::IronRebar),
+            117 => Some(Self::PortableMiner),
+            118 => Some(Self::ShatterRebar),
+            119 => Some(Self::TurboRifleAmmo),
+            120 => Some(Self::RifleAmmo),
+            121 => Some(Self::IronOre),
+            122 => Some(Self::Coal),
+            123 => Some(Self::Water),
+            124 => Some(Self::NitrogenGas),
+            125 => Some(Self::Sulfur),
+            126 => Some(Self::Sam),
+            127 => Some(Self::Bauxite),
+            128 => Some(Self::CateriumOre),
+            129 => Some(Self::CopperOre),
+            130 => Some(Self::RawQuartz),
+            131 => Some(Self::Limestone),
+            132 => Some(Self::Uranium),
+            133 => Some(Self::CrudeOil),
+            134 => Some(Self::SolidBiofuel),
+            135 => Some(Self::PackagedLiquidBiofuel),
+            136 => Some(Self::Biomass),
+            137 => Some(Self::Leaves),
+            138 => Some(Self::Mycelia),
+            139 => Some(Self::Wood),
+            140 => Some(Self::LiquidBiofuel),
+            141 => Some(Self::PackagedAluminaSolution),
+            142 => Some(Self::PackagedNitrogenGas),
+            143 => Some(Self::PackagedNitricAcid),
+            144 => Some(Self::PackagedSulfuricAcid),
+            145 => Some(Self::Fabric),
+            146 => Some(Self::HogRemains),
+            147 => Some(Self::SpitterRemains),
+            148 => Some(Self::StingerRemains),
+            149 => Some(Self::HatcherRemains),
+            150 => Some(Self::PowerShard),
+            151 => Some(Self::AlienPowerMatrix),
+            _ => None,
+        }
+    }
+}
+
+pub struct ItemInfo {
+    pub name: &'static str,
+    pub form: ItemForm,
+    /// Maximum number of items per inventory slot. 0 for fluids.
+    pub stack_size: u32,
+    /// Energy in MJ when burned, per item or per m³ for fluids. 0 for non-fuels.
+    pub energy: u32,
+    /// Points awarded by the AWESOME Sink. 0 if the item cannot be sunk.
+    pub sink_points: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ItemForm {
+    Solid,
+    Liquid,
+    Gas,
+}
+
+impl ItemForm {
+    /// Whether this is transported via pipes, with rates in m³/min instead of items/min.
+    pub fn is_fluid(self) -> bool {
+        self != Self::Solid
+    }
+}
+
+const ITEMS: [ItemInfo; 152] = [
+    ItemInfo {
+        name: "Uranium Waste",
+        form: ItemForm::Solid,
+        stack_size: 500,
+        energy: 0,
+        sink_points: 0,
+    },
+    ItemInfo {
+        name: "Plutonium Waste",
+        form: ItemForm::Solid,
+        stack_size: 500,
+        energy: 0,
+        sink_points: 0,
+    },
+    ItemInfo {
+        name: "Concrete",
+        form: ItemForm::Solid,
+        stack_size: 500,
+        energy: 0,
+        sink_points: 12,
+    },
+    ItemInfo {
+        name: "Silica",
+        form: ItemForm::Solid,
+        stack_size: 500,
+        energy: 0,
+        sink_points: 20,
+    },
+    ItemInfo {
+        name: "Iron Plate",
+        form: ItemForm::Solid,
+        stack_size: 200,
+        energy: 0,
+        sink_points: 6,
+    },
+    ItemInfo {
+        name: "Steel Beam",
+        form: ItemForm::Solid,
+        stack_size: 200,
+        energy: 0,
+        sink_points: 64,
+    },
+    ItemInfo {
+        name: "Aluminum Ingot",
+        form: ItemForm::Solid,
+        stack_size: 100,
+        energy: 0,
+        sink_points: 131,
+    },
+    ItemInfo {
+        name: "Battery",
+        form: ItemForm::Solid,
+        stack_size: 200,
+        energy: 6000,
+        sink_points: 465,
+    },
+    ItemInfo {
+        name: "Packaged Fuel",
+        form: ItemForm::Solid,
+        stack_size: 100,
+        energy: 750,
+        sink_points: 270,
+    },
+    ItemInfo {
+        name: "Packaged Ionized Fuel",
+        form: ItemForm::Solid,
+        stack_size: 100,
+        energy: 5000,
+        sink_points: 5246,
+    },
+    ItemInfo {
+        name: "Packaged Rocket Fuel",
+        form: ItemForm::Solid,
+        stack_size: 100,
+        energy: 3600,
+        sink_points: 1028,
+    },
+    ItemInfo {
+        name: "Packaged Turbofuel",
+        form: ItemForm::Solid,
+        stack_size: 100,
+        energy: 2000,
+        sink_points: 570,
+    },
+    ItemInfo {
+        name: "Iodine-Infused Filter",
+        form: ItemForm::Solid,
+        stack_size: 50,
+        energy: 0,
+        sink_points: 2718,
+    },
+    ItemInfo {
+        name: "Compacted Coal",
+        form: ItemForm::Solid,
+        stack_size: 100,
+        energy: 630,
+        sink_points: 28,
+    },
+    ItemInfo {
+        name: "Packaged Heavy Oil Residue",
+        form: ItemForm::Solid,
+        stack_size: 100,
+        energy: 0,
+        sink_points: 180,
+    },
+    ItemInfo {
+        name: "Petroleum Coke",
+        form: ItemForm::Solid,
+        stack_size: 200,
+        energy: 180,
+        sink_points: 20,
+    },
+    ItemInfo {
+        name: "Packaged Oil",
+        form: ItemForm::Solid,
+        stack_size: 100,
+        energy: 0,
+        sink_points: 160,
+    },
+    ItemInfo {
+        name: "Iron Rod",
+        form: ItemForm::Solid,
+        stack_size: 200,
+        energy: 0,
+        sink_points: 4,
+    },
+    ItemInfo {
+        name: "Wire",
+        form: ItemForm::Solid,
+        stack_size: 500,
+        energy: 0,
+        sink_points: 6,
+    },
+    ItemInfo {
+        name: "Cable",
+        form: ItemForm::Solid,
+        stack_size: 200,
+        energy: 0,
+        sink_points: 24,
+    },
+    ItemInfo {
+        name: "Iron Ingot",
+        form: ItemForm::Solid,
+        stack_size: 100,
+        energy: 0,
+        sink_points: 2,
+    },
+    ItemInfo {
+        name: "Reinforced Iron Plate",
+        form: ItemForm::Solid,
+        stack_size: 100,
+        energy: 0,
+        sink_points: 120,
+    },
+    ItemInfo {
+        name: "Rotor",
+        form: ItemForm::Solid,
+        stack_size: 100,
+        energy: 0,
+        sink_points: 140,
+    },
+    ItemInfo {
+        name: "Fuel",
+        form: ItemForm::Liquid,
+        stack_size: 0,
+        energy: 750,
+        sink_points: 0,
+    },
+    ItemInfo {
+        name: "Rocket Fuel",
+        form: ItemForm::Liquid,
+        stack_size: 0,
+        energy: 3600,
+        sink_points: 0,
+    },
+    ItemInfo {
+        name: "Copper Sheet",
+        form: ItemForm::Solid,
+        stack_size: 200,
+        energy: 0,
+        sink_points: 24,
+    },
+    ItemInfo {
+        name: "Modular Frame",
+        form: ItemForm::Solid,
+        stack_size: 50,
+        energy: 0,
+        sink_points: 408,
+    },
+    ItemInfo {
+        name: "Screw",
+        form: ItemForm::Solid,
+        stack_size: 500,
+        energy: 0,
+        sink_points: 2,
+    },
+    ItemInfo {
+        name: "Nitric Acid",
+        form: ItemForm::Liquid,
+        stack_size: 0,
+        energy: 0,
+        sink_points: 0,
+    },
+    ItemInfo {
+        name: "Turbofuel",
+        form: ItemForm::Liquid,
+        stack_size: 0,
+        energy: 2000,
+        sink_points: 0,
+    },
+    ItemInfo {
+        name: "Empty Fluid Tank",
+        form: ItemForm::Solid,
+        stack_size: 100,
+        energy: 0,
+        sink_points: 225,
+    },
+    ItemInfo {
+        name: "Crystal Oscillator",
+        form: ItemForm::Solid,
+        stack_size: 100,
+        energy: 0,
+        sink_points: 3072,
+    },
+    ItemInfo {
+        name: "Motor",
+        form: ItemForm::Solid,
+        stack_size: 50,
+        energy: 0,
+        sink_points: 1520,
+    },
+    ItemInfo {
+        name: "Dark Matter Crystal",
+        form: ItemForm::Solid,
+        stack_size: 200,
+        energy: 0,
+        sink_points: 780,
+    },
+    ItemInfo {
+        name: "Ionized Fuel",
+        form: ItemForm::Liquid,
+        stack_size: 0,
+        energy: 5000,
+        sink_points: 0,
+    },
+    ItemInfo {
+        name: "Supercomputer",
+        form: ItemForm::Solid,
+        stack_size: 50,
+        energy: 0,
+        sink_points: 97718,
+    },
+    ItemInfo {
+        name: "Cooling System",
+        form: ItemForm::Solid,
+        stack_size: 100,
+        energy: 0,
+        sink_points: 12006,
+    },
+    ItemInfo {
+        name: "Ficsite Trigon",
+        form: ItemForm::Solid,
+        stack_size: 400,
+        energy: 0,
+        sink_points: 1291,
+    },
+    ItemInfo {
+        name: "Turbo Motor",
+        form: ItemForm::Solid,
+        stack_size: 50,
+        energy: 0,
+        sink_points: 240496,
+    },
+    ItemInfo {
+        name: "Time Crystal",
+        form: ItemForm::Solid,
+        stack_size: 50,
+        energy: 0,
+        sink_points: 960,
+    },
+    ItemInfo {
+        name: "Dark Matter Residue",
+        form: ItemForm::Gas,
+        stack_size: 0,
+        energy: 0,
+        sink_points: 0,
+    },
+    ItemInfo {
+        name: "Reanimated SAM",
+        form: ItemForm::Solid,
+        stack_size: 100,
+        energy: 0,
+        sink_points: 160,
+    },
+    ItemInfo {
+        name: "Excited Photonic Matter",
+        form: ItemForm::Gas,
+        stack_size: 0,
+        energy: 0,
+        sink_points: 0,
+    },
+    ItemInfo {
+        name: "Diamonds",
+        form: ItemForm::Solid,
+        stack_size: 30,
+        energy: 0,
+        sink_points: 240,
+    },
+    ItemInfo {
+        name: "Alclad Aluminum Sheet",
+        form: ItemForm::Solid,
+        stack_size: 200,
+        energy: 0,
+        sink_points: 266,
+    },
+    ItemInfo {
+        name: "Superposition Oscillator",
+        form: ItemForm::Solid,
+        stack_size: 50,
+        energy: 0,
+        sink_points: 37292,
+    },
+    ItemInfo {
+        name: "Neural-Quantum Processor",
+        form: ItemForm::Solid,
+        stack_size: 50,
+        energy: 0,
+        sink_points: 248034,
+    },
+    ItemInfo {
+        name: "AI Expansion Server",
+        form: ItemForm::Solid,
+        stack_size: 50,
+        energy: 0,
+        sink_points: 597652,
+    },
+    ItemInfo {
+        name: "Magnetic Field Generator",
+        form: ItemForm::Solid,
+        stack_size: 50,
+        energy: 0,
+        sink_points: 11000,
+    },
+    ItemInfo {
+        name: "SAM Fluctuator",
+        form: ItemForm::Solid,
+        stack_size: 100,
+        energy: 0,
+        sink_points: 1968,
+    },
+    ItemInfo {
+        name: "Steel Pipe",
+        form: ItemForm::Solid,
+        stack_size: 200,
+        energy: 0,
+        sink_points: 24,
+    },
+    ItemInfo {
+        name: "Fused Modular Frame",
+        form: ItemForm::Solid,
+        stack_size: 50,
+        energy: 0,
+        sink_points: 62840,
+    },
+    ItemInfo {
+        name: "Radio Control Unit",
+        form: ItemForm::Solid,
+        stack_size: 50,
+        energy: 0,
+        sink_points: 32352,
+    },
+    ItemInfo {
+        name: "Ficsite Ingot",
+        form: ItemForm::Solid,
+        stack_size: 100,
+        energy: 0,
+        sink_points: 1936,
+    },
+    ItemInfo {
+        name: "Biochemical Sculptor",
+        form: ItemForm::Solid,
+        stack_size: 50,
+        energy: 0,
+        sink_points: 301778,
+    },
+    ItemInfo {
+        name: "Assembly Director System",
+        form: ItemForm::Solid,
+        stack_size: 50,
+        energy: 0,
+        sink_points: 500176,
+    },
+    ItemInfo {
+        name: "Caterium Ingot",
+        form: ItemForm::Solid,
+        stack_size: 100,
+        energy: 0,
+        sink_points: 42,
+    },
+    ItemInfo {
+        name: "Empty Canister",
+        form: ItemForm::Solid,
+        stack_size: 100,
+        energy: 0,
+        sink_points: 60,
+    },
+    ItemInfo {
+        name: "Circuit Board",
+        form: ItemForm::Solid,
+        stack_size: 200,
+        energy: 0,
+        sink_points: 696,
+    },
+    ItemInfo {
+        name: "Plastic",
+        form: ItemForm::Solid,
+        stack_size: 200,
+        energy: 0,
+        sink_points: 75,
+    },
+    ItemInfo {
+        name: "Encased Industrial Beam",
+        form: ItemForm::Solid,
+        stack_size: 100,
+        energy: 0,
+        sink_points: 528,
+    },
+    ItemInfo {
+        name: "Rubber",
+        form: ItemForm::Solid,
+        stack_size: 200,
+        energy: 0,
+        sink_points: 60,
+    },
+    ItemInfo {
+        name: "Polymer Resin",
+        form: ItemForm::Solid,
+        stack_size: 200,
+        energy: 0,
+        sink_points: 12,
+    },
+    ItemInfo {
+        name: "Heavy Oil Residue",
+        form: ItemForm::Liquid,
+        stack_size: 0,
+        energy: 0,
+        sink_points: 0,
+    },
+    ItemInfo {
+        name: "Quartz Crystal",
+        form: ItemForm::Solid,
+        stack_size: 200,
+        energy: 0,
+        sink_points: 50,
+    },
+    ItemInfo {
+        name: "Steel Ingot",
+        form: ItemForm::Solid,
+        stack_size: 100,
+        energy: 0,
+        sink_points: 8,
+    },
+    ItemInfo {
+        name: "Versatile Framework",
+        form: ItemForm::Solid,
+        stack_size: 50,
+        energy: 0,
+        sink_points: 1176,
+    },
+    ItemInfo {
+        name: "Packaged Water",
+        form: ItemForm::Solid,
+        stack_size: 100,
+        energy: 0,
+        sink_points: 130,
+    },
+    ItemInfo {
+        name: "Copper Ingot",
+        form: ItemForm::Solid,
+        stack_size: 100,
+        energy: 0,
+        sink_points: 6,
+    },
+    ItemInfo {
+        name: "Aluminum Scrap",
+        form: ItemForm::Solid,
+        stack_size: 500,
+        energy: 0,
+        sink_points: 27,
+    },
+    ItemInfo {
+        name: "Aluminum Casing",
+        form: ItemForm::Solid,
+        stack_size: 200,
+        energy: 0,
+        sink_points: 393,
+    },
+    ItemInfo {
+        name: "Alumina Solution",
+        form: ItemForm::Liquid,
+        stack_size: 0,
+        energy: 0,
+        sink_points: 0,
+    },
+    ItemInfo {
+        name: "Computer",
+        form: ItemForm::Solid,
+        stack_size: 50,
+        energy: 0,
+        sink_points: 8352,
+    },
+    ItemInfo {
+        name: "Heavy Modular Frame",
+        form: ItemForm::Solid,
+        stack_size: 50,
+        energy: 0,
+        sink_points: 10800,
+    },
+    ItemInfo {
+        name: "Smart Plating",
+        form: ItemForm::Solid,
+        stack_size: 50,
+        energy: 0,
+        sink_points: 520,
+    },
+    ItemInfo {
+        name: "High-Speed Connector",
+        form: ItemForm::Solid,
+        stack_size: 100,
+        energy: 0,
+        sink_points: 3776,
+    },
+    ItemInfo {
+        name: "Automated Wiring",
+        form: ItemForm::Solid,
+        stack_size: 50,
+        energy: 0,
+        sink_points: 1440,
+    },
+    ItemInfo {
+        name: "Stator",
+        form: ItemForm::Solid,
+        stack_size: 100,
+        energy: 0,
+        sink_points: 240,
+    },
+    ItemInfo {
+        name: "AI Limiter",
+        form: ItemForm::Solid,
+        stack_size: 100,
+        energy: 0,
+        sink_points: 920,
+    },
+    ItemInfo {
+        name: "Quickwire",
+        form: ItemForm::Solid,
+        stack_size: 500,
+        energy: 0,
+        sink_points: 17,
+    },
+    ItemInfo {
+        name: "Modular Engine",
+        form: ItemForm::Solid,
+        stack_size: 50,
+        energy: 0,
+        sink_points: 9960,
+    },
+    ItemInfo {
+        name: "Adaptive Control Unit",
+        form: ItemForm::Solid,
+        stack_size: 50,
+        energy: 0,
+        sink_points: 76368,
+    },
+    ItemInfo {
+        name: "Pressure Conversion Cube",
+        form: ItemForm::Solid,
+        stack_size: 50,
+        energy: 0,
+        sink_points: 255088,
+    },
+    ItemInfo {
+        name: "Encased Plutonium Cell",
+        form: ItemForm::Solid,
+        stack_size: 200,
+        energy: 0,
+        sink_points: 0,
+    },
+    ItemInfo {
+        name: "Plutonium Pellet",
+        form: ItemForm::Solid,
+        stack_size: 100,
+        energy: 0,
+        sink_points: 0,
+    },
+    ItemInfo {
+        name: "Non-Fissile Uranium",
+        form: ItemForm::Solid,
+        stack_size: 500,
+        energy: 0,
+        sink_points: 0,
+    },
+    ItemInfo {
+        name: "Sulfuric Acid",
+        form: ItemForm::Liquid,
+        stack_size: 0,
+        energy: 0,
+        sink_points: 0,
+    },
+    ItemInfo {
+        name: "Copper Powder",
+        form: ItemForm::Solid,
+        stack_size: 500,
+        energy: 0,
+        sink_points: 72,
+    },
+    ItemInfo {
+        name: "Heat Sink",
+        form: ItemForm::Solid,
+        stack_size: 100,
+        energy: 0,
+        sink_points: 2804,
+    },
+    ItemInfo {
+        name: "Electromagnetic Control Rod",
+        form: ItemForm::Solid,
+        stack_size: 100,
+        energy: 0,
+        sink_points: 2560,
+    },
+    ItemInfo {
+        name: "Nuclear Pasta",
+        form: ItemForm::Solid,
+        stack_size: 50,
+        energy: 0,
+        sink_points: 538976,
+    },
+    ItemInfo {
+        name: "Encased Uranium Cell",
+        form: ItemForm::Solid,
+        stack_size: 200,
+        energy: 0,
+        sink_points: 147,
+    },
+    ItemInfo {
+        name: "Dissolved Silica",
+        form: ItemForm::Liquid,
+        stack_size: 0,
+        energy: 0,
+        sink_points: 0,
+    },
+    ItemInfo {
+        name: "Thermal Propulsion Rocket",
+        form: ItemForm::Solid,
+        stack_size: 50,
+        energy: 0,
+        sink_points: 728508,
+    },
+    ItemInfo {
+        name: "Black Powder",
+        form: ItemForm::Solid,
+        stack_size: 200,
+        energy: 0,
+        sink_points: 14,
+    },
+    ItemInfo {
+        name: "Ficsonium",
+        form: ItemForm::Solid,
+        stack_size: 100,
+        energy: 0,
+        sink_points: 0,
+    },
+    ItemInfo {
+        name: "Singularity Cell",
+        form: ItemForm::Solid,
+        stack_size: 50,
+        energy: 0,
+        sink_points: 114675,
+    },
+    ItemInfo {
+        name: "Ballistic Warp Drive",
+        form: ItemForm::Solid,
+        stack_size: 50,
+        energy: 0,
+        sink_points: 2895334,
+    },
+    ItemInfo {
+        name: "Gas Filter",
+        form: ItemForm::Solid,
+        stack_size: 50,
+        energy: 0,
+        sink_points: 608,
+    },
+    ItemInfo {
+        name: "Alien Protein",
+        form: ItemForm::Solid,
+        stack_size: 100,
+        energy: 0,
+        sink_points: 0,
+    },
+    ItemInfo {
+        name: "Blue Power Slug",
+        form: ItemForm::Solid,
+        stack_size: 50,
+        energy: 0,
+        sink_points: 0,
+    },
+    ItemInfo {
+        name: "Alien DNA Capsule",
+        form: ItemForm::Solid,
+        stack_size: 50,
+        energy: 0,
+        sink_points: 1000,
+    },
+    ItemInfo {
+        name: "Purple Power Slug",
+        form: ItemForm::Solid,
+        stack_size: 50,
+        energy: 0,
+        sink_points: 0,
+    },
+    ItemInfo {
+        name: "Yellow Power Slug",
+        form: ItemForm::Solid,
+        stack_size: 50,
+        energy: 0,
+        sink_points: 0,
+    },
+    ItemInfo {
+        name: "Smokeless Powder",
+        form: ItemForm::Solid,
+        stack_size: 200,
+        energy: 0,
+        sink_points: 58,
+    },
+    ItemInfo {
+        name: "Uranium Fuel Rod",
+        form: ItemForm::Solid,
+        stack_size: 50,
+        energy: 750000,
+        sink_points: 0,
+    },
+    ItemInfo {
+        name: "Plutonium Fuel Rod",
+        form: ItemForm::Solid,
+        stack_size: 50,
+        energy: 1500000,
+        sink_points: 0,
+    },
+    ItemInfo {
+        name: "Ficsonium Fuel Rod",
+        form: ItemForm::Solid,
+        stack_size: 50,
+        energy: 150000,
+        sink_points: 0,
+    },
+    ItemInfo {
+        name: "Explosive Rebar",
+        form: ItemForm::Solid,
+        stack_size: 100,
+        energy: 0,
+        sink_points: 360,
+    },
+    ItemInfo {
+        name: "Stun Rebar",
+        form: ItemForm::Solid,
+        stack_size: 100,
+        energy: 0,
+        sink_points: 186,
+    },
+    ItemInfo {
+        name: "Homing Rifle Ammo",
+        form: ItemForm::Solid,
+        stack_size: 500,
+        energy: 0,
+        sink_points: 855,
+    },
+    ItemInfo {
+        name: "Cluster Nobelisk",
+        form: ItemForm::Solid,
+        stack_size: 50,
+        energy: 0,
+        sink_points: 1376,
+    },
+    ItemInfo {
+        name: "Nobelisk",
+        form: ItemForm::Solid,
+        stack_size: 50,
+        energy: 0,
+        sink_points: 152,
+    },
+    ItemInfo {
+        name: "Gas Nobelisk",
+        form: ItemForm::Solid,
+        stack_size: 50,
+        energy: 0,
+        sink_points: 544,
+    },
+    ItemInfo {
+        name: "Nuke Nobelisk",
+        form: ItemForm::Solid,
+        stack_size: 50,
+        energy: 0,
+        sink_points: 19600,
+    },
+    ItemInfo {
+        name: "Pulse Nobelisk",
+        form: ItemForm::Solid,
+        stack_size: 50,
+        energy: 0,
+        sink_points: 1533,
+    },
+    ItemInfo {
+        name: "Iron Rebar",
+        form: ItemForm::Solid,
+        stack_size: 100,
+        energy: 0,
+        sink_points: 8,
+    },
+    ItemInfo {
+        name: "Portable Miner",
+        form: ItemForm::Solid,
+        stack_size: 1,
+        energy: 0,
+        sink_points: 0,
+    },
+    ItemInfo {
+        name: "Shatter Rebar",
+        form: ItemForm::Solid,
+        stack_size: 100,
+        energy: 0,
+        sink_points: 332,
+    },
+    ItemInfo {
+        name: "Turbo Rifle Ammo",
+        form: ItemForm::Solid,
+        stack_size: 500,
+        energy: 0,
+        sink_points: 1120,
+    },
+    ItemInfo {
+        name: "Rifle Ammo",
+        form: ItemForm::Solid,
+        stack_size: 500,
+        energy: 0,
+        sink_points: 82,
+    },
+    ItemInfo {
+        name: "Iron Ore",
+        form: ItemForm::Solid,
+        stack_size: 100,
+        energy: 0,
+        sink_points: 1,
+    },
+    ItemInfo {
+        name: "Coal",
+        form: ItemForm::Solid,
+        stack_size: 100,
+        energy: 300,
+        sink_points: 3,
+    },
+    ItemInfo {
+        name: "Water",
+        form: ItemForm::Liquid,
+        stack_size: 0,
+        energy: 0,
+        sink_points: 0,
+    },
+    ItemInfo {
+        name: "Nitrogen Gas",
+        form: ItemForm::Gas,
+        stack_size: 0,
+        energy: 0,
+        sink_points: 0,
+    },
+    ItemInfo {
+        name: "Sulfur",
+        form: ItemForm::Solid,
+        stack_size: 100,
+        energy: 0,
+        sink_points: 11,
+    },
+    ItemInfo {
+        name: "SAM",
+        form: ItemForm::Solid,
+        stack_size: 100,
+        energy: 0,
+        sink_points: 0,
+    },
+    ItemInfo {
+        name: "Bauxite",
+        form: ItemForm::Solid,
+        stack_size: 100,
+        energy: 0,
+        sink_points: 8,
+    },
+    ItemInfo {
+        name: "Caterium Ore",
+        form: ItemForm::Solid,
+        stack_size: 100,
+        energy: 0,
+        sink_points: 7,
+    },
+    ItemInfo {
+        name: "Copper Ore",
+        form: ItemForm::Solid,
+        stack_size: 100,
+        energy: 0,
+        sink_points: 3,
+    },
+    ItemInfo {
+        name: "Raw Quartz",
+        form: ItemForm::Solid,
+        stack_size: 100,
+        energy: 0,
+        sink_points: 15,
+    },
+    ItemInfo {
+        name: "Limestone",
+        form: ItemForm::Solid,
+        stack_size: 100,
+        energy: 0,
+        sink_points: 2,
+    },
+    ItemInfo {
+        name: "Uranium",
+        form: ItemForm::Solid,
+        stack_size: 100,
+        energy: 0,
+        sink_points: 35,
+    },
+    ItemInfo {
+        name: "Crude Oil",
+        form: ItemForm::Liquid,
+        stack_size: 0,
+        energy: 0,
+        sink_points: 0,
+    },
+    ItemInfo {
+        name: "Solid Biofuel",
+        form: ItemForm::Solid,
+        stack_size: 200,
+        energy: 450,
+        sink_points: 48,
+    },
+    ItemInfo {
+        name: "Packaged Liquid Biofuel",
+        form: ItemForm::Solid,
+        stack_size: 100,
+        energy: 750,
+        sink_points: 370,
+    },
+    ItemInfo {
+        name: "Biomass",
+        form: ItemForm::Solid,
+        stack_size: 200,
+        energy: 180,
+        sink_points: 12,
+    },
+    ItemInfo {
+        name: "Leaves",
+        form: ItemForm::Solid,
+        stack_size: 500,
+        energy: 15,
+        sink_points: 3,
+    },
+    ItemInfo {
+        name: "Mycelia",
+        form: ItemForm::Solid,
+        stack_size: 200,
+        energy: 20,
+        sink_points: 10,
+    },
+    ItemInfo {
+        name: "Wood",
+        form: ItemForm::Solid,
+        stack_size: 200,
+        energy: 100,
+        sink_points: 30,
+    },
+    ItemInfo {
+        name: "Liquid Biofuel",
+        form: ItemForm::Liquid,
+        stack_size: 0,
+        energy: 750,
+        sink_points: 0,
+    },
+    ItemInfo {
+        name: "Packaged Alumina Solution",
+        form: ItemForm::Solid,
+        stack_size: 100,
+        energy: 0,
+        sink_points: 160,
+    },
+    ItemInfo {
+        name: "Packaged Nitrogen Gas",
+        form: ItemForm::Solid,
+        stack_size: 100,
+        energy: 0,
+        sink_points: 212,
+    },
+    ItemInfo {
+        name: "Packaged Nitric Acid",
+        form: ItemForm::Solid,
+        stack_size: 100,
+        energy: 0,
+        sink_points: 412,
+    },
+    ItemInfo {
+        name: "Packaged Sulfuric Acid",
+        form: ItemForm::Solid,
+        stack_size: 100,
+        energy: 0,
+        sink_points: 152,
+    },
+    ItemInfo {
+        name: "Fabric",
+        form: ItemForm::Solid,
+        stack_size: 100,
+        energy: 15,
+        sink_points: 140,
+    },
+    ItemInfo {
+        name: "Hog Remains",
+        form: ItemForm::Solid,
+        stack_size: 50,
+        energy: 0,
+        sink_points: 0,
+    },
+    ItemInfo {
+        name: "Spitter Remains",
+        form: ItemForm::Solid,
+        stack_size: 50,
+        energy: 0,
+        sink_points: 0,
+    },
+    ItemInfo {
+        name: "Stinger Remains",
+        form: ItemForm::Solid,
+        stack_size: 50,
+        energy: 0,
+        sink_points: 0,
+    },
+    ItemInfo {
+        name: "Hatcher Remains",
+        form: ItemForm::Solid,
+        stack_size: 50,
+        energy: 0,
+        sink_points: 0,
+    },
+    ItemInfo {
+        name: "Power Shard",
+        form: ItemForm::Solid,
+        stack_size: 100,
+        energy: 0,
+        sink_points: 0,
+    },
+    ItemInfo {
+        name: "Alien Power Matrix",
+        form: ItemForm::Solid,
+        stack_size: 50,
+        energy: 0,
+        sink_points: 0,
+    },
+];
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Deserialize, serde::Serialize)]
 #[repr(u8)]
 pub enum SourceItemKind {
@@ -0,0 +1,200 @@
+//! Recipes that were removed or renamed between game versions.
+//!
+//! Plans made with an older game version may still reference such recipes, by stable ID in
+//! digests or by string ID in JSON. Those are mapped onto a successor recipe, with a warning for
+//! the user, as the successor usually differs in its inputs or rates. Connections are moved to
+//! the successor's handle for the same item, see `Migration::handle`.
+
+use crate::state::HandleId;
+use super::{recipes::MIGRATIONS, ItemKind, RecipeKind};
+
+
+#[derive(Debug, PartialEq)]
+pub struct Migration {
+    /// Stable ID of the removed recipe, see `RecipeKind::stable_id`.
+    pub stable_id: u16,
+    /// String ID the removed recipe had in JSON plans.
+    pub old_id: &'static str,
+    pub successor: RecipeKind,
+    /// Explanation for the user, e.g. in which game version it changed.
+    pub note: &'static str,
+    /// Items of the removed recipe, in the order of their handles. Plans made with it connect
+    /// these handles, and digests rely on them to decode edges.
+    pub inputs: &'static [ItemKind],
+    pub outputs: &'static [ItemKind],
+}
+
+impl Migration {
+    pub fn warning(&self) -> String {
+        format!(
+            "recipe '{}' no longer exists and was replaced by '{}': {}",
+            self.old_id,
+            self.successor.info().name,
+            self.note,
+        )
+    }
+
+    /// Maps a handle of the removed recipe to the successor's handle for the same item. Returns a
+    /// warning for the user if the successor has no such input or output.
+    pub fn handle(&self, handle: HandleId) -> Result<HandleId, String> {
+        let info = self.successor.info();
+        let (old, new, offset, kind) = match handle {
+            0..=3 => (self.inputs, info.inputs, 0, "input"),
+            _ => (self.outputs, info.outputs, 4, "output"),
+        };
+        let idx = (handle - offset) as usize;
+        let Some(&item) = old.get(idx) else {
+            return Err(format!("recipe '{}' has no handle {handle}", self.old_id));
+        };
+        // Recipes rarely list an item twice, but then the n-th stays the n-th.
+        let nth = old[..idx].iter().filter(|&&i| i == item).count();
+        new.iter()
+            .enumerate()
+            .filter(|(_, e)| e.item == item)
+            .nth(nth)
+            .map(|(i, _)| i as HandleId + offset)
+            .ok_or_else(|| format!(
+                "a connection of recipe '{}' was removed, as '{}' has no {kind} '{}'",
+                self.old_id,
+                info.name,
+                item.info().name,
+            ))
+    }
+}
+
+/// Resolves a stable ID from a digest. For removed recipes, returns the successor and the
+/// migration to it.
+pub fn resolve_stable_id(id: u16) -> Result<(RecipeKind, Option<&'static Migration>), String> {
+    resolve_stable_id_in(&MIGRATIONS, id)
+}
+
+/// Like `resolve_stable_id`, but for string IDs as used in JSON plans.
+pub fn resolve_id(id: &str) -> Option<(RecipeKind, Option<&'static Migration>)> {
+    resolve_id_in(&MIGRATIONS, id)
+}
+
+fn resolve_stable_id_in(
+    migrations: &[Migration],
+    id: u16,
+) -> Result<(RecipeKind, Option<&Migration>), String> {
+    if let Some(recipe) = RecipeKind::from_stable_id(id) {
+        return Ok((recipe, None));
+    }
+    migrations.iter()
+        .find(|m| m.stable_id == id)
+        .map(|m| (m.successor, Some(m)))
+        .ok_or_else(|| format!("unknown recipe ID {id}"))
+}
+
+fn resolve_id_in<'a>(
+    migrations: &'a [Migration],
+    id: &str,
+) -> Option<(RecipeKind, Option<&'a Migration>)> {
+    if let Ok(recipe) = serde_json::from_value(id.into()) {
+        return Some((recipe, None));
+    }
+    migrations.iter()
+        .find(|m| m.old_id == id)
+        .map(|m| (m.successor, Some(m)))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// All stable IDs ever assigned, see `data/gen.ts`.
+    const REGISTRY: &str = include_str!("../../data/recipe-ids.json");
+
+    /// Number of IDs when the registry was introduced and FNV-1a hash over them. IDs must never
+    /// change, so this must never change either.
+    const FROZEN: (usize, u64) = (278, 0x15d3_13c8_c96a_91ec);
+
+    fn registry() -> Vec<String> {
+        serde_json::from_str(REGISTRY).unwrap()
+    }
+
+    fn fnv1a(ids: &[String]) -> u64 {
+        ids.iter()
+            .flat_map(|id| id.bytes().chain([0]))
+            .fold(0xcbf2_9ce4_8422_2325, |h, b| (h ^ b as u64).wrapping_mul(0x100_0000_01b3))
+    }
+
+    #[test]
+    fn registry_is_append_only() {
+        let ids = registry();
+        assert!(ids.len() >= FROZEN.0);
+        assert_eq!(fnv1a(&ids[..FROZEN.0]), FROZEN.1, "existing stable recipe IDs were changed");
+    }
+
+    #[test]
+    fn registry_matches_recipes() {
+        let ids = registry();
+        for (stable_id, id) in ids.iter().enumerate() {
+            let stable_id = stable_id as u16;
+            match RecipeKind::from_stable_id(stable_id) {
+                Some(recipe) => {
+                    assert_eq!(serde_json::to_value(recipe).unwrap(), id.as_str());
+                    assert_eq!(recipe.stable_id(), stable_id);
+                }
+                None => assert!(
+                    MIGRATIONS.iter().any(|m| m.stable_id == stable_id && m.old_id == id),
+                    "removed recipe '{id}' has no migration",
+                ),
+            }
+        }
+        for recipe in (0..).map_while(|i| RecipeKind::try_from(i).ok()) {
+            assert!((recipe.stable_id() as usize) < ids.len());
+        }
+    }
+
+    #[test]
+    fn resolve() {
+        let migrations = [Migration {
+            stable_id: 9000,
+            old_id: "iron-plate-legacy",
+            successor: RecipeKind::IronPlate,
+            note: "removed in 1.1",
+            inputs: &[ItemKind::IronIngot],
+            outputs: &[ItemKind::IronPlate],
+        }];
+        let plate = RecipeKind::IronPlate;
+        let migration = Some(&migrations[0]);
+        assert_eq!(resolve_stable_id_in(&migrations, plate.stable_id()), Ok((plate, None)));
+        assert_eq!(resolve_stable_id_in(&migrations, 9000), Ok((plate, migration)));
+        assert_eq!(resolve_stable_id_in(&migrations, 9001), Err("unknown recipe ID 9001".into()));
+        assert_eq!(
+            migrations[0].warning(),
+            "recipe 'iron-plate-legacy' no longer exists and was replaced by 'Iron Plate': \
+                removed in 1.1",
+        );
+
+        assert_eq!(resolve_id_in(&migrations, "iron-plate"), Some((plate, None)));
+        assert_eq!(resolve_id_in(&migrations, "iron-plate-legacy"), Some((plate, migration)));
+        assert_eq!(resolve_id_in(&migrations, "nonsense"), None);
+    }
+
+    #[test]
+    fn handles() {
+        // Iron Alloy Ingot replaced by Iron Ingot, which has no copper input.
+        let migration = Migration {
+            stable_id: 9000,
+            old_id: "iron-alloy-ingot",
+            successor: RecipeKind::IronIngot,
+            note: "",
+            inputs: &[ItemKind::CopperOre, ItemKind::IronOre],
+            outputs: &[ItemKind::IronIngot],
+        };
+        assert_eq!(migration.handle(1), Ok(0));
+        assert_eq!(migration.handle(4), Ok(4));
+        assert_eq!(
+            migration.handle(0),
+            Err("a connection of recipe 'iron-alloy-ingot' was removed, as 'Iron Ingot' has no \
+                input 'Copper Ore'".into()),
+        );
+        assert_eq!(
+            migration.handle(5),
+            Err("recipe 'iron-alloy-ingot' has no handle 5".into()),
+        );
+    }
+}
@@ -1,8 +1,84 @@
+mod buildings;
+mod construction;
 mod items;
+mod migrations;
+mod packs;
 mod recipes;
+mod unlocks;
 
 
 pub use self::{
-    items::{ItemKind, SourceItemKind},
-    recipes::RecipeKind,
+    buildings::Building,
+    construction::{
+        BeltTier, Ingredient, PipeTier, MERGER_COST, PRIORITY_MERGER_COST,
+        PROGRAMMABLE_SPLITTER_COST, SMART_SPLITTER_COST, SPLITTER_COST,
+    },
+    items::{ItemForm, ItemInfo, ItemKind, SourceItemKind},
+    migrations::{resolve_id, resolve_stable_id, Migration},
+    packs::{
+        DataPack, GameData, ItemEntry, PackIoEntry, PackItem, PackItemForm, PackRecipe, PackRef,
+        RecipeEntry,
+    },
+    recipes::{IoEntry, RecipeInfo, RecipeKind},
+    unlocks::{MamTree, RecipeFilter, Unlock},
 };
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn all_recipes() -> impl Iterator<Item = RecipeKind> {
+        (0..).map_while(|id| RecipeKind::try_from(id).ok())
+    }
+
+    #[test]
+    fn reverse_index_matches_recipes() {
+        for item in (0..).map_while(|id| ItemKind::try_from(id).ok()) {
+            let producers = all_recipes()
+                .filter(|r| r.info().outputs.iter().any(|o| o.item == item))
+                .collect::<Vec<_>>();
+            let consumers = all_recipes()
+                .filter(|r| r.info().inputs.iter().any(|i| i.item == item))
+                .collect::<Vec<_>>();
+            assert_eq!(item.produced_by(), producers, "producers of {item:?}");
+            assert_eq!(item.consumed_by(), consumers, "consumers of {item:?}");
+        }
+    }
+
+    #[test]
+    fn class_names_roundtrip() {
+        for item in (0..).map_while(|id| ItemKind::try_from(id).ok()) {
+            assert_eq!(ItemKind::from_class_name(item.class_name()), Some(item));
+        }
+        for recipe in all_recipes() {
+            assert_eq!(RecipeKind::from_class_name(recipe.class_name()), Some(recipe));
+        }
+        for building in Building::ALL {
+            assert_eq!(Building::from_class_name(building.class_name()), Some(building));
+        }
+        assert_eq!(ItemKind::IronPlate.class_name(), "Desc_IronPlate_C");
+        assert_eq!(RecipeKind::from_class_name("Recipe_IronPlate_C"), Some(RecipeKind::IronPlate));
+        assert_eq!(ItemKind::from_class_name("Desc_Nonsense_C"), None);
+    }
+
+    #[test]
+    fn item_registry() {
+        let ids = serde_json::from_str::<Vec<String>>(include_str!("../../data/item-ids.json"))
+            .unwrap();
+
+        // Number of IDs when the registry was introduced and FNV-1a hash over them, like for
+        // recipes. IDs must never change, so this must never change either.
+        let frozen = &ids[..152];
+        let hash = frozen.iter()
+            .flat_map(|id| id.bytes().chain([0]))
+            .fold(0xcbf2_9ce4_8422_2325, |h, b| (h ^ b as u64).wrapping_mul(0x100_0000_01b3));
+        assert_eq!(hash, 0x732d_fe9b_b3da_ba6e, "existing stable item IDs were changed");
+
+        for item in (0..).map_while(|id| ItemKind::try_from(id).ok()) {
+            let stable_id = item.stable_id();
+            assert_eq!(serde_json::to_value(item).unwrap(), ids[stable_id as usize].as_str());
+            assert_eq!(ItemKind::from_stable_id(stable_id), Some(item));
+        }
+    }
+}
@@ -0,0 +1,509 @@
+//! Data packs: game data loaded at runtime, e.g. for mods or experimental game versions.
+//!
+//! A data pack is JSON that adds or replaces items and recipes, or removes recipes. Packs are
+//! applied in order on top of the built-in data (or on top of nothing, if a pack sets
+//! `replaceBase`), resulting in a `GameData` catalog.
+//!
+//! Entries are identified by their string ID (e.g. `iron-plate`), never by position. Replacing an
+//! entry keeps its place, and entries with the ID of a built-in one stay linked to its `ItemKind`
+//! or `RecipeKind`. New entries are appended in pack order. So as long as a plan records which
+//! packs it was made with (see `PackRef`), its IDs resolve to the same entries again. Plans refer
+//! to recipes without a built-in one by ID, see `state::Node::PackRecipe`.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::{Building, ItemForm, ItemKind, RecipeKind};
+
+
+/// Identifies a specific version of a data pack. Stored in plans and digests.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct PackRef {
+    pub id: String,
+    pub version: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DataPack {
+    /// Short unique name, e.g. `refined-power`.
+    pub id: String,
+    pub version: String,
+    /// Start from an empty catalog instead of the built-in game data.
+    #[serde(default)]
+    pub replace_base: bool,
+    #[serde(default)]
+    pub items: Vec<PackItem>,
+    #[serde(default)]
+    pub recipes: Vec<PackRecipe>,
+    /// IDs of recipes to remove, e.g. ones that were removed from the game.
+    #[serde(default)]
+    pub remove_recipes: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PackItem {
+    pub id: String,
+    #[serde(default)]
+    pub class_name: Option<String>,
+    pub name: String,
+    pub form: PackItemForm,
+    #[serde(default)]
+    pub stack_size: u32,
+    #[serde(default)]
+    pub energy: u32,
+    #[serde(default)]
+    pub sink_points: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum PackItemForm {
+    Solid,
+    Liquid,
+    Gas,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PackRecipe {
+    pub id: String,
+    #[serde(default)]
+    pub class_name: Option<String>,
+    pub name: String,
+    /// ID of a built-in building (e.g. `constructor`) or any name for buildings added by mods.
+    pub building: String,
+    /// Crafting duration in seconds, at 100%.
+    pub duration: f64,
+    #[serde(default)]
+    pub alternative: bool,
+    /// Power consumption in MW at 100%. Required for buildings that are not built-in.
+    #[serde(default)]
+    pub power: Option<f64>,
+    pub inputs: Vec<PackIoEntry>,
+    pub outputs: Vec<PackIoEntry>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PackIoEntry {
+    /// Item ID, referring to built-in items or items of this or earlier packs.
+    pub item: String,
+    pub amount: f64,
+}
+
+/// An item of the catalog.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ItemEntry {
+    pub id: String,
+    pub class_name: Option<String>,
+    pub name: String,
+    pub form: PackItemForm,
+    pub stack_size: u32,
+    pub energy: u32,
+    pub sink_points: u32,
+    /// The built-in item with the same ID, if any.
+    #[serde(skip)]
+    pub builtin: Option<ItemKind>,
+}
+
+/// A recipe of the catalog.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecipeEntry {
+    pub id: String,
+    pub class_name: Option<String>,
+    pub name: String,
+    pub building: String,
+    pub duration: f64,
+    pub alternative: bool,
+    /// Average power consumption in MW at 100%.
+    pub power: f64,
+    pub inputs: Vec<PackIoEntry>,
+    pub outputs: Vec<PackIoEntry>,
+    /// The built-in recipe with the same ID, if any.
+    #[serde(skip)]
+    pub builtin: Option<RecipeKind>,
+}
+
+impl RecipeEntry {
+    /// The building, unless it was added by a mod.
+    pub fn builtin_building(&self) -> Option<Building> {
+        builtin_kind(&self.building)
+    }
+}
+
+/// Items and recipes after applying data packs.
+#[derive(Debug, Clone, Serialize)]
+pub struct GameData {
+    pub packs: Vec<PackRef>,
+    pub items: Vec<ItemEntry>,
+    pub recipes: Vec<RecipeEntry>,
+    #[serde(skip)]
+    item_index: HashMap<String, usize>,
+    #[serde(skip)]
+    recipe_index: HashMap<String, usize>,
+}
+
+impl GameData {
+    /// The compiled-in game data without any packs.
+    pub fn builtin() -> Self {
+        let items = (0..)
+            .map_while(|i| ItemKind::try_from(i).ok())
+            .map(|kind| {
+                let info = kind.info();
+                ItemEntry {
+                    id: serde_id(&kind),
+                    class_name: Some(kind.class_name().to_owned()),
+                    name: info.name.to_owned(),
+                    form: info.form.into(),
+                    stack_size: info.stack_size,
+                    energy: info.energy,
+                    sink_points: info.sink_points,
+                    builtin: Some(kind),
+                }
+            })
+            .collect();
+        let recipes = (0..)
+            .map_while(|i| RecipeKind::try_from(i).ok())
+            .map(|kind| {
+                let info = kind.info();
+                let io = |entries: &[super::IoEntry]| entries.iter()
+                    .map(|e| PackIoEntry { item: serde_id(&e.item), amount: e.amount })
+                    .collect();
+                RecipeEntry {
+                    id: serde_id(&kind),
+                    class_name: Some(kind.class_name().to_owned()),
+                    name: info.name.to_owned(),
+                    building: serde_id(&info.building),
+                    duration: info.duration,
+                    alternative: info.alternative,
+                    power: info.average_power(),
+                    inputs: io(info.inputs),
+                    outputs: io(info.outputs),
+                    builtin: Some(kind),
+                }
+            })
+            .collect();
+
+        let mut out = Self {
+            packs: vec![],
+            items,
+            recipes,
+            item_index: HashMap::new(),
+            recipe_index: HashMap::new(),
+        };
+        out.reindex();
+        out
+    }
+
+    /// Applies all `packs` in order on top of the built-in data.
+    pub fn with_packs(packs: &[DataPack]) -> Result<Self, String> {
+        let mut out = Self::builtin();
+        for pack in packs {
+            out.apply(pack)?;
+        }
+        Ok(out)
+    }
+
+    /// Applies a single pack. On error, `self` is left unchanged.
+    pub fn apply(&mut self, pack: &DataPack) -> Result<(), String> {
+        let mut next = self.clone();
+        next.apply_unchecked(pack).map_err(|e| format!("data pack '{}': {e}", pack.id))?;
+        *self = next;
+        Ok(())
+    }
+
+    fn apply_unchecked(&mut self, pack: &DataPack) -> Result<(), String> {
+        if self.packs.iter().any(|p| p.id == pack.id) {
+            return Err("already applied".into());
+        }
+        if pack.replace_base {
+            self.items.clear();
+            self.recipes.clear();
+            self.reindex();
+        }
+
+        for item in &pack.items {
+            let entry = ItemEntry {
+                id: item.id.clone(),
+                class_name: item.class_name.clone(),
+                name: item.name.clone(),
+                form: item.form,
+                stack_size: item.stack_size,
+                energy: item.energy,
+                sink_points: item.sink_points,
+                builtin: builtin_kind(&item.id),
+            };
+            match self.item_index.get(&item.id) {
+                Some(&idx) => self.items[idx] = entry,
+                None => {
+                    self.item_index.insert(item.id.clone(), self.items.len());
+                    self.items.push(entry);
+                }
+            }
+        }
+
+        for id in &pack.remove_recipes {
+            if self.recipe(id).is_none() {
+                return Err(format!("cannot remove unknown recipe '{id}'"));
+            }
+            self.recipes.retain(|r| r.id != *id);
+            self.reindex();
+        }
+
+        for recipe in &pack.recipes {
+            for entry in recipe.inputs.iter().chain(&recipe.outputs) {
+                if self.item(&entry.item).is_none() {
+                    let (id, item) = (&recipe.id, &entry.item);
+                    return Err(format!("recipe '{id}' uses unknown item '{item}'"));
+                }
+            }
+            if recipe.outputs.is_empty() {
+                return Err(format!("recipe '{}' has no outputs", recipe.id));
+            }
+            if recipe.duration <= 0.0 {
+                return Err(format!("recipe '{}' has invalid duration", recipe.id));
+            }
+            let power = match (recipe.power, builtin_kind::<Building>(&recipe.building)) {
+                (Some(power), _) => power,
+                (None, Some(building)) => building.base_power(),
+                (None, None) => {
+                    return Err(format!(
+                        "recipe '{}' needs `power`, as building '{}' is not built-in",
+                        recipe.id,
+                        recipe.building,
+                    ));
+                }
+            };
+
+            let entry = RecipeEntry {
+                id: recipe.id.clone(),
+                class_name: recipe.class_name.clone(),
+                name: recipe.name.clone(),
+                building: recipe.building.clone(),
+                duration: recipe.duration,
+                alternative: recipe.alternative,
+                power,
+                inputs: recipe.inputs.clone(),
+                outputs: recipe.outputs.clone(),
+                builtin: builtin_kind(&recipe.id),
+            };
+            match self.recipe_index.get(&recipe.id) {
+                Some(&idx) => self.recipes[idx] = entry,
+                None => {
+                    self.recipe_index.insert(recipe.id.clone(), self.recipes.len());
+                    self.recipes.push(entry);
+                }
+            }
+        }
+
+        self.packs.push(PackRef { id: pack.id.clone(), version: pack.version.clone() });
+        Ok(())
+    }
+
+    fn reindex(&mut self) {
+        self.item_index = self.items.iter().enumerate().map(|(i, e)| (e.id.clone(), i)).collect();
+        self.recipe_index =
+            self.recipes.iter().enumerate().map(|(i, e)| (e.id.clone(), i)).collect();
+    }
+
+    pub fn item(&self, id: &str) -> Option<&ItemEntry> {
+        self.item_index.get(id).map(|&i| &self.items[i])
+    }
+
+    pub fn recipe(&self, id: &str) -> Option<&RecipeEntry> {
+        self.recipe_index.get(id).map(|&i| &self.recipes[i])
+    }
+
+    /// Checks that this catalog was built from exactly the packs a plan was made with.
+    pub fn check_packs(&self, plan: &[PackRef]) -> Result<(), String> {
+        if self.packs == plan {
+            return Ok(());
+        }
+        let list = |packs: &[PackRef]| if packs.is_empty() {
+            "none".to_owned()
+        } else {
+            packs.iter().map(|p| format!("{} {}", p.id, p.version)).collect::<Vec<_>>().join(", ")
+        };
+        Err(format!(
+            "plan was made with data packs {}, but {} are loaded",
+            list(plan),
+            list(&self.packs),
+        ))
+    }
+}
+
+/// Parses the ID of a built-in item, recipe or building. Pack entries with such an ID stay linked
+/// to the built-in one, even if they were removed and added again.
+fn builtin_kind<T: for<'de> Deserialize<'de>>(id: &str) -> Option<T> {
+    serde_json::from_value(id.into()).ok()
+}
+
+impl From<ItemForm> for PackItemForm {
+    fn from(value: ItemForm) -> Self {
+        match value {
+            ItemForm::Solid => Self::Solid,
+            ItemForm::Liquid => Self::Liquid,
+            ItemForm::Gas => Self::Gas,
+        }
+    }
+}
+
+/// The string ID of built-in game data, which is their serde name.
+fn serde_id<T: Serialize>(v: &T) -> String {
+    match serde_json::to_value(v) {
+        Ok(serde_json::Value::String(s)) => s,
+        _ => unreachable!("IDs serialize as strings"),
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::{digest, state::{Input, Node}};
+    use super::*;
+
+    fn pack(json: &str) -> DataPack {
+        serde_json::from_str(json).unwrap()
+    }
+
+    fn modded() -> DataPack {
+        pack(r#"{
+            "id": "test-mod",
+            "version": "1.2.0",
+            "items": [
+                {"id": "iron-plate", "className": "Desc_IronPlate_C", "name": "Iron Plate", "form": "solid", "stackSize": 500},
+                {"id": "steel-plate", "name": "Steel Plate", "form": "solid", "stackSize": 200}
+            ],
+            "recipes": [
+                {
+                    "id": "steel-plate",
+                    "name": "Steel Plate",
+                    "building": "constructor",
+                    "duration": 4,
+                    "inputs": [{"item": "steel-ingot", "amount": 2}],
+                    "outputs": [{"item": "steel-plate", "amount": 1}]
+                },
+                {
+                    "id": "iron-plate",
+                    "name": "Iron Plate",
+                    "building": "press",
+                    "duration": 2,
+                    "power": 10,
+                    "inputs": [{"item": "iron-ingot", "amount": 1}],
+                    "outputs": [{"item": "iron-plate", "amount": 1}]
+                }
+            ],
+            "removeRecipes": ["iron-rod"]
+        }"#)
+    }
+
+    #[test]
+    fn builtin() {
+        let data = GameData::builtin();
+        let plate = data.recipe("iron-plate").unwrap();
+        assert_eq!(plate.builtin, Some(RecipeKind::IronPlate));
+        assert_eq!(plate.class_name.as_deref(), Some("Recipe_IronPlate_C"));
+        assert_eq!(plate.building, "constructor");
+        assert_eq!(plate.inputs[0].item, "iron-ingot");
+        assert_eq!(data.item("iron-plate").unwrap().builtin, Some(ItemKind::IronPlate));
+        assert!(data.packs.is_empty());
+    }
+
+    #[test]
+    fn apply_pack() {
+        let base = GameData::builtin();
+        let data = GameData::with_packs(&[modded()]).unwrap();
+        assert_eq!(data.packs, [PackRef { id: "test-mod".into(), version: "1.2.0".into() }]);
+
+        // Replaced entries keep their position and built-in link.
+        let pos = |d: &GameData, id| d.items.iter().position(|i| i.id == id);
+        assert_eq!(pos(&data, "iron-plate"), pos(&base, "iron-plate"));
+        assert_eq!(data.item("iron-plate").unwrap().stack_size, 500);
+        assert_eq!(data.item("iron-plate").unwrap().builtin, Some(ItemKind::IronPlate));
+        assert_eq!(data.recipe("iron-plate").unwrap().building, "press");
+        assert_eq!(data.recipe("iron-plate").unwrap().builtin, Some(RecipeKind::IronPlate));
+
+        // New entries are appended.
+        assert_eq!(data.items.last().unwrap().id, "steel-plate");
+        assert_eq!(data.recipes.last().unwrap().id, "steel-plate");
+        assert_eq!(data.recipe("steel-plate").unwrap().power, Building::Constructor.base_power());
+        assert_eq!(data.recipe("steel-plate").unwrap().builtin, None);
+
+        assert!(data.recipe("iron-rod").is_none());
+        assert_eq!(data.recipes.len(), base.recipes.len());
+
+        assert!(data.check_packs(&data.packs).is_ok());
+        assert_eq!(
+            base.check_packs(&data.packs).unwrap_err(),
+            "plan was made with data packs test-mod 1.2.0, but none are loaded",
+        );
+    }
+
+    #[test]
+    fn invalid_packs() {
+        let mut data = GameData::builtin();
+        let mut bad = modded();
+        bad.recipes[1].power = None;
+        let err = data.apply(&bad).unwrap_err();
+        assert!(err.contains("needs `power`"), "{err}");
+        // Nothing was applied.
+        assert!(data.packs.is_empty());
+        assert_eq!(data.item("iron-plate").unwrap().stack_size, 200);
+
+        let mut bad = modded();
+        bad.recipes[0].inputs[0].item = "unobtainium".into();
+        assert!(data.apply(&bad).unwrap_err().contains("unknown item 'unobtainium'"));
+
+        data.apply(&modded()).unwrap();
+        assert!(data.apply(&modded()).unwrap_err().contains("already applied"));
+
+        let empty = pack(r#"{"id": "empty", "version": "1", "replaceBase": true}"#);
+        let data = GameData::with_packs(&[empty]).unwrap();
+        assert!(data.items.is_empty() && data.recipes.is_empty());
+    }
+
+    #[test]
+    fn plan_with_pack_recipe() {
+        let data = GameData::with_packs(&[modded()]).unwrap();
+        let json = r#"{"state":{"graph":{
+            "nodes": [
+                {"type":"recipe","recipe":"steel-ingot","pos":{"x":100,"y":0},"buildingsCount":2,"overclock":1},
+                {"type":"packRecipe","recipe":"steel-plate","pos":{"x":400,"y":0},"buildingsCount":3,"overclock":1.5,"somersloops":1},
+                {"type":"merger","pos":{"x":700,"y":0}},
+                {"type":"source","item":"iron-ore","pos":{"x":0,"y":0},"rate":90}
+            ],
+            "edges": [
+                {"source":{"node":3,"handle":0},"target":{"node":0,"handle":0}},
+                {"source":{"node":0,"handle":4},"target":{"node":1,"handle":0}},
+                {"source":{"node":1,"handle":4},"target":{"node":2,"handle":1}}
+            ]
+        }},"dataPacks":[{"id":"test-mod","version":"1.2.0"}],"version":0}"#;
+        let input = serde_json::from_str::<Input>(json).unwrap();
+        let json = serde_json::to_value(&input).unwrap();
+
+        let (decoded, warnings) = digest::decode(&digest::encode(&input)).unwrap();
+        assert!(warnings.is_empty());
+        assert!(data.check_packs(&decoded.data_packs).is_ok());
+        let Node::PackRecipe { recipe, somersloops, .. } = &decoded.state.graph.nodes[1] else {
+            panic!("pack recipe was not kept");
+        };
+        assert_eq!(data.recipe(recipe).unwrap().name, "Steel Plate");
+        assert_eq!(*somersloops, 1);
+        let nodes = serde_json::to_value(&decoded.state.graph.nodes).unwrap();
+        assert_eq!(nodes, json["state"]["graph"]["nodes"]);
+        assert_eq!(decoded.state.graph.edges.len(), 3);
+
+        // The IDs are stored in the last extension (after tag, count, node ID and length), which
+        // can't be left out.
+        let digest = digest::encode(&input);
+        let without_ids = &digest[..digest.len() - 4 - "steel-plate".len()];
+        assert_eq!(
+            digest::decode(without_ids).err().unwrap(),
+            "pack recipe node without recipe",
+        );
+    }
+}
@@ -5,9 +5,9 @@
 // game. See the main README for more information.
 
 
-use super::ItemKind;
+use super::{Building, ItemKind, MamTree, Migration, Unlock};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Deserialize, serde::Serialize)]
 #[repr(u16)]
 pub enum RecipeKind {
     #[serde(rename = "nuclear-waste")]
@@ -859,1404 +859,4276 @@ impl RecipeKind {
     pub fn info(self) -> &'static RecipeInfo {
         &RECIPES[self as u16 as usize]
     }
+
+    /// The class name used by the game, e.g. `Recipe_IronPlate_C`.
+    pub fn class_name(self) -> &'static str {
+        match self {
+            Self::NuclearWaste => "TempRecipe_NuclearWaste_C",
+            Self::PlutoniumWaste => "TempRecipe_PlutoniumWaste_C",
+            Self::IronPlate => "Recipe_IronPlate_C",
+            Self::IronRod => "Recipe_IronRod_C",
+            Self::IronIngot => "Recipe_IngotIron_C",
+            Self::NitroRocketFuel => "Recipe_Alternate_RocketFuel_Nitro_C",
+            Self::RocketFuel => "Recipe_RocketFuel_C",
+            Self::PackagedRocketFuel => "Recipe_PackagedRocketFuel_C",
+            Self::UnpackageRocketFuel => "Recipe_UnpackageRocketFuel_C",
+            Self::DarkIonFuel => "Recipe_Alternate_IonizedFuel_Dark_C",
+            Self::DarkMatterResidue => "Recipe_DarkEnergy_C",
+            Self::ExcitedPhotonicMatter => "Recipe_QuantumEnergy_C",
+            Self::DarkMatterCrystal => "Recipe_DarkMatter_C",
+            Self::SuperpositionOscillator => "Recipe_SuperpositionOscillator_C",
+            Self::NeuralQuantumProcessor => "Recipe_TemporalProcessor_C",
+            Self::AiExpansionServer => "Recipe_SpaceElevatorPart_12_C",
+            Self::IonizedFuel => "Recipe_IonizedFuel_C",
+            Self::PackagedIonizedFuel => "Recipe_PackagedIonizedFuel_C",
+            Self::UnpackageIonizedFuel => "Recipe_UnpackageIonizedFuel_C",
+            Self::TurboDiamonds => "Recipe_Alternate_Diamond_Turbo_C",
+            Self::SamFluctuator => "Recipe_SAMFluctuator_C",
+            Self::FicsiteTrigon => "Recipe_FicsiteMesh_C",
+            Self::FicsiteIngotIron => "Recipe_FicsiteIngot_Iron_C",
+            Self::TimeCrystal => "Recipe_TimeCrystal_C",
+            Self::Diamonds => "Recipe_Diamond_C",
+            Self::ReanimatedSam => "Recipe_IngotSAM_C",
+            Self::BiochemicalSculptor => "Recipe_SpaceElevatorPart_10_C",
+            Self::FicsiteIngotAluminum => "Recipe_FicsiteIngot_AL_C",
+            Self::FicsiteIngotCaterium => "Recipe_FicsiteIngot_CAT_C",
+            Self::BauxiteCaterium => "Recipe_Bauxite_Caterium_C",
+            Self::BauxiteCopper => "Recipe_Bauxite_Copper_C",
+            Self::CateriumOreCopper => "Recipe_Caterium_Copper_C",
+            Self::CateriumOreQuartz => "Recipe_Caterium_Quartz_C",
+            Self::CoalIron => "Recipe_Coal_Iron_C",
+            Self::CoalLimestone => "Recipe_Coal_Limestone_C",
+            Self::CopperOreQuartz => "Recipe_Copper_Quartz_C",
+            Self::CopperOreSulfur => "Recipe_Copper_Sulfur_C",
+            Self::IronOreLimestone => "Recipe_Iron_Limestone_C",
+            Self::LimestoneSulfur => "Recipe_Limestone_Sulfur_C",
+            Self::NitrogenGasBauxite => "Recipe_Nitrogen_Bauxite_C",
+            Self::NitrogenGasCaterium => "Recipe_Nitrogen_Caterium_C",
+            Self::RawQuartzBauxite => "Recipe_Quartz_Bauxite_C",
+            Self::RawQuartzCoal => "Recipe_Quartz_Coal_C",
+            Self::SulfurCoal => "Recipe_Sulfur_Coal_C",
+            Self::SulfurIron => "Recipe_Sulfur_Iron_C",
+            Self::UraniumOreBauxite => "Recipe_Uranium_Bauxite_C",
+            Self::Turbofuel => "Recipe_Alternate_Turbofuel_C",
+            Self::PackagedTurbofuel => "Recipe_PackagedTurboFuel_C",
+            Self::UnpackageTurbofuel => "Recipe_UnpackageTurboFuel_C",
+            Self::Charcoal => "Recipe_Alternate_Coal_1_C",
+            Self::Biocoal => "Recipe_Alternate_Coal_2_C",
+            Self::CompactedCoal => "Recipe_Alternate_EnrichedCoal_C",
+            Self::CircuitBoard => "Recipe_CircuitBoard_C",
+            Self::Fuel => "Recipe_LiquidFuel_C",
+            Self::PetroleumCoke => "Recipe_PetroleumCoke_C",
+            Self::Plastic => "Recipe_Plastic_C",
+            Self::Rubber => "Recipe_Rubber_C",
+            Self::ResidualFuel => "Recipe_ResidualFuel_C",
+            Self::ResidualPlastic => "Recipe_ResidualPlastic_C",
+            Self::ResidualRubber => "Recipe_ResidualRubber_C",
+            Self::PinkDiamonds => "Recipe_Alternate_Diamond_Pink_C",
+            Self::PetroleumDiamonds => "Recipe_Alternate_Diamond_Petroleum_C",
+            Self::OilBasedDiamonds => "Recipe_Alternate_Diamond_OilBased_C",
+            Self::CloudyDiamonds => "Recipe_Alternate_Diamond_Cloudy_C",
+            Self::DarkMatterTrap => "Recipe_Alternate_DarkMatter_Trap_C",
+            Self::DarkMatterCrystallization => "Recipe_Alternate_DarkMatter_Crystallization_C",
+            Self::WetConcrete => "Recipe_Alternate_WetConcrete_C",
+            Self::TurboHeavyFuel => "Recipe_Alternate_TurboHeavyFuel_C",
+            Self::SteelRod => "Recipe_Alternate_SteelRod_C",
+            Self::SteelBeam => "Recipe_SteelBeam_C",
+            Self::SteelPipe => "Recipe_SteelPipe_C",
+            Self::SteelIngot => "Recipe_IngotSteel_C",
+            Self::VersatileFramework => "Recipe_SpaceElevatorPart_2_C",
+            Self::SteelCanister => "Recipe_Alternate_SteelCanister_C",
+            Self::EmptyCanister => "Recipe_FluidCanister_C",
+            Self::PackagedFuel => "Recipe_Fuel_C",
+            Self::LiquidBiofuel => "Recipe_LiquidBiofuel_C",
+            Self::PackagedLiquidBiofuel => "Recipe_PackagedBiofuel_C",
+            Self::PackagedOil => "Recipe_PackagedCrudeOil_C",
+            Self::PackagedHeavyOilResidue => "Recipe_PackagedOilResidue_C",
+            Self::PackagedWater => "Recipe_PackagedWater_C",
+            Self::UnpackageLiquidBiofuel => "Recipe_UnpackageBioFuel_C",
+            Self::UnpackageFuel => "Recipe_UnpackageFuel_C",
+            Self::UnpackageOil => "Recipe_UnpackageOil_C",
+            Self::UnpackageHeavyOilResidue => "Recipe_UnpackageOilResidue_C",
+            Self::UnpackageWater => "Recipe_UnpackageWater_C",
+            Self::SteamedCopperSheet => "Recipe_Alternate_SteamedCopperSheet_C",
+            Self::RubberConcrete => "Recipe_Alternate_RubberConcrete_C",
+            Self::RecycledRubber => "Recipe_Alternate_RecycledRubber_C",
+            Self::PureQuartzCrystal => "Recipe_Alternate_PureQuartzCrystal_C",
+            Self::QuartzCrystal => "Recipe_QuartzCrystal_C",
+            Self::PureIronIngot => "Recipe_Alternate_PureIronIngot_C",
+            Self::PureCopperIngot => "Recipe_Alternate_PureCopperIngot_C",
+            Self::PureCateriumIngot => "Recipe_Alternate_PureCateriumIngot_C",
+            Self::PureAluminumIngot => "Recipe_PureAluminumIngot_C",
+            Self::AluminumCasing => "Recipe_AluminumCasing_C",
+            Self::AlcladAluminumSheet => "Recipe_AluminumSheet_C",
+            Self::AluminaSolution => "Recipe_AluminaSolution_C",
+            Self::AluminumScrap => "Recipe_AluminumScrap_C",
+            Self::PackagedAluminaSolution => "Recipe_PackagedAlumina_C",
+            Self::AluminumIngot => "Recipe_IngotAluminum_C",
+            Self::Silica => "Recipe_Silica_C",
+            Self::CrystalOscillator => "Recipe_CrystalOscillator_C",
+            Self::UnpackageAluminaSolution => "Recipe_UnpackageAlumina_C",
+            Self::PolymerResin => "Recipe_Alternate_PolymerResin_C",
+            Self::PlasticSmartPlating => "Recipe_Alternate_PlasticSmartPlating_C",
+            Self::AutomatedSpeedWiring => "Recipe_Alternate_HighSpeedWiring_C",
+            Self::EncasedIndustrialBeam => "Recipe_EncasedIndustrialBeam_C",
+            Self::Motor => "Recipe_Motor_C",
+            Self::Stator => "Recipe_Stator_C",
+            Self::AutomatedWiring => "Recipe_SpaceElevatorPart_3_C",
+            Self::AiLimiter => "Recipe_AILimiter_C",
+            Self::HeavyOilResidue => "Recipe_Alternate_HeavyOilResidue_C",
+            Self::HeavyFlexibleFrame => "Recipe_Alternate_HeavyFlexibleFrame_C",
+            Self::Computer => "Recipe_Computer_C",
+            Self::HeavyModularFrame => "Recipe_ModularFrameHeavy_C",
+            Self::ModularEngine => "Recipe_SpaceElevatorPart_4_C",
+            Self::AdaptiveControlUnit => "Recipe_SpaceElevatorPart_5_C",
+            Self::FusedWire => "Recipe_Alternate_FusedWire_C",
+            Self::FlexibleFramework => "Recipe_Alternate_FlexibleFramework_C",
+            Self::ElectrodeCircuitBoard => "Recipe_Alternate_ElectrodeCircuitBoard_C",
+            Self::ElectrodeAluminumScrap => "Recipe_Alternate_ElectroAluminumScrap_C",
+            Self::DilutedPackagedFuel => "Recipe_Alternate_DilutedPackagedFuel_C",
+            Self::CopperRotor => "Recipe_Alternate_CopperRotor_C",
+            Self::ModularFrame => "Recipe_ModularFrame_C",
+            Self::Rotor => "Recipe_Rotor_C",
+            Self::CopperSheet => "Recipe_CopperSheet_C",
+            Self::SmartPlating => "Recipe_SpaceElevatorPart_1_C",
+            Self::CopperAlloyIngot => "Recipe_Alternate_CopperAlloyIngot_C",
+            Self::CokeSteelIngot => "Recipe_Alternate_CokeSteelIngot_C",
+            Self::CoatedIronPlate => "Recipe_Alternate_CoatedIronPlate_C",
+            Self::CoatedIronCanister => "Recipe_Alternate_CoatedIronCanister_C",
+            Self::CoatedCable => "Recipe_Alternate_CoatedCable_C",
+            Self::BoltedFrame => "Recipe_Alternate_BoltedFrame_C",
+            Self::AdheredIronPlate => "Recipe_Alternate_AdheredIronPlate_C",
+            Self::TurboPressureMotor => "Recipe_Alternate_TurboPressureMotor_C",
+            Self::EncasedPlutoniumCell => "Recipe_PlutoniumCell_C",
+            Self::PressureConversionCube => "Recipe_PressureConversionCube_C",
+            Self::NitricAcid => "Recipe_NitricAcid_C",
+            Self::NonFissileUranium => "Recipe_NonFissileUranium_C",
+            Self::CopperPowder => "Recipe_CopperDust_C",
+            Self::PlutoniumPellet => "Recipe_Plutonium_C",
+            Self::PlutoniumFuelRod => "Recipe_PlutoniumFuelRod_C",
+            Self::PackagedNitricAcid => "Recipe_PackagedNitricAcid_C",
+            Self::NuclearPasta => "Recipe_SpaceElevatorPart_9_C",
+            Self::UnpackageNitricAcid => "Recipe_UnpackageNitricAcid_C",
+            Self::TurboBlendFuel => "Recipe_Alternate_TurboBlendFuel_C",
+            Self::EncasedUraniumCell => "Recipe_UraniumCell_C",
+            Self::CoolingSystem => "Recipe_CoolingSystem_C",
+            Self::Battery => "Recipe_Battery_C",
+            Self::Supercomputer => "Recipe_ComputerSuper_C",
+            Self::RadioControlUnit => "Recipe_RadioControlUnit_C",
+            Self::SulfuricAcid => "Recipe_SulfuricAcid_C",
+            Self::PackagedSulfuricAcid => "Recipe_PackagedSulfuricAcid_C",
+            Self::AssemblyDirectorSystem => "Recipe_SpaceElevatorPart_7_C",
+            Self::HighSpeedConnector => "Recipe_HighSpeedConnector_C",
+            Self::UnpackageSulfuricAcid => "Recipe_UnpackageSulfuricAcid_C",
+            Self::SuperStateComputer => "Recipe_Alternate_SuperStateComputer_C",
+            Self::ElectromagneticControlRod => "Recipe_ElectromagneticControlRod_C",
+            Self::UraniumFuelRod => "Recipe_NuclearFuelRod_C",
+            Self::MagneticFieldGenerator => "Recipe_SpaceElevatorPart_6_C",
+            Self::SloppyAlumina => "Recipe_Alternate_SloppyAlumina_C",
+            Self::RadioControlSystem => "Recipe_Alternate_RadioControlSystem_C",
+            Self::PlutoniumFuelUnit => "Recipe_Alternate_PlutoniumFuelUnit_C",
+            Self::OcSupercomputer => "Recipe_Alternate_OCSupercomputer_C",
+            Self::HeatSink => "Recipe_HeatSink_C",
+            Self::FusedModularFrame => "Recipe_FusedModularFrame_C",
+            Self::EmptyFluidTank => "Recipe_GasTank_C",
+            Self::PackagedNitrogenGas => "Recipe_PackagedNitrogen_C",
+            Self::UnpackageNitrogenGas => "Recipe_UnpackageNitrogen_C",
+            Self::InstantScrap => "Recipe_Alternate_InstantScrap_C",
+            Self::InstantPlutoniumCell => "Recipe_Alternate_InstantPlutoniumCell_C",
+            Self::HeatFusedFrame => "Recipe_Alternate_HeatFusedFrame_C",
+            Self::FertileUranium => "Recipe_Alternate_FertileUranium_C",
+            Self::ElectricMotor => "Recipe_Alternate_ElectricMotor_C",
+            Self::DilutedFuel => "Recipe_Alternate_DilutedFuel_C",
+            Self::CoolingDevice => "Recipe_Alternate_CoolingDevice_C",
+            Self::ClassicBattery => "Recipe_Alternate_ClassicBattery_C",
+            Self::AutomatedMiner => "Recipe_Alternate_AutomatedMiner_C",
+            Self::AlcladCasing => "Recipe_Alternate_AlcladCasing_C",
+            Self::MoldedSteelPipe => "Recipe_Alternate_SteelPipe_Molded_C",
+            Self::IronPipe => "Recipe_Alternate_SteelPipe_Iron_C",
+            Self::SteelCastPlate => "Recipe_Alternate_SteelCastedPlate_C",
+            Self::MoldedBeam => "Recipe_Alternate_SteelBeam_Molded_C",
+            Self::AluminumBeam => "Recipe_Alternate_SteelBeam_Aluminum_C",
+            Self::AluminumRod => "Recipe_Alternate_AluminumRod_C",
+            Self::PlasticAiLimiter => "Recipe_Alternate_AILimiter_Plastic_C",
+            Self::DistilledSilica => "Recipe_Alternate_Silica_Distilled_C",
+            Self::QuartzPurification => "Recipe_Alternate_Quartz_Purified_C",
+            Self::FusedQuartzCrystal => "Recipe_Alternate_Quartz_Fused_C",
+            Self::LeachedIronIngot => "Recipe_Alternate_IronIngot_Leached_C",
+            Self::BasicIronIngot => "Recipe_Alternate_IronIngot_Basic_C",
+            Self::TemperedCopperIngot => "Recipe_Alternate_CopperIngot_Tempered_C",
+            Self::LeachedCopperIngot => "Recipe_Alternate_CopperIngot_Leached_C",
+            Self::TemperedCateriumIngot => "Recipe_Alternate_CateriumIngot_Tempered_C",
+            Self::LeachedCateriumIngot => "Recipe_Alternate_CateriumIngot_Leached_C",
+            Self::CateriumWire => "Recipe_Alternate_Wire_2_C",
+            Self::IronWire => "Recipe_Alternate_Wire_1_C",
+            Self::InfusedUraniumCell => "Recipe_Alternate_UraniumCell_1_C",
+            Self::CateriumIngot => "Recipe_IngotCaterium_C",
+            Self::TurboElectricMotor => "Recipe_Alternate_TurboMotor_1_C",
+            Self::TurboMotor => "Recipe_MotorTurbo_C",
+            Self::ThermalPropulsionRocket => "Recipe_SpaceElevatorPart_8_C",
+            Self::QuickwireStator => "Recipe_Alternate_Stator_C",
+            Self::CheapSilica => "Recipe_Alternate_Silica_C",
+            Self::SteelScrew => "Recipe_Alternate_Screw_2_C",
+            Self::CastScrew => "Recipe_Alternate_Screw_C",
+            Self::SteelRotor => "Recipe_Alternate_Rotor_C",
+            Self::EncasedIndustrialPipe => "Recipe_Alternate_EncasedIndustrialBeam_C",
+            Self::StitchedIronPlate => "Recipe_Alternate_ReinforcedIronPlate_2_C",
+            Self::BoltedIronPlate => "Recipe_Alternate_ReinforcedIronPlate_1_C",
+            Self::RadioConnectionUnit => "Recipe_Alternate_RadioControlUnit_1_C",
+            Self::FusedQuickwire => "Recipe_Alternate_Quickwire_C",
+            Self::RecycledPlastic => "Recipe_Alternate_Plastic_1_C",
+            Self::UraniumFuelUnit => "Recipe_Alternate_NuclearFuelRod_1_C",
+            Self::RigorMotor => "Recipe_Alternate_Motor_1_C",
+            Self::SteeledFrame => "Recipe_Alternate_ModularFrame_C",
+            Self::CompactedSteelIngot => "Recipe_Alternate_IngotSteel_2_C",
+            Self::SolidSteelIngot => "Recipe_Alternate_IngotSteel_1_C",
+            Self::IronAlloyIngot => "Recipe_Alternate_IngotIron_C",
+            Self::SiliconHighSpeedConnector => "Recipe_Alternate_HighSpeedConnector_C",
+            Self::HeavyEncasedFrame => "Recipe_Alternate_ModularFrameHeavy_C",
+            Self::HeatExchanger => "Recipe_Alternate_HeatSink_1_C",
+            Self::FineBlackPowder => "Recipe_Alternate_Gunpowder_1_C",
+            Self::ElectromagneticConnectionRod => "Recipe_Alternate_ElectromagneticControlRod_1_C",
+            Self::InsulatedCrystalOscillator => "Recipe_Alternate_CrystalOscillator_C",
+            Self::FineConcrete => "Recipe_Alternate_Concrete_C",
+            Self::CrystalComputer => "Recipe_Alternate_Computer_2_C",
+            Self::CateriumComputer => "Recipe_Alternate_Computer_1_C",
+            Self::CateriumCircuitBoard => "Recipe_Alternate_CircuitBoard_2_C",
+            Self::SiliconCircuitBoard => "Recipe_Alternate_CircuitBoard_1_C",
+            Self::QuickwireCable => "Recipe_Alternate_Cable_2_C",
+            Self::InsulatedCable => "Recipe_Alternate_Cable_1_C",
+            Self::Ficsonium => "Recipe_Ficsonium_C",
+            Self::FicsoniumFuelRod => "Recipe_FicsoniumFuelRod_C",
+            Self::SingularityCell => "Recipe_SingularityCell_C",
+            Self::BallisticWarpDrive => "Recipe_SpaceElevatorPart_11_C",
+            Self::IodineInfusedFilter => "Recipe_FilterHazmat_C",
+            Self::Quickwire => "Recipe_Quickwire_C",
+            Self::SolidBiofuel => "Recipe_Biofuel_C",
+            Self::HogProtein => "Recipe_Protein_Hog_C",
+            Self::SpitterProtein => "Recipe_Protein_Spitter_C",
+            Self::BiomassMycelia => "Recipe_Biomass_Mycelia_C",
+            Self::PowerShard1 => "Recipe_PowerCrystalShard_1_C",
+            Self::BlackPowder => "Recipe_Gunpowder_C",
+            Self::AlienPowerMatrix => "Recipe_AlienPowerFuel_C",
+            Self::StingerProtein => "Recipe_Protein_Stinger_C",
+            Self::HatcherProtein => "Recipe_Protein_Crab_C",
+            Self::AlienDnaCapsule => "Recipe_AlienDNACapsule_C",
+            Self::BiomassAlienProtein => "Recipe_Biomass_AlienProtein_C",
+            Self::IronRebar => "Recipe_SpikedRebar_C",
+            Self::HomingRifleAmmo => "Recipe_CartridgeSmart_C",
+            Self::StunRebar => "Recipe_Rebar_Stunshot_C",
+            Self::GasFilter => "Recipe_FilterGasMask_C",
+            Self::GasNobelisk => "Recipe_NobeliskGas_C",
+            Self::PolyesterFabric => "Recipe_Alternate_PolyesterFabric_C",
+            Self::Fabric => "Recipe_Fabric_C",
+            Self::SyntheticPowerShard => "Recipe_SyntheticPowerShard_C",
+            Self::PowerShard5 => "Recipe_PowerCrystalShard_3_C",
+            Self::PowerShard2 => "Recipe_PowerCrystalShard_2_C",
+            Self::PulseNobelisk => "Recipe_NobeliskShockwave_C",
+            Self::ShatterRebar => "Recipe_Rebar_Spreadshot_C",
+            Self::TurboRifleAmmoPackaged => "Recipe_CartridgeChaos_Packaged_C",
+            Self::TurboRifleAmmo => "Recipe_CartridgeChaos_C",
+            Self::NukeNobelisk => "Recipe_NobeliskNuke_C",
+            Self::RifleAmmo => "Recipe_Cartridge_C",
+            Self::ExplosiveRebar => "Recipe_Rebar_Explosive_C",
+            Self::ClusterNobelisk => "Recipe_NobeliskCluster_C",
+            Self::Nobelisk => "Recipe_Nobelisk_C",
+            Self::SmokelessPowder => "Recipe_GunpowderMK2_C",
+            Self::BiomassLeaves => "Recipe_Biomass_Leaves_C",
+            Self::BiomassWood => "Recipe_Biomass_Wood_C",
+            Self::ReinforcedIronPlate => "Recipe_IronPlateReinforced_C",
+            Self::Concrete => "Recipe_Concrete_C",
+            Self::Screw => "Recipe_Screw_C",
+            Self::Cable => "Recipe_Cable_C",
+            Self::Wire => "Recipe_Wire_C",
+            Self::CopperIngot => "Recipe_IngotCopper_C",
+        }
+    }
+
+    /// Looks up a recipe by the class name used by the game, e.g. `Recipe_IronPlate_C`.
+    pub fn from_class_name(name: &str) -> Option<Self> {
+        match name {
+            "TempRecipe_NuclearWaste_C" => Some(Self::NuclearWaste),
+            "TempRecipe_PlutoniumWaste_C" => Some(Self::PlutoniumWaste),
+            "Recipe_IronPlate_C" => Some(Self::IronPlate),
+            "Recipe_IronRod_C" => Some(Self::IronRod),
+            "Recipe_IngotIron_C" => Some(Self::IronIngot),
+            "Recipe_Alternate_RocketFuel_Nitro_C" => Some(Self::NitroRocketFuel),
+            "Recipe_RocketFuel_C" => Some(Self::RocketFuel),
+            "Recipe_PackagedRocketFuel_C" => Some(Self::PackagedRocketFuel),
+            "Recipe_UnpackageRocketFuel_C" => Some(Self::UnpackageRocketFuel),
+            "Recipe_Alternate_IonizedFuel_Dark_C" => Some(Self::DarkIonFuel),
+            "Recipe_DarkEnergy_C" => Some(Self::DarkMatterResidue),
+            "Recipe_QuantumEnergy_C" => Some(Self::ExcitedPhotonicMatter),
+            "Recipe_DarkMatter_C" => Some(Self::DarkMatterCrystal),
+            "Recipe_SuperpositionOscillator_C" => Some(Self::SuperpositionOscillator),
+            "Recipe_TemporalProcessor_C" => Some(Self::NeuralQuantumProcessor),
+            "Recipe_SpaceElevatorPart_12_C" => Some(Self::AiExpansionServer),
+            "Recipe_IonizedFuel_C" => Some(Self::IonizedFuel),
+            "Recipe_PackagedIonizedFuel_C" => Some(Self::PackagedIonizedFuel),
+            "Recipe_UnpackageIonizedFuel_C" => Some(Self::UnpackageIonizedFuel),
+            "Recipe_Alternate_Diamond_Turbo_C" => Some(Self::TurboDiamonds),
+            "Recipe_SAMFluctuator_C" => Some(Self::SamFluctuator),
+            "Recipe_FicsiteMesh_C" => Some(Self::FicsiteTrigon),
+            "Recipe_FicsiteIngot_Iron_C" => Some(Self::FicsiteIngotIron),
+            "Recipe_TimeCrystal_C" => Some(Self::TimeCrystal),
+            "Recipe_Diamond_C" => Some(Self::Diamonds),
+            "Recipe_IngotSAM_C" => Some(Self::ReanimatedSam),
+            "Recipe_SpaceElevatorPart_10_C" => Some(Self::BiochemicalSculptor),
+            "Recipe_FicsiteIngot_AL_C" => Some(Self::FicsiteIngotAluminum),
+            "Recipe_FicsiteIngot_CAT_C" => Some(Self::FicsiteIngotCaterium),
+            "Recipe_Bauxite_Caterium_C" => Some(Self::BauxiteCaterium),
+            "Recipe_Bauxite_Copper_C" => Some(Self::BauxiteCopper),
+            "Recipe_Caterium_Copper_C" => Some(Self::CateriumOreCopper),
+            "Recipe_Caterium_Quartz_C" => Some(Self::CateriumOreQuartz),
+            "Recipe_Coal_Iron_C" => Some(Self::CoalIron),
+            "Recipe_Coal_Limestone_C" => Some(Self::CoalLimestone),
+            "Recipe_Copper_Quartz_C" => Some(Self::CopperOreQuartz),
+            "Recipe_Copper_Sulfur_C" => Some(Self::CopperOreSulfur),
+            "Recipe_Iron_Limestone_C" => Some(Self::IronOreLimestone),
+            "Recipe_Limestone_Sulfur_C" => Some(Self::LimestoneSulfur),
+            "Recipe_Nitrogen_Bauxite_C" => Some(Self::NitrogenGasBauxite),
+            "Recipe_Nitrogen_Caterium_C" => Some(Self::NitrogenGasCaterium),
+            "Recipe_Quartz_Bauxite_C" => Some(Self::RawQuartzBauxite),
+            "Recipe_Quartz_Coal_C" => Some(Self::RawQuartzCoal),
+            "Recipe_Sulfur_Coal_C" => Some(Self::SulfurCoal),
+            "Recipe_Sulfur_Iron_C" => Some(Self::SulfurIron),
+            "Recipe_Uranium_Bauxite_C" => Some(Self::UraniumOreBauxite),
+            "Recipe_Alternate_Turbofuel_C" => Some(Self::Turbofuel),
+            "Recipe_PackagedTurboFuel_C" => Some(Self::PackagedTurbofuel),
+            "Recipe_UnpackageTurboFuel_C" => Some(Self::UnpackageTurbofuel),
+            "Recipe_Alternate_Coal_1_C" => Some(Self::Charcoal),
+            "Recipe_Alternate_Coal_2_C" => Some(Self::Biocoal),
+            "Recipe_Alternate_EnrichedCoal_C" => Some(Self::CompactedCoal),
+            "Recipe_CircuitBoard_C" => Some(Self::CircuitBoard),
+            "Recipe_LiquidFuel_C" => Some(Self::Fuel),
+            "Recipe_PetroleumCoke_C" => Some(Self::PetroleumCoke),
+            "Recipe_Plastic_C" => Some(Self::Plastic),
+            "Recipe_Rubber_C" => Some(Self::Rubber),
+            "Recipe_ResidualFuel_C" => Some(Self::ResidualFuel),
+            "Recipe_ResidualPlastic_C" => Some(Self::ResidualPlastic),
+            "Recipe_ResidualRubber_C" => Some(Self::ResidualRubber),
+            "Recipe_Alternate_Diamond_Pink_C" => Some(Self::PinkDiamonds),
+            "Recipe_Alternate_Diamond_Petroleum_C" => Some(Self::PetroleumDiamonds),
+            "Recipe_Alternate_Diamond_OilBased_C" => Some(Self::OilBasedDiamonds),
+            "Recipe_Alternate_Diamond_Cloudy_C" => Some(Self::CloudyDiamonds),
+            "Recipe_Alternate_DarkMatter_Trap_C" => Some(Self::DarkMatterTrap),
+            "Recipe_Alternate_DarkMatter_Crystallization_C" => Some(Self::DarkMatterCrystallization),
+            "Recipe_Alternate_WetConcrete_C" => Some(Self::WetConcrete),
+            "Recipe_Alternate_TurboHeavyFuel_C" => Some(Self::TurboHeavyFuel),
+            "Recipe_Alternate_SteelRod_C" => Some(Self::SteelRod),
+            "Recipe_SteelBeam_C" => Some(Self::SteelBeam),
+            "Recipe_SteelPipe_C" => Some(Self::SteelPipe),
+            "Recipe_IngotSteel_C" => Some(Self::SteelIngot),
+            "Recipe_SpaceElevatorPart_2_C" => Some(Self::VersatileFramework),
+            "Recipe_Alternate_SteelCanister_C" => Some(Self::SteelCanister),
+            "Recipe_FluidCanister_C" => Some(Self::EmptyCanister),
+            "Recipe_Fuel_C" => Some(Self::PackagedFuel),
+            "Recipe_LiquidBiofuel_C" => Some(Self::LiquidBiofuel),
+            "Recipe_PackagedBiofuel_C" => Some(Self::PackagedLiquidBiofuel),
+            "Recipe_PackagedCrudeOil_C" => Some(Self::PackagedOil),
+            "Recipe_PackagedOilResidue_C" => Some(Self::PackagedHeavyOilResidue),
+            "Recipe_PackagedWater_C" => Some(Self::PackagedWater),
+            "Recipe_UnpackageBioFuel_C" => Some(Self::UnpackageLiquidBiofuel),
+            "Recipe_UnpackageFuel_C" => Some(Self::UnpackageFuel),
+            "Recipe_UnpackageOil_C" => Some(Self::UnpackageOil),
+            "Recipe_UnpackageOilResidue_C" => Some(Self::UnpackageHeavyOilResidue),
+            "Recipe_UnpackageWater_C" => Some(Self::UnpackageWater),
+            "Recipe_Alternate_SteamedCopperSheet_C" => Some(Self::SteamedCopperSheet),
+            "Recipe_Alternate_RubberConcrete_C" => Some(Self::RubberConcrete),
+            "Recipe_Alternate_RecycledRubber_C" => Some(Self::RecycledRubber),
+            "Recipe_Alternate_PureQuartzCrystal_C" => Some(Self::PureQuartzCrystal),
+            "Recipe_QuartzCrystal_C" => Some(Self::QuartzCrystal),
+            "Recipe_Alternate_PureIronIngot_C" => Some(Self::PureIronIngot),
+            "Recipe_Alternate_PureCopperIngot_C" => Some(Self::PureCopperIngot),
+            "Recipe_Alternate_PureCateriumIngot_C" => Some(Self::PureCateriumIngot),
+            "Recipe_PureAluminumIngot_C" => Some(Self::PureAluminumIngot),
+            "Recipe_AluminumCasing_C" => Some(Self::AluminumCasing),
+            "Recipe_AluminumSheet_C" => Some(Self::AlcladAluminumSheet),
+            "Recipe_AluminaSolution_C" => Some(Self::AluminaSolution),
+            "Recipe_AluminumScrap_C" => Some(Self::AluminumScrap),
+            "Recipe_PackagedAlumina_C" => Some(Self::PackagedAluminaSolution),
+            "Recipe_IngotAluminum_C" => Some(Self::AluminumIngot),
+            "Recipe_Silica_C" => Some(Self::Silica),
+            "Recipe_CrystalOscillator_C" => Some(Self::CrystalOscillator),
+            "Recipe_UnpackageAlumina_C" => Some(Self::UnpackageAluminaSolution),
+            "Recipe_Alternate_PolymerResin_C" => Some(Self::PolymerResin),
+            "Recipe_Alternate_PlasticSmartPlating_C" => Some(Self::PlasticSmartPlating),
+            "Recipe_Alternate_HighSpeedWiring_C" => Some(Self::AutomatedSpeedWiring),
+            "Recipe_EncasedIndustrialBeam_C" => Some(Self::EncasedIndustrialBeam),
+            "Recipe_Motor_C" => Some(Self::Motor),
+            "Recipe_Stator_C" => Some(Self::Stator),
+            "Recipe_SpaceElevatorPart_3_C" => Some(Self::AutomatedWiring),
+            "Recipe_AILimiter_C" => Some(Self::AiLimiter),
+            "Recipe_Alternate_HeavyOilResidue_C" => Some(Self::HeavyOilResidue),
+            "Recipe_Alternate_HeavyFlexibleFrame_C" => Some(Self::HeavyFlexibleFrame),
+            "Recipe_Computer_C" => Some(Self::Computer),
+            "Recipe_ModularFrameHeavy_C" => Some(Self::HeavyModularFrame),
+            "Recipe_SpaceElevatorPart_4_C" => Some(Self::ModularEngine),
+            "Recipe_SpaceElevatorPart_5_C" => Some(Self::AdaptiveControlUnit),
+            "Recipe_Alternate_FusedWire_C" => Some(Self::FusedWire),
+            "Recipe_Alternate_FlexibleFramework_C" => Some(Self::FlexibleFramework),
+            "Recipe_Alternate_ElectrodeCircuitBoard_C" => Some(Self::ElectrodeCircuitBoard),
+            "Recipe_Alternate_ElectroAluminumScrap_C" => Some(Self::ElectrodeAluminumScrap),
+            "Recipe_Alternate_DilutedPackagedFuel_C" => Some(Self::DilutedPackagedFuel),
+            "Recipe_Alternate_CopperRotor_C" => Some(Self::CopperRotor),
+            "Recipe_ModularFrame_C" => Some(Self::ModularFrame),
+            "Recipe_Rotor_C" => Some(Self::Rotor),
+            "Recipe_CopperSheet_C" => Some(Self::CopperSheet),
+            "Recipe_SpaceElevatorPart_1_C" => Some(Self::SmartPlating),
+            "Recipe_Alternate_CopperAlloyIngot_C" => Some(Self::CopperAlloyIngot),
+            "Recipe_Alternate_CokeSteelIngot_C" => Some(Self::CokeSteelIngot),
+            "Recipe_Alternate_CoatedIronPlate_C" => Some(Self::CoatedIronPlate),
+            "Recipe_Alternate_CoatedIronCanister_C" => Some(Self::CoatedIronCanister),
+            "Recipe_Alternate_CoatedCable_C" => Some(Self::CoatedCable),
+            "Recipe_Alternate_BoltedFrame_C" => Some(Self::BoltedFrame),
+            "Recipe_Alternate_AdheredIronPlate_C" => Some(Self::AdheredIronPlate),
+            "Recipe_Alternate_TurboPressureMotor_C" => Some(Self::TurboPressureMotor),
+            "Recipe_PlutoniumCell_C" => Some(Self::EncasedPlutoniumCell),
+            "Recipe_PressureConversionCube_C" => Some(Self::PressureConversionCube),
+            "Recipe_NitricAcid_C" => Some(Self::NitricAcid),
+            "Recipe_NonFissileUranium_C" => Some(Self::NonFissileUranium),
+            "Recipe_CopperDust_C" => Some(Self::CopperPowder),
+            "Recipe_Plutonium_C" => Some(Self::PlutoniumPellet),
+            "Recipe_PlutoniumFuelRod_C" => Some(Self::PlutoniumFuelRod),
+            "Recipe_PackagedNitricAcid_C" => Some(Self::PackagedNitricAcid),
+            "Recipe_SpaceElevatorPart_9_C" => Some(Self::NuclearPasta),
+            "Recipe_UnpackageNitricAcid_C" => Some(Self::UnpackageNitricAcid),
+            "Recipe_Alternate_TurboBlendFuel_C" => Some(Self::TurboBlendFuel),
+            "Recipe_UraniumCell_C" => Some(Self::EncasedUraniumCell),
+            "Recipe_CoolingSystem_C" => Some(Self::CoolingSystem),
+            "Recipe_Battery_C" => Some(Self::Battery),
+            "Recipe_ComputerSuper_C" => Some(Self::Supercomputer),
+            "Recipe_RadioControlUnit_C" => Some(Self::RadioControlUnit),
+            "Recipe_SulfuricAcid_C" => Some(Self::SulfuricAcid),
+            "Recipe_PackagedSulfuricAcid_C" => Some(Self::PackagedSulfuricAcid),
+            "Recipe_SpaceElevatorPart_7_C" => Some(Self::AssemblyDirectorSystem),
+            "Recipe_HighSpeedConnector_C" => Some(Self::HighSpeedConnector),
+            "Recipe_UnpackageSulfuricAcid_C" => Some(Self::UnpackageSulfuricAcid),
+            "Recipe_Alternate_SuperStateComputer_C" => Some(Self::SuperStateComputer),
+            "Recipe_ElectromagneticControlRod_C" => Some(Self::ElectromagneticControlRod),
+            "Recipe_NuclearFuelRod_C" => Some(Self::UraniumFuelRod),
+            "Recipe_SpaceElevatorPart_6_C" => Some(Self::MagneticFieldGenerator),
+            "Recipe_Alternate_SloppyAlumina_C" => Some(Self::SloppyAlumina),
+            "Recipe_Alternate_RadioControlSystem_C" => Some(Self::RadioControlSystem),
+            "Recipe_Alternate_PlutoniumFuelUnit_C" => Some(Self::PlutoniumFuelUnit),
+            "Recipe_Alternate_OCSupercomputer_C" => Some(Self::OcSupercomputer),
+            "Recipe_HeatSink_C" => Some(Self::HeatSink),
+            "Recipe_FusedModularFrame_C" => Some(Self::FusedModularFrame),
+            "Recipe_GasTank_C" => Some(Self::EmptyFluidTank),
+            "Recipe_PackagedNitrogen_C" => Some(Self::PackagedNitrogenGas),
+            "Recipe_UnpackageNitrogen_C" => Some(Self::UnpackageNitrogenGas),
+            "Recipe_Alternate_InstantScrap_C" => Some(Self::InstantScrap),
+            "Recipe_Alternate_InstantPlutoniumCell_C" => Some(Self::InstantPlutoniumCell),
+            "Recipe_Alternate_HeatFusedFrame_C" => Some(Self::HeatFusedFrame),
+            "Recipe_Alternate_FertileUranium_C" => Some(Self::FertileUranium),
+            "Recipe_Alternate_ElectricMotor_C" => Some(Self::ElectricMotor),
+            "Recipe_Alternate_DilutedFuel_C" => Some(Self::DilutedFuel),
+            "Recipe_Alternate_CoolingDevice_C" => Some(Self::CoolingDevice),
+            "Recipe_Alternate_ClassicBattery_C" => Some(Self::ClassicBattery),
+            "Recipe_Alternate_AutomatedMiner_C" => Some(Self::AutomatedMiner),
+            "Recipe_Alternate_AlcladCasing_C" => Some(Self::AlcladCasing),
+            "Recipe_Alternate_SteelPipe_Molded_C" => Some(Self::MoldedSteelPipe),
+            "Recipe_Alternate_SteelPipe_Iron_C" => Some(Self::IronPipe),
+            "Recipe_Alternate_SteelCastedPlate_C" => Some(Self::SteelCastPlate),
+            "Recipe_Alternate_SteelBeam_Molded_C" => Some(Self::MoldedBeam),
+            "Recipe_Alternate_SteelBeam_Aluminum_C" => Some(Self::AluminumBeam),
+            "Recipe_Alternate_AluminumRod_C" => Some(Self::AluminumRod),
+            "Recipe_Alternate_AILimiter_Plastic_C" => Some(Self::PlasticAiLimiter),
+            "Recipe_Alternate_Silica_Distilled_C" => Some(Self::DistilledSilica),
+            "Recipe_Alternate_Quartz_Purified_C" => Some(Self::QuartzPurification),
+            "Recipe_Alternate_Quartz_Fused_C" => Some(Self::FusedQuartzCrystal),
+            "Recipe_Alternate_IronIngot_Leached_C" => Some(Self::LeachedIronIngot),
+            "Recipe_Alternate_IronIngot_Basic_C" => Some(Self::BasicIronIngot),
+            "Recipe_Alternate_CopperIngot_Tempered_C" => Some(Self::TemperedCopperIngot),
+            "Recipe_Alternate_CopperIngot_Leached_C" => Some(Self::LeachedCopperIngot),
+            "Recipe_Alternate_CateriumIngot_Tempered_C" => Some(Self::TemperedCateriumIngot),
+            "Recipe_Alternate_CateriumIngot_Leached_C" => Some(Self::LeachedCateriumIngot),
+            "Recipe_Alternate_Wire_2_C" => Some(Self::CateriumWire),
+            "Recipe_Alternate_Wire_1_C" => Some(Self::IronWire),
+            "Recipe_Alternate_UraniumCell_1_C" => Some(Self::InfusedUraniumCell),
+            "Recipe_IngotCaterium_C" => Some(Self::CateriumIngot),
+            "Recipe_Alternate_TurboMotor_1_C" => Some(Self::TurboElectricMotor),
+            "Recipe_MotorTurbo_C" => Some(Self::TurboMotor),
+            "Recipe_SpaceElevatorPart_8_C" => Some(Self::ThermalPropulsionRocket),
+            "Recipe_Alternate_Stator_C" => Some(Self::QuickwireStator),
+            "Recipe_Alternate_Silica_C" => Some(Self::CheapSilica),
+            "Recipe_Alternate_Screw_2_C" => Some(Self::SteelScrew),
+            "Recipe_Alternate_Screw_C" => Some(Self::CastScrew),
+            "Recipe_Alternate_Rotor_C" => Some(Self::SteelRotor),
+            "Recipe_Alternate_EncasedIndustrialBeam_C" => Some(Self::EncasedIndustrialPipe),
+            "Recipe_Alternate_ReinforcedIronPlate_2_C" => Some(Self::StitchedIronPlate),
+            "Recipe_Alternate_ReinforcedIronPlate_1_C" => Some(Self::BoltedIronPlate),
+            "Recipe_Alternate_RadioControlUnit_1_C" => Some(Self::RadioConnectionUnit),
+            "Recipe_Alternate_Quickwire_C" => Some(Self::FusedQuickwire),
+            "Recipe_Alternate_Plastic_1_C" => Some(Self::RecycledPlastic),
+            "Recipe_Alternate_NuclearFuelRod_1_C" => Some(Self::UraniumFuelUnit),
+            "Recipe_Alternate_Motor_1_C" => Some(Self::RigorMotor),
+            "Recipe_Alternate_ModularFrame_C" => Some(Self::SteeledFrame),
+            "Recipe_Alternate_IngotSteel_2_C" => Some(Self::CompactedSteelIngot),
+            "Recipe_Alternate_IngotSteel_1_C" => Some(Self::SolidSteelIngot),
+            "Recipe_Alternate_IngotIron_C" => Some(Self::IronAlloyIngot),
+            "Recipe_Alternate_HighSpeedConnector_C" => Some(Self::SiliconHighSpeedConnector),
+            "Recipe_Alternate_ModularFrameHeavy_C" => Some(Self::HeavyEncasedFrame),
+            "Recipe_Alternate_HeatSink_1_C" => Some(Self::HeatExchanger),
+            "Recipe_Alternate_Gunpowder_1_C" => Some(Self::FineBlackPowder),
+            "Recipe_Alternate_ElectromagneticControlRod_1_C" => Some(Self::ElectromagneticConnectionRod),
+            "Recipe_Alternate_CrystalOscillator_C" => Some(Self::InsulatedCrystalOscillator),
+            "Recipe_Alternate_Concrete_C" => Some(Self::FineConcrete),
+            "Recipe_Alternate_Computer_2_C" => Some(Self::CrystalComputer),
+            "Recipe_Alternate_Computer_1_C" => Some(Self::CateriumComputer),
+            "Recipe_Alternate_CircuitBoard_2_C" => Some(Self::CateriumCircuitBoard),
+            "Recipe_Alternate_CircuitBoard_1_C" => Some(Self::SiliconCircuitBoard),
+            "Recipe_Alternate_Cable_2_C" => Some(Self::QuickwireCable),
+            "Recipe_Alternate_Cable_1_C" => Some(Self::InsulatedCable),
+            "Recipe_Ficsonium_C" => Some(Self::Ficsonium),
+            "Recipe_FicsoniumFuelRod_C" => Some(Self::FicsoniumFuelRod),
+            "Recipe_SingularityCell_C" => Some(Self::SingularityCell),
+            "Recipe_SpaceElevatorPart_11_C" => Some(Self::BallisticWarpDrive),
+            "Recipe_FilterHazmat_C" => Some(Self::IodineInfusedFilter),
+            "Recipe_Quickwire_C" => Some(Self::Quickwire),
+            "Recipe_Biofuel_C" => Some(Self::SolidBiofuel),
+            "Recipe_Protein_Hog_C" => Some(Self::HogProtein),
+            "Recipe_Protein_Spitter_C" => Some(Self::SpitterProtein),
+            "Recipe_Biomass_Mycelia_C" => Some(Self::BiomassMycelia),
+            "Recipe_PowerCrystalShard_1_C" => Some(Self::PowerShard1),
+            "Recipe_Gunpowder_C" => Some(Self::BlackPowder),
+            "Recipe_AlienPowerFuel_C" => Some(Self::AlienPowerMatrix),
+            "Recipe_Protein_Stinger_C" => Some(Self::StingerProtein),
+            "Recipe_Protein_Crab_C" => Some(Self::HatcherProtein),
+            "Recipe_AlienDNACapsule_C" => Some(Self::AlienDnaCapsule),
+            "Recipe_Biomass_AlienProtein_C" => Some(Self::BiomassAlienProtein),
+            "Recipe_SpikedRebar_C" => Some(Self::IronRebar),
+            "Recipe_CartridgeSmart_C" => Some(Self::HomingRifleAmmo),
+            "Recipe_Rebar_Stunshot_C" => Some(Self::StunRebar),
+            "Recipe_FilterGasMask_C" => Some(Self::GasFilter),
+            "Recipe_NobeliskGas_C" => Some(Self::GasNobelisk),
+            "Recipe_Alternate_PolyesterFabric_C" => Some(Self::PolyesterFabric),
+            "Recipe_Fabric_C" => Some(Self::Fabric),
+            "Recipe_SyntheticPowerShard_C" => Some(Self::SyntheticPowerShard),
+            "Recipe_PowerCrystalShard_3_C" => Some(Self::PowerShard5),
+            "Recipe_PowerCrystalShard_2_C" => Some(Self::PowerShard2),
+            "Recipe_NobeliskShockwave_C" => Some(Self::PulseNobelisk),
+            "Recipe_Rebar_Spreadshot_C" => Some(Self::ShatterRebar),
+            "Recipe_CartridgeChaos_Packaged_C" => Some(Self::TurboRifleAmmoPackaged),
+            "Recipe_CartridgeChaos_C" => Some(Self::TurboRifleAmmo),
+            "Recipe_NobeliskNuke_C" => Some(Self::NukeNobelisk),
+            "Recipe_Cartridge_C" => Some(Self::RifleAmmo),
+            "Recipe_Rebar_Explosive_C" => Some(Self::ExplosiveRebar),
+            "Recipe_NobeliskCluster_C" => Some(Self::ClusterNobelisk),
+            "Recipe_Nobelisk_C" => Some(Self::Nobelisk),
+            "Recipe_GunpowderMK2_C" => Some(Self::SmokelessPowder),
+            "Recipe_Biomass_Leaves_C" => Some(Self::BiomassLeaves),
+            "Recipe_Biomass_Wood_C" => Some(Self::BiomassWood),
+            "Recipe_IronPlateReinforced_C" => Some(Self::ReinforcedIronPlate),
+            "Recipe_Concrete_C" => Some(Self::Concrete),
+            "Recipe_Screw_C" => Some(Self::Screw),
+            "Recipe_Cable_C" => Some(Self::Cable),
+            "Recipe_Wire_C" => Some(Self::Wire),
+            "Recipe_IngotCopper_C" => Some(Self::CopperIngot),
+            _ => None,
+        }
+    }
+
+    /// ID used in digests, see `data/recipe-ids.json`. Unlike the discriminant, it never
+    /// changes when the game data is regenerated.
+    pub fn stable_id(self) -> u16 {
+        match self {
+            Self::NuclearWaste => 0,
+            Self::PlutoniumWaste => 1,
+            Self::IronPlate => 2,
+            Self::IronRod => 3,
+            Self::IronIngot => 4,
+            Self::NitroRocketFuel => 5,
+            Self::RocketFuel => 6,
+            Self::PackagedRocketFuel => 7,
+            Self::UnpackageRocketFuel => 8,
+            Self::DarkIonFuel => 9,
+            Self::DarkMatterResidue => 10,
+            Self::ExcitedPhotonicMatter => 11,
+            Self::DarkMatterCrystal => 12,
+            Self::SuperpositionOscillator => 13,
+            Self::NeuralQuantumProcessor => 14,
+            Self::AiExpansionServer => 15,
+            Self::IonizedFuel => 16,
+            Self::PackagedIonizedFuel => 17,
+            Self::UnpackageIonizedFuel => 18,
+            Self::TurboDiamonds => 19,
+            Self::SamFluctuator => 20,
+            Self::FicsiteTrigon => 21,
+            Self::FicsiteIngotIron => 22,
+            Self::TimeCrystal => 23,
+            Self::Diamonds => 24,
+            Self::ReanimatedSam => 25,
+            Self::BiochemicalSculptor => 26,
+            Self::FicsiteIngotAluminum => 27,
+            Self::FicsiteIngotCaterium => 28,
+            Self::BauxiteCaterium => 29,
+            Self::BauxiteCopper => 30,
+            Self::CateriumOreCopper => 31,
+            Self::CateriumOreQuartz => 32,
+            Self::CoalIron => 33,
+            Self::CoalLimestone => 34,
+            Self::CopperOreQuartz => 35,
+            Self::CopperOreSulfur => 36,
+            Self::IronOreLimestone => 37,
+            Self::LimestoneSulfur => 38,
+            Self::NitrogenGasBauxite => 39,
+            Self::NitrogenGasCaterium => 40,
+            Self::RawQuartzBauxite => 41,
+            Self::RawQuartzCoal => 42,
+            Self::SulfurCoal => 43,
+            Self::SulfurIron => 44,
+            Self::UraniumOreBauxite => 45,
+            Self::Turbofuel => 46,
+            Self::PackagedTurbofuel => 47,
+            Self::UnpackageTurbofuel => 48,
+            Self::Charcoal => 49,
+            Self::Biocoal => 50,
+            Self::CompactedCoal => 51,
+            Self::CircuitBoard => 52,
+            Self::Fuel => 53,
+            Self::PetroleumCoke => 54,
+            Self::Plastic => 55,
+            Self::Rubber => 56,
+            Self::ResidualFuel => 57,
+            Self::ResidualPlastic => 58,
+            Self::ResidualRubber => 59,
+            Self::PinkDiamonds => 60,
+            Self::PetroleumDiamonds => 61,
+            Self::OilBasedDiamonds => 62,
+            Self::CloudyDiamonds => 63,
+            Self::DarkMatterTrap => 64,
+            Self::DarkMatterCrystallization => 65,
+            Self::WetConcrete => 66,
+            Self::TurboHeavyFuel => 67,
+            Self::SteelRod => 68,
+            Self::SteelBeam => 69,
+            Self::SteelPipe => 70,
+            Self::SteelIngot => 71,
+            Self::VersatileFramework => 72,
+            Self::SteelCanister => 73,
+            Self::EmptyCanister => 74,
+            Self::PackagedFuel => 75,
+            Self::LiquidBiofuel => 76,
+            Self::PackagedLiquidBiofuel => 77,
+            Self::PackagedOil => 78,
+            Self::PackagedHeavyOilResidue => 79,
+            Self::PackagedWater => 80,
+            Self::UnpackageLiquidBiofuel => 81,
+            Self::UnpackageFuel => 82,
+            Self::UnpackageOil => 83,
+            Self::UnpackageHeavyOilResidue => 84,
+            Self::UnpackageWater => 85,
+            Self::SteamedCopperSheet => 86,
+            Self::RubberConcrete => 87,
+            Self::RecycledRubber => 88,
+            Self::PureQuartzCrystal => 89,
+            Self::QuartzCrystal => 90,
+            Self::PureIronIngot => 91,
+            Self::PureCopperIngot => 92,
+            Self::PureCateriumIngot => 93,
+            Self::PureAluminumIngot => 94,
+            Self::AluminumCasing => 95,
+            Self::AlcladAluminumSheet => 96,
+            Self::AluminaSolution => 97,
+            Self::AluminumScrap => 98,
+            Self::PackagedAluminaSolution => 99,
+            Self::AluminumIngot => 100,
+            Self::Silica => 101,
+            Self::CrystalOscillator => 102,
+            Self::UnpackageAluminaSolution => 103,
+            Self::PolymerResin => 104,
+            Self::PlasticSmartPlating => 105,
+            Self::AutomatedSpeedWiring => 106,
+            Self::EncasedIndustrialBeam => 107,
+            Self::Motor => 108,
+            Self::Stator => 109,
+            Self::AutomatedWiring => 110,
+            Self::AiLimiter => 111,
+            Self::HeavyOilResidue => 112,
+            Self::HeavyFlexibleFrame => 113,
+            Self::Computer => 114,
+            Self::HeavyModularFrame => 115,
+            Self::ModularEngine => 116,
+            Self::AdaptiveControlUnit => 117,
+            Self::FusedWire => 118,
+            Self::FlexibleFramework => 119,
+            Self::ElectrodeCircuitBoard => 120,
+            Self::ElectrodeAluminumScrap => 121,
+            Self::DilutedPackagedFuel => 122,
+            Self::CopperRotor => 123,
+            Self::ModularFrame => 124,
+            Self::Rotor => 125,
+            Self::CopperSheet => 126,
+            Self::SmartPlating => 127,
+            Self::CopperAlloyIngot => 128,
+            Self::CokeSteelIngot => 129,
+            Self::CoatedIronPlate => 130,
+            Self::CoatedIronCanister => 131,
+            Self::CoatedCable => 132,
+            Self::BoltedFrame => 133,
+            Self::AdheredIronPlate => 134,
+            Self::TurboPressureMotor => 135,
+            Self::EncasedPlutoniumCell => 136,
+            Self::PressureConversionCube => 137,
+            Self::NitricAcid => 138,
+            Self::NonFissileUranium => 139,
+            Self::CopperPowder => 140,
+            Self::PlutoniumPellet => 141,
+            Self::PlutoniumFuelRod => 142,
+            Self::PackagedNitricAcid => 143,
+            Self::NuclearPasta => 144,
+            Self::UnpackageNitricAcid => 145,
+            Self::TurboBlendFuel => 146,
+            Self::EncasedUraniumCell => 147,
+            Self::CoolingSystem => 148,
+            Self::Battery => 149,
+            Self::Supercomputer => 150,
+            Self::RadioControlUnit => 151,
+            Self::SulfuricAcid => 152,
+            Self::PackagedSulfuricAcid => 153,
+            Self::AssemblyDirectorSystem => 154,
+            Self::HighSpeedConnector => 155,
+            Self::UnpackageSulfuricAcid => 156,
+            Self::SuperStateComputer => 157,
+            Self::ElectromagneticControlRod => 158,
+            Self::UraniumFuelRod => 159,
+            Self::MagneticFieldGenerator => 160,
+            Self::SloppyAlumina => 161,
+            Self::RadioControlSystem => 162,
+            Self::PlutoniumFuelUnit => 163,
+            Self::OcSupercomputer => 164,
+            Self::HeatSink => 165,
+            Self::FusedModularFrame => 166,
+            Self::EmptyFluidTank => 167,
+            Self::PackagedNitrogenGas => 168,
+            Self::UnpackageNitrogenGas => 169,
+            Self::InstantScrap => 170,
+            Self::InstantPlutoniumCell => 171,
+            Self::HeatFusedFrame => 172,
+            Self::FertileUranium => 173,
+            Self::ElectricMotor => 174,
+            Self::DilutedFuel => 175,
+            Self::CoolingDevice => 176,
+            Self::ClassicBattery => 177,
+            Self::AutomatedMiner => 178,
+            Self::AlcladCasing => 179,
+            Self::MoldedSteelPipe => 180,
+            Self::IronPipe => 181,
+            Self::SteelCastPlate => 182,
+            Self::MoldedBeam => 183,
+            Self::AluminumBeam => 184,
+            Self::AluminumRod => 185,
+            Self::PlasticAiLimiter => 186,
+            Self::DistilledSilica => 187,
+            Self::QuartzPurification => 188,
+            Self::FusedQuartzCrystal => 189,
+            Self::LeachedIronIngot => 190,
+            Self::BasicIronIngot => 191,
+            Self::TemperedCopperIngot => 192,
+            Self::LeachedCopperIngot => 193,
+            Self::TemperedCateriumIngot => 194,
+            Self::LeachedCateriumIngot => 195,
+            Self::CateriumWire => 196,
+            Self::IronWire => 197,
+            Self::InfusedUraniumCell => 198,
+            Self::CateriumIngot => 199,
+            Self::TurboElectricMotor => 200,
+            Self::TurboMotor => 201,
+            Self::ThermalPropulsionRocket => 202,
+            Self::QuickwireStator => 203,
+            Self::CheapSilica => 204,
+            Self::SteelScrew => 205,
+            Self::CastScrew => 206,
+            Self::SteelRotor => 207,
+            Self::EncasedIndustrialPipe => 208,
+            Self::StitchedIronPlate => 209,
+            Self::BoltedIronPlate => 210,
+            Self::RadioConnectionUnit => 211,
+            Self::FusedQuickwire => 212,
+            Self::RecycledPlastic => 213,
+            Self::UraniumFuelUnit => 214,
+            Self::RigorMotor => 215,
+            Self::SteeledFrame => 216,
+            Self::CompactedSteelIngot => 217,
+            Self::SolidSteelIngot => 218,
+            Self::IronAlloyIngot => 219,
+            Self::SiliconHighSpeedConnector => 220,
+            Self::HeavyEncasedFrame => 221,
+            Self::HeatExchanger => 222,
+            Self::FineBlackPowder => 223,
+            Self::ElectromagneticConnectionRod => 224,
+            Self::InsulatedCrystalOscillator => 225,
+            Self::FineConcrete => 226,
+            Self::CrystalComputer => 227,
+            Self::CateriumComputer => 228,
+            Self::CateriumCircuitBoard => 229,
+            Self::SiliconCircuitBoard => 230,
+            Self::QuickwireCable => 231,
+            Self::InsulatedCable => 232,
+            Self::Ficsonium => 233,
+            Self::FicsoniumFuelRod => 234,
+            Self::SingularityCell => 235,
+            Self::BallisticWarpDrive => 236,
+            Self::IodineInfusedFilter => 237,
+            Self::Quickwire => 238,
+            Self::SolidBiofuel => 239,
+            Self::HogProtein => 240,
+            Self::SpitterProtein => 241,
+            Self::BiomassMycelia => 242,
+            Self::PowerShard1 => 243,
+            Self::BlackPowder => 244,
+            Self::AlienPowerMatrix => 245,
+            Self::StingerProtein => 246,
+            Self::HatcherProtein => 247,
+            Self::AlienDnaCapsule => 248,
+            Self::BiomassAlienProtein => 249,
+            Self::IronRebar => 250,
+            Self::HomingRifleAmmo => 251,
+            Self::StunRebar => 252,
+            Self::GasFilter => 253,
+            Self::GasNobelisk => 254,
+            Self::PolyesterFabric => 255,
+            Self::Fabric => 256,
+            Self::SyntheticPowerShard => 257,
+            Self::PowerShard5 => 258,
+            Self::PowerShard2 => 259,
+            Self::PulseNobelisk => 260,
+            Self::ShatterRebar => 261,
+            Self::TurboRifleAmmoPackaged => 262,
+            Self::TurboRifleAmmo => 263,
+            Self::NukeNobelisk => 264,
+            Self::RifleAmmo => 265,
+            Self::ExplosiveRebar => 266,
+            Self::ClusterNobelisk => 267,
+            Self::Nobelisk => 268,
+            Self::SmokelessPowder => 269,
+            Self::BiomassLeaves => 270,
+            Self::BiomassWood => 271,
+            Self::ReinforcedIronPlate => 272,
+            Self::Concrete => 273,
+            Self::Screw => 274,
+            Self::Cable => 275,
+            Self::Wire => 276,
+            Self::CopperIngot => 277,
+        }
+    }
+
+    /// Inverse of `stable_id`. Returns `None` for unknown IDs and removed recipes, see
+    /// `MIGRATIONS` for those.
+    pub fn from_stable_id(id: u16) -> Option<Self> {
+        match id {
+            0 => Some(Self::NuclearWaste),
+            1 => Some(Self::PlutoniumWaste),
+            2 => Some(Self::IronPlate),
+            3 => Some(Self::IronRod),
+            4 => Some(Self::IronIngot),
+            5 => Some(Self::NitroRocketFuel),
+            6 => Some(Self::RocketFuel),
+            7 => Some(Self::PackagedRocketFuel),
+            8 => Some(Self::UnpackageRocketFuel),
+            9 => Some(Self::DarkIonFuel),
+            10 => Some(Self::DarkMatterResidue),
+            11 => Some(Self::ExcitedPhotonicMatter),
+            12 => Some(Self::DarkMatterCrystal),
+            13 => Some(Self::SuperpositionOscillator),
+            14 => Some(Self::NeuralQuantumProcessor),
+            15 => Some(Self::AiExpansionServer),
+            16 => Some(Self::IonizedFuel),
+            17 => Some(Self::PackagedIonizedFuel),
+            18 => Some(Self::UnpackageIonizedFuel),
+            19 => Some(Self::TurboDiamonds),
+            20 => Some(Self::SamFluctuator),
+            21 => Some(Self::FicsiteTrigon),
+            22 => Some(Self::FicsiteIngotIron),
+            23 => Some(Self::TimeCrystal),
+            24 => Some(Self::Diamonds),
+            25 => Some(Self::ReanimatedSam),
+            26 => Some(Self::BiochemicalSculptor),
+            27 => Some(Self::FicsiteIngotAluminum),
+            28 => Some(Self::FicsiteIngotCaterium),
+            29 => Some(Self::BauxiteCaterium),
+            30 => Some(Self::BauxiteCopper),
+            31 => Some(Self::CateriumOreCopper),
+            32 => Some(Self::CateriumOreQuartz),
+            33 => Some(Self::CoalIron),
+            34 => Some(Self::CoalLimestone),
+            35 => Some(Self::CopperOreQuartz),
+            36 => Some(Self::CopperOreSulfur),
+            37 => Some(Self::IronOreLimestone),
+            38 => Some(Self::LimestoneSulfur),
+            39 => Some(Self::NitrogenGasBauxite),
+            40 => Some(Self::NitrogenGasCaterium),
+            41 => Some(Self::RawQuartzBauxite),
+            42 => Some(Self::RawQuartzCoal),
+            43 => Some(Self::SulfurCoal),
+            44 => Some(Self::SulfurIron),
+            45 => Some(Self::UraniumOreBauxite),
+            46 => Some(Self::Turbofuel),
+            47 => Some(Self::PackagedTurbofuel),
+            48 => Some(Self::UnpackageTurbofuel),
+            49 => Some(Self::Charcoal),
+            50 => Some(Self::Biocoal),
+            51 => Some(Self::CompactedCoal),
+            52 => Some(Self::CircuitBoard),
+            53 => Some(Self::Fuel),
+            54 => Some(Self::PetroleumCoke),
+            55 => Some(Self::Plastic),
+            56 => Some(Self::Rubber),
+            57 => Some(Self::ResidualFuel),
+            58 => Some(Self::ResidualPlastic),
+            59 => Some(Self::ResidualRubber),
+            60 => Some(Self::PinkDiamonds),
+            61 => Some(Self::PetroleumDiamonds),
+            62 => Some(Self::OilBasedDiamonds),
+            63 => Some(Self::CloudyDiamonds),
+            64 => Some(Self::DarkMatterTrap),
+            65 => Some(Self::DarkMatterCrystallization),
+            66 => Some(Self::WetConcrete),
+            67 => Some(Self::TurboHeavyFuel),
+            68 => Some(Self::SteelRod),
+            69 => Some(Self::SteelBeam),
+            70 => Some(Self::SteelPipe),
+            71 => Some(Self::SteelIngot),
+            72 => Some(Self::VersatileFramework),
+            73 => Some(Self::SteelCanister),
+            74 => Some(Self::EmptyCanister),
+            75 => Some(Self::PackagedFuel),
+            76 => Some(Self::LiquidBiofuel),
+            77 => Some(Self::PackagedLiquidBiofuel),
+            78 => Some(Self::PackagedOil),
+            79 => Some(Self::PackagedHeavyOilResidue),
+            80 => Some(Self::PackagedWater),
+            81 => Some(Self::UnpackageLiquidBiofuel),
+            82 => Some(Self::UnpackageFuel),
+            83 => Some(Self::UnpackageOil),
+            84 => Some(Self::UnpackageHeavyOilResidue),
+            85 => Some(Self::UnpackageWater),
+            86 => Some(Self::SteamedCopperSheet),
+            87 => Some(Self::RubberConcrete),
+            88 => Some(Self::RecycledRubber),
+            89 => Some(Self::PureQuartzCrystal),
+            90 => Some(Self::QuartzCrystal),
+            91 => Some(Self::PureIronIngot),
+            92 => Some(Self::PureCopperIngot),
+            93 => Some(Self::PureCateriumIngot),
+            94 => Some(Self::PureAluminumIngot),
+            95 => Some(Self::AluminumCasing),
+            96 => Some(Self::AlcladAluminumSheet),
+            97 => Some(Self::AluminaSolution),
+            98 => Some(Self::AluminumScrap),
+            99 => Some(Self::PackagedAluminaSolution),
+            100 => Some(Self::AluminumIngot),
+            101 => Some(Self::Silica),
+            102 => Some(Self::CrystalOscillator),
+            103 => Some(Self::UnpackageAluminaSolution),
+            104 => Some(Self::PolymerResin),
+            105 => Some(Self::PlasticSmartPlating),
+            106 => Some(Self::AutomatedSpeedWiring),
+            107 => Some(Self::EncasedIndustrialBeam),
+            108 => Some(Self::Motor),
+            109 => Some(Self::Stator),
+            110 => Some(Self::AutomatedWiring),
+            111 => Some(Self::AiLimiter),
+            112 => Some(Self::HeavyOilResidue),
+            113 => Some(Self::HeavyFlexibleFrame),
+            114 => Some(Self::Computer),
+            115 => Some(Self::HeavyModularFrame),
+            116 => Some(Self::ModularEngine),
+            117 => Some(Self::AdaptiveControlUnit),
+            118 => Some(Self::FusedWire),
+            119 => Some(Self::FlexibleFramework),
+            120 => Some(Self::ElectrodeCircuitBoard),
+            121 => Some(Self::ElectrodeAluminumScrap),
+            122 => Some(Self::DilutedPackagedFuel),
+            123 => Some(Self::CopperRotor),
+            124 => Some(Self::ModularFrame),
+            125 => Some(Self::Rotor),
+            126 => Some(Self::CopperSheet),
+            127 => Some(Self::SmartPlating),
+            128 => Some(Self::CopperAlloyIngot),
+            129 => Some(Self::CokeSteelIngot),
+            130 => Some(Self::CoatedIronPlate),
+            131 => Some(Self::CoatedIronCanister),
+            132 => Some(Self::CoatedCable),
+            133 => Some(Self::BoltedFrame),
+            134 => Some(Self::AdheredIronPlate),
+            135 => Some(Self::TurboPressureMotor),
+            136 => Some(Self::EncasedPlutoniumCell),
+            137 => Some(Self::PressureConversionCube),
+            138 => Some(Self::NitricAcid),
+            139 => Some(Self::NonFissileUranium),
+            140 => Some(Self::CopperPowder),
+            141 => Some(Self::PlutoniumPellet),
+            142 => Some(Self::PlutoniumFuelRod),
+            143 => Some(Self::PackagedNitricAcid),
+            144 => Some(Self::NuclearPasta),
+            145 => Some(Self::UnpackageNitricAcid),
+            146 => Some(Self::TurboBlendFuel),
+            147 => Some(Self::EncasedUraniumCell),
+            148 => Some(Self::CoolingSystem),
+            149 => Some(Self::Battery),
+            150 => Some(Self::Supercomputer),
+            151 => Some(Self::RadioControlUnit),
+            152 => Some(Self::SulfuricAcid),
+            153 => Some(Self::PackagedSulfuricAcid),
+            154 => Some(Self::AssemblyDirectorSystem),
+            155 => Some(Self::HighSpeedConnector),
+            156 => Some(Self::UnpackageSulfuricAcid),
+            157 => Some(Self::SuperStateComputer),
+            158 => Some(Self::ElectromagneticControlRod),
+            159 => Some(Self::UraniumFuelRod),
+            160 => Some(Self::MagneticFieldGenerator),
+            161 => Some(Self::SloppyAlumina),
+            162 => Some(Self::RadioControlSystem),
+            163 => Some(Self::PlutoniumFuelUnit),
+            164 => Some(Self::OcSupercomputer),
+            165 => Some(Self::HeatSink),
+            166 => Some(Self::FusedModularFrame),
+            167 => Some(Self::EmptyFluidTank),
+            168 => Some(Self::PackagedNitrogenGas),
+            169 => Some(Self::UnpackageNitrogenGas),
+            170 => Some(Self::InstantScrap),
+            171 => Some(Self::InstantPlutoniumCell),
+            172 => Some(Self::HeatFusedFrame),
+            173 => Some(Self::FertileUranium),
+            174 => Some(Self::ElectricMotor),
+            175 => Some(Self::DilutedFuel),
+            176 => Some(Self::CoolingDevice),
+            177 => Some(Self::ClassicBattery),
+            178 => Some(Self::AutomatedMiner),
+            179 => Some(Self::AlcladCasing),
+            180 => Some(Self::MoldedSteelPipe),
+            181 => Some(Self::IronPipe),
+            182 => Some(Self::SteelCastPlate),
+            183 => Some(Self::MoldedBeam),
+            184 => Some(Self::AluminumBeam),
+            185 => Some(Self::AluminumRod),
+            186 => Some(Self::PlasticAiLimiter),
+            187 => Some(Self::DistilledSilica),
+            188 => Some(Self::QuartzPurification),
+            189 => Some(Self::FusedQuartzCrystal),
+            190 => Some(Self::LeachedIronIngot),
+            191 => Some(Self::BasicIronIngot),
+            192 => Some(Self::TemperedCopperIngot),
+            193 => Some(Self::LeachedCopperIngot),
+            194 => Some(Self::TemperedCateriumIngot),
+            195 => Some(Self::LeachedCateriumIngot),
+            196 => Some(Self::CateriumWire),
+            197 => Some(Self::IronWire),
+            198 => Some(Self::InfusedUraniumCell),
+            199 => Some(Self::CateriumIngot),
+            200 => Some(Self::TurboElectricMotor),
+            201 => Some(Self::TurboMotor),
+            202 => Some(Self::ThermalPropulsionRocket),
+            203 => Some(Self::QuickwireStator),
+            204 => Some(Self::CheapSilica),
+            205 => Some(Self::SteelScrew),
+            206 => Some(Self::CastScrew),
+            207 => Some(Self::SteelRotor),
+            208 => Some(Self::EncasedIndustrialPipe),
+            209 => Some(Self::StitchedIronPlate),
+            210 => Some(Self::BoltedIronPlate),
+            211 => Some(Self::RadioConnectionUnit),
+            212 => Some(Self::FusedQuickwire),
+            213 => Some(Self::RecycledPlastic),
+            214 => Some(Self::UraniumFuelUnit),
+            215 => Some(Self::RigorMotor),
+            216 => Some(Self::SteeledFrame),
+            217 => Some(Self::CompactedSteelIngot),
+            218 => Some(Self::SolidSteelIngot),
+            219 => Some(Self::IronAlloyIngot),
+            220 => Some(Self::SiliconHighSpeedConnector),
+            221 => Some(Self::HeavyEncasedFrame),
+            222 => Some(Self::HeatExchanger),
+            223 => Some(Self::FineBlackPowder),
+            224 => Some(Self::ElectromagneticConnectionRod),
+            225 => Some(Self::InsulatedCrystalOscillator),
+            226 => Some(Self::FineConcrete),
+            227 => Some(Self::CrystalComputer),
+            228 => Some(Self::CateriumComputer),
+            229 => Some(Self::CateriumCircuitBoard),
+            230 => Some(Self::SiliconCircuitBoard),
+            231 => Some(Self::QuickwireCable),
+            232 => Some(Self::InsulatedCable),
+            233 => Some(Self::Ficsonium),
+            234 => Some(Self::FicsoniumFuelRod),
+            235 => Some(Self::SingularityCell),
+            236 => Some(Self::BallisticWarpDrive),
+            237 => Some(Self::IodineInfusedFilter),
+            238 => Some(Self::Quickwire),
+            239 => Some(Self::SolidBiofuel),
+            240 => Some(Self::HogProtein),
+            241 => Some(Self::SpitterProtein),
+            242 => Some(Self::BiomassMycelia),
+            243 => Some(Self::PowerShard1),
+            244 => Some(Self::BlackPowder),
+            245 => Some(Self::AlienPowerMatrix),
+            246 => Some(Self::StingerProtein),
+            247 => Some(Self::HatcherProtein),
+            248 => Some(Self::AlienDnaCapsule),
+            249 => Some(Self::BiomassAlienProtein),
+            250 => Some(Self::IronRebar),
+            251 => Some(Self::HomingRifleAmmo),
+            252 => Some(Self::StunRebar),
+            253 => Some(Self::GasFilter),
+            254 => Some(Self::GasNobelisk),
+            255 => Some(Self::PolyesterFabric),
+            256 => Some(Self::Fabric),
+            257 => Some(Self::SyntheticPowerShard),
+            258 => Some(Self::PowerShard5),
+            259 => Some(Self::PowerShard2),
+            260 => Some(Self::PulseNobelisk),
+            261 => Some(Self::ShatterRebar),
+            262 => Some(Self::TurboRifleAmmoPackaged),
+            263 => Some(Self::TurboRifleAmmo),
+            264 => Some(Self::NukeNobelisk),
+            265 => Some(Self::RifleAmmo),
+            266 => Some(Self::ExplosiveRebar),
+            267 => Some(Self::ClusterNobelisk),
+            268 => Some(Self::Nobelisk),
+            269 => Some(Self::SmokelessPowder),
+            270 => Some(Self::BiomassLeaves),
+            271 => Some(Self::BiomassWood),
+            272 => Some(Self::ReinforcedIronPlate),
+            273 => Some(Self::Concrete),
+            274 => Some(Self::Screw),
+            275 => Some(Self::Cable),
+            276 => Some(Self::Wire),
+            277 => Some(Self::CopperIngot),
+            _ => None,
+        }
+    }
 }
 
+/// Recipes that were removed or renamed, see `data/recipe-migrations.json`.
+pub(super) static MIGRATIONS: [Migration; 0] = [
+];
+
 pub struct RecipeInfo {
-    #[allow(dead_code)]
     pub name: &'static str,
-    pub inputs: &'static [ItemKind],
-    pub outputs: &'static [ItemKind],
+    pub building: Building,
+    /// Crafting duration in seconds, at 100%.
+    pub duration: f64,
+    pub alternative: bool,
+    pub unlock: Unlock,
+    /// For recipes inside fluctuating power buildings, the min and max power in MW.
+    pub power_range: Option<(f64, f64)>,
+    pub inputs: &'static [IoEntry],
+    pub outputs: &'static [IoEntry],
 }
 
-const RECIPES: [RecipeInfo; 278] = [
+pub struct IoEntry {
+    pub item: ItemKind,
+    /// Amount per craft. For fluids, this is in m³.
+    pub amount: f64,
+}
+
+const fn io(item: ItemKind, amount: f64) -> IoEntry {
+    IoEntry { item, amount }
+}
+
+static RECIPES: [RecipeInfo; 278] = [
     RecipeInfo {
         name: "Uranium Fuel Rod (burning)",
-        inputs: &[ItemKind::UraniumFuelRod, ItemKind::Water],
-        outputs: &[ItemKind::UraniumWaste],
+        building: Building::NuclearReactor,
+        duration: 300.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 8 },
+        power_range: None,
+        inputs: &[io(ItemKind::UraniumFuelRod, 1.0), io(ItemKind::Water, 1200.0)],
+        outputs: &[io(ItemKind::UraniumWaste, 50.0)],
     },
     RecipeInfo {
         name: "Plutonium Fuel Rod (burning)",
-        inputs: &[ItemKind::PlutoniumFuelRod, ItemKind::Water],
-        outputs: &[ItemKind::PlutoniumWaste],
+        building: Building::NuclearReactor,
+        duration: 600.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 8 },
+        power_range: None,
+        inputs: &[io(ItemKind::PlutoniumFuelRod, 1.0), io(ItemKind::Water, 2400.0)],
+        outputs: &[io(ItemKind::PlutoniumWaste, 10.0)],
     },
     RecipeInfo {
         name: "Iron Plate",
-        inputs: &[ItemKind::IronIngot],
-        outputs: &[ItemKind::IronPlate],
+        building: Building::Constructor,
+        duration: 6.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 0 },
+        power_range: None,
+        inputs: &[io(ItemKind::IronIngot, 3.0)],
+        outputs: &[io(ItemKind::IronPlate, 2.0)],
     },
     RecipeInfo {
         name: "Iron Rod",
-        inputs: &[ItemKind::IronIngot],
-        outputs: &[ItemKind::IronRod],
+        building: Building::Constructor,
+        duration: 4.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 0 },
+        power_range: None,
+        inputs: &[io(ItemKind::IronIngot, 1.0)],
+        outputs: &[io(ItemKind::IronRod, 1.0)],
     },
     RecipeInfo {
         name: "Iron Ingot",
-        inputs: &[ItemKind::IronOre],
-        outputs: &[ItemKind::IronIngot],
+        building: Building::Smelter,
+        duration: 2.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 0 },
+        power_range: None,
+        inputs: &[io(ItemKind::IronOre, 1.0)],
+        outputs: &[io(ItemKind::IronIngot, 1.0)],
     },
     RecipeInfo {
         name: "Nitro Rocket Fuel",
-        inputs: &[ItemKind::Fuel, ItemKind::NitrogenGas, ItemKind::Sulfur, ItemKind::Coal],
-        outputs: &[ItemKind::RocketFuel, ItemKind::CompactedCoal],
+        building: Building::Blender,
+        duration: 2.4,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::Fuel, 4.0), io(ItemKind::NitrogenGas, 3.0), io(ItemKind::Sulfur, 4.0), io(ItemKind::Coal, 2.0)],
+        outputs: &[io(ItemKind::RocketFuel, 6.0), io(ItemKind::CompactedCoal, 1.0)],
     },
     RecipeInfo {
         name: "Rocket Fuel",
-        inputs: &[ItemKind::Turbofuel, ItemKind::NitricAcid],
-        outputs: &[ItemKind::RocketFuel, ItemKind::CompactedCoal],
+        building: Building::Blender,
+        duration: 6.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 8 },
+        power_range: None,
+        inputs: &[io(ItemKind::Turbofuel, 6.0), io(ItemKind::NitricAcid, 1.0)],
+        outputs: &[io(ItemKind::RocketFuel, 10.0), io(ItemKind::CompactedCoal, 1.0)],
     },
     RecipeInfo {
         name: "Packaged Rocket Fuel",
-        inputs: &[ItemKind::RocketFuel, ItemKind::EmptyFluidTank],
-        outputs: &[ItemKind::PackagedRocketFuel],
+        building: Building::Packager,
+        duration: 1.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 8 },
+        power_range: None,
+        inputs: &[io(ItemKind::RocketFuel, 2.0), io(ItemKind::EmptyFluidTank, 1.0)],
+        outputs: &[io(ItemKind::PackagedRocketFuel, 1.0)],
     },
     RecipeInfo {
         name: "Unpackage Rocket Fuel",
-        inputs: &[ItemKind::PackagedRocketFuel],
-        outputs: &[ItemKind::RocketFuel, ItemKind::EmptyFluidTank],
+        building: Building::Packager,
+        duration: 1.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 8 },
+        power_range: None,
+        inputs: &[io(ItemKind::PackagedRocketFuel, 1.0)],
+        outputs: &[io(ItemKind::RocketFuel, 2.0), io(ItemKind::EmptyFluidTank, 1.0)],
     },
     RecipeInfo {
         name: "Dark-Ion Fuel",
-        inputs: &[ItemKind::PackagedRocketFuel, ItemKind::DarkMatterCrystal],
-        outputs: &[ItemKind::IonizedFuel, ItemKind::CompactedCoal],
+        building: Building::Converter,
+        duration: 3.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: Some((100.0, 400.0)),
+        inputs: &[io(ItemKind::PackagedRocketFuel, 12.0), io(ItemKind::DarkMatterCrystal, 4.0)],
+        outputs: &[io(ItemKind::IonizedFuel, 10.0), io(ItemKind::CompactedCoal, 2.0)],
     },
     RecipeInfo {
         name: "Dark Matter Residue",
-        inputs: &[ItemKind::ReanimatedSam],
-        outputs: &[ItemKind::DarkMatterResidue],
+        building: Building::Converter,
+        duration: 6.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 9 },
+        power_range: Some((100.0, 400.0)),
+        inputs: &[io(ItemKind::ReanimatedSam, 5.0)],
+        outputs: &[io(ItemKind::DarkMatterResidue, 10.0)],
     },
     RecipeInfo {
         name: "Excited Photonic Matter",
+        building: Building::Converter,
+        duration: 3.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 9 },
+        power_range: Some((100.0, 400.0)),
         inputs: &[],
-        outputs: &[ItemKind::ExcitedPhotonicMatter],
+        outputs: &[io(ItemKind::ExcitedPhotonicMatter, 10.0)],
     },
     RecipeInfo {
         name: "Dark Matter Crystal",
-        inputs: &[ItemKind::Diamonds, ItemKind::DarkMatterResidue],
-        outputs: &[ItemKind::DarkMatterCrystal],
+        building: Building::ParticleAccelerator,
+        duration: 2.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 9 },
+        power_range: Some((500.0, 1500.0)),
+        inputs: &[io(ItemKind::Diamonds, 1.0), io(ItemKind::DarkMatterResidue, 5.0)],
+        outputs: &[io(ItemKind::DarkMatterCrystal, 1.0)],
     },
     RecipeInfo {
         name: "Superposition Oscillator",
-        inputs: &[ItemKind::DarkMatterCrystal, ItemKind::CrystalOscillator, ItemKind::AlcladAluminumSheet, ItemKind::ExcitedPhotonicMatter],
-        outputs: &[ItemKind::SuperpositionOscillator, ItemKind::DarkMatterResidue],
+        building: Building::QuantumEncoder,
+        duration: 12.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 9 },
+        power_range: Some((0.0, 2000.0)),
+        inputs: &[io(ItemKind::DarkMatterCrystal, 6.0), io(ItemKind::CrystalOscillator, 1.0), io(ItemKind::AlcladAluminumSheet, 9.0), io(ItemKind::ExcitedPhotonicMatter, 25.0)],
+        outputs: &[io(ItemKind::SuperpositionOscillator, 1.0), io(ItemKind::DarkMatterResidue, 25.0)],
     },
     RecipeInfo {
         name: "Neural-Quantum Processor",
-        inputs: &[ItemKind::TimeCrystal, ItemKind::Supercomputer, ItemKind::FicsiteTrigon, ItemKind::ExcitedPhotonicMatter],
-        outputs: &[ItemKind::NeuralQuantumProcessor, ItemKind::DarkMatterResidue],
+        building: Building::QuantumEncoder,
+        duration: 20.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 9 },
+        power_range: Some((0.0, 2000.0)),
+        inputs: &[io(ItemKind::TimeCrystal, 5.0), io(ItemKind::Supercomputer, 1.0), io(ItemKind::FicsiteTrigon, 15.0), io(ItemKind::ExcitedPhotonicMatter, 25.0)],
+        outputs: &[io(ItemKind::NeuralQuantumProcessor, 1.0), io(ItemKind::DarkMatterResidue, 25.0)],
     },
     RecipeInfo {
         name: "AI Expansion Server",
-        inputs: &[ItemKind::MagneticFieldGenerator, ItemKind::NeuralQuantumProcessor, ItemKind::SuperpositionOscillator, ItemKind::ExcitedPhotonicMatter],
-        outputs: &[ItemKind::AiExpansionServer, ItemKind::DarkMatterResidue],
+        building: Building::QuantumEncoder,
+        duration: 15.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 9 },
+        power_range: Some((0.0, 2000.0)),
+        inputs: &[io(ItemKind::MagneticFieldGenerator, 1.0), io(ItemKind::NeuralQuantumProcessor, 1.0), io(ItemKind::SuperpositionOscillator, 1.0), io(ItemKind::ExcitedPhotonicMatter, 25.0)],
+        outputs: &[io(ItemKind::AiExpansionServer, 1.0), io(ItemKind::DarkMatterResidue, 25.0)],
     },
     RecipeInfo {
         name: "Ionized Fuel",
-        inputs: &[ItemKind::RocketFuel, ItemKind::PowerShard],
-        outputs: &[ItemKind::IonizedFuel, ItemKind::CompactedCoal],
+        building: Building::Refinery,
+        duration: 24.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 9 },
+        power_range: None,
+        inputs: &[io(ItemKind::RocketFuel, 16.0), io(ItemKind::PowerShard, 1.0)],
+        outputs: &[io(ItemKind::IonizedFuel, 16.0), io(ItemKind::CompactedCoal, 2.0)],
     },
     RecipeInfo {
         name: "Packaged Ionized Fuel",
-        inputs: &[ItemKind::IonizedFuel, ItemKind::EmptyFluidTank],
-        outputs: &[ItemKind::PackagedIonizedFuel],
+        building: Building::Packager,
+        duration: 3.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 9 },
+        power_range: None,
+        inputs: &[io(ItemKind::IonizedFuel, 4.0), io(ItemKind::EmptyFluidTank, 2.0)],
+        outputs: &[io(ItemKind::PackagedIonizedFuel, 2.0)],
     },
     RecipeInfo {
         name: "Unpackage Ionized Fuel",
-        inputs: &[ItemKind::PackagedIonizedFuel],
-        outputs: &[ItemKind::IonizedFuel, ItemKind::EmptyFluidTank],
+        building: Building::Packager,
+        duration: 3.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 9 },
+        power_range: None,
+        inputs: &[io(ItemKind::PackagedIonizedFuel, 2.0)],
+        outputs: &[io(ItemKind::IonizedFuel, 4.0), io(ItemKind::EmptyFluidTank, 2.0)],
     },
     RecipeInfo {
         name: "Turbo Diamonds",
-        inputs: &[ItemKind::Coal, ItemKind::PackagedTurbofuel],
-        outputs: &[ItemKind::Diamonds],
+        building: Building::ParticleAccelerator,
+        duration: 3.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: Some((250.0, 750.0)),
+        inputs: &[io(ItemKind::Coal, 30.0), io(ItemKind::PackagedTurbofuel, 2.0)],
+        outputs: &[io(ItemKind::Diamonds, 3.0)],
     },
     RecipeInfo {
         name: "SAM Fluctuator",
-        inputs: &[ItemKind::ReanimatedSam, ItemKind::Wire, ItemKind::SteelPipe],
-        outputs: &[ItemKind::SamFluctuator],
+        building: Building::Manufacturer,
+        duration: 6.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::AlienTechnology },
+        power_range: None,
+        inputs: &[io(ItemKind::ReanimatedSam, 6.0), io(ItemKind::Wire, 5.0), io(ItemKind::SteelPipe, 3.0)],
+        outputs: &[io(ItemKind::SamFluctuator, 1.0)],
     },
     RecipeInfo {
         name: "Ficsite Trigon",
-        inputs: &[ItemKind::FicsiteIngot],
-        outputs: &[ItemKind::FicsiteTrigon],
+        building: Building::Constructor,
+        duration: 6.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 9 },
+        power_range: None,
+        inputs: &[io(ItemKind::FicsiteIngot, 1.0)],
+        outputs: &[io(ItemKind::FicsiteTrigon, 3.0)],
     },
     RecipeInfo {
         name: "Ficsite Ingot (Iron)",
-        inputs: &[ItemKind::ReanimatedSam, ItemKind::IronIngot],
-        outputs: &[ItemKind::FicsiteIngot],
+        building: Building::Converter,
+        duration: 6.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 9 },
+        power_range: Some((100.0, 400.0)),
+        inputs: &[io(ItemKind::ReanimatedSam, 4.0), io(ItemKind::IronIngot, 24.0)],
+        outputs: &[io(ItemKind::FicsiteIngot, 1.0)],
     },
     RecipeInfo {
         name: "Time Crystal",
-        inputs: &[ItemKind::Diamonds],
-        outputs: &[ItemKind::TimeCrystal],
+        building: Building::Converter,
+        duration: 10.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 9 },
+        power_range: Some((100.0, 400.0)),
+        inputs: &[io(ItemKind::Diamonds, 2.0)],
+        outputs: &[io(ItemKind::TimeCrystal, 1.0)],
     },
     RecipeInfo {
         name: "Diamonds",
-        inputs: &[ItemKind::Coal],
-        outputs: &[ItemKind::Diamonds],
+        building: Building::ParticleAccelerator,
+        duration: 2.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 9 },
+        power_range: Some((250.0, 750.0)),
+        inputs: &[io(ItemKind::Coal, 20.0)],
+        outputs: &[io(ItemKind::Diamonds, 1.0)],
     },
     RecipeInfo {
         name: "Reanimated SAM",
-        inputs: &[ItemKind::Sam],
-        outputs: &[ItemKind::ReanimatedSam],
+        building: Building::Constructor,
+        duration: 2.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::AlienTechnology },
+        power_range: None,
+        inputs: &[io(ItemKind::Sam, 4.0)],
+        outputs: &[io(ItemKind::ReanimatedSam, 1.0)],
     },
     RecipeInfo {
         name: "Biochemical Sculptor",
-        inputs: &[ItemKind::AssemblyDirectorSystem, ItemKind::FicsiteTrigon, ItemKind::Water],
-        outputs: &[ItemKind::BiochemicalSculptor],
+        building: Building::Blender,
+        duration: 120.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 9 },
+        power_range: Some((500.0, 1500.0)),
+        inputs: &[io(ItemKind::AssemblyDirectorSystem, 1.0), io(ItemKind::FicsiteTrigon, 80.0), io(ItemKind::Water, 20.0)],
+        outputs: &[io(ItemKind::BiochemicalSculptor, 4.0)],
     },
     RecipeInfo {
         name: "Ficsite Ingot (Aluminum)",
-        inputs: &[ItemKind::ReanimatedSam, ItemKind::AluminumIngot],
-        outputs: &[ItemKind::FicsiteIngot],
+        building: Building::Converter,
+        duration: 2.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 9 },
+        power_range: Some((100.0, 400.0)),
+        inputs: &[io(ItemKind::ReanimatedSam, 2.0), io(ItemKind::AluminumIngot, 4.0)],
+        outputs: &[io(ItemKind::FicsiteIngot, 1.0)],
     },
     RecipeInfo {
         name: "Ficsite Ingot (Caterium)",
-        inputs: &[ItemKind::ReanimatedSam, ItemKind::CateriumIngot],
-        outputs: &[ItemKind::FicsiteIngot],
+        building: Building::Converter,
+        duration: 4.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 9 },
+        power_range: Some((100.0, 400.0)),
+        inputs: &[io(ItemKind::ReanimatedSam, 3.0), io(ItemKind::CateriumIngot, 4.0)],
+        outputs: &[io(ItemKind::FicsiteIngot, 1.0)],
     },
     RecipeInfo {
         name: "Bauxite (Caterium)",
-        inputs: &[ItemKind::ReanimatedSam, ItemKind::CateriumOre],
-        outputs: &[ItemKind::Bauxite],
+        building: Building::Converter,
+        duration: 6.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::AlienTechnology },
+        power_range: Some((100.0, 400.0)),
+        inputs: &[io(ItemKind::ReanimatedSam, 1.0), io(ItemKind::CateriumOre, 15.0)],
+        outputs: &[io(ItemKind::Bauxite, 12.0)],
     },
     RecipeInfo {
         name: "Bauxite (Copper)",
-        inputs: &[ItemKind::ReanimatedSam, ItemKind::CopperOre],
-        outputs: &[ItemKind::Bauxite],
+        building: Building::Converter,
+        duration: 6.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::AlienTechnology },
+        power_range: Some((100.0, 400.0)),
+        inputs: &[io(ItemKind::ReanimatedSam, 1.0), io(ItemKind::CopperOre, 18.0)],
+        outputs: &[io(ItemKind::Bauxite, 12.0)],
     },
     RecipeInfo {
         name: "Caterium Ore (Copper)",
-        inputs: &[ItemKind::ReanimatedSam, ItemKind::CopperOre],
-        outputs: &[ItemKind::CateriumOre],
+        building: Building::Converter,
+        duration: 6.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::AlienTechnology },
+        power_range: Some((100.0, 400.0)),
+        inputs: &[io(ItemKind::ReanimatedSam, 1.0), io(ItemKind::CopperOre, 15.0)],
+        outputs: &[io(ItemKind::CateriumOre, 12.0)],
     },
     RecipeInfo {
         name: "Caterium Ore (Quartz)",
-        inputs: &[ItemKind::ReanimatedSam, ItemKind::RawQuartz],
-        outputs: &[ItemKind::CateriumOre],
+        building: Building::Converter,
+        duration: 6.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::AlienTechnology },
+        power_range: Some((100.0, 400.0)),
+        inputs: &[io(ItemKind::ReanimatedSam, 1.0), io(ItemKind::RawQuartz, 12.0)],
+        outputs: &[io(ItemKind::CateriumOre, 12.0)],
     },
     RecipeInfo {
         name: "Coal (Iron)",
-        inputs: &[ItemKind::ReanimatedSam, ItemKind::IronOre],
-        outputs: &[ItemKind::Coal],
+        building: Building::Converter,
+        duration: 6.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::AlienTechnology },
+        power_range: Some((100.0, 400.0)),
+        inputs: &[io(ItemKind::ReanimatedSam, 1.0), io(ItemKind::IronOre, 18.0)],
+        outputs: &[io(ItemKind::Coal, 12.0)],
     },
     RecipeInfo {
         name: "Coal (Limestone)",
-        inputs: &[ItemKind::ReanimatedSam, ItemKind::Limestone],
-        outputs: &[ItemKind::Coal],
+        building: Building::Converter,
+        duration: 6.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::AlienTechnology },
+        power_range: Some((100.0, 400.0)),
+        inputs: &[io(ItemKind::ReanimatedSam, 1.0), io(ItemKind::Limestone, 36.0)],
+        outputs: &[io(ItemKind::Coal, 12.0)],
     },
     RecipeInfo {
         name: "Copper Ore (Quartz)",
-        inputs: &[ItemKind::ReanimatedSam, ItemKind::RawQuartz],
-        outputs: &[ItemKind::CopperOre],
+        building: Building::Converter,
+        duration: 6.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::AlienTechnology },
+        power_range: Some((100.0, 400.0)),
+        inputs: &[io(ItemKind::ReanimatedSam, 1.0), io(ItemKind::RawQuartz, 10.0)],
+        outputs: &[io(ItemKind::CopperOre, 12.0)],
     },
     RecipeInfo {
         name: "Copper Ore (Sulfur)",
-        inputs: &[ItemKind::ReanimatedSam, ItemKind::Sulfur],
-        outputs: &[ItemKind::CopperOre],
+        building: Building::Converter,
+        duration: 6.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::AlienTechnology },
+        power_range: Some((100.0, 400.0)),
+        inputs: &[io(ItemKind::ReanimatedSam, 1.0), io(ItemKind::Sulfur, 12.0)],
+        outputs: &[io(ItemKind::CopperOre, 12.0)],
     },
     RecipeInfo {
         name: "Iron Ore (Limestone)",
-        inputs: &[ItemKind::ReanimatedSam, ItemKind::Limestone],
-        outputs: &[ItemKind::IronOre],
+        building: Building::Converter,
+        duration: 6.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::AlienTechnology },
+        power_range: Some((100.0, 400.0)),
+        inputs: &[io(ItemKind::ReanimatedSam, 1.0), io(ItemKind::Limestone, 24.0)],
+        outputs: &[io(ItemKind::IronOre, 12.0)],
     },
     RecipeInfo {
         name: "Limestone (Sulfur)",
-        inputs: &[ItemKind::ReanimatedSam, ItemKind::Sulfur],
-        outputs: &[ItemKind::Limestone],
+        building: Building::Converter,
+        duration: 6.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::AlienTechnology },
+        power_range: Some((100.0, 400.0)),
+        inputs: &[io(ItemKind::ReanimatedSam, 1.0), io(ItemKind::Sulfur, 2.0)],
+        outputs: &[io(ItemKind::Limestone, 12.0)],
     },
     RecipeInfo {
         name: "Nitrogen Gas (Bauxite)",
-        inputs: &[ItemKind::ReanimatedSam, ItemKind::Bauxite],
-        outputs: &[ItemKind::NitrogenGas],
+        building: Building::Converter,
+        duration: 6.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::AlienTechnology },
+        power_range: Some((100.0, 400.0)),
+        inputs: &[io(ItemKind::ReanimatedSam, 1.0), io(ItemKind::Bauxite, 10.0)],
+        outputs: &[io(ItemKind::NitrogenGas, 12.0)],
     },
     RecipeInfo {
         name: "Nitrogen Gas (Caterium)",
-        inputs: &[ItemKind::ReanimatedSam, ItemKind::CateriumOre],
-        outputs: &[ItemKind::NitrogenGas],
+        building: Building::Converter,
+        duration: 6.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::AlienTechnology },
+        power_range: Some((100.0, 400.0)),
+        inputs: &[io(ItemKind::ReanimatedSam, 1.0), io(ItemKind::CateriumOre, 12.0)],
+        outputs: &[io(ItemKind::NitrogenGas, 12.0)],
     },
     RecipeInfo {
         name: "Raw Quartz (Bauxite)",
-        inputs: &[ItemKind::ReanimatedSam, ItemKind::Bauxite],
-        outputs: &[ItemKind::RawQuartz],
+        building: Building::Converter,
+        duration: 6.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::AlienTechnology },
+        power_range: Some((100.0, 400.0)),
+        inputs: &[io(ItemKind::ReanimatedSam, 1.0), io(ItemKind::Bauxite, 10.0)],
+        outputs: &[io(ItemKind::RawQuartz, 12.0)],
     },
     RecipeInfo {
         name: "Raw Quartz (Coal)",
-        inputs: &[ItemKind::ReanimatedSam, ItemKind::Coal],
-        outputs: &[ItemKind::RawQuartz],
+        building: Building::Converter,
+        duration: 6.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::AlienTechnology },
+        power_range: Some((100.0, 400.0)),
+        inputs: &[io(ItemKind::ReanimatedSam, 1.0), io(ItemKind::Coal, 24.0)],
+        outputs: &[io(ItemKind::RawQuartz, 12.0)],
     },
     RecipeInfo {
         name: "Sulfur (Coal)",
-        inputs: &[ItemKind::ReanimatedSam, ItemKind::Coal],
-        outputs: &[ItemKind::Sulfur],
+        building: Building::Converter,
+        duration: 6.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::AlienTechnology },
+        power_range: Some((100.0, 400.0)),
+        inputs: &[io(ItemKind::ReanimatedSam, 1.0), io(ItemKind::Coal, 20.0)],
+        outputs: &[io(ItemKind::Sulfur, 12.0)],
     },
     RecipeInfo {
         name: "Sulfur (Iron)",
-        inputs: &[ItemKind::ReanimatedSam, ItemKind::IronOre],
-        outputs: &[ItemKind::Sulfur],
+        building: Building::Converter,
+        duration: 6.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::AlienTechnology },
+        power_range: Some((100.0, 400.0)),
+        inputs: &[io(ItemKind::ReanimatedSam, 1.0), io(ItemKind::IronOre, 30.0)],
+        outputs: &[io(ItemKind::Sulfur, 12.0)],
     },
     RecipeInfo {
         name: "Uranium Ore (Bauxite)",
-        inputs: &[ItemKind::ReanimatedSam, ItemKind::Bauxite],
-        outputs: &[ItemKind::Uranium],
+        building: Building::Converter,
+        duration: 6.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::AlienTechnology },
+        power_range: Some((100.0, 400.0)),
+        inputs: &[io(ItemKind::ReanimatedSam, 1.0), io(ItemKind::Bauxite, 48.0)],
+        outputs: &[io(ItemKind::Uranium, 12.0)],
     },
     RecipeInfo {
         name: "Turbofuel",
-        inputs: &[ItemKind::Fuel, ItemKind::CompactedCoal],
-        outputs: &[ItemKind::Turbofuel],
+        building: Building::Refinery,
+        duration: 16.0,
+        alternative: true,
+        unlock: Unlock::Mam { tree: MamTree::Sulfur },
+        power_range: None,
+        inputs: &[io(ItemKind::Fuel, 6.0), io(ItemKind::CompactedCoal, 4.0)],
+        outputs: &[io(ItemKind::Turbofuel, 5.0)],
     },
     RecipeInfo {
         name: "Packaged Turbofuel",
-        inputs: &[ItemKind::Turbofuel, ItemKind::EmptyCanister],
-        outputs: &[ItemKind::PackagedTurbofuel],
+        building: Building::Packager,
+        duration: 6.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::Sulfur },
+        power_range: None,
+        inputs: &[io(ItemKind::Turbofuel, 2.0), io(ItemKind::EmptyCanister, 2.0)],
+        outputs: &[io(ItemKind::PackagedTurbofuel, 2.0)],
     },
     RecipeInfo {
         name: "Unpackage Turbofuel",
-        inputs: &[ItemKind::PackagedTurbofuel],
-        outputs: &[ItemKind::Turbofuel, ItemKind::EmptyCanister],
+        building: Building::Packager,
+        duration: 6.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::Sulfur },
+        power_range: None,
+        inputs: &[io(ItemKind::PackagedTurbofuel, 2.0)],
+        outputs: &[io(ItemKind::Turbofuel, 2.0), io(ItemKind::EmptyCanister, 2.0)],
     },
     RecipeInfo {
         name: "Charcoal",
-        inputs: &[ItemKind::Wood],
-        outputs: &[ItemKind::Coal],
+        building: Building::Constructor,
+        duration: 4.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::Wood, 1.0)],
+        outputs: &[io(ItemKind::Coal, 10.0)],
     },
     RecipeInfo {
         name: "Biocoal",
-        inputs: &[ItemKind::Biomass],
-        outputs: &[ItemKind::Coal],
+        building: Building::Constructor,
+        duration: 8.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::Biomass, 5.0)],
+        outputs: &[io(ItemKind::Coal, 6.0)],
     },
     RecipeInfo {
         name: "Compacted Coal",
-        inputs: &[ItemKind::Coal, ItemKind::Sulfur],
-        outputs: &[ItemKind::CompactedCoal],
+        building: Building::Assembler,
+        duration: 12.0,
+        alternative: true,
+        unlock: Unlock::Mam { tree: MamTree::Sulfur },
+        power_range: None,
+        inputs: &[io(ItemKind::Coal, 5.0), io(ItemKind::Sulfur, 5.0)],
+        outputs: &[io(ItemKind::CompactedCoal, 5.0)],
     },
     RecipeInfo {
         name: "Circuit Board",
-        inputs: &[ItemKind::CopperSheet, ItemKind::Plastic],
-        outputs: &[ItemKind::CircuitBoard],
+        building: Building::Assembler,
+        duration: 8.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 5 },
+        power_range: None,
+        inputs: &[io(ItemKind::CopperSheet, 2.0), io(ItemKind::Plastic, 4.0)],
+        outputs: &[io(ItemKind::CircuitBoard, 1.0)],
     },
     RecipeInfo {
         name: "Fuel",
-        inputs: &[ItemKind::CrudeOil],
-        outputs: &[ItemKind::Fuel, ItemKind::PolymerResin],
+        building: Building::Refinery,
+        duration: 6.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 5 },
+        power_range: None,
+        inputs: &[io(ItemKind::CrudeOil, 6.0)],
+        outputs: &[io(ItemKind::Fuel, 4.0), io(ItemKind::PolymerResin, 3.0)],
     },
     RecipeInfo {
         name: "Petroleum Coke",
-        inputs: &[ItemKind::HeavyOilResidue],
-        outputs: &[ItemKind::PetroleumCoke],
+        building: Building::Refinery,
+        duration: 6.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 5 },
+        power_range: None,
+        inputs: &[io(ItemKind::HeavyOilResidue, 4.0)],
+        outputs: &[io(ItemKind::PetroleumCoke, 12.0)],
     },
     RecipeInfo {
         name: "Plastic",
-        inputs: &[ItemKind::CrudeOil],
-        outputs: &[ItemKind::Plastic, ItemKind::HeavyOilResidue],
+        building: Building::Refinery,
+        duration: 6.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 5 },
+        power_range: None,
+        inputs: &[io(ItemKind::CrudeOil, 3.0)],
+        outputs: &[io(ItemKind::Plastic, 2.0), io(ItemKind::HeavyOilResidue, 1.0)],
     },
     RecipeInfo {
         name: "Rubber",
-        inputs: &[ItemKind::CrudeOil],
-        outputs: &[ItemKind::Rubber, ItemKind::HeavyOilResidue],
+        building: Building::Refinery,
+        duration: 6.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 5 },
+        power_range: None,
+        inputs: &[io(ItemKind::CrudeOil, 3.0)],
+        outputs: &[io(ItemKind::Rubber, 2.0), io(ItemKind::HeavyOilResidue, 2.0)],
     },
     RecipeInfo {
         name: "Residual Fuel",
-        inputs: &[ItemKind::HeavyOilResidue],
-        outputs: &[ItemKind::Fuel],
+        building: Building::Refinery,
+        duration: 6.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 5 },
+        power_range: None,
+        inputs: &[io(ItemKind::HeavyOilResidue, 6.0)],
+        outputs: &[io(ItemKind::Fuel, 4.0)],
     },
     RecipeInfo {
         name: "Residual Plastic",
-        inputs: &[ItemKind::PolymerResin, ItemKind::Water],
-        outputs: &[ItemKind::Plastic],
+        building: Building::Refinery,
+        duration: 6.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 5 },
+        power_range: None,
+        inputs: &[io(ItemKind::PolymerResin, 6.0), io(ItemKind::Water, 2.0)],
+        outputs: &[io(ItemKind::Plastic, 2.0)],
     },
     RecipeInfo {
         name: "Residual Rubber",
-        inputs: &[ItemKind::PolymerResin, ItemKind::Water],
-        outputs: &[ItemKind::Rubber],
+        building: Building::Refinery,
+        duration: 6.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 5 },
+        power_range: None,
+        inputs: &[io(ItemKind::PolymerResin, 4.0), io(ItemKind::Water, 4.0)],
+        outputs: &[io(ItemKind::Rubber, 2.0)],
     },
     RecipeInfo {
         name: "Pink Diamonds",
-        inputs: &[ItemKind::Coal, ItemKind::QuartzCrystal],
-        outputs: &[ItemKind::Diamonds],
+        building: Building::Converter,
+        duration: 4.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: Some((100.0, 400.0)),
+        inputs: &[io(ItemKind::Coal, 8.0), io(ItemKind::QuartzCrystal, 3.0)],
+        outputs: &[io(ItemKind::Diamonds, 1.0)],
     },
     RecipeInfo {
         name: "Petroleum Diamonds",
-        inputs: &[ItemKind::PetroleumCoke],
-        outputs: &[ItemKind::Diamonds],
+        building: Building::ParticleAccelerator,
+        duration: 2.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: Some((250.0, 750.0)),
+        inputs: &[io(ItemKind::PetroleumCoke, 24.0)],
+        outputs: &[io(ItemKind::Diamonds, 1.0)],
     },
     RecipeInfo {
         name: "Oil-Based Diamonds",
-        inputs: &[ItemKind::CrudeOil],
-        outputs: &[ItemKind::Diamonds],
+        building: Building::ParticleAccelerator,
+        duration: 3.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: Some((250.0, 750.0)),
+        inputs: &[io(ItemKind::CrudeOil, 10.0)],
+        outputs: &[io(ItemKind::Diamonds, 2.0)],
     },
     RecipeInfo {
         name: "Cloudy Diamonds",
-        inputs: &[ItemKind::Coal, ItemKind::Limestone],
-        outputs: &[ItemKind::Diamonds],
+        building: Building::ParticleAccelerator,
+        duration: 3.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: Some((250.0, 750.0)),
+        inputs: &[io(ItemKind::Coal, 12.0), io(ItemKind::Limestone, 24.0)],
+        outputs: &[io(ItemKind::Diamonds, 1.0)],
     },
     RecipeInfo {
         name: "Dark Matter Trap",
-        inputs: &[ItemKind::TimeCrystal, ItemKind::DarkMatterResidue],
-        outputs: &[ItemKind::DarkMatterCrystal],
+        building: Building::ParticleAccelerator,
+        duration: 2.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: Some((500.0, 1500.0)),
+        inputs: &[io(ItemKind::TimeCrystal, 1.0), io(ItemKind::DarkMatterResidue, 5.0)],
+        outputs: &[io(ItemKind::DarkMatterCrystal, 2.0)],
     },
     RecipeInfo {
         name: "Dark Matter Crystallization",
-        inputs: &[ItemKind::DarkMatterResidue],
-        outputs: &[ItemKind::DarkMatterCrystal],
+        building: Building::ParticleAccelerator,
+        duration: 3.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: Some((500.0, 1500.0)),
+        inputs: &[io(ItemKind::DarkMatterResidue, 10.0)],
+        outputs: &[io(ItemKind::DarkMatterCrystal, 1.0)],
     },
     RecipeInfo {
         name: "Wet Concrete",
-        inputs: &[ItemKind::Limestone, ItemKind::Water],
-        outputs: &[ItemKind::Concrete],
+        building: Building::Refinery,
+        duration: 3.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::Limestone, 6.0), io(ItemKind::Water, 5.0)],
+        outputs: &[io(ItemKind::Concrete, 4.0)],
     },
     RecipeInfo {
         name: "Turbo Heavy Fuel",
-        inputs: &[ItemKind::HeavyOilResidue, ItemKind::CompactedCoal],
-        outputs: &[ItemKind::Turbofuel],
+        building: Building::Refinery,
+        duration: 8.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::HeavyOilResidue, 5.0), io(ItemKind::CompactedCoal, 4.0)],
+        outputs: &[io(ItemKind::Turbofuel, 4.0)],
     },
     RecipeInfo {
         name: "Steel Rod",
-        inputs: &[ItemKind::SteelIngot],
-        outputs: &[ItemKind::IronRod],
+        building: Building::Constructor,
+        duration: 5.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::SteelIngot, 1.0)],
+        outputs: &[io(ItemKind::IronRod, 4.0)],
     },
     RecipeInfo {
         name: "Steel Beam",
-        inputs: &[ItemKind::SteelIngot],
-        outputs: &[ItemKind::SteelBeam],
+        building: Building::Constructor,
+        duration: 4.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 3 },
+        power_range: None,
+        inputs: &[io(ItemKind::SteelIngot, 4.0)],
+        outputs: &[io(ItemKind::SteelBeam, 1.0)],
     },
     RecipeInfo {
         name: "Steel Pipe",
-        inputs: &[ItemKind::SteelIngot],
-        outputs: &[ItemKind::SteelPipe],
+        building: Building::Constructor,
+        duration: 6.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 3 },
+        power_range: None,
+        inputs: &[io(ItemKind::SteelIngot, 3.0)],
+        outputs: &[io(ItemKind::SteelPipe, 2.0)],
     },
     RecipeInfo {
         name: "Steel Ingot",
-        inputs: &[ItemKind::IronOre, ItemKind::Coal],
-        outputs: &[ItemKind::SteelIngot],
+        building: Building::Foundry,
+        duration: 4.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 3 },
+        power_range: None,
+        inputs: &[io(ItemKind::IronOre, 3.0), io(ItemKind::Coal, 3.0)],
+        outputs: &[io(ItemKind::SteelIngot, 3.0)],
     },
     RecipeInfo {
         name: "Versatile Framework",
-        inputs: &[ItemKind::ModularFrame, ItemKind::SteelBeam],
-        outputs: &[ItemKind::VersatileFramework],
+        building: Building::Assembler,
+        duration: 24.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 3 },
+        power_range: None,
+        inputs: &[io(ItemKind::ModularFrame, 1.0), io(ItemKind::SteelBeam, 12.0)],
+        outputs: &[io(ItemKind::VersatileFramework, 2.0)],
     },
     RecipeInfo {
         name: "Steel Canister",
-        inputs: &[ItemKind::SteelIngot],
-        outputs: &[ItemKind::EmptyCanister],
+        building: Building::Constructor,
+        duration: 6.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::SteelIngot, 4.0)],
+        outputs: &[io(ItemKind::EmptyCanister, 4.0)],
     },
     RecipeInfo {
         name: "Empty Canister",
-        inputs: &[ItemKind::Plastic],
-        outputs: &[ItemKind::EmptyCanister],
+        building: Building::Constructor,
+        duration: 4.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 5 },
+        power_range: None,
+        inputs: &[io(ItemKind::Plastic, 2.0)],
+        outputs: &[io(ItemKind::EmptyCanister, 4.0)],
     },
     RecipeInfo {
         name: "Packaged Fuel",
-        inputs: &[ItemKind::Fuel, ItemKind::EmptyCanister],
-        outputs: &[ItemKind::PackagedFuel],
+        building: Building::Packager,
+        duration: 3.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 5 },
+        power_range: None,
+        inputs: &[io(ItemKind::Fuel, 2.0), io(ItemKind::EmptyCanister, 2.0)],
+        outputs: &[io(ItemKind::PackagedFuel, 2.0)],
     },
     RecipeInfo {
         name: "Liquid Biofuel",
-        inputs: &[ItemKind::SolidBiofuel, ItemKind::Water],
-        outputs: &[ItemKind::LiquidBiofuel],
+        building: Building::Refinery,
+        duration: 4.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 5 },
+        power_range: None,
+        inputs: &[io(ItemKind::SolidBiofuel, 6.0), io(ItemKind::Water, 3.0)],
+        outputs: &[io(ItemKind::LiquidBiofuel, 4.0)],
     },
     RecipeInfo {
         name: "Packaged Liquid Biofuel",
-        inputs: &[ItemKind::LiquidBiofuel, ItemKind::EmptyCanister],
-        outputs: &[ItemKind::PackagedLiquidBiofuel],
+        building: Building::Packager,
+        duration: 3.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 5 },
+        power_range: None,
+        inputs: &[io(ItemKind::LiquidBiofuel, 2.0), io(ItemKind::EmptyCanister, 2.0)],
+        outputs: &[io(ItemKind::PackagedLiquidBiofuel, 2.0)],
     },
     RecipeInfo {
         name: "Packaged Oil",
-        inputs: &[ItemKind::CrudeOil, ItemKind::EmptyCanister],
-        outputs: &[ItemKind::PackagedOil],
+        building: Building::Packager,
+        duration: 4.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 5 },
+        power_range: None,
+        inputs: &[io(ItemKind::CrudeOil, 2.0), io(ItemKind::EmptyCanister, 2.0)],
+        outputs: &[io(ItemKind::PackagedOil, 2.0)],
     },
     RecipeInfo {
         name: "Packaged Heavy Oil Residue",
-        inputs: &[ItemKind::HeavyOilResidue, ItemKind::EmptyCanister],
-        outputs: &[ItemKind::PackagedHeavyOilResidue],
+        building: Building::Packager,
+        duration: 4.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 5 },
+        power_range: None,
+        inputs: &[io(ItemKind::HeavyOilResidue, 2.0), io(ItemKind::EmptyCanister, 2.0)],
+        outputs: &[io(ItemKind::PackagedHeavyOilResidue, 2.0)],
     },
     RecipeInfo {
         name: "Packaged Water",
-        inputs: &[ItemKind::Water, ItemKind::EmptyCanister],
-        outputs: &[ItemKind::PackagedWater],
+        building: Building::Packager,
+        duration: 2.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 5 },
+        power_range: None,
+        inputs: &[io(ItemKind::Water, 2.0), io(ItemKind::EmptyCanister, 2.0)],
+        outputs: &[io(ItemKind::PackagedWater, 2.0)],
     },
     RecipeInfo {
         name: "Unpackage Liquid Biofuel",
-        inputs: &[ItemKind::PackagedLiquidBiofuel],
-        outputs: &[ItemKind::LiquidBiofuel, ItemKind::EmptyCanister],
+        building: Building::Packager,
+        duration: 2.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 5 },
+        power_range: None,
+        inputs: &[io(ItemKind::PackagedLiquidBiofuel, 2.0)],
+        outputs: &[io(ItemKind::LiquidBiofuel, 2.0), io(ItemKind::EmptyCanister, 2.0)],
     },
     RecipeInfo {
         name: "Unpackage Fuel",
-        inputs: &[ItemKind::PackagedFuel],
-        outputs: &[ItemKind::Fuel, ItemKind::EmptyCanister],
+        building: Building::Packager,
+        duration: 2.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 5 },
+        power_range: None,
+        inputs: &[io(ItemKind::PackagedFuel, 2.0)],
+        outputs: &[io(ItemKind::Fuel, 2.0), io(ItemKind::EmptyCanister, 2.0)],
     },
     RecipeInfo {
         name: "Unpackage Oil",
-        inputs: &[ItemKind::PackagedOil],
-        outputs: &[ItemKind::CrudeOil, ItemKind::EmptyCanister],
+        building: Building::Packager,
+        duration: 2.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 5 },
+        power_range: None,
+        inputs: &[io(ItemKind::PackagedOil, 2.0)],
+        outputs: &[io(ItemKind::CrudeOil, 2.0), io(ItemKind::EmptyCanister, 2.0)],
     },
     RecipeInfo {
         name: "Unpackage Heavy Oil Residue",
-        inputs: &[ItemKind::PackagedHeavyOilResidue],
-        outputs: &[ItemKind::HeavyOilResidue, ItemKind::EmptyCanister],
+        building: Building::Packager,
+        duration: 6.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 5 },
+        power_range: None,
+        inputs: &[io(ItemKind::PackagedHeavyOilResidue, 2.0)],
+        outputs: &[io(ItemKind::HeavyOilResidue, 2.0), io(ItemKind::EmptyCanister, 2.0)],
     },
     RecipeInfo {
         name: "Unpackage Water",
-        inputs: &[ItemKind::PackagedWater],
-        outputs: &[ItemKind::Water, ItemKind::EmptyCanister],
+        building: Building::Packager,
+        duration: 1.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 5 },
+        power_range: None,
+        inputs: &[io(ItemKind::PackagedWater, 2.0)],
+        outputs: &[io(ItemKind::Water, 2.0), io(ItemKind::EmptyCanister, 2.0)],
     },
     RecipeInfo {
         name: "Steamed Copper Sheet",
-        inputs: &[ItemKind::CopperIngot, ItemKind::Water],
-        outputs: &[ItemKind::CopperSheet],
+        building: Building::Refinery,
+        duration: 8.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::CopperIngot, 3.0), io(ItemKind::Water, 3.0)],
+        outputs: &[io(ItemKind::CopperSheet, 3.0)],
     },
     RecipeInfo {
         name: "Rubber Concrete",
-        inputs: &[ItemKind::Limestone, ItemKind::Rubber],
-        outputs: &[ItemKind::Concrete],
+        building: Building::Assembler,
+        duration: 6.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::Limestone, 10.0), io(ItemKind::Rubber, 2.0)],
+        outputs: &[io(ItemKind::Concrete, 9.0)],
     },
     RecipeInfo {
         name: "Recycled Rubber",
-        inputs: &[ItemKind::Plastic, ItemKind::Fuel],
-        outputs: &[ItemKind::Rubber],
+        building: Building::Refinery,
+        duration: 12.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::Plastic, 6.0), io(ItemKind::Fuel, 6.0)],
+        outputs: &[io(ItemKind::Rubber, 12.0)],
     },
     RecipeInfo {
         name: "Pure Quartz Crystal",
-        inputs: &[ItemKind::RawQuartz, ItemKind::Water],
-        outputs: &[ItemKind::QuartzCrystal],
+        building: Building::Refinery,
+        duration: 8.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::RawQuartz, 9.0), io(ItemKind::Water, 5.0)],
+        outputs: &[io(ItemKind::QuartzCrystal, 7.0)],
     },
     RecipeInfo {
         name: "Quartz Crystal",
-        inputs: &[ItemKind::RawQuartz],
-        outputs: &[ItemKind::QuartzCrystal],
+        building: Building::Constructor,
+        duration: 8.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::Quartz },
+        power_range: None,
+        inputs: &[io(ItemKind::RawQuartz, 5.0)],
+        outputs: &[io(ItemKind::QuartzCrystal, 3.0)],
     },
     RecipeInfo {
         name: "Pure Iron Ingot",
-        inputs: &[ItemKind::IronOre, ItemKind::Water],
-        outputs: &[ItemKind::IronIngot],
+        building: Building::Refinery,
+        duration: 12.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::IronOre, 7.0), io(ItemKind::Water, 4.0)],
+        outputs: &[io(ItemKind::IronIngot, 13.0)],
     },
     RecipeInfo {
         name: "Pure Copper Ingot",
-        inputs: &[ItemKind::CopperOre, ItemKind::Water],
-        outputs: &[ItemKind::CopperIngot],
+        building: Building::Refinery,
+        duration: 24.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::CopperOre, 6.0), io(ItemKind::Water, 4.0)],
+        outputs: &[io(ItemKind::CopperIngot, 15.0)],
     },
     RecipeInfo {
         name: "Pure Caterium Ingot",
-        inputs: &[ItemKind::CateriumOre, ItemKind::Water],
-        outputs: &[ItemKind::CateriumIngot],
+        building: Building::Refinery,
+        duration: 5.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::CateriumOre, 2.0), io(ItemKind::Water, 2.0)],
+        outputs: &[io(ItemKind::CateriumIngot, 1.0)],
     },
     RecipeInfo {
         name: "Pure Aluminum Ingot",
-        inputs: &[ItemKind::AluminumScrap],
-        outputs: &[ItemKind::AluminumIngot],
+        building: Building::Smelter,
+        duration: 2.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::AluminumScrap, 2.0)],
+        outputs: &[io(ItemKind::AluminumIngot, 1.0)],
     },
     RecipeInfo {
         name: "Aluminum Casing",
-        inputs: &[ItemKind::AluminumIngot],
-        outputs: &[ItemKind::AluminumCasing],
+        building: Building::Constructor,
+        duration: 2.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 7 },
+        power_range: None,
+        inputs: &[io(ItemKind::AluminumIngot, 3.0)],
+        outputs: &[io(ItemKind::AluminumCasing, 2.0)],
     },
     RecipeInfo {
         name: "Alclad Aluminum Sheet",
-        inputs: &[ItemKind::AluminumIngot, ItemKind::CopperIngot],
-        outputs: &[ItemKind::AlcladAluminumSheet],
+        building: Building::Assembler,
+        duration: 6.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 7 },
+        power_range: None,
+        inputs: &[io(ItemKind::AluminumIngot, 3.0), io(ItemKind::CopperIngot, 1.0)],
+        outputs: &[io(ItemKind::AlcladAluminumSheet, 3.0)],
     },
     RecipeInfo {
         name: "Alumina Solution",
-        inputs: &[ItemKind::Bauxite, ItemKind::Water],
-        outputs: &[ItemKind::AluminaSolution, ItemKind::Silica],
+        building: Building::Refinery,
+        duration: 6.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 7 },
+        power_range: None,
+        inputs: &[io(ItemKind::Bauxite, 12.0), io(ItemKind::Water, 18.0)],
+        outputs: &[io(ItemKind::AluminaSolution, 12.0), io(ItemKind::Silica, 5.0)],
     },
     RecipeInfo {
         name: "Aluminum Scrap",
-        inputs: &[ItemKind::AluminaSolution, ItemKind::Coal],
-        outputs: &[ItemKind::AluminumScrap, ItemKind::Water],
+        building: Building::Refinery,
+        duration: 1.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 7 },
+        power_range: None,
+        inputs: &[io(ItemKind::AluminaSolution, 4.0), io(ItemKind::Coal, 2.0)],
+        outputs: &[io(ItemKind::AluminumScrap, 6.0), io(ItemKind::Water, 2.0)],
     },
     RecipeInfo {
         name: "Packaged Alumina Solution",
-        inputs: &[ItemKind::AluminaSolution, ItemKind::EmptyCanister],
-        outputs: &[ItemKind::PackagedAluminaSolution],
+        building: Building::Packager,
+        duration: 1.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 7 },
+        power_range: None,
+        inputs: &[io(ItemKind::AluminaSolution, 2.0), io(ItemKind::EmptyCanister, 2.0)],
+        outputs: &[io(ItemKind::PackagedAluminaSolution, 2.0)],
     },
     RecipeInfo {
         name: "Aluminum Ingot",
-        inputs: &[ItemKind::AluminumScrap, ItemKind::Silica],
-        outputs: &[ItemKind::AluminumIngot],
+        building: Building::Foundry,
+        duration: 4.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 7 },
+        power_range: None,
+        inputs: &[io(ItemKind::AluminumScrap, 6.0), io(ItemKind::Silica, 5.0)],
+        outputs: &[io(ItemKind::AluminumIngot, 4.0)],
     },
     RecipeInfo {
         name: "Silica",
-        inputs: &[ItemKind::RawQuartz],
-        outputs: &[ItemKind::Silica],
+        building: Building::Constructor,
+        duration: 8.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::Quartz },
+        power_range: None,
+        inputs: &[io(ItemKind::RawQuartz, 3.0)],
+        outputs: &[io(ItemKind::Silica, 5.0)],
     },
     RecipeInfo {
         name: "Crystal Oscillator",
-        inputs: &[ItemKind::QuartzCrystal, ItemKind::Cable, ItemKind::ReinforcedIronPlate],
-        outputs: &[ItemKind::CrystalOscillator],
+        building: Building::Manufacturer,
+        duration: 120.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::Quartz },
+        power_range: None,
+        inputs: &[io(ItemKind::QuartzCrystal, 36.0), io(ItemKind::Cable, 28.0), io(ItemKind::ReinforcedIronPlate, 5.0)],
+        outputs: &[io(ItemKind::CrystalOscillator, 2.0)],
     },
     RecipeInfo {
         name: "Unpackage Alumina Solution",
-        inputs: &[ItemKind::PackagedAluminaSolution],
-        outputs: &[ItemKind::AluminaSolution, ItemKind::EmptyCanister],
+        building: Building::Packager,
+        duration: 1.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 7 },
+        power_range: None,
+        inputs: &[io(ItemKind::PackagedAluminaSolution, 2.0)],
+        outputs: &[io(ItemKind::AluminaSolution, 2.0), io(ItemKind::EmptyCanister, 2.0)],
     },
     RecipeInfo {
         name: "Polymer Resin",
-        inputs: &[ItemKind::CrudeOil],
-        outputs: &[ItemKind::PolymerResin, ItemKind::HeavyOilResidue],
+        building: Building::Refinery,
+        duration: 6.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::CrudeOil, 6.0)],
+        outputs: &[io(ItemKind::PolymerResin, 13.0), io(ItemKind::HeavyOilResidue, 2.0)],
     },
     RecipeInfo {
         name: "Plastic Smart Plating",
-        inputs: &[ItemKind::ReinforcedIronPlate, ItemKind::Rotor, ItemKind::Plastic],
-        outputs: &[ItemKind::SmartPlating],
+        building: Building::Manufacturer,
+        duration: 24.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::ReinforcedIronPlate, 1.0), io(ItemKind::Rotor, 1.0), io(ItemKind::Plastic, 3.0)],
+        outputs: &[io(ItemKind::SmartPlating, 2.0)],
     },
     RecipeInfo {
         name: "Automated Speed Wiring",
-        inputs: &[ItemKind::Stator, ItemKind::Wire, ItemKind::HighSpeedConnector],
-        outputs: &[ItemKind::AutomatedWiring],
+        building: Building::Manufacturer,
+        duration: 32.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::Stator, 2.0), io(ItemKind::Wire, 40.0), io(ItemKind::HighSpeedConnector, 1.0)],
+        outputs: &[io(ItemKind::AutomatedWiring, 4.0)],
     },
     RecipeInfo {
         name: "Encased Industrial Beam",
-        inputs: &[ItemKind::SteelBeam, ItemKind::Concrete],
-        outputs: &[ItemKind::EncasedIndustrialBeam],
+        building: Building::Assembler,
+        duration: 10.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 4 },
+        power_range: None,
+        inputs: &[io(ItemKind::SteelBeam, 3.0), io(ItemKind::Concrete, 6.0)],
+        outputs: &[io(ItemKind::EncasedIndustrialBeam, 1.0)],
     },
     RecipeInfo {
         name: "Motor",
-        inputs: &[ItemKind::Rotor, ItemKind::Stator],
-        outputs: &[ItemKind::Motor],
+        building: Building::Assembler,
+        duration: 12.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 4 },
+        power_range: None,
+        inputs: &[io(ItemKind::Rotor, 2.0), io(ItemKind::Stator, 2.0)],
+        outputs: &[io(ItemKind::Motor, 1.0)],
     },
     RecipeInfo {
         name: "Stator",
-        inputs: &[ItemKind::SteelPipe, ItemKind::Wire],
-        outputs: &[ItemKind::Stator],
+        building: Building::Assembler,
+        duration: 12.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 4 },
+        power_range: None,
+        inputs: &[io(ItemKind::SteelPipe, 3.0), io(ItemKind::Wire, 8.0)],
+        outputs: &[io(ItemKind::Stator, 1.0)],
     },
     RecipeInfo {
         name: "Automated Wiring",
-        inputs: &[ItemKind::Stator, ItemKind::Cable],
-        outputs: &[ItemKind::AutomatedWiring],
+        building: Building::Assembler,
+        duration: 24.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 4 },
+        power_range: None,
+        inputs: &[io(ItemKind::Stator, 1.0), io(ItemKind::Cable, 20.0)],
+        outputs: &[io(ItemKind::AutomatedWiring, 1.0)],
     },
     RecipeInfo {
         name: "AI Limiter",
-        inputs: &[ItemKind::CopperSheet, ItemKind::Quickwire],
-        outputs: &[ItemKind::AiLimiter],
+        building: Building::Assembler,
+        duration: 12.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::Caterium },
+        power_range: None,
+        inputs: &[io(ItemKind::CopperSheet, 5.0), io(ItemKind::Quickwire, 20.0)],
+        outputs: &[io(ItemKind::AiLimiter, 1.0)],
     },
     RecipeInfo {
         name: "Heavy Oil Residue",
-        inputs: &[ItemKind::CrudeOil],
-        outputs: &[ItemKind::HeavyOilResidue, ItemKind::PolymerResin],
+        building: Building::Refinery,
+        duration: 6.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::CrudeOil, 3.0)],
+        outputs: &[io(ItemKind::HeavyOilResidue, 4.0), io(ItemKind::PolymerResin, 2.0)],
     },
     RecipeInfo {
         name: "Heavy Flexible Frame",
-        inputs: &[ItemKind::ModularFrame, ItemKind::EncasedIndustrialBeam, ItemKind::Rubber, ItemKind::Screw],
-        outputs: &[ItemKind::HeavyModularFrame],
+        building: Building::Manufacturer,
+        duration: 16.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::ModularFrame, 5.0), io(ItemKind::EncasedIndustrialBeam, 3.0), io(ItemKind::Rubber, 20.0), io(ItemKind::Screw, 104.0)],
+        outputs: &[io(ItemKind::HeavyModularFrame, 1.0)],
     },
     RecipeInfo {
         name: "Computer",
-        inputs: &[ItemKind::CircuitBoard, ItemKind::Cable, ItemKind::Plastic],
-        outputs: &[ItemKind::Computer],
+        building: Building::Manufacturer,
+        duration: 24.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 5 },
+        power_range: None,
+        inputs: &[io(ItemKind::CircuitBoard, 4.0), io(ItemKind::Cable, 8.0), io(ItemKind::Plastic, 16.0)],
+        outputs: &[io(ItemKind::Computer, 1.0)],
     },
     RecipeInfo {
         name: "Heavy Modular Frame",
-        inputs: &[ItemKind::ModularFrame, ItemKind::SteelPipe, ItemKind::EncasedIndustrialBeam, ItemKind::Screw],
-        outputs: &[ItemKind::HeavyModularFrame],
+        building: Building::Manufacturer,
+        duration: 30.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 5 },
+        power_range: None,
+        inputs: &[io(ItemKind::ModularFrame, 5.0), io(ItemKind::SteelPipe, 20.0), io(ItemKind::EncasedIndustrialBeam, 5.0), io(ItemKind::Screw, 120.0)],
+        outputs: &[io(ItemKind::HeavyModularFrame, 1.0)],
     },
     RecipeInfo {
         name: "Modular Engine",
-        inputs: &[ItemKind::Motor, ItemKind::Rubber, ItemKind::SmartPlating],
-        outputs: &[ItemKind::ModularEngine],
+        building: Building::Manufacturer,
+        duration: 60.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 5 },
+        power_range: None,
+        inputs: &[io(ItemKind::Motor, 2.0), io(ItemKind::Rubber, 15.0), io(ItemKind::SmartPlating, 2.0)],
+        outputs: &[io(ItemKind::ModularEngine, 1.0)],
     },
     RecipeInfo {
         name: "Adaptive Control Unit",
-        inputs: &[ItemKind::AutomatedWiring, ItemKind::CircuitBoard, ItemKind::HeavyModularFrame, ItemKind::Computer],
-        outputs: &[ItemKind::AdaptiveControlUnit],
+        building: Building::Manufacturer,
+        duration: 60.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 5 },
+        power_range: None,
+        inputs: &[io(ItemKind::AutomatedWiring, 5.0), io(ItemKind::CircuitBoard, 5.0), io(ItemKind::HeavyModularFrame, 1.0), io(ItemKind::Computer, 2.0)],
+        outputs: &[io(ItemKind::AdaptiveControlUnit, 1.0)],
     },
     RecipeInfo {
         name: "Fused Wire",
-        inputs: &[ItemKind::CopperIngot, ItemKind::CateriumIngot],
-        outputs: &[ItemKind::Wire],
+        building: Building::Assembler,
+        duration: 20.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::CopperIngot, 4.0), io(ItemKind::CateriumIngot, 1.0)],
+        outputs: &[io(ItemKind::Wire, 30.0)],
     },
     RecipeInfo {
         name: "Flexible Framework",
-        inputs: &[ItemKind::ModularFrame, ItemKind::SteelBeam, ItemKind::Rubber],
-        outputs: &[ItemKind::VersatileFramework],
+        building: Building::Manufacturer,
+        duration: 16.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::ModularFrame, 1.0), io(ItemKind::SteelBeam, 6.0), io(ItemKind::Rubber, 8.0)],
+        outputs: &[io(ItemKind::VersatileFramework, 2.0)],
     },
     RecipeInfo {
         name: "Electrode Circuit Board",
-        inputs: &[ItemKind::Rubber, ItemKind::PetroleumCoke],
-        outputs: &[ItemKind::CircuitBoard],
+        building: Building::Assembler,
+        duration: 12.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::Rubber, 4.0), io(ItemKind::PetroleumCoke, 8.0)],
+        outputs: &[io(ItemKind::CircuitBoard, 1.0)],
     },
     RecipeInfo {
         name: "Electrode Aluminum Scrap",
-        inputs: &[ItemKind::AluminaSolution, ItemKind::PetroleumCoke],
-        outputs: &[ItemKind::AluminumScrap, ItemKind::Water],
+        building: Building::Refinery,
+        duration: 4.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::AluminaSolution, 12.0), io(ItemKind::PetroleumCoke, 4.0)],
+        outputs: &[io(ItemKind::AluminumScrap, 20.0), io(ItemKind::Water, 7.0)],
     },
     RecipeInfo {
         name: "Diluted Packaged Fuel",
-        inputs: &[ItemKind::HeavyOilResidue, ItemKind::PackagedWater],
-        outputs: &[ItemKind::PackagedFuel],
+        building: Building::Refinery,
+        duration: 2.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::HeavyOilResidue, 1.0), io(ItemKind::PackagedWater, 2.0)],
+        outputs: &[io(ItemKind::PackagedFuel, 2.0)],
     },
     RecipeInfo {
         name: "Copper Rotor",
-        inputs: &[ItemKind::CopperSheet, ItemKind::Screw],
-        outputs: &[ItemKind::Rotor],
+        building: Building::Assembler,
+        duration: 16.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::CopperSheet, 6.0), io(ItemKind::Screw, 52.0)],
+        outputs: &[io(ItemKind::Rotor, 3.0)],
     },
     RecipeInfo {
         name: "Modular Frame",
-        inputs: &[ItemKind::ReinforcedIronPlate, ItemKind::IronRod],
-        outputs: &[ItemKind::ModularFrame],
+        building: Building::Assembler,
+        duration: 60.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 2 },
+        power_range: None,
+        inputs: &[io(ItemKind::ReinforcedIronPlate, 3.0), io(ItemKind::IronRod, 12.0)],
+        outputs: &[io(ItemKind::ModularFrame, 2.0)],
     },
     RecipeInfo {
         name: "Rotor",
-        inputs: &[ItemKind::IronRod, ItemKind::Screw],
-        outputs: &[ItemKind::Rotor],
+        building: Building::Assembler,
+        duration: 15.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 2 },
+        power_range: None,
+        inputs: &[io(ItemKind::IronRod, 5.0), io(ItemKind::Screw, 25.0)],
+        outputs: &[io(ItemKind::Rotor, 1.0)],
     },
     RecipeInfo {
         name: "Copper Sheet",
-        inputs: &[ItemKind::CopperIngot],
-        outputs: &[ItemKind::CopperSheet],
+        building: Building::Constructor,
+        duration: 6.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 2 },
+        power_range: None,
+        inputs: &[io(ItemKind::CopperIngot, 2.0)],
+        outputs: &[io(ItemKind::CopperSheet, 1.0)],
     },
     RecipeInfo {
         name: "Smart Plating",
-        inputs: &[ItemKind::ReinforcedIronPlate, ItemKind::Rotor],
-        outputs: &[ItemKind::SmartPlating],
+        building: Building::Assembler,
+        duration: 30.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 2 },
+        power_range: None,
+        inputs: &[io(ItemKind::ReinforcedIronPlate, 1.0), io(ItemKind::Rotor, 1.0)],
+        outputs: &[io(ItemKind::SmartPlating, 1.0)],
     },
     RecipeInfo {
         name: "Copper Alloy Ingot",
-        inputs: &[ItemKind::CopperOre, ItemKind::IronOre],
-        outputs: &[ItemKind::CopperIngot],
+        building: Building::Foundry,
+        duration: 6.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::CopperOre, 5.0), io(ItemKind::IronOre, 5.0)],
+        outputs: &[io(ItemKind::CopperIngot, 10.0)],
     },
     RecipeInfo {
         name: "Coke Steel Ingot",
-        inputs: &[ItemKind::IronOre, ItemKind::PetroleumCoke],
-        outputs: &[ItemKind::SteelIngot],
+        building: Building::Foundry,
+        duration: 12.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::IronOre, 15.0), io(ItemKind::PetroleumCoke, 15.0)],
+        outputs: &[io(ItemKind::SteelIngot, 20.0)],
     },
     RecipeInfo {
         name: "Coated Iron Plate",
-        inputs: &[ItemKind::IronIngot, ItemKind::Plastic],
-        outputs: &[ItemKind::IronPlate],
+        building: Building::Assembler,
+        duration: 8.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::IronIngot, 5.0), io(ItemKind::Plastic, 1.0)],
+        outputs: &[io(ItemKind::IronPlate, 10.0)],
     },
     RecipeInfo {
         name: "Coated Iron Canister",
-        inputs: &[ItemKind::IronPlate, ItemKind::CopperSheet],
-        outputs: &[ItemKind::EmptyCanister],
+        building: Building::Assembler,
+        duration: 4.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::IronPlate, 2.0), io(ItemKind::CopperSheet, 1.0)],
+        outputs: &[io(ItemKind::EmptyCanister, 4.0)],
     },
     RecipeInfo {
         name: "Coated Cable",
-        inputs: &[ItemKind::Wire, ItemKind::HeavyOilResidue],
-        outputs: &[ItemKind::Cable],
+        building: Building::Refinery,
+        duration: 8.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::Wire, 5.0), io(ItemKind::HeavyOilResidue, 2.0)],
+        outputs: &[io(ItemKind::Cable, 9.0)],
     },
     RecipeInfo {
         name: "Bolted Frame",
-        inputs: &[ItemKind::ReinforcedIronPlate, ItemKind::Screw],
-        outputs: &[ItemKind::ModularFrame],
+        building: Building::Assembler,
+        duration: 24.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::ReinforcedIronPlate, 3.0), io(ItemKind::Screw, 56.0)],
+        outputs: &[io(ItemKind::ModularFrame, 2.0)],
     },
     RecipeInfo {
         name: "Adhered Iron Plate",
-        inputs: &[ItemKind::IronPlate, ItemKind::Rubber],
-        outputs: &[ItemKind::ReinforcedIronPlate],
+        building: Building::Assembler,
+        duration: 16.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::IronPlate, 3.0), io(ItemKind::Rubber, 1.0)],
+        outputs: &[io(ItemKind::ReinforcedIronPlate, 1.0)],
     },
     RecipeInfo {
         name: "Turbo Pressure Motor",
-        inputs: &[ItemKind::Motor, ItemKind::PressureConversionCube, ItemKind::PackagedNitrogenGas, ItemKind::Stator],
-        outputs: &[ItemKind::TurboMotor],
+        building: Building::Manufacturer,
+        duration: 32.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::Motor, 4.0), io(ItemKind::PressureConversionCube, 1.0), io(ItemKind::PackagedNitrogenGas, 24.0), io(ItemKind::Stator, 8.0)],
+        outputs: &[io(ItemKind::TurboMotor, 2.0)],
     },
     RecipeInfo {
         name: "Encased Plutonium Cell",
-        inputs: &[ItemKind::PlutoniumPellet, ItemKind::Concrete],
-        outputs: &[ItemKind::EncasedPlutoniumCell],
+        building: Building::Assembler,
+        duration: 12.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 8 },
+        power_range: None,
+        inputs: &[io(ItemKind::PlutoniumPellet, 2.0), io(ItemKind::Concrete, 4.0)],
+        outputs: &[io(ItemKind::EncasedPlutoniumCell, 1.0)],
     },
     RecipeInfo {
         name: "Pressure Conversion Cube",
-        inputs: &[ItemKind::FusedModularFrame, ItemKind::RadioControlUnit],
-        outputs: &[ItemKind::PressureConversionCube],
+        building: Building::Assembler,
+        duration: 60.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 8 },
+        power_range: None,
+        inputs: &[io(ItemKind::FusedModularFrame, 1.0), io(ItemKind::RadioControlUnit, 2.0)],
+        outputs: &[io(ItemKind::PressureConversionCube, 1.0)],
     },
     RecipeInfo {
         name: "Nitric Acid",
-        inputs: &[ItemKind::NitrogenGas, ItemKind::Water, ItemKind::IronPlate],
-        outputs: &[ItemKind::NitricAcid],
+        building: Building::Blender,
+        duration: 6.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 8 },
+        power_range: None,
+        inputs: &[io(ItemKind::NitrogenGas, 12.0), io(ItemKind::Water, 3.0), io(ItemKind::IronPlate, 1.0)],
+        outputs: &[io(ItemKind::NitricAcid, 3.0)],
     },
     RecipeInfo {
         name: "Non-Fissile Uranium",
-        inputs: &[ItemKind::UraniumWaste, ItemKind::Silica, ItemKind::NitricAcid, ItemKind::SulfuricAcid],
-        outputs: &[ItemKind::NonFissileUranium, ItemKind::Water],
+        building: Building::Blender,
+        duration: 24.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 8 },
+        power_range: None,
+        inputs: &[io(ItemKind::UraniumWaste, 15.0), io(ItemKind::Silica, 10.0), io(ItemKind::NitricAcid, 6.0), io(ItemKind::SulfuricAcid, 6.0)],
+        outputs: &[io(ItemKind::NonFissileUranium, 20.0), io(ItemKind::Water, 6.0)],
     },
     RecipeInfo {
         name: "Copper Powder",
-        inputs: &[ItemKind::CopperIngot],
-        outputs: &[ItemKind::CopperPowder],
+        building: Building::Constructor,
+        duration: 6.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 8 },
+        power_range: None,
+        inputs: &[io(ItemKind::CopperIngot, 30.0)],
+        outputs: &[io(ItemKind::CopperPowder, 5.0)],
     },
     RecipeInfo {
         name: "Plutonium Pellet",
-        inputs: &[ItemKind::NonFissileUranium, ItemKind::UraniumWaste],
-        outputs: &[ItemKind::PlutoniumPellet],
+        building: Building::ParticleAccelerator,
+        duration: 60.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 8 },
+        power_range: Some((250.0, 750.0)),
+        inputs: &[io(ItemKind::NonFissileUranium, 100.0), io(ItemKind::UraniumWaste, 25.0)],
+        outputs: &[io(ItemKind::PlutoniumPellet, 30.0)],
     },
     RecipeInfo {
         name: "Plutonium Fuel Rod",
-        inputs: &[ItemKind::EncasedPlutoniumCell, ItemKind::SteelBeam, ItemKind::ElectromagneticControlRod, ItemKind::HeatSink],
-        outputs: &[ItemKind::PlutoniumFuelRod],
+        building: Building::Manufacturer,
+        duration: 240.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 8 },
+        power_range: None,
+        inputs: &[io(ItemKind::EncasedPlutoniumCell, 30.0), io(ItemKind::SteelBeam, 18.0), io(ItemKind::ElectromagneticControlRod, 6.0), io(ItemKind::HeatSink, 10.0)],
+        outputs: &[io(ItemKind::PlutoniumFuelRod, 1.0)],
     },
     RecipeInfo {
         name: "Packaged Nitric Acid",
-        inputs: &[ItemKind::NitricAcid, ItemKind::EmptyFluidTank],
-        outputs: &[ItemKind::PackagedNitricAcid],
+        building: Building::Packager,
+        duration: 2.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 8 },
+        power_range: None,
+        inputs: &[io(ItemKind::NitricAcid, 1.0), io(ItemKind::EmptyFluidTank, 1.0)],
+        outputs: &[io(ItemKind::PackagedNitricAcid, 1.0)],
     },
     RecipeInfo {
         name: "Nuclear Pasta",
-        inputs: &[ItemKind::CopperPowder, ItemKind::PressureConversionCube],
-        outputs: &[ItemKind::NuclearPasta],
+        building: Building::ParticleAccelerator,
+        duration: 120.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 8 },
+        power_range: Some((500.0, 1500.0)),
+        inputs: &[io(ItemKind::CopperPowder, 200.0), io(ItemKind::PressureConversionCube, 1.0)],
+        outputs: &[io(ItemKind::NuclearPasta, 1.0)],
     },
     RecipeInfo {
         name: "Unpackage Nitric Acid",
-        inputs: &[ItemKind::PackagedNitricAcid],
-        outputs: &[ItemKind::NitricAcid, ItemKind::EmptyFluidTank],
+        building: Building::Packager,
+        duration: 3.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 8 },
+        power_range: None,
+        inputs: &[io(ItemKind::PackagedNitricAcid, 1.0)],
+        outputs: &[io(ItemKind::NitricAcid, 1.0), io(ItemKind::EmptyFluidTank, 1.0)],
     },
     RecipeInfo {
         name: "Turbo Blend Fuel",
-        inputs: &[ItemKind::Fuel, ItemKind::HeavyOilResidue, ItemKind::Sulfur, ItemKind::PetroleumCoke],
-        outputs: &[ItemKind::Turbofuel],
+        building: Building::Blender,
+        duration: 8.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::Fuel, 2.0), io(ItemKind::HeavyOilResidue, 4.0), io(ItemKind::Sulfur, 3.0), io(ItemKind::PetroleumCoke, 3.0)],
+        outputs: &[io(ItemKind::Turbofuel, 6.0)],
     },
     RecipeInfo {
         name: "Encased Uranium Cell",
-        inputs: &[ItemKind::Uranium, ItemKind::Concrete, ItemKind::SulfuricAcid],
-        outputs: &[ItemKind::EncasedUraniumCell, ItemKind::SulfuricAcid],
+        building: Building::Blender,
+        duration: 12.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 8 },
+        power_range: None,
+        inputs: &[io(ItemKind::Uranium, 10.0), io(ItemKind::Concrete, 3.0), io(ItemKind::SulfuricAcid, 8.0)],
+        outputs: &[io(ItemKind::EncasedUraniumCell, 5.0), io(ItemKind::SulfuricAcid, 2.0)],
     },
     RecipeInfo {
         name: "Cooling System",
-        inputs: &[ItemKind::HeatSink, ItemKind::Rubber, ItemKind::Water, ItemKind::NitrogenGas],
-        outputs: &[ItemKind::CoolingSystem],
+        building: Building::Blender,
+        duration: 10.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 8 },
+        power_range: None,
+        inputs: &[io(ItemKind::HeatSink, 2.0), io(ItemKind::Rubber, 2.0), io(ItemKind::Water, 5.0), io(ItemKind::NitrogenGas, 25.0)],
+        outputs: &[io(ItemKind::CoolingSystem, 1.0)],
     },
     RecipeInfo {
         name: "Battery",
-        inputs: &[ItemKind::SulfuricAcid, ItemKind::AluminaSolution, ItemKind::AluminumCasing],
-        outputs: &[ItemKind::Battery, ItemKind::Water],
+        building: Building::Blender,
+        duration: 3.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 7 },
+        power_range: None,
+        inputs: &[io(ItemKind::SulfuricAcid, 2.5), io(ItemKind::AluminaSolution, 2.0), io(ItemKind::AluminumCasing, 1.0)],
+        outputs: &[io(ItemKind::Battery, 1.0), io(ItemKind::Water, 1.5)],
     },
     RecipeInfo {
         name: "Supercomputer",
-        inputs: &[ItemKind::Computer, ItemKind::AiLimiter, ItemKind::HighSpeedConnector, ItemKind::Plastic],
-        outputs: &[ItemKind::Supercomputer],
+        building: Building::Manufacturer,
+        duration: 32.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 7 },
+        power_range: None,
+        inputs: &[io(ItemKind::Computer, 4.0), io(ItemKind::AiLimiter, 2.0), io(ItemKind::HighSpeedConnector, 3.0), io(ItemKind::Plastic, 28.0)],
+        outputs: &[io(ItemKind::Supercomputer, 1.0)],
     },
     RecipeInfo {
         name: "Radio Control Unit",
-        inputs: &[ItemKind::AluminumCasing, ItemKind::CrystalOscillator, ItemKind::Computer],
-        outputs: &[ItemKind::RadioControlUnit],
+        building: Building::Manufacturer,
+        duration: 48.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 7 },
+        power_range: None,
+        inputs: &[io(ItemKind::AluminumCasing, 32.0), io(ItemKind::CrystalOscillator, 1.0), io(ItemKind::Computer, 2.0)],
+        outputs: &[io(ItemKind::RadioControlUnit, 2.0)],
     },
     RecipeInfo {
         name: "Sulfuric Acid",
-        inputs: &[ItemKind::Sulfur, ItemKind::Water],
-        outputs: &[ItemKind::SulfuricAcid],
+        building: Building::Refinery,
+        duration: 6.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 7 },
+        power_range: None,
+        inputs: &[io(ItemKind::Sulfur, 5.0), io(ItemKind::Water, 5.0)],
+        outputs: &[io(ItemKind::SulfuricAcid, 5.0)],
     },
     RecipeInfo {
         name: "Packaged Sulfuric Acid",
-        inputs: &[ItemKind::SulfuricAcid, ItemKind::EmptyCanister],
-        outputs: &[ItemKind::PackagedSulfuricAcid],
+        building: Building::Packager,
+        duration: 3.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 7 },
+        power_range: None,
+        inputs: &[io(ItemKind::SulfuricAcid, 2.0), io(ItemKind::EmptyCanister, 2.0)],
+        outputs: &[io(ItemKind::PackagedSulfuricAcid, 2.0)],
     },
     RecipeInfo {
         name: "Assembly Director System",
-        inputs: &[ItemKind::AdaptiveControlUnit, ItemKind::Supercomputer],
-        outputs: &[ItemKind::AssemblyDirectorSystem],
+        building: Building::Assembler,
+        duration: 80.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 7 },
+        power_range: None,
+        inputs: &[io(ItemKind::AdaptiveControlUnit, 2.0), io(ItemKind::Supercomputer, 1.0)],
+        outputs: &[io(ItemKind::AssemblyDirectorSystem, 1.0)],
     },
     RecipeInfo {
         name: "High-Speed Connector",
-        inputs: &[ItemKind::Quickwire, ItemKind::Cable, ItemKind::CircuitBoard],
-        outputs: &[ItemKind::HighSpeedConnector],
+        building: Building::Manufacturer,
+        duration: 16.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::Caterium },
+        power_range: None,
+        inputs: &[io(ItemKind::Quickwire, 56.0), io(ItemKind::Cable, 10.0), io(ItemKind::CircuitBoard, 1.0)],
+        outputs: &[io(ItemKind::HighSpeedConnector, 1.0)],
     },
     RecipeInfo {
         name: "Unpackage Sulfuric Acid",
-        inputs: &[ItemKind::PackagedSulfuricAcid],
-        outputs: &[ItemKind::SulfuricAcid, ItemKind::EmptyCanister],
+        building: Building::Packager,
+        duration: 1.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 7 },
+        power_range: None,
+        inputs: &[io(ItemKind::PackagedSulfuricAcid, 1.0)],
+        outputs: &[io(ItemKind::SulfuricAcid, 1.0), io(ItemKind::EmptyCanister, 1.0)],
     },
     RecipeInfo {
         name: "Super-State Computer",
-        inputs: &[ItemKind::Computer, ItemKind::ElectromagneticControlRod, ItemKind::Battery, ItemKind::Wire],
-        outputs: &[ItemKind::Supercomputer],
+        building: Building::Manufacturer,
+        duration: 25.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::Computer, 3.0), io(ItemKind::ElectromagneticControlRod, 1.0), io(ItemKind::Battery, 10.0), io(ItemKind::Wire, 25.0)],
+        outputs: &[io(ItemKind::Supercomputer, 1.0)],
     },
     RecipeInfo {
         name: "Electromagnetic Control Rod",
-        inputs: &[ItemKind::Stator, ItemKind::AiLimiter],
-        outputs: &[ItemKind::ElectromagneticControlRod],
+        building: Building::Assembler,
+        duration: 30.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 8 },
+        power_range: None,
+        inputs: &[io(ItemKind::Stator, 3.0), io(ItemKind::AiLimiter, 2.0)],
+        outputs: &[io(ItemKind::ElectromagneticControlRod, 2.0)],
     },
     RecipeInfo {
         name: "Uranium Fuel Rod",
-        inputs: &[ItemKind::EncasedUraniumCell, ItemKind::EncasedIndustrialBeam, ItemKind::ElectromagneticControlRod],
-        outputs: &[ItemKind::UraniumFuelRod],
+        building: Building::Manufacturer,
+        duration: 150.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 8 },
+        power_range: None,
+        inputs: &[io(ItemKind::EncasedUraniumCell, 50.0), io(ItemKind::EncasedIndustrialBeam, 3.0), io(ItemKind::ElectromagneticControlRod, 5.0)],
+        outputs: &[io(ItemKind::UraniumFuelRod, 1.0)],
     },
     RecipeInfo {
         name: "Magnetic Field Generator",
-        inputs: &[ItemKind::VersatileFramework, ItemKind::ElectromagneticControlRod],
-        outputs: &[ItemKind::MagneticFieldGenerator],
+        building: Building::Assembler,
+        duration: 120.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 8 },
+        power_range: None,
+        inputs: &[io(ItemKind::VersatileFramework, 5.0), io(ItemKind::ElectromagneticControlRod, 2.0)],
+        outputs: &[io(ItemKind::MagneticFieldGenerator, 2.0)],
     },
     RecipeInfo {
         name: "Sloppy Alumina",
-        inputs: &[ItemKind::Bauxite, ItemKind::Water],
-        outputs: &[ItemKind::AluminaSolution],
+        building: Building::Refinery,
+        duration: 3.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::Bauxite, 10.0), io(ItemKind::Water, 10.0)],
+        outputs: &[io(ItemKind::AluminaSolution, 12.0)],
     },
     RecipeInfo {
         name: "Radio Control System",
-        inputs: &[ItemKind::CrystalOscillator, ItemKind::CircuitBoard, ItemKind::AluminumCasing, ItemKind::Rubber],
-        outputs: &[ItemKind::RadioControlUnit],
+        building: Building::Manufacturer,
+        duration: 40.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::CrystalOscillator, 1.0), io(ItemKind::CircuitBoard, 10.0), io(ItemKind::AluminumCasing, 60.0), io(ItemKind::Rubber, 30.0)],
+        outputs: &[io(ItemKind::RadioControlUnit, 3.0)],
     },
     RecipeInfo {
         name: "Plutonium Fuel Unit",
-        inputs: &[ItemKind::EncasedPlutoniumCell, ItemKind::PressureConversionCube],
-        outputs: &[ItemKind::PlutoniumFuelRod],
+        building: Building::Assembler,
+        duration: 120.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::EncasedPlutoniumCell, 20.0), io(ItemKind::PressureConversionCube, 1.0)],
+        outputs: &[io(ItemKind::PlutoniumFuelRod, 1.0)],
     },
     RecipeInfo {
         name: "OC Supercomputer",
-        inputs: &[ItemKind::RadioControlUnit, ItemKind::CoolingSystem],
-        outputs: &[ItemKind::Supercomputer],
+        building: Building::Assembler,
+        duration: 20.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::RadioControlUnit, 2.0), io(ItemKind::CoolingSystem, 2.0)],
+        outputs: &[io(ItemKind::Supercomputer, 1.0)],
     },
     RecipeInfo {
         name: "Heat Sink",
-        inputs: &[ItemKind::AlcladAluminumSheet, ItemKind::CopperSheet],
-        outputs: &[ItemKind::HeatSink],
+        building: Building::Assembler,
+        duration: 8.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 8 },
+        power_range: None,
+        inputs: &[io(ItemKind::AlcladAluminumSheet, 5.0), io(ItemKind::CopperSheet, 3.0)],
+        outputs: &[io(ItemKind::HeatSink, 1.0)],
     },
     RecipeInfo {
         name: "Fused Modular Frame",
-        inputs: &[ItemKind::HeavyModularFrame, ItemKind::AluminumCasing, ItemKind::NitrogenGas],
-        outputs: &[ItemKind::FusedModularFrame],
+        building: Building::Blender,
+        duration: 40.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 8 },
+        power_range: None,
+        inputs: &[io(ItemKind::HeavyModularFrame, 1.0), io(ItemKind::AluminumCasing, 50.0), io(ItemKind::NitrogenGas, 25.0)],
+        outputs: &[io(ItemKind::FusedModularFrame, 1.0)],
     },
     RecipeInfo {
         name: "Empty Fluid Tank",
-        inputs: &[ItemKind::AluminumIngot],
-        outputs: &[ItemKind::EmptyFluidTank],
+        building: Building::Constructor,
+        duration: 1.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 8 },
+        power_range: None,
+        inputs: &[io(ItemKind::AluminumIngot, 1.0)],
+        outputs: &[io(ItemKind::EmptyFluidTank, 1.0)],
     },
     RecipeInfo {
         name: "Packaged Nitrogen Gas",
-        inputs: &[ItemKind::NitrogenGas, ItemKind::EmptyFluidTank],
-        outputs: &[ItemKind::PackagedNitrogenGas],
+        building: Building::Packager,
+        duration: 1.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 8 },
+        power_range: None,
+        inputs: &[io(ItemKind::NitrogenGas, 4.0), io(ItemKind::EmptyFluidTank, 1.0)],
+        outputs: &[io(ItemKind::PackagedNitrogenGas, 1.0)],
     },
     RecipeInfo {
         name: "Unpackage Nitrogen Gas",
-        inputs: &[ItemKind::PackagedNitrogenGas],
-        outputs: &[ItemKind::NitrogenGas, ItemKind::EmptyFluidTank],
+        building: Building::Packager,
+        duration: 1.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 8 },
+        power_range: None,
+        inputs: &[io(ItemKind::PackagedNitrogenGas, 1.0)],
+        outputs: &[io(ItemKind::NitrogenGas, 4.0), io(ItemKind::EmptyFluidTank, 1.0)],
     },
     RecipeInfo {
         name: "Instant Scrap",
-        inputs: &[ItemKind::Bauxite, ItemKind::Coal, ItemKind::SulfuricAcid, ItemKind::Water],
-        outputs: &[ItemKind::AluminumScrap, ItemKind::Water],
+        building: Building::Blender,
+        duration: 6.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::Bauxite, 15.0), io(ItemKind::Coal, 10.0), io(ItemKind::SulfuricAcid, 5.0), io(ItemKind::Water, 6.0)],
+        outputs: &[io(ItemKind::AluminumScrap, 30.0), io(ItemKind::Water, 5.0)],
     },
     RecipeInfo {
         name: "Instant Plutonium Cell",
-        inputs: &[ItemKind::NonFissileUranium, ItemKind::AluminumCasing],
-        outputs: &[ItemKind::EncasedPlutoniumCell],
+        building: Building::ParticleAccelerator,
+        duration: 120.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: Some((250.0, 750.0)),
+        inputs: &[io(ItemKind::NonFissileUranium, 150.0), io(ItemKind::AluminumCasing, 20.0)],
+        outputs: &[io(ItemKind::EncasedPlutoniumCell, 20.0)],
     },
     RecipeInfo {
         name: "Heat-Fused Frame",
-        inputs: &[ItemKind::HeavyModularFrame, ItemKind::AluminumIngot, ItemKind::NitricAcid, ItemKind::Fuel],
-        outputs: &[ItemKind::FusedModularFrame],
+        building: Building::Blender,
+        duration: 20.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::HeavyModularFrame, 1.0), io(ItemKind::AluminumIngot, 50.0), io(ItemKind::NitricAcid, 8.0), io(ItemKind::Fuel, 10.0)],
+        outputs: &[io(ItemKind::FusedModularFrame, 1.0)],
     },
     RecipeInfo {
         name: "Fertile Uranium",
-        inputs: &[ItemKind::Uranium, ItemKind::UraniumWaste, ItemKind::NitricAcid, ItemKind::SulfuricAcid],
-        outputs: &[ItemKind::NonFissileUranium, ItemKind::Water],
+        building: Building::Blender,
+        duration: 12.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::Uranium, 5.0), io(ItemKind::UraniumWaste, 5.0), io(ItemKind::NitricAcid, 3.0), io(ItemKind::SulfuricAcid, 5.0)],
+        outputs: &[io(ItemKind::NonFissileUranium, 20.0), io(ItemKind::Water, 8.0)],
     },
     RecipeInfo {
         name: "Electric Motor",
-        inputs: &[ItemKind::ElectromagneticControlRod, ItemKind::Rotor],
-        outputs: &[ItemKind::Motor],
+        building: Building::Assembler,
+        duration: 16.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::ElectromagneticControlRod, 1.0), io(ItemKind::Rotor, 2.0)],
+        outputs: &[io(ItemKind::Motor, 2.0)],
     },
     RecipeInfo {
         name: "Diluted Fuel",
-        inputs: &[ItemKind::HeavyOilResidue, ItemKind::Water],
-        outputs: &[ItemKind::Fuel],
+        building: Building::Blender,
+        duration: 6.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::HeavyOilResidue, 5.0), io(ItemKind::Water, 10.0)],
+        outputs: &[io(ItemKind::Fuel, 10.0)],
     },
     RecipeInfo {
         name: "Cooling Device",
-        inputs: &[ItemKind::HeatSink, ItemKind::Motor, ItemKind::NitrogenGas],
-        outputs: &[ItemKind::CoolingSystem],
+        building: Building::Blender,
+        duration: 24.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::HeatSink, 4.0), io(ItemKind::Motor, 1.0), io(ItemKind::NitrogenGas, 24.0)],
+        outputs: &[io(ItemKind::CoolingSystem, 2.0)],
     },
     RecipeInfo {
         name: "Classic Battery",
-        inputs: &[ItemKind::Sulfur, ItemKind::AlcladAluminumSheet, ItemKind::Plastic, ItemKind::Wire],
-        outputs: &[ItemKind::Battery],
+        building: Building::Manufacturer,
+        duration: 8.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::Sulfur, 6.0), io(ItemKind::AlcladAluminumSheet, 7.0), io(ItemKind::Plastic, 8.0), io(ItemKind::Wire, 12.0)],
+        outputs: &[io(ItemKind::Battery, 4.0)],
     },
     RecipeInfo {
         name: "Automated Miner",
-        inputs: &[ItemKind::SteelPipe, ItemKind::IronPlate],
-        outputs: &[ItemKind::PortableMiner],
+        building: Building::Assembler,
+        duration: 60.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::SteelPipe, 4.0), io(ItemKind::IronPlate, 4.0)],
+        outputs: &[io(ItemKind::PortableMiner, 1.0)],
     },
     RecipeInfo {
         name: "Alclad Casing",
-        inputs: &[ItemKind::AluminumIngot, ItemKind::CopperIngot],
-        outputs: &[ItemKind::AluminumCasing],
+        building: Building::Assembler,
+        duration: 8.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::AluminumIngot, 20.0), io(ItemKind::CopperIngot, 10.0)],
+        outputs: &[io(ItemKind::AluminumCasing, 15.0)],
     },
     RecipeInfo {
         name: "Molded Steel Pipe",
-        inputs: &[ItemKind::SteelIngot, ItemKind::Concrete],
-        outputs: &[ItemKind::SteelPipe],
+        building: Building::Foundry,
+        duration: 6.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::SteelIngot, 5.0), io(ItemKind::Concrete, 3.0)],
+        outputs: &[io(ItemKind::SteelPipe, 5.0)],
     },
     RecipeInfo {
         name: "Iron Pipe",
-        inputs: &[ItemKind::IronIngot],
-        outputs: &[ItemKind::SteelPipe],
+        building: Building::Constructor,
+        duration: 12.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::IronIngot, 20.0)],
+        outputs: &[io(ItemKind::SteelPipe, 5.0)],
     },
     RecipeInfo {
         name: "Steel Cast Plate",
-        inputs: &[ItemKind::IronIngot, ItemKind::SteelIngot],
-        outputs: &[ItemKind::IronPlate],
+        building: Building::Foundry,
+        duration: 4.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::IronIngot, 1.0), io(ItemKind::SteelIngot, 1.0)],
+        outputs: &[io(ItemKind::IronPlate, 3.0)],
     },
     RecipeInfo {
         name: "Molded Beam",
-        inputs: &[ItemKind::SteelIngot, ItemKind::Concrete],
-        outputs: &[ItemKind::SteelBeam],
+        building: Building::Foundry,
+        duration: 12.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::SteelIngot, 24.0), io(ItemKind::Concrete, 16.0)],
+        outputs: &[io(ItemKind::SteelBeam, 9.0)],
     },
     RecipeInfo {
         name: "Aluminum Beam",
-        inputs: &[ItemKind::AluminumIngot],
-        outputs: &[ItemKind::SteelBeam],
+        building: Building::Constructor,
+        duration: 8.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::AluminumIngot, 3.0)],
+        outputs: &[io(ItemKind::SteelBeam, 3.0)],
     },
     RecipeInfo {
         name: "Aluminum Rod",
-        inputs: &[ItemKind::AluminumIngot],
-        outputs: &[ItemKind::IronRod],
+        building: Building::Constructor,
+        duration: 8.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::AluminumIngot, 1.0)],
+        outputs: &[io(ItemKind::IronRod, 7.0)],
     },
     RecipeInfo {
         name: "Plastic AI Limiter",
-        inputs: &[ItemKind::Quickwire, ItemKind::Plastic],
-        outputs: &[ItemKind::AiLimiter],
+        building: Building::Assembler,
+        duration: 15.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::Quickwire, 30.0), io(ItemKind::Plastic, 7.0)],
+        outputs: &[io(ItemKind::AiLimiter, 2.0)],
     },
     RecipeInfo {
         name: "Distilled Silica",
-        inputs: &[ItemKind::DissolvedSilica, ItemKind::Limestone, ItemKind::Water],
-        outputs: &[ItemKind::Silica, ItemKind::Water],
+        building: Building::Blender,
+        duration: 6.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::DissolvedSilica, 12.0), io(ItemKind::Limestone, 5.0), io(ItemKind::Water, 10.0)],
+        outputs: &[io(ItemKind::Silica, 27.0), io(ItemKind::Water, 8.0)],
     },
     RecipeInfo {
         name: "Quartz Purification",
-        inputs: &[ItemKind::RawQuartz, ItemKind::NitricAcid],
-        outputs: &[ItemKind::QuartzCrystal, ItemKind::DissolvedSilica],
+        building: Building::Refinery,
+        duration: 12.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::RawQuartz, 24.0), io(ItemKind::NitricAcid, 2.0)],
+        outputs: &[io(ItemKind::QuartzCrystal, 15.0), io(ItemKind::DissolvedSilica, 12.0)],
     },
     RecipeInfo {
         name: "Fused Quartz Crystal",
-        inputs: &[ItemKind::RawQuartz, ItemKind::Coal],
-        outputs: &[ItemKind::QuartzCrystal],
+        building: Building::Foundry,
+        duration: 20.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::RawQuartz, 25.0), io(ItemKind::Coal, 12.0)],
+        outputs: &[io(ItemKind::QuartzCrystal, 18.0)],
     },
     RecipeInfo {
         name: "Leached Iron ingot",
-        inputs: &[ItemKind::IronOre, ItemKind::SulfuricAcid],
-        outputs: &[ItemKind::IronIngot],
+        building: Building::Refinery,
+        duration: 6.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::IronOre, 5.0), io(ItemKind::SulfuricAcid, 1.0)],
+        outputs: &[io(ItemKind::IronIngot, 10.0)],
     },
     RecipeInfo {
         name: "Basic Iron Ingot",
-        inputs: &[ItemKind::IronOre, ItemKind::Limestone],
-        outputs: &[ItemKind::IronIngot],
+        building: Building::Foundry,
+        duration: 12.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::IronOre, 5.0), io(ItemKind::Limestone, 8.0)],
+        outputs: &[io(ItemKind::IronIngot, 10.0)],
     },
     RecipeInfo {
         name: "Tempered Copper Ingot",
-        inputs: &[ItemKind::CopperOre, ItemKind::PetroleumCoke],
-        outputs: &[ItemKind::CopperIngot],
+        building: Building::Foundry,
+        duration: 12.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::CopperOre, 5.0), io(ItemKind::PetroleumCoke, 8.0)],
+        outputs: &[io(ItemKind::CopperIngot, 12.0)],
     },
     RecipeInfo {
         name: "Leached Copper Ingot",
-        inputs: &[ItemKind::CopperOre, ItemKind::SulfuricAcid],
-        outputs: &[ItemKind::CopperIngot],
+        building: Building::Refinery,
+        duration: 12.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::CopperOre, 9.0), io(ItemKind::SulfuricAcid, 5.0)],
+        outputs: &[io(ItemKind::CopperIngot, 22.0)],
     },
     RecipeInfo {
         name: "Tempered Caterium Ingot",
-        inputs: &[ItemKind::CateriumOre, ItemKind::PetroleumCoke],
-        outputs: &[ItemKind::CateriumIngot],
+        building: Building::Foundry,
+        duration: 8.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::CateriumOre, 6.0), io(ItemKind::PetroleumCoke, 2.0)],
+        outputs: &[io(ItemKind::CateriumIngot, 3.0)],
     },
     RecipeInfo {
         name: "Leached Caterium Ingot",
-        inputs: &[ItemKind::CateriumOre, ItemKind::SulfuricAcid],
-        outputs: &[ItemKind::CateriumIngot],
+        building: Building::Refinery,
+        duration: 10.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::CateriumOre, 9.0), io(ItemKind::SulfuricAcid, 5.0)],
+        outputs: &[io(ItemKind::CateriumIngot, 6.0)],
     },
     RecipeInfo {
         name: "Caterium Wire",
-        inputs: &[ItemKind::CateriumIngot],
-        outputs: &[ItemKind::Wire],
+        building: Building::Constructor,
+        duration: 4.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::CateriumIngot, 1.0)],
+        outputs: &[io(ItemKind::Wire, 8.0)],
     },
     RecipeInfo {
         name: "Iron Wire",
-        inputs: &[ItemKind::IronIngot],
-        outputs: &[ItemKind::Wire],
+        building: Building::Constructor,
+        duration: 24.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::IronIngot, 5.0)],
+        outputs: &[io(ItemKind::Wire, 9.0)],
     },
     RecipeInfo {
         name: "Infused Uranium Cell",
-        inputs: &[ItemKind::Uranium, ItemKind::Silica, ItemKind::Sulfur, ItemKind::Quickwire],
-        outputs: &[ItemKind::EncasedUraniumCell],
+        building: Building::Manufacturer,
+        duration: 12.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::Uranium, 5.0), io(ItemKind::Silica, 3.0), io(ItemKind::Sulfur, 5.0), io(ItemKind::Quickwire, 15.0)],
+        outputs: &[io(ItemKind::EncasedUraniumCell, 4.0)],
     },
     RecipeInfo {
         name: "Caterium Ingot",
-        inputs: &[ItemKind::CateriumOre],
-        outputs: &[ItemKind::CateriumIngot],
+        building: Building::Smelter,
+        duration: 4.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::Caterium },
+        power_range: None,
+        inputs: &[io(ItemKind::CateriumOre, 3.0)],
+        outputs: &[io(ItemKind::CateriumIngot, 1.0)],
     },
     RecipeInfo {
         name: "Turbo Electric Motor",
-        inputs: &[ItemKind::Motor, ItemKind::RadioControlUnit, ItemKind::ElectromagneticControlRod, ItemKind::Rotor],
-        outputs: &[ItemKind::TurboMotor],
+        building: Building::Manufacturer,
+        duration: 64.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::Motor, 7.0), io(ItemKind::RadioControlUnit, 9.0), io(ItemKind::ElectromagneticControlRod, 5.0), io(ItemKind::Rotor, 7.0)],
+        outputs: &[io(ItemKind::TurboMotor, 3.0)],
     },
     RecipeInfo {
         name: "Turbo Motor",
-        inputs: &[ItemKind::CoolingSystem, ItemKind::RadioControlUnit, ItemKind::Motor, ItemKind::Rubber],
-        outputs: &[ItemKind::TurboMotor],
+        building: Building::Manufacturer,
+        duration: 32.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 8 },
+        power_range: None,
+        inputs: &[io(ItemKind::CoolingSystem, 4.0), io(ItemKind::RadioControlUnit, 2.0), io(ItemKind::Motor, 4.0), io(ItemKind::Rubber, 24.0)],
+        outputs: &[io(ItemKind::TurboMotor, 1.0)],
     },
     RecipeInfo {
         name: "Thermal Propulsion Rocket",
-        inputs: &[ItemKind::ModularEngine, ItemKind::TurboMotor, ItemKind::CoolingSystem, ItemKind::FusedModularFrame],
-        outputs: &[ItemKind::ThermalPropulsionRocket],
+        building: Building::Manufacturer,
+        duration: 120.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 8 },
+        power_range: None,
+        inputs: &[io(ItemKind::ModularEngine, 5.0), io(ItemKind::TurboMotor, 2.0), io(ItemKind::CoolingSystem, 6.0), io(ItemKind::FusedModularFrame, 2.0)],
+        outputs: &[io(ItemKind::ThermalPropulsionRocket, 2.0)],
     },
     RecipeInfo {
         name: "Quickwire Stator",
-        inputs: &[ItemKind::SteelPipe, ItemKind::Quickwire],
-        outputs: &[ItemKind::Stator],
+        building: Building::Assembler,
+        duration: 15.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::SteelPipe, 4.0), io(ItemKind::Quickwire, 15.0)],
+        outputs: &[io(ItemKind::Stator, 2.0)],
     },
     RecipeInfo {
         name: "Cheap Silica",
-        inputs: &[ItemKind::RawQuartz, ItemKind::Limestone],
-        outputs: &[ItemKind::Silica],
+        building: Building::Assembler,
+        duration: 8.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::RawQuartz, 3.0), io(ItemKind::Limestone, 5.0)],
+        outputs: &[io(ItemKind::Silica, 7.0)],
     },
     RecipeInfo {
         name: "Steel Screw",
-        inputs: &[ItemKind::SteelBeam],
-        outputs: &[ItemKind::Screw],
+        building: Building::Constructor,
+        duration: 12.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::SteelBeam, 1.0)],
+        outputs: &[io(ItemKind::Screw, 52.0)],
     },
     RecipeInfo {
         name: "Cast Screw",
-        inputs: &[ItemKind::IronIngot],
-        outputs: &[ItemKind::Screw],
+        building: Building::Constructor,
+        duration: 24.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::IronIngot, 5.0)],
+        outputs: &[io(ItemKind::Screw, 20.0)],
     },
     RecipeInfo {
         name: "Steel Rotor",
-        inputs: &[ItemKind::SteelPipe, ItemKind::Wire],
-        outputs: &[ItemKind::Rotor],
+        building: Building::Assembler,
+        duration: 12.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::SteelPipe, 2.0), io(ItemKind::Wire, 6.0)],
+        outputs: &[io(ItemKind::Rotor, 1.0)],
     },
     RecipeInfo {
         name: "Encased Industrial Pipe",
-        inputs: &[ItemKind::SteelPipe, ItemKind::Concrete],
-        outputs: &[ItemKind::EncasedIndustrialBeam],
+        building: Building::Assembler,
+        duration: 15.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::SteelPipe, 6.0), io(ItemKind::Concrete, 5.0)],
+        outputs: &[io(ItemKind::EncasedIndustrialBeam, 1.0)],
     },
     RecipeInfo {
         name: "Stitched Iron Plate",
-        inputs: &[ItemKind::IronPlate, ItemKind::Wire],
-        outputs: &[ItemKind::ReinforcedIronPlate],
+        building: Building::Assembler,
+        duration: 32.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::IronPlate, 10.0), io(ItemKind::Wire, 20.0)],
+        outputs: &[io(ItemKind::ReinforcedIronPlate, 3.0)],
     },
     RecipeInfo {
         name: "Bolted Iron Plate",
-        inputs: &[ItemKind::IronPlate, ItemKind::Screw],
-        outputs: &[ItemKind::ReinforcedIronPlate],
+        building: Building::Assembler,
+        duration: 12.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::IronPlate, 18.0), io(ItemKind::Screw, 50.0)],
+        outputs: &[io(ItemKind::ReinforcedIronPlate, 3.0)],
     },
     RecipeInfo {
         name: "Radio Connection Unit",
-        inputs: &[ItemKind::HeatSink, ItemKind::HighSpeedConnector, ItemKind::QuartzCrystal],
-        outputs: &[ItemKind::RadioControlUnit],
+        building: Building::Manufacturer,
+        duration: 16.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::HeatSink, 4.0), io(ItemKind::HighSpeedConnector, 2.0), io(ItemKind::QuartzCrystal, 12.0)],
+        outputs: &[io(ItemKind::RadioControlUnit, 1.0)],
     },
     RecipeInfo {
         name: "Fused Quickwire",
-        inputs: &[ItemKind::CateriumIngot, ItemKind::CopperIngot],
-        outputs: &[ItemKind::Quickwire],
+        building: Building::Assembler,
+        duration: 8.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::CateriumIngot, 1.0), io(ItemKind::CopperIngot, 5.0)],
+        outputs: &[io(ItemKind::Quickwire, 12.0)],
     },
     RecipeInfo {
         name: "Recycled Plastic",
-        inputs: &[ItemKind::Rubber, ItemKind::Fuel],
-        outputs: &[ItemKind::Plastic],
+        building: Building::Refinery,
+        duration: 12.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::Rubber, 6.0), io(ItemKind::Fuel, 6.0)],
+        outputs: &[io(ItemKind::Plastic, 12.0)],
     },
     RecipeInfo {
         name: "Uranium Fuel Unit",
-        inputs: &[ItemKind::EncasedUraniumCell, ItemKind::ElectromagneticControlRod, ItemKind::CrystalOscillator, ItemKind::Rotor],
-        outputs: &[ItemKind::UraniumFuelRod],
+        building: Building::Manufacturer,
+        duration: 300.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::EncasedUraniumCell, 100.0), io(ItemKind::ElectromagneticControlRod, 10.0), io(ItemKind::CrystalOscillator, 3.0), io(ItemKind::Rotor, 10.0)],
+        outputs: &[io(ItemKind::UraniumFuelRod, 3.0)],
     },
     RecipeInfo {
         name: "Rigor Motor",
-        inputs: &[ItemKind::Rotor, ItemKind::Stator, ItemKind::CrystalOscillator],
-        outputs: &[ItemKind::Motor],
+        building: Building::Manufacturer,
+        duration: 48.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::Rotor, 3.0), io(ItemKind::Stator, 3.0), io(ItemKind::CrystalOscillator, 1.0)],
+        outputs: &[io(ItemKind::Motor, 6.0)],
     },
     RecipeInfo {
         name: "Steeled Frame",
-        inputs: &[ItemKind::ReinforcedIronPlate, ItemKind::SteelPipe],
-        outputs: &[ItemKind::ModularFrame],
+        building: Building::Assembler,
+        duration: 60.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::ReinforcedIronPlate, 2.0), io(ItemKind::SteelPipe, 10.0)],
+        outputs: &[io(ItemKind::ModularFrame, 3.0)],
     },
     RecipeInfo {
         name: "Compacted Steel Ingot",
-        inputs: &[ItemKind::IronOre, ItemKind::CompactedCoal],
-        outputs: &[ItemKind::SteelIngot],
+        building: Building::Foundry,
+        duration: 24.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::IronOre, 2.0), io(ItemKind::CompactedCoal, 1.0)],
+        outputs: &[io(ItemKind::SteelIngot, 4.0)],
     },
     RecipeInfo {
         name: "Solid Steel Ingot",
-        inputs: &[ItemKind::IronIngot, ItemKind::Coal],
-        outputs: &[ItemKind::SteelIngot],
+        building: Building::Foundry,
+        duration: 3.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::IronIngot, 2.0), io(ItemKind::Coal, 2.0)],
+        outputs: &[io(ItemKind::SteelIngot, 3.0)],
     },
     RecipeInfo {
         name: "Iron Alloy Ingot",
-        inputs: &[ItemKind::IronOre, ItemKind::CopperOre],
-        outputs: &[ItemKind::IronIngot],
+        building: Building::Foundry,
+        duration: 12.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::IronOre, 8.0), io(ItemKind::CopperOre, 2.0)],
+        outputs: &[io(ItemKind::IronIngot, 15.0)],
     },
     RecipeInfo {
         name: "Silicon High-Speed Connector",
-        inputs: &[ItemKind::Quickwire, ItemKind::Silica, ItemKind::CircuitBoard],
-        outputs: &[ItemKind::HighSpeedConnector],
+        building: Building::Manufacturer,
+        duration: 40.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::Quickwire, 60.0), io(ItemKind::Silica, 25.0), io(ItemKind::CircuitBoard, 2.0)],
+        outputs: &[io(ItemKind::HighSpeedConnector, 2.0)],
     },
     RecipeInfo {
         name: "Heavy Encased Frame",
-        inputs: &[ItemKind::ModularFrame, ItemKind::EncasedIndustrialBeam, ItemKind::SteelPipe, ItemKind::Concrete],
-        outputs: &[ItemKind::HeavyModularFrame],
+        building: Building::Manufacturer,
+        duration: 64.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::ModularFrame, 8.0), io(ItemKind::EncasedIndustrialBeam, 10.0), io(ItemKind::SteelPipe, 36.0), io(ItemKind::Concrete, 22.0)],
+        outputs: &[io(ItemKind::HeavyModularFrame, 3.0)],
     },
     RecipeInfo {
         name: "Heat Exchanger",
-        inputs: &[ItemKind::AluminumCasing, ItemKind::Rubber],
-        outputs: &[ItemKind::HeatSink],
+        building: Building::Assembler,
+        duration: 6.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::AluminumCasing, 3.0), io(ItemKind::Rubber, 3.0)],
+        outputs: &[io(ItemKind::HeatSink, 1.0)],
     },
     RecipeInfo {
         name: "Fine Black Powder",
-        inputs: &[ItemKind::Sulfur, ItemKind::CompactedCoal],
-        outputs: &[ItemKind::BlackPowder],
+        building: Building::Assembler,
+        duration: 8.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::Sulfur, 1.0), io(ItemKind::CompactedCoal, 2.0)],
+        outputs: &[io(ItemKind::BlackPowder, 6.0)],
     },
     RecipeInfo {
         name: "Electromagnetic Connection Rod",
-        inputs: &[ItemKind::Stator, ItemKind::HighSpeedConnector],
-        outputs: &[ItemKind::ElectromagneticControlRod],
+        building: Building::Assembler,
+        duration: 15.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::Stator, 2.0), io(ItemKind::HighSpeedConnector, 1.0)],
+        outputs: &[io(ItemKind::ElectromagneticControlRod, 2.0)],
     },
     RecipeInfo {
         name: "Insulated Crystal Oscillator",
-        inputs: &[ItemKind::QuartzCrystal, ItemKind::Rubber, ItemKind::AiLimiter],
-        outputs: &[ItemKind::CrystalOscillator],
+        building: Building::Manufacturer,
+        duration: 32.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::QuartzCrystal, 10.0), io(ItemKind::Rubber, 7.0), io(ItemKind::AiLimiter, 1.0)],
+        outputs: &[io(ItemKind::CrystalOscillator, 1.0)],
     },
     RecipeInfo {
         name: "Fine Concrete",
-        inputs: &[ItemKind::Silica, ItemKind::Limestone],
-        outputs: &[ItemKind::Concrete],
+        building: Building::Assembler,
+        duration: 12.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::Silica, 3.0), io(ItemKind::Limestone, 12.0)],
+        outputs: &[io(ItemKind::Concrete, 10.0)],
     },
     RecipeInfo {
         name: "Crystal Computer",
-        inputs: &[ItemKind::CircuitBoard, ItemKind::CrystalOscillator],
-        outputs: &[ItemKind::Computer],
+        building: Building::Assembler,
+        duration: 36.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::CircuitBoard, 3.0), io(ItemKind::CrystalOscillator, 1.0)],
+        outputs: &[io(ItemKind::Computer, 2.0)],
     },
     RecipeInfo {
         name: "Caterium Computer",
-        inputs: &[ItemKind::CircuitBoard, ItemKind::Quickwire, ItemKind::Rubber],
-        outputs: &[ItemKind::Computer],
+        building: Building::Manufacturer,
+        duration: 16.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::CircuitBoard, 4.0), io(ItemKind::Quickwire, 14.0), io(ItemKind::Rubber, 6.0)],
+        outputs: &[io(ItemKind::Computer, 1.0)],
     },
     RecipeInfo {
         name: "Caterium Circuit Board",
-        inputs: &[ItemKind::Plastic, ItemKind::Quickwire],
-        outputs: &[ItemKind::CircuitBoard],
+        building: Building::Assembler,
+        duration: 48.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::Plastic, 10.0), io(ItemKind::Quickwire, 30.0)],
+        outputs: &[io(ItemKind::CircuitBoard, 7.0)],
     },
     RecipeInfo {
         name: "Silicon Circuit Board",
-        inputs: &[ItemKind::CopperSheet, ItemKind::Silica],
-        outputs: &[ItemKind::CircuitBoard],
+        building: Building::Assembler,
+        duration: 24.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::CopperSheet, 11.0), io(ItemKind::Silica, 11.0)],
+        outputs: &[io(ItemKind::CircuitBoard, 5.0)],
     },
     RecipeInfo {
         name: "Quickwire Cable",
-        inputs: &[ItemKind::Quickwire, ItemKind::Rubber],
-        outputs: &[ItemKind::Cable],
+        building: Building::Assembler,
+        duration: 24.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::Quickwire, 3.0), io(ItemKind::Rubber, 2.0)],
+        outputs: &[io(ItemKind::Cable, 11.0)],
     },
     RecipeInfo {
         name: "Insulated Cable",
-        inputs: &[ItemKind::Wire, ItemKind::Rubber],
-        outputs: &[ItemKind::Cable],
+        building: Building::Assembler,
+        duration: 12.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::Wire, 9.0), io(ItemKind::Rubber, 6.0)],
+        outputs: &[io(ItemKind::Cable, 20.0)],
     },
     RecipeInfo {
         name: "Ficsonium",
-        inputs: &[ItemKind::PlutoniumWaste, ItemKind::SingularityCell, ItemKind::DarkMatterResidue],
-        outputs: &[ItemKind::Ficsonium],
+        building: Building::ParticleAccelerator,
+        duration: 6.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 9 },
+        power_range: Some((500.0, 1500.0)),
+        inputs: &[io(ItemKind::PlutoniumWaste, 1.0), io(ItemKind::SingularityCell, 1.0), io(ItemKind::DarkMatterResidue, 20.0)],
+        outputs: &[io(ItemKind::Ficsonium, 1.0)],
     },
     RecipeInfo {
         name: "Ficsonium Fuel Rod",
-        inputs: &[ItemKind::Ficsonium, ItemKind::ElectromagneticControlRod, ItemKind::FicsiteTrigon, ItemKind::ExcitedPhotonicMatter],
-        outputs: &[ItemKind::FicsoniumFuelRod, ItemKind::DarkMatterResidue],
+        building: Building::QuantumEncoder,
+        duration: 24.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 9 },
+        power_range: Some((0.0, 2000.0)),
+        inputs: &[io(ItemKind::Ficsonium, 2.0), io(ItemKind::ElectromagneticControlRod, 2.0), io(ItemKind::FicsiteTrigon, 40.0), io(ItemKind::ExcitedPhotonicMatter, 20.0)],
+        outputs: &[io(ItemKind::FicsoniumFuelRod, 1.0), io(ItemKind::DarkMatterResidue, 20.0)],
     },
     RecipeInfo {
         name: "Singularity Cell",
-        inputs: &[ItemKind::NuclearPasta, ItemKind::DarkMatterCrystal, ItemKind::IronPlate, ItemKind::Concrete],
-        outputs: &[ItemKind::SingularityCell],
+        building: Building::Manufacturer,
+        duration: 60.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 9 },
+        power_range: Some((0.0, 0.0)),
+        inputs: &[io(ItemKind::NuclearPasta, 1.0), io(ItemKind::DarkMatterCrystal, 20.0), io(ItemKind::IronPlate, 100.0), io(ItemKind::Concrete, 200.0)],
+        outputs: &[io(ItemKind::SingularityCell, 10.0)],
     },
     RecipeInfo {
         name: "Ballistic Warp Drive",
-        inputs: &[ItemKind::ThermalPropulsionRocket, ItemKind::SingularityCell, ItemKind::SuperpositionOscillator, ItemKind::DarkMatterCrystal],
-        outputs: &[ItemKind::BallisticWarpDrive],
+        building: Building::Manufacturer,
+        duration: 60.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 9 },
+        power_range: Some((500.0, 1500.0)),
+        inputs: &[io(ItemKind::ThermalPropulsionRocket, 1.0), io(ItemKind::SingularityCell, 5.0), io(ItemKind::SuperpositionOscillator, 2.0), io(ItemKind::DarkMatterCrystal, 40.0)],
+        outputs: &[io(ItemKind::BallisticWarpDrive, 1.0)],
     },
     RecipeInfo {
         name: "Iodine-Infused Filter",
-        inputs: &[ItemKind::GasFilter, ItemKind::Quickwire, ItemKind::AluminumCasing],
-        outputs: &[ItemKind::IodineInfusedFilter],
+        building: Building::Manufacturer,
+        duration: 16.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::Quartz },
+        power_range: None,
+        inputs: &[io(ItemKind::GasFilter, 1.0), io(ItemKind::Quickwire, 8.0), io(ItemKind::AluminumCasing, 1.0)],
+        outputs: &[io(ItemKind::IodineInfusedFilter, 1.0)],
     },
     RecipeInfo {
         name: "Quickwire",
-        inputs: &[ItemKind::CateriumIngot],
-        outputs: &[ItemKind::Quickwire],
+        building: Building::Constructor,
+        duration: 5.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::Caterium },
+        power_range: None,
+        inputs: &[io(ItemKind::CateriumIngot, 1.0)],
+        outputs: &[io(ItemKind::Quickwire, 5.0)],
     },
     RecipeInfo {
         name: "Solid Biofuel",
-        inputs: &[ItemKind::Biomass],
-        outputs: &[ItemKind::SolidBiofuel],
+        building: Building::Constructor,
+        duration: 4.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 2 },
+        power_range: None,
+        inputs: &[io(ItemKind::Biomass, 8.0)],
+        outputs: &[io(ItemKind::SolidBiofuel, 4.0)],
     },
     RecipeInfo {
         name: "Hog Protein",
-        inputs: &[ItemKind::HogRemains],
-        outputs: &[ItemKind::AlienProtein],
+        building: Building::Constructor,
+        duration: 3.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::AlienOrganisms },
+        power_range: None,
+        inputs: &[io(ItemKind::HogRemains, 1.0)],
+        outputs: &[io(ItemKind::AlienProtein, 1.0)],
     },
     RecipeInfo {
         name: "Spitter Protein",
-        inputs: &[ItemKind::SpitterRemains],
-        outputs: &[ItemKind::AlienProtein],
+        building: Building::Constructor,
+        duration: 3.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::AlienOrganisms },
+        power_range: None,
+        inputs: &[io(ItemKind::SpitterRemains, 1.0)],
+        outputs: &[io(ItemKind::AlienProtein, 1.0)],
     },
     RecipeInfo {
         name: "Biomass (Mycelia)",
-        inputs: &[ItemKind::Mycelia],
-        outputs: &[ItemKind::Biomass],
+        building: Building::Constructor,
+        duration: 4.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::Mycelia },
+        power_range: None,
+        inputs: &[io(ItemKind::Mycelia, 1.0)],
+        outputs: &[io(ItemKind::Biomass, 10.0)],
     },
     RecipeInfo {
         name: "Power Shard (1)",
-        inputs: &[ItemKind::BluePowerSlug],
-        outputs: &[ItemKind::PowerShard],
+        building: Building::Constructor,
+        duration: 8.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::PowerSlugs },
+        power_range: None,
+        inputs: &[io(ItemKind::BluePowerSlug, 1.0)],
+        outputs: &[io(ItemKind::PowerShard, 1.0)],
     },
     RecipeInfo {
         name: "Black Powder",
-        inputs: &[ItemKind::Coal, ItemKind::Sulfur],
-        outputs: &[ItemKind::BlackPowder],
+        building: Building::Assembler,
+        duration: 4.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::Sulfur },
+        power_range: None,
+        inputs: &[io(ItemKind::Coal, 1.0), io(ItemKind::Sulfur, 1.0)],
+        outputs: &[io(ItemKind::BlackPowder, 2.0)],
     },
     RecipeInfo {
         name: "Alien Power Matrix",
-        inputs: &[ItemKind::SamFluctuator, ItemKind::PowerShard, ItemKind::SuperpositionOscillator, ItemKind::ExcitedPhotonicMatter],
-        outputs: &[ItemKind::AlienPowerMatrix, ItemKind::DarkMatterResidue],
+        building: Building::QuantumEncoder,
+        duration: 24.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::AlienTechnology },
+        power_range: Some((0.0, 2000.0)),
+        inputs: &[io(ItemKind::SamFluctuator, 5.0), io(ItemKind::PowerShard, 3.0), io(ItemKind::SuperpositionOscillator, 3.0), io(ItemKind::ExcitedPhotonicMatter, 24.0)],
+        outputs: &[io(ItemKind::AlienPowerMatrix, 1.0), io(ItemKind::DarkMatterResidue, 24.0)],
     },
     RecipeInfo {
         name: "Stinger Protein",
-        inputs: &[ItemKind::StingerRemains],
-        outputs: &[ItemKind::AlienProtein],
+        building: Building::Constructor,
+        duration: 3.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::AlienOrganisms },
+        power_range: None,
+        inputs: &[io(ItemKind::StingerRemains, 1.0)],
+        outputs: &[io(ItemKind::AlienProtein, 1.0)],
     },
     RecipeInfo {
         name: "Hatcher Protein",
-        inputs: &[ItemKind::HatcherRemains],
-        outputs: &[ItemKind::AlienProtein],
+        building: Building::Constructor,
+        duration: 3.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::AlienOrganisms },
+        power_range: None,
+        inputs: &[io(ItemKind::HatcherRemains, 1.0)],
+        outputs: &[io(ItemKind::AlienProtein, 1.0)],
     },
     RecipeInfo {
         name: "Alien DNA Capsule",
-        inputs: &[ItemKind::AlienProtein],
-        outputs: &[ItemKind::AlienDnaCapsule],
+        building: Building::Constructor,
+        duration: 6.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::AlienOrganisms },
+        power_range: None,
+        inputs: &[io(ItemKind::AlienProtein, 1.0)],
+        outputs: &[io(ItemKind::AlienDnaCapsule, 1.0)],
     },
     RecipeInfo {
         name: "Biomass (Alien Protein)",
-        inputs: &[ItemKind::AlienProtein],
-        outputs: &[ItemKind::Biomass],
+        building: Building::Constructor,
+        duration: 4.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::AlienOrganisms },
+        power_range: None,
+        inputs: &[io(ItemKind::AlienProtein, 1.0)],
+        outputs: &[io(ItemKind::Biomass, 100.0)],
     },
     RecipeInfo {
         name: "Iron Rebar",
-        inputs: &[ItemKind::IronRod],
-        outputs: &[ItemKind::IronRebar],
+        building: Building::Constructor,
+        duration: 4.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 2 },
+        power_range: None,
+        inputs: &[io(ItemKind::IronRod, 1.0)],
+        outputs: &[io(ItemKind::IronRebar, 1.0)],
     },
     RecipeInfo {
         name: "Homing Rifle Ammo",
-        inputs: &[ItemKind::RifleAmmo, ItemKind::HighSpeedConnector],
-        outputs: &[ItemKind::HomingRifleAmmo],
+        building: Building::Assembler,
+        duration: 24.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::Caterium },
+        power_range: None,
+        inputs: &[io(ItemKind::RifleAmmo, 20.0), io(ItemKind::HighSpeedConnector, 1.0)],
+        outputs: &[io(ItemKind::HomingRifleAmmo, 10.0)],
     },
     RecipeInfo {
         name: "Stun Rebar",
-        inputs: &[ItemKind::IronRebar, ItemKind::Quickwire],
-        outputs: &[ItemKind::StunRebar],
+        building: Building::Assembler,
+        duration: 6.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::Quartz },
+        power_range: None,
+        inputs: &[io(ItemKind::IronRebar, 1.0), io(ItemKind::Quickwire, 5.0)],
+        outputs: &[io(ItemKind::StunRebar, 1.0)],
     },
     RecipeInfo {
         name: "Gas Filter",
-        inputs: &[ItemKind::Fabric, ItemKind::Coal, ItemKind::IronPlate],
-        outputs: &[ItemKind::GasFilter],
+        building: Building::Manufacturer,
+        duration: 8.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::Mycelia },
+        power_range: None,
+        inputs: &[io(ItemKind::Fabric, 2.0), io(ItemKind::Coal, 4.0), io(ItemKind::IronPlate, 2.0)],
+        outputs: &[io(ItemKind::GasFilter, 1.0)],
     },
     RecipeInfo {
         name: "Gas Nobelisk",
-        inputs: &[ItemKind::Nobelisk, ItemKind::Biomass],
-        outputs: &[ItemKind::GasNobelisk],
+        building: Building::Assembler,
+        duration: 12.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::AlienOrganisms },
+        power_range: None,
+        inputs: &[io(ItemKind::Nobelisk, 1.0), io(ItemKind::Biomass, 10.0)],
+        outputs: &[io(ItemKind::GasNobelisk, 1.0)],
     },
     RecipeInfo {
         name: "Polyester Fabric",
-        inputs: &[ItemKind::PolymerResin, ItemKind::Water],
-        outputs: &[ItemKind::Fabric],
+        building: Building::Refinery,
+        duration: 2.0,
+        alternative: true,
+        unlock: Unlock::HardDrive,
+        power_range: None,
+        inputs: &[io(ItemKind::PolymerResin, 1.0), io(ItemKind::Water, 1.0)],
+        outputs: &[io(ItemKind::Fabric, 1.0)],
     },
     RecipeInfo {
         name: "Fabric",
-        inputs: &[ItemKind::Mycelia, ItemKind::Biomass],
-        outputs: &[ItemKind::Fabric],
+        building: Building::Assembler,
+        duration: 4.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::Mycelia },
+        power_range: None,
+        inputs: &[io(ItemKind::Mycelia, 1.0), io(ItemKind::Biomass, 5.0)],
+        outputs: &[io(ItemKind::Fabric, 1.0)],
     },
     RecipeInfo {
         name: "Synthetic Power Shard",
-        inputs: &[ItemKind::TimeCrystal, ItemKind::DarkMatterCrystal, ItemKind::QuartzCrystal, ItemKind::ExcitedPhotonicMatter],
-        outputs: &[ItemKind::PowerShard, ItemKind::DarkMatterResidue],
+        building: Building::QuantumEncoder,
+        duration: 12.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::AlienTechnology },
+        power_range: Some((0.0, 2000.0)),
+        inputs: &[io(ItemKind::TimeCrystal, 2.0), io(ItemKind::DarkMatterCrystal, 2.0), io(ItemKind::QuartzCrystal, 12.0), io(ItemKind::ExcitedPhotonicMatter, 12.0)],
+        outputs: &[io(ItemKind::PowerShard, 1.0), io(ItemKind::DarkMatterResidue, 12.0)],
     },
     RecipeInfo {
         name: "Power Shard (5)",
-        inputs: &[ItemKind::PurplePowerSlug],
-        outputs: &[ItemKind::PowerShard],
+        building: Building::Constructor,
+        duration: 24.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::PowerSlugs },
+        power_range: None,
+        inputs: &[io(ItemKind::PurplePowerSlug, 1.0)],
+        outputs: &[io(ItemKind::PowerShard, 5.0)],
     },
     RecipeInfo {
         name: "Power Shard (2)",
-        inputs: &[ItemKind::YellowPowerSlug],
-        outputs: &[ItemKind::PowerShard],
+        building: Building::Constructor,
+        duration: 12.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::PowerSlugs },
+        power_range: None,
+        inputs: &[io(ItemKind::YellowPowerSlug, 1.0)],
+        outputs: &[io(ItemKind::PowerShard, 2.0)],
     },
     RecipeInfo {
         name: "Pulse Nobelisk",
-        inputs: &[ItemKind::Nobelisk, ItemKind::CrystalOscillator],
-        outputs: &[ItemKind::PulseNobelisk],
+        building: Building::Assembler,
+        duration: 60.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::Quartz },
+        power_range: None,
+        inputs: &[io(ItemKind::Nobelisk, 5.0), io(ItemKind::CrystalOscillator, 1.0)],
+        outputs: &[io(ItemKind::PulseNobelisk, 5.0)],
     },
     RecipeInfo {
         name: "Shatter Rebar",
-        inputs: &[ItemKind::IronRebar, ItemKind::QuartzCrystal],
-        outputs: &[ItemKind::ShatterRebar],
+        building: Building::Assembler,
+        duration: 12.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::Quartz },
+        power_range: None,
+        inputs: &[io(ItemKind::IronRebar, 2.0), io(ItemKind::QuartzCrystal, 3.0)],
+        outputs: &[io(ItemKind::ShatterRebar, 1.0)],
     },
     RecipeInfo {
         name: "Turbo Rifle Ammo",
-        inputs: &[ItemKind::RifleAmmo, ItemKind::AluminumCasing, ItemKind::PackagedTurbofuel],
-        outputs: &[ItemKind::TurboRifleAmmo],
+        building: Building::Manufacturer,
+        duration: 12.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::Sulfur },
+        power_range: None,
+        inputs: &[io(ItemKind::RifleAmmo, 25.0), io(ItemKind::AluminumCasing, 3.0), io(ItemKind::PackagedTurbofuel, 3.0)],
+        outputs: &[io(ItemKind::TurboRifleAmmo, 50.0)],
     },
     RecipeInfo {
         name: "Turbo Rifle Ammo",
-        inputs: &[ItemKind::RifleAmmo, ItemKind::AluminumCasing, ItemKind::Turbofuel],
-        outputs: &[ItemKind::TurboRifleAmmo],
+        building: Building::Blender,
+        duration: 12.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::Sulfur },
+        power_range: None,
+        inputs: &[io(ItemKind::RifleAmmo, 25.0), io(ItemKind::AluminumCasing, 3.0), io(ItemKind::Turbofuel, 3.0)],
+        outputs: &[io(ItemKind::TurboRifleAmmo, 50.0)],
     },
     RecipeInfo {
         name: "Nuke Nobelisk",
-        inputs: &[ItemKind::Nobelisk, ItemKind::EncasedUraniumCell, ItemKind::SmokelessPowder, ItemKind::AiLimiter],
-        outputs: &[ItemKind::NukeNobelisk],
+        building: Building::Manufacturer,
+        duration: 120.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::Sulfur },
+        power_range: None,
+        inputs: &[io(ItemKind::Nobelisk, 5.0), io(ItemKind::EncasedUraniumCell, 20.0), io(ItemKind::SmokelessPowder, 10.0), io(ItemKind::AiLimiter, 6.0)],
+        outputs: &[io(ItemKind::NukeNobelisk, 1.0)],
     },
     RecipeInfo {
         name: "Rifle Ammo",
-        inputs: &[ItemKind::CopperSheet, ItemKind::SmokelessPowder],
-        outputs: &[ItemKind::RifleAmmo],
+        building: Building::Assembler,
+        duration: 12.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::Sulfur },
+        power_range: None,
+        inputs: &[io(ItemKind::CopperSheet, 3.0), io(ItemKind::SmokelessPowder, 2.0)],
+        outputs: &[io(ItemKind::RifleAmmo, 15.0)],
     },
     RecipeInfo {
         name: "Explosive Rebar",
-        inputs: &[ItemKind::IronRebar, ItemKind::SmokelessPowder, ItemKind::SteelPipe],
-        outputs: &[ItemKind::ExplosiveRebar],
+        building: Building::Manufacturer,
+        duration: 12.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::Sulfur },
+        power_range: None,
+        inputs: &[io(ItemKind::IronRebar, 2.0), io(ItemKind::SmokelessPowder, 2.0), io(ItemKind::SteelPipe, 2.0)],
+        outputs: &[io(ItemKind::ExplosiveRebar, 1.0)],
     },
     RecipeInfo {
         name: "Cluster Nobelisk",
-        inputs: &[ItemKind::Nobelisk, ItemKind::SmokelessPowder],
-        outputs: &[ItemKind::ClusterNobelisk],
+        building: Building::Assembler,
+        duration: 24.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::Sulfur },
+        power_range: None,
+        inputs: &[io(ItemKind::Nobelisk, 3.0), io(ItemKind::SmokelessPowder, 4.0)],
+        outputs: &[io(ItemKind::ClusterNobelisk, 1.0)],
     },
     RecipeInfo {
         name: "Nobelisk",
-        inputs: &[ItemKind::BlackPowder, ItemKind::SteelPipe],
-        outputs: &[ItemKind::Nobelisk],
+        building: Building::Assembler,
+        duration: 6.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::Sulfur },
+        power_range: None,
+        inputs: &[io(ItemKind::BlackPowder, 2.0), io(ItemKind::SteelPipe, 2.0)],
+        outputs: &[io(ItemKind::Nobelisk, 1.0)],
     },
     RecipeInfo {
         name: "Smokeless Powder",
-        inputs: &[ItemKind::BlackPowder, ItemKind::HeavyOilResidue],
-        outputs: &[ItemKind::SmokelessPowder],
+        building: Building::Refinery,
+        duration: 6.0,
+        alternative: false,
+        unlock: Unlock::Mam { tree: MamTree::Sulfur },
+        power_range: None,
+        inputs: &[io(ItemKind::BlackPowder, 2.0), io(ItemKind::HeavyOilResidue, 1.0)],
+        outputs: &[io(ItemKind::SmokelessPowder, 2.0)],
     },
     RecipeInfo {
         name: "Biomass (Leaves)",
-        inputs: &[ItemKind::Leaves],
-        outputs: &[ItemKind::Biomass],
+        building: Building::Constructor,
+        duration: 5.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 0 },
+        power_range: None,
+        inputs: &[io(ItemKind::Leaves, 10.0)],
+        outputs: &[io(ItemKind::Biomass, 5.0)],
     },
     RecipeInfo {
         name: "Biomass (Wood)",
-        inputs: &[ItemKind::Wood],
-        outputs: &[ItemKind::Biomass],
+        building: Building::Constructor,
+        duration: 4.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 0 },
+        power_range: None,
+        inputs: &[io(ItemKind::Wood, 4.0)],
+        outputs: &[io(ItemKind::Biomass, 20.0)],
     },
     RecipeInfo {
         name: "Reinforced Iron Plate",
-        inputs: &[ItemKind::IronPlate, ItemKind::Screw],
-        outputs: &[ItemKind::ReinforcedIronPlate],
+        building: Building::Assembler,
+        duration: 12.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 0 },
+        power_range: None,
+        inputs: &[io(ItemKind::IronPlate, 6.0), io(ItemKind::Screw, 12.0)],
+        outputs: &[io(ItemKind::ReinforcedIronPlate, 1.0)],
     },
     RecipeInfo {
         name: "Concrete",
-        inputs: &[ItemKind::Limestone],
-        outputs: &[ItemKind::Concrete],
+        building: Building::Constructor,
+        duration: 4.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 0 },
+        power_range: None,
+        inputs: &[io(ItemKind::Limestone, 3.0)],
+        outputs: &[io(ItemKind::Concrete, 1.0)],
     },
     RecipeInfo {
         name: "Screw",
-        inputs: &[ItemKind::IronRod],
-        outputs: &[ItemKind::Screw],
+        building: Building::Constructor,
+        duration: 6.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 0 },
+        power_range: None,
+        inputs: &[io(ItemKind::IronRod, 1.0)],
+        outputs: &[io(ItemKind::Screw, 4.0)],
     },
     RecipeInfo {
         name: "Cable",
-        inputs: &[ItemKind::Wire],
-        outputs: &[ItemKind::Cable],
+        building: Building::Constructor,
+        duration: 2.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 0 },
+        power_range: None,
+        inputs: &[io(ItemKind::Wire, 2.0)],
+        outputs: &[io(ItemKind::Cable, 1.0)],
     },
     RecipeInfo {
         name: "Wire",
-        inputs: &[ItemKind::CopperIngot],
-        outputs: &[ItemKind::Wire],
+        building: Building::Constructor,
+        duration: 4.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 0 },
+        power_range: None,
+        inputs: &[io(ItemKind::CopperIngot, 1.0)],
+        outputs: &[io(ItemKind::Wire, 2.0)],
     },
     RecipeInfo {
         name: "Copper Ingot",
-        inputs: &[ItemKind::CopperOre],
-        outputs: &[ItemKind::CopperIngot],
+        building: Building::Smelter,
+        duration: 2.0,
+        alternative: false,
+        unlock: Unlock::Milestone { tier: 0 },
+        power_range: None,
+        inputs: &[io(ItemKind::CopperOre, 1.0)],
+        outputs: &[io(ItemKind::CopperIngot, 1.0)],
     },
 ];
+
+impl ItemKind {
+    /// Returns all recipes that have this item as output.
+    pub fn produced_by(self) -> &'static [RecipeKind] {
+        PRODUCED_BY[self as u8 as usize]
+    }
+
+    /// Returns all recipes that have this item as input.
+    pub fn consumed_by(self) -> &'static [RecipeKind] {
+        CONSUMED_BY[self as u8 as usize]
+    }
+}
+
+const PRODUCED_BY: [&[RecipeKind]; 152] = [
+    &[RecipeKind::NuclearWaste],
+    &[RecipeKind::PlutoniumWaste],
+    &[RecipeKind::WetConcrete, RecipeKind::RubberConcrete, RecipeKind::FineConcrete, RecipeKind::Concrete],
+    &[RecipeKind::AluminaSolution, RecipeKind::Silica, RecipeKind::DistilledSilica, RecipeKind::CheapSilica],
+    &[RecipeKind::IronPlate, RecipeKind::CoatedIronPlate, RecipeKind::SteelCastPlate],
+    &[RecipeKind::SteelBeam, RecipeKind::MoldedBeam, RecipeKind::AluminumBeam],
+    &[RecipeKind::PureAluminumIngot, RecipeKind::AluminumIngot],
+    &[RecipeKind::Battery, RecipeKind::ClassicBattery],
+    &[RecipeKind::PackagedFuel, RecipeKind::DilutedPackagedFuel],
+    &[RecipeKind::PackagedIonizedFuel],
+    &[RecipeKind::PackagedRocketFuel],
+    &[RecipeKind::PackagedTurbofuel],
+    &[RecipeKind::IodineInfusedFilter],
+    &[RecipeKind::NitroRocketFuel, RecipeKind::RocketFuel, RecipeKind::DarkIonFuel, RecipeKind::IonizedFuel, RecipeKind::CompactedCoal],
+    &[RecipeKind::PackagedHeavyOilResidue],
+    &[RecipeKind::PetroleumCoke],
+    &[RecipeKind::PackagedOil],
+    &[RecipeKind::IronRod, RecipeKind::SteelRod, RecipeKind::AluminumRod],
+    &[RecipeKind::FusedWire, RecipeKind::CateriumWire, RecipeKind::IronWire, RecipeKind::Wire],
+    &[RecipeKind::CoatedCable, RecipeKind::QuickwireCable, RecipeKind::InsulatedCable, RecipeKind::Cable],
+    &[RecipeKind::IronIngot, RecipeKind::PureIronIngot, RecipeKind::LeachedIronIngot, RecipeKind::BasicIronIngot, RecipeKind::IronAlloyIngot],
+    &[RecipeKind::AdheredIronPlate, RecipeKind::StitchedIronPlate, RecipeKind::BoltedIronPlate, RecipeKind::ReinforcedIronPlate],
+    &[RecipeKind::CopperRotor, RecipeKind::Rotor, RecipeKind::SteelRotor],
+    &[RecipeKind::Fuel, RecipeKind::ResidualFuel, RecipeKind::UnpackageFuel, RecipeKind::DilutedFuel],
+    &[RecipeKind::NitroRocketFuel, RecipeKind::RocketFuel, RecipeKind::UnpackageRocketFuel],
+    &[RecipeKind::SteamedCopperSheet, RecipeKind::CopperSheet],
+    &[RecipeKind::ModularFrame, RecipeKind::BoltedFrame, RecipeKind::SteeledFrame],
+    &[RecipeKind::SteelScrew, RecipeKind::CastScrew, RecipeKind::Screw],
+    &[RecipeKind::NitricAcid, RecipeKind::UnpackageNitricAcid],
+    &[RecipeKind::Turbofuel, RecipeKind::UnpackageTurbofuel, RecipeKind::TurboHeavyFuel, RecipeKind::TurboBlendFuel],
+    &[RecipeKind::UnpackageRocketFuel, RecipeKind::UnpackageIonizedFuel, RecipeKind::UnpackageNitricAcid, RecipeKind::EmptyFluidTank, RecipeKind::UnpackageNitrogenGas],
+    &[RecipeKind::CrystalOscillator, RecipeKind::InsulatedCrystalOscillator],
+    &[RecipeKind::Motor, RecipeKind::ElectricMotor, RecipeKind::RigorMotor],
+    &[RecipeKind::DarkMatterCrystal, RecipeKind::DarkMatterTrap, RecipeKind::DarkMatterCrystallization],
+    &[RecipeKind::DarkIonFuel, RecipeKind::IonizedFuel, RecipeKind::UnpackageIonizedFuel],
+    &[RecipeKind::Supercomputer, RecipeKind::SuperStateComputer, RecipeKind::OcSupercomputer],
+    &[RecipeKind::CoolingSystem, RecipeKind::CoolingDevice],
+    &[RecipeKind::FicsiteTrigon],
+    &[RecipeKind::TurboPressureMotor, RecipeKind::TurboElectricMotor, RecipeKind::TurboMotor],
+    &[RecipeKind::TimeCrystal],
+    &[RecipeKind::DarkMatterResidue, RecipeKind::SuperpositionOscillator, RecipeKind::NeuralQuantumProcessor, RecipeKind::AiExpansionServer, RecipeKind::FicsoniumFuelRod, RecipeKind::AlienPowerMatrix, RecipeKind::SyntheticPowerShard],
+    &[RecipeKind::ReanimatedSam],
+    &[RecipeKind::ExcitedPhotonicMatter],
+    &[RecipeKind::TurboDiamonds, RecipeKind::Diamonds, RecipeKind::PinkDiamonds, RecipeKind::PetroleumDiamonds, RecipeKind::OilBasedDiamonds, RecipeKind::CloudyDiamonds],
+    &[RecipeKind::AlcladAluminumSheet],
+    &[RecipeKind::SuperpositionOscillator],
+    &[RecipeKind::NeuralQuantumProcessor],
+    &[RecipeKind::AiExpansionServer],
+    &[RecipeKind::MagneticFieldGenerator],
+    &[RecipeKind::SamFluctuator],
+    &[RecipeKind::SteelPipe, RecipeKind::MoldedSteelPipe, RecipeKind::IronPipe],
+    &[RecipeKind::FusedModularFrame, RecipeKind::HeatFusedFrame],
+    &[RecipeKind::RadioControlUnit, RecipeKind::RadioControlSystem, RecipeKind::RadioConnectionUnit],
+    &[RecipeKind::FicsiteIngotIron, RecipeKind::FicsiteIngotAluminum, RecipeKind::FicsiteIngotCaterium],
+    &[RecipeKind::BiochemicalSculptor],
+    &[RecipeKind::AssemblyDirectorSystem],
+    &[RecipeKind::PureCateriumIngot, RecipeKind::TemperedCateriumIngot, RecipeKind::LeachedCateriumIngot, RecipeKind::CateriumIngot],
+    &[RecipeKind::UnpackageTurbofuel, RecipeKind::SteelCanister, RecipeKind::EmptyCanister, RecipeKind::UnpackageLiquidBiofuel, RecipeKind::UnpackageFuel, RecipeKind::UnpackageOil, RecipeKind::UnpackageHeavyOilResidue, RecipeKind::UnpackageWater, RecipeKind::UnpackageAluminaSolution, RecipeKind::CoatedIronCanister, RecipeKind::UnpackageSulfuricAcid],
+    &[RecipeKind::CircuitBoard, RecipeKind::ElectrodeCircuitBoard, RecipeKind::CateriumCircuitBoard, RecipeKind::SiliconCircuitBoard],
+    &[RecipeKind::Plastic, RecipeKind::ResidualPlastic, RecipeKind::RecycledPlastic],
+    &[RecipeKind::EncasedIndustrialBeam, RecipeKind::EncasedIndustrialPipe],
+    &[RecipeKind::Rubber, RecipeKind::ResidualRubber, RecipeKind::RecycledRubber],
+    &[RecipeKind::Fuel, RecipeKind::PolymerResin, RecipeKind::HeavyOilResidue],
+    &[RecipeKind::Plastic, RecipeKind::Rubber, RecipeKind::UnpackageHeavyOilResidue, RecipeKind::PolymerResin, RecipeKind::HeavyOilResidue],
+    &[RecipeKind::PureQuartzCrystal, RecipeKind::QuartzCrystal, RecipeKind::QuartzPurification, RecipeKind::FusedQuartzCrystal],
+    &[RecipeKind::SteelIngot, RecipeKind::CokeSteelIngot, RecipeKind::CompactedSteelIngot, RecipeKind::SolidSteelIngot],
+    &[RecipeKind::VersatileFramework, RecipeKind::FlexibleFramework],
+    &[RecipeKind::PackagedWater],
+    &[RecipeKind::PureCopperIngot, RecipeKind::CopperAlloyIngot, RecipeKind::TemperedCopperIngot, RecipeKind::LeachedCopperIngot, RecipeKind::CopperIngot],
+    &[RecipeKind::AluminumScrap, RecipeKind::ElectrodeAluminumScrap, RecipeKind::InstantScrap],
+    &[RecipeKind::AluminumCasing, RecipeKind::AlcladCasing],
+    &[RecipeKind::AluminaSolution, RecipeKind::UnpackageAluminaSolution, RecipeKind::SloppyAlumina],
+    &[RecipeKind::Computer, RecipeKind::CrystalComputer, RecipeKind::CateriumComputer],
+    &[RecipeKind::HeavyFlexibleFrame, RecipeKind::HeavyModularFrame, RecipeKind::HeavyEncasedFrame],
+    &[RecipeKind::PlasticSmartPlating, RecipeKind::SmartPlating],
+    &[RecipeKind::HighSpeedConnector, RecipeKind::SiliconHighSpeedConnector],
+    &[RecipeKind::AutomatedSpeedWiring, RecipeKind::AutomatedWiring],
+    &[RecipeKind::Stator, RecipeKind::QuickwireStator],
+    &[RecipeKind::AiLimiter, RecipeKind::PlasticAiLimiter],
+    &[RecipeKind::FusedQuickwire, RecipeKind::Quickwire],
+    &[RecipeKind::ModularEngine],
+    &[RecipeKind::AdaptiveControlUnit],
+    &[RecipeKind::PressureConversionCube],
+    &[RecipeKind::EncasedPlutoniumCell, RecipeKind::InstantPlutoniumCell],
+    &[RecipeKind::PlutoniumPellet],
+    &[RecipeKind::NonFissileUranium, RecipeKind::FertileUranium],
+    &[RecipeKind::EncasedUraniumCell, RecipeKind::SulfuricAcid, RecipeKind::UnpackageSulfuricAcid],
+    &[RecipeKind::CopperPowder],
+    &[RecipeKind::HeatSink, RecipeKind::HeatExchanger],
+    &[RecipeKind::ElectromagneticControlRod, RecipeKind::ElectromagneticConnectionRod],
+    &[RecipeKind::NuclearPasta],
+    &[RecipeKind::EncasedUraniumCell, RecipeKind::InfusedUraniumCell],
+    &[RecipeKind::QuartzPurification],
+    &[RecipeKind::ThermalPropulsionRocket],
+    &[RecipeKind::FineBlackPowder, RecipeKind::BlackPowder],
+    &[RecipeKind::Ficsonium],
+    &[RecipeKind::SingularityCell],
+    &[RecipeKind::BallisticWarpDrive],
+    &[RecipeKind::GasFilter],
+    &[RecipeKind::HogProtein, RecipeKind::SpitterProtein, RecipeKind::StingerProtein, RecipeKind::HatcherProtein],
+    &[],
+    &[RecipeKind::AlienDnaCapsule],
+    &[],
+    &[],
+    &[RecipeKind::SmokelessPowder],
+    &[RecipeKind::UraniumFuelRod, RecipeKind::UraniumFuelUnit],
+    &[RecipeKind::PlutoniumFuelRod, RecipeKind::PlutoniumFuelUnit],
+    &[RecipeKind::FicsoniumFuelRod],
+    &[RecipeKind::ExplosiveRebar],
+    &[RecipeKind::StunRebar],
+    &[RecipeKind::HomingRifleAmmo],
+    &[RecipeKind::ClusterNobelisk],
+    &[RecipeKind::Nobelisk],
+    &[RecipeKind::GasNobelisk],
+    &[RecipeKind::NukeNobelisk],
+    &[RecipeKind::PulseNobelisk],
+    &[RecipeKind::IronRebar],
+    &[RecipeKind::AutomatedMiner],
+    &[RecipeKind::ShatterRebar],
+    &[RecipeKind::TurboRifleAmmoPackaged, RecipeKind::TurboRifleAmmo],
+    &[RecipeKind::RifleAmmo],
+    &[RecipeKind::IronOreLimestone],
+    &[RecipeKind::CoalIron, RecipeKind::CoalLimestone, RecipeKind::Charcoal, RecipeKind::Biocoal],
+    &[RecipeKind::UnpackageWater, RecipeKind::AluminumScrap, RecipeKind::ElectrodeAluminumScrap, RecipeKind::NonFissileUranium, RecipeKind::Battery, RecipeKind::InstantScrap, RecipeKind::FertileUranium, RecipeKind::DistilledSilica],
+    &[RecipeKind::NitrogenGasBauxite, RecipeKind::NitrogenGasCaterium, RecipeKind::UnpackageNitrogenGas],
+    &[RecipeKind::SulfurCoal, RecipeKind::SulfurIron],
+    &[],
+    &[RecipeKind::BauxiteCaterium, RecipeKind::BauxiteCopper],
+    &[RecipeKind::CateriumOreCopper, RecipeKind::CateriumOreQuartz],
+    &[RecipeKind::CopperOreQuartz, RecipeKind::CopperOreSulfur],
+    &[RecipeKind::RawQuartzBauxite, RecipeKind::RawQuartzCoal],
+    &[RecipeKind::LimestoneSulfur],
+    &[RecipeKind::UraniumOreBauxite],
+    &[RecipeKind::UnpackageOil],
+    &[RecipeKind::SolidBiofuel],
+    &[RecipeKind::PackagedLiquidBiofuel],
+    &[RecipeKind::BiomassMycelia, RecipeKind::BiomassAlienProtein, RecipeKind::BiomassLeaves, RecipeKind::BiomassWood],
+    &[],
+    &[],
+    &[],
+    &[RecipeKind::LiquidBiofuel, RecipeKind::UnpackageLiquidBiofuel],
+    &[RecipeKind::PackagedAluminaSolution],
+    &[RecipeKind::PackagedNitrogenGas],
+    &[RecipeKind::PackagedNitricAcid],
+    &[RecipeKind::PackagedSulfuricAcid],
+    &[RecipeKind::PolyesterFabric, RecipeKind::Fabric],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[RecipeKind::PowerShard1, RecipeKind::SyntheticPowerShard, RecipeKind::PowerShard5, RecipeKind::PowerShard2],
+    &[RecipeKind::AlienPowerMatrix],
+];
+
+const CONSUMED_BY: [&[RecipeKind]; 152] = [
+    &[RecipeKind::NonFissileUranium, RecipeKind::PlutoniumPellet, RecipeKind::FertileUranium],
+    &[RecipeKind::Ficsonium],
+    &[RecipeKind::EncasedIndustrialBeam, RecipeKind::EncasedPlutoniumCell, RecipeKind::EncasedUraniumCell, RecipeKind::MoldedSteelPipe, RecipeKind::MoldedBeam, RecipeKind::EncasedIndustrialPipe, RecipeKind::HeavyEncasedFrame, RecipeKind::SingularityCell],
+    &[RecipeKind::AluminumIngot, RecipeKind::NonFissileUranium, RecipeKind::InfusedUraniumCell, RecipeKind::SiliconHighSpeedConnector, RecipeKind::FineConcrete, RecipeKind::SiliconCircuitBoard],
+    &[RecipeKind::CoatedIronCanister, RecipeKind::AdheredIronPlate, RecipeKind::NitricAcid, RecipeKind::AutomatedMiner, RecipeKind::StitchedIronPlate, RecipeKind::BoltedIronPlate, RecipeKind::SingularityCell, RecipeKind::GasFilter, RecipeKind::ReinforcedIronPlate],
+    &[RecipeKind::VersatileFramework, RecipeKind::EncasedIndustrialBeam, RecipeKind::FlexibleFramework, RecipeKind::PlutoniumFuelRod, RecipeKind::SteelScrew],
+    &[RecipeKind::FicsiteIngotAluminum, RecipeKind::AluminumCasing, RecipeKind::AlcladAluminumSheet, RecipeKind::EmptyFluidTank, RecipeKind::HeatFusedFrame, RecipeKind::AlcladCasing, RecipeKind::AluminumBeam, RecipeKind::AluminumRod],
+    &[RecipeKind::SuperStateComputer],
+    &[RecipeKind::UnpackageFuel],
+    &[RecipeKind::UnpackageIonizedFuel],
+    &[RecipeKind::UnpackageRocketFuel, RecipeKind::DarkIonFuel],
+    &[RecipeKind::TurboDiamonds, RecipeKind::UnpackageTurbofuel, RecipeKind::TurboRifleAmmoPackaged],
+    &[],
+    &[RecipeKind::Turbofuel, RecipeKind::TurboHeavyFuel, RecipeKind::CompactedSteelIngot, RecipeKind::FineBlackPowder],
+    &[RecipeKind::UnpackageHeavyOilResidue],
+    &[RecipeKind::PetroleumDiamonds, RecipeKind::ElectrodeCircuitBoard, RecipeKind::ElectrodeAluminumScrap, RecipeKind::CokeSteelIngot, RecipeKind::TurboBlendFuel, RecipeKind::TemperedCopperIngot, RecipeKind::TemperedCateriumIngot],
+    &[RecipeKind::UnpackageOil],
+    &[RecipeKind::ModularFrame, RecipeKind::Rotor, RecipeKind::IronRebar, RecipeKind::Screw],
+    &[RecipeKind::SamFluctuator, RecipeKind::AutomatedSpeedWiring, RecipeKind::Stator, RecipeKind::CoatedCable, RecipeKind::SuperStateComputer, RecipeKind::ClassicBattery, RecipeKind::SteelRotor, RecipeKind::StitchedIronPlate, RecipeKind::InsulatedCable, RecipeKind::Cable],
+    &[RecipeKind::CrystalOscillator, RecipeKind::AutomatedWiring, RecipeKind::Computer, RecipeKind::HighSpeedConnector],
+    &[RecipeKind::IronPlate, RecipeKind::IronRod, RecipeKind::FicsiteIngotIron, RecipeKind::CoatedIronPlate, RecipeKind::IronPipe, RecipeKind::SteelCastPlate, RecipeKind::IronWire, RecipeKind::CastScrew, RecipeKind::SolidSteelIngot],
+    &[RecipeKind::CrystalOscillator, RecipeKind::PlasticSmartPlating, RecipeKind::ModularFrame, RecipeKind::SmartPlating, RecipeKind::BoltedFrame, RecipeKind::SteeledFrame],
+    &[RecipeKind::PlasticSmartPlating, RecipeKind::Motor, RecipeKind::SmartPlating, RecipeKind::ElectricMotor, RecipeKind::TurboElectricMotor, RecipeKind::UraniumFuelUnit, RecipeKind::RigorMotor],
+    &[RecipeKind::NitroRocketFuel, RecipeKind::Turbofuel, RecipeKind::PackagedFuel, RecipeKind::RecycledRubber, RecipeKind::TurboBlendFuel, RecipeKind::HeatFusedFrame, RecipeKind::RecycledPlastic],
+    &[RecipeKind::PackagedRocketFuel, RecipeKind::IonizedFuel],
+    &[RecipeKind::CircuitBoard, RecipeKind::AiLimiter, RecipeKind::CopperRotor, RecipeKind::CoatedIronCanister, RecipeKind::HeatSink, RecipeKind::SiliconCircuitBoard, RecipeKind::RifleAmmo],
+    &[RecipeKind::VersatileFramework, RecipeKind::HeavyFlexibleFrame, RecipeKind::HeavyModularFrame, RecipeKind::FlexibleFramework, RecipeKind::HeavyEncasedFrame],
+    &[RecipeKind::HeavyFlexibleFrame, RecipeKind::HeavyModularFrame, RecipeKind::CopperRotor, RecipeKind::Rotor, RecipeKind::BoltedFrame, RecipeKind::BoltedIronPlate, RecipeKind::ReinforcedIronPlate],
+    &[RecipeKind::RocketFuel, RecipeKind::NonFissileUranium, RecipeKind::PackagedNitricAcid, RecipeKind::HeatFusedFrame, RecipeKind::FertileUranium, RecipeKind::QuartzPurification],
+    &[RecipeKind::RocketFuel, RecipeKind::PackagedTurbofuel, RecipeKind::TurboRifleAmmo],
+    &[RecipeKind::PackagedRocketFuel, RecipeKind::PackagedIonizedFuel, RecipeKind::PackagedNitricAcid, RecipeKind::PackagedNitrogenGas],
+    &[RecipeKind::SuperpositionOscillator, RecipeKind::RadioControlUnit, RecipeKind::RadioControlSystem, RecipeKind::UraniumFuelUnit, RecipeKind::RigorMotor, RecipeKind::CrystalComputer, RecipeKind::PulseNobelisk],
+    &[RecipeKind::ModularEngine, RecipeKind::TurboPressureMotor, RecipeKind::CoolingDevice, RecipeKind::TurboElectricMotor, RecipeKind::TurboMotor],
+    &[RecipeKind::DarkIonFuel, RecipeKind::SuperpositionOscillator, RecipeKind::SingularityCell, RecipeKind::BallisticWarpDrive, RecipeKind::SyntheticPowerShard],
+    &[RecipeKind::PackagedIonizedFuel],
+    &[RecipeKind::NeuralQuantumProcessor, RecipeKind::AssemblyDirectorSystem],
+    &[RecipeKind::OcSupercomputer, RecipeKind::TurboMotor, RecipeKind::ThermalPropulsionRocket],
+    &[RecipeKind::NeuralQuantumProcessor, RecipeKind::BiochemicalSculptor, RecipeKind::FicsoniumFuelRod],
+    &[RecipeKind::ThermalPropulsionRocket],
+    &[RecipeKind::NeuralQuantumProcessor, RecipeKind::DarkMatterTrap, RecipeKind::SyntheticPowerShard],
+    &[RecipeKind::DarkMatterCrystal, RecipeKind::DarkMatterTrap, RecipeKind::DarkMatterCrystallization, RecipeKind::Ficsonium],
+    &[RecipeKind::DarkMatterResidue, RecipeKind::SamFluctuator, RecipeKind::FicsiteIngotIron, RecipeKind::FicsiteIngotAluminum, RecipeKind::FicsiteIngotCaterium, RecipeKind::BauxiteCaterium, RecipeKind::BauxiteCopper, RecipeKind::CateriumOreCopper, RecipeKind::CateriumOreQuartz, RecipeKind::CoalIron, RecipeKind::CoalLimestone, RecipeKind::CopperOreQuartz, RecipeKind::CopperOreSulfur, RecipeKind::IronOreLimestone, RecipeKind::LimestoneSulfur, RecipeKind::NitrogenGasBauxite, RecipeKind::NitrogenGasCaterium, RecipeKind::RawQuartzBauxite, RecipeKind::RawQuartzCoal, RecipeKind::SulfurCoal, RecipeKind::SulfurIron, RecipeKind::UraniumOreBauxite],
+    &[RecipeKind::SuperpositionOscillator, RecipeKind::NeuralQuantumProcessor, RecipeKind::AiExpansionServer, RecipeKind::FicsoniumFuelRod, RecipeKind::AlienPowerMatrix, RecipeKind::SyntheticPowerShard],
+    &[RecipeKind::DarkMatterCrystal, RecipeKind::TimeCrystal],
+    &[RecipeKind::SuperpositionOscillator, RecipeKind::HeatSink, RecipeKind::ClassicBattery],
+    &[RecipeKind::AiExpansionServer, RecipeKind::BallisticWarpDrive, RecipeKind::AlienPowerMatrix],
+    &[RecipeKind::AiExpansionServer],
+    &[],
+    &[RecipeKind::AiExpansionServer],
+    &[RecipeKind::AlienPowerMatrix],
+    &[RecipeKind::SamFluctuator, RecipeKind::Stator, RecipeKind::HeavyModularFrame, RecipeKind::AutomatedMiner, RecipeKind::QuickwireStator, RecipeKind::SteelRotor, RecipeKind::EncasedIndustrialPipe, RecipeKind::SteeledFrame, RecipeKind::HeavyEncasedFrame, RecipeKind::ExplosiveRebar, RecipeKind::Nobelisk],
+    &[RecipeKind::PressureConversionCube, RecipeKind::ThermalPropulsionRocket],
+    &[RecipeKind::PressureConversionCube, RecipeKind::OcSupercomputer, RecipeKind::TurboElectricMotor, RecipeKind::TurboMotor],
+    &[RecipeKind::FicsiteTrigon],
+    &[],
+    &[RecipeKind::BiochemicalSculptor],
+    &[RecipeKind::FicsiteIngotCaterium, RecipeKind::FusedWire, RecipeKind::CateriumWire, RecipeKind::FusedQuickwire, RecipeKind::Quickwire],
+    &[RecipeKind::PackagedTurbofuel, RecipeKind::PackagedFuel, RecipeKind::PackagedLiquidBiofuel, RecipeKind::PackagedOil, RecipeKind::PackagedHeavyOilResidue, RecipeKind::PackagedWater, RecipeKind::PackagedAluminaSolution, RecipeKind::PackagedSulfuricAcid],
+    &[RecipeKind::Computer, RecipeKind::AdaptiveControlUnit, RecipeKind::HighSpeedConnector, RecipeKind::RadioControlSystem, RecipeKind::SiliconHighSpeedConnector, RecipeKind::CrystalComputer, RecipeKind::CateriumComputer],
+    &[RecipeKind::CircuitBoard, RecipeKind::EmptyCanister, RecipeKind::RecycledRubber, RecipeKind::PlasticSmartPlating, RecipeKind::Computer, RecipeKind::CoatedIronPlate, RecipeKind::Supercomputer, RecipeKind::ClassicBattery, RecipeKind::PlasticAiLimiter, RecipeKind::CateriumCircuitBoard],
+    &[RecipeKind::HeavyFlexibleFrame, RecipeKind::HeavyModularFrame, RecipeKind::UraniumFuelRod, RecipeKind::HeavyEncasedFrame],
+    &[RecipeKind::RubberConcrete, RecipeKind::HeavyFlexibleFrame, RecipeKind::ModularEngine, RecipeKind::FlexibleFramework, RecipeKind::ElectrodeCircuitBoard, RecipeKind::AdheredIronPlate, RecipeKind::CoolingSystem, RecipeKind::RadioControlSystem, RecipeKind::TurboMotor, RecipeKind::RecycledPlastic, RecipeKind::HeatExchanger, RecipeKind::InsulatedCrystalOscillator, RecipeKind::CateriumComputer, RecipeKind::QuickwireCable, RecipeKind::InsulatedCable],
+    &[RecipeKind::ResidualPlastic, RecipeKind::ResidualRubber, RecipeKind::PolyesterFabric],
+    &[RecipeKind::PetroleumCoke, RecipeKind::ResidualFuel, RecipeKind::TurboHeavyFuel, RecipeKind::PackagedHeavyOilResidue, RecipeKind::DilutedPackagedFuel, RecipeKind::CoatedCable, RecipeKind::TurboBlendFuel, RecipeKind::DilutedFuel, RecipeKind::SmokelessPowder],
+    &[RecipeKind::PinkDiamonds, RecipeKind::CrystalOscillator, RecipeKind::RadioConnectionUnit, RecipeKind::InsulatedCrystalOscillator, RecipeKind::SyntheticPowerShard, RecipeKind::ShatterRebar],
+    &[RecipeKind::SteelRod, RecipeKind::SteelBeam, RecipeKind::SteelPipe, RecipeKind::SteelCanister, RecipeKind::MoldedSteelPipe, RecipeKind::SteelCastPlate, RecipeKind::MoldedBeam],
+    &[RecipeKind::MagneticFieldGenerator],
+    &[RecipeKind::UnpackageWater, RecipeKind::DilutedPackagedFuel],
+    &[RecipeKind::SteamedCopperSheet, RecipeKind::AlcladAluminumSheet, RecipeKind::FusedWire, RecipeKind::CopperSheet, RecipeKind::CopperPowder, RecipeKind::AlcladCasing, RecipeKind::FusedQuickwire, RecipeKind::Wire],
+    &[RecipeKind::PureAluminumIngot, RecipeKind::AluminumIngot],
+    &[RecipeKind::Battery, RecipeKind::RadioControlUnit, RecipeKind::RadioControlSystem, RecipeKind::FusedModularFrame, RecipeKind::InstantPlutoniumCell, RecipeKind::HeatExchanger, RecipeKind::IodineInfusedFilter, RecipeKind::TurboRifleAmmoPackaged, RecipeKind::TurboRifleAmmo],
+    &[RecipeKind::AluminumScrap, RecipeKind::PackagedAluminaSolution, RecipeKind::ElectrodeAluminumScrap, RecipeKind::Battery],
+    &[RecipeKind::AdaptiveControlUnit, RecipeKind::Supercomputer, RecipeKind::RadioControlUnit, RecipeKind::SuperStateComputer],
+    &[RecipeKind::AdaptiveControlUnit, RecipeKind::FusedModularFrame, RecipeKind::HeatFusedFrame],
+    &[RecipeKind::ModularEngine],
+    &[RecipeKind::AutomatedSpeedWiring, RecipeKind::Supercomputer, RecipeKind::RadioConnectionUnit, RecipeKind::ElectromagneticConnectionRod, RecipeKind::HomingRifleAmmo],
+    &[RecipeKind::AdaptiveControlUnit],
+    &[RecipeKind::AutomatedSpeedWiring, RecipeKind::Motor, RecipeKind::AutomatedWiring, RecipeKind::TurboPressureMotor, RecipeKind::ElectromagneticControlRod, RecipeKind::RigorMotor, RecipeKind::ElectromagneticConnectionRod],
+    &[RecipeKind::Supercomputer, RecipeKind::ElectromagneticControlRod, RecipeKind::InsulatedCrystalOscillator, RecipeKind::NukeNobelisk],
+    &[RecipeKind::AiLimiter, RecipeKind::HighSpeedConnector, RecipeKind::PlasticAiLimiter, RecipeKind::InfusedUraniumCell, RecipeKind::QuickwireStator, RecipeKind::SiliconHighSpeedConnector, RecipeKind::CateriumComputer, RecipeKind::CateriumCircuitBoard, RecipeKind::QuickwireCable, RecipeKind::IodineInfusedFilter, RecipeKind::StunRebar],
+    &[RecipeKind::ThermalPropulsionRocket],
+    &[RecipeKind::AssemblyDirectorSystem],
+    &[RecipeKind::TurboPressureMotor, RecipeKind::NuclearPasta, RecipeKind::PlutoniumFuelUnit],
+    &[RecipeKind::PlutoniumFuelRod, RecipeKind::PlutoniumFuelUnit],
+    &[RecipeKind::EncasedPlutoniumCell],
+    &[RecipeKind::PlutoniumPellet, RecipeKind::InstantPlutoniumCell],
+    &[RecipeKind::NonFissileUranium, RecipeKind::EncasedUraniumCell, RecipeKind::Battery, RecipeKind::PackagedSulfuricAcid, RecipeKind::InstantScrap, RecipeKind::FertileUranium, RecipeKind::LeachedIronIngot, RecipeKind::LeachedCopperIngot, RecipeKind::LeachedCateriumIngot],
+    &[RecipeKind::NuclearPasta],
+    &[RecipeKind::PlutoniumFuelRod, RecipeKind::CoolingSystem, RecipeKind::CoolingDevice, RecipeKind::RadioConnectionUnit],
+    &[RecipeKind::PlutoniumFuelRod, RecipeKind::SuperStateComputer, RecipeKind::UraniumFuelRod, RecipeKind::MagneticFieldGenerator, RecipeKind::ElectricMotor, RecipeKind::TurboElectricMotor, RecipeKind::UraniumFuelUnit, RecipeKind::FicsoniumFuelRod],
+    &[RecipeKind::SingularityCell],
+    &[RecipeKind::UraniumFuelRod, RecipeKind::UraniumFuelUnit, RecipeKind::NukeNobelisk],
+    &[RecipeKind::DistilledSilica],
+    &[RecipeKind::BallisticWarpDrive],
+    &[RecipeKind::Nobelisk, RecipeKind::SmokelessPowder],
+    &[RecipeKind::FicsoniumFuelRod],
+    &[RecipeKind::Ficsonium, RecipeKind::BallisticWarpDrive],
+    &[],
+    &[RecipeKind::IodineInfusedFilter],
+    &[RecipeKind::AlienDnaCapsule, RecipeKind::BiomassAlienProtein],
+    &[RecipeKind::PowerShard1],
+    &[],
+    &[RecipeKind::PowerShard5],
+    &[RecipeKind::PowerShard2],
+    &[RecipeKind::NukeNobelisk, RecipeKind::RifleAmmo, RecipeKind::ExplosiveRebar, RecipeKind::ClusterNobelisk],
+    &[RecipeKind::NuclearWaste],
+    &[RecipeKind::PlutoniumWaste],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[RecipeKind::GasNobelisk, RecipeKind::PulseNobelisk, RecipeKind::NukeNobelisk, RecipeKind::ClusterNobelisk],
+    &[],
+    &[],
+    &[],
+    &[RecipeKind::StunRebar, RecipeKind::ShatterRebar, RecipeKind::ExplosiveRebar],
+    &[],
+    &[],
+    &[],
+    &[RecipeKind::HomingRifleAmmo, RecipeKind::TurboRifleAmmoPackaged, RecipeKind::TurboRifleAmmo],
+    &[RecipeKind::IronIngot, RecipeKind::CoalIron, RecipeKind::SulfurIron, RecipeKind::SteelIngot, RecipeKind::PureIronIngot, RecipeKind::CopperAlloyIngot, RecipeKind::CokeSteelIngot, RecipeKind::LeachedIronIngot, RecipeKind::BasicIronIngot, RecipeKind::CompactedSteelIngot, RecipeKind::IronAlloyIngot],
+    &[RecipeKind::NitroRocketFuel, RecipeKind::TurboDiamonds, RecipeKind::Diamonds, RecipeKind::RawQuartzCoal, RecipeKind::SulfurCoal, RecipeKind::CompactedCoal, RecipeKind::PinkDiamonds, RecipeKind::CloudyDiamonds, RecipeKind::SteelIngot, RecipeKind::AluminumScrap, RecipeKind::InstantScrap, RecipeKind::FusedQuartzCrystal, RecipeKind::SolidSteelIngot, RecipeKind::BlackPowder, RecipeKind::GasFilter],
+    &[RecipeKind::NuclearWaste, RecipeKind::PlutoniumWaste, RecipeKind::BiochemicalSculptor, RecipeKind::ResidualPlastic, RecipeKind::ResidualRubber, RecipeKind::WetConcrete, RecipeKind::LiquidBiofuel, RecipeKind::PackagedWater, RecipeKind::SteamedCopperSheet, RecipeKind::PureQuartzCrystal, RecipeKind::PureIronIngot, RecipeKind::PureCopperIngot, RecipeKind::PureCateriumIngot, RecipeKind::AluminaSolution, RecipeKind::NitricAcid, RecipeKind::CoolingSystem, RecipeKind::SulfuricAcid, RecipeKind::SloppyAlumina, RecipeKind::InstantScrap, RecipeKind::DilutedFuel, RecipeKind::DistilledSilica, RecipeKind::PolyesterFabric],
+    &[RecipeKind::NitroRocketFuel, RecipeKind::NitricAcid, RecipeKind::CoolingSystem, RecipeKind::FusedModularFrame, RecipeKind::PackagedNitrogenGas, RecipeKind::CoolingDevice],
+    &[RecipeKind::NitroRocketFuel, RecipeKind::CopperOreSulfur, RecipeKind::LimestoneSulfur, RecipeKind::CompactedCoal, RecipeKind::TurboBlendFuel, RecipeKind::SulfuricAcid, RecipeKind::ClassicBattery, RecipeKind::InfusedUraniumCell, RecipeKind::FineBlackPowder, RecipeKind::BlackPowder],
+    &[RecipeKind::ReanimatedSam],
+    &[RecipeKind::NitrogenGasBauxite, RecipeKind::RawQuartzBauxite, RecipeKind::UraniumOreBauxite, RecipeKind::AluminaSolution, RecipeKind::SloppyAlumina, RecipeKind::InstantScrap],
+    &[RecipeKind::BauxiteCaterium, RecipeKind::NitrogenGasCaterium, RecipeKind::PureCateriumIngot, RecipeKind::TemperedCateriumIngot, RecipeKind::LeachedCateriumIngot, RecipeKind::CateriumIngot],
+    &[RecipeKind::BauxiteCopper, RecipeKind::CateriumOreCopper, RecipeKind::PureCopperIngot, RecipeKind::CopperAlloyIngot, RecipeKind::TemperedCopperIngot, RecipeKind::LeachedCopperIngot, RecipeKind::IronAlloyIngot, RecipeKind::CopperIngot],
+    &[RecipeKind::CateriumOreQuartz, RecipeKind::CopperOreQuartz, RecipeKind::PureQuartzCrystal, RecipeKind::QuartzCrystal, RecipeKind::Silica, RecipeKind::QuartzPurification, RecipeKind::FusedQuartzCrystal, RecipeKind::CheapSilica],
+    &[RecipeKind::CoalLimestone, RecipeKind::IronOreLimestone, RecipeKind::CloudyDiamonds, RecipeKind::WetConcrete, RecipeKind::RubberConcrete, RecipeKind::DistilledSilica, RecipeKind::BasicIronIngot, RecipeKind::CheapSilica, RecipeKind::FineConcrete, RecipeKind::Concrete],
+    &[RecipeKind::EncasedUraniumCell, RecipeKind::FertileUranium, RecipeKind::InfusedUraniumCell],
+    &[RecipeKind::Fuel, RecipeKind::Plastic, RecipeKind::Rubber, RecipeKind::OilBasedDiamonds, RecipeKind::PackagedOil, RecipeKind::PolymerResin, RecipeKind::HeavyOilResidue],
+    &[RecipeKind::LiquidBiofuel],
+    &[RecipeKind::UnpackageLiquidBiofuel],
+    &[RecipeKind::Biocoal, RecipeKind::SolidBiofuel, RecipeKind::GasNobelisk, RecipeKind::Fabric],
+    &[RecipeKind::BiomassLeaves],
+    &[RecipeKind::BiomassMycelia, RecipeKind::Fabric],
+    &[RecipeKind::Charcoal, RecipeKind::BiomassWood],
+    &[RecipeKind::PackagedLiquidBiofuel],
+    &[RecipeKind::UnpackageAluminaSolution],
+    &[RecipeKind::TurboPressureMotor, RecipeKind::UnpackageNitrogenGas],
+    &[RecipeKind::UnpackageNitricAcid],
+    &[RecipeKind::UnpackageSulfuricAcid],
+    &[RecipeKind::GasFilter],
+    &[RecipeKind::HogProtein],
+    &[RecipeKind::SpitterProtein],
+    &[RecipeKind::StingerProtein],
+    &[RecipeKind::HatcherProtein],
+    &[RecipeKind::IonizedFuel, RecipeKind::AlienPowerMatrix],
+    &[],
+];
@@ -7,11 +7,16 @@
 import type { Recipe } from ".";
 
 export const RECIPES = {
-    "nuclear-waste": { // TempRecipe_NuclearWaste_C
+    "nuclear-waste": {
+        "className": "TempRecipe_NuclearWaste_C",
         "name": "Uranium Fuel Rod (burning)",
         "duration": 300,
         "producedIn": "nuclear-reactor",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 8
+        },
         "inputs": [
             {
                 "item": "uranium-fuel-rod",
@@ -29,11 +34,16 @@ export const RECIPES = {
             }
         ]
     },
-    "plutonium-waste": { // TempRecipe_PlutoniumWaste_C
+    "plutonium-waste": {
+        "className": "TempRecipe_PlutoniumWaste_C",
         "name": "Plutonium Fuel Rod (burning)",
         "duration": 600,
         "producedIn": "nuclear-reactor",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 8
+        },
         "inputs": [
             {
                 "item": "plutonium-fuel-rod",
@@ -51,11 +61,16 @@ export const RECIPES = {
             }
         ]
     },
-    "iron-plate": { // Recipe_IronPlate_C
+    "iron-plate": {
+        "className": "Recipe_IronPlate_C",
         "name": "Iron Plate",
         "duration": 6,
         "producedIn": "constructor",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 0
+        },
         "inputs": [
             {
                 "item": "iron-ingot",
@@ -69,11 +84,16 @@ export const RECIPES = {
             }
         ]
     },
-    "iron-rod": { // Recipe_IronRod_C
+    "iron-rod": {
+        "className": "Recipe_IronRod_C",
         "name": "Iron Rod",
         "duration": 4,
         "producedIn": "constructor",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 0
+        },
         "inputs": [
             {
                 "item": "iron-ingot",
@@ -87,11 +107,16 @@ export const RECIPES = {
             }
         ]
     },
-    "iron-ingot": { // Recipe_IngotIron_C
+    "iron-ingot": {
+        "className": "Recipe_IngotIron_C",
         "name": "Iron Ingot",
         "duration": 2,
         "producedIn": "smelter",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 0
+        },
         "inputs": [
             {
                 "item": "iron-ore",
@@ -105,11 +130,15 @@ export const RECIPES = {
             }
         ]
     },
-    "nitro-rocket-fuel": { // Recipe_Alternate_RocketFuel_Nitro_C
+    "nitro-rocket-fuel": {
+        "className": "Recipe_Alternate_RocketFuel_Nitro_C",
         "name": "Nitro Rocket Fuel",
         "duration": 2.4,
         "producedIn": "blender",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "fuel",
@@ -139,11 +168,16 @@ export const RECIPES = {
             }
         ]
     },
-    "rocket-fuel": { // Recipe_RocketFuel_C
+    "rocket-fuel": {
+        "className": "Recipe_RocketFuel_C",
         "name": "Rocket Fuel",
         "duration": 6,
         "producedIn": "blender",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 8
+        },
         "inputs": [
             {
                 "item": "turbofuel",
@@ -165,11 +199,16 @@ export const RECIPES = {
             }
         ]
     },
-    "packaged-rocket-fuel": { // Recipe_PackagedRocketFuel_C
+    "packaged-rocket-fuel": {
+        "className": "Recipe_PackagedRocketFuel_C",
         "name": "Packaged Rocket Fuel",
         "duration": 1,
         "producedIn": "packager",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 8
+        },
         "inputs": [
             {
                 "item": "rocket-fuel",
@@ -187,11 +226,16 @@ export const RECIPES = {
             }
         ]
     },
-    "unpackage-rocket-fuel": { // Recipe_UnpackageRocketFuel_C
+    "unpackage-rocket-fuel": {
+        "className": "Recipe_UnpackageRocketFuel_C",
         "name": "Unpackage Rocket Fuel",
         "duration": 1,
         "producedIn": "packager",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 8
+        },
         "inputs": [
             {
                 "item": "packaged-rocket-fuel",
@@ -209,11 +253,15 @@ export const RECIPES = {
             }
         ]
     },
-    "dark-ion-fuel": { // Recipe_Alternate_IonizedFuel_Dark_C
+    "dark-ion-fuel": {
+        "className": "Recipe_Alternate_IonizedFuel_Dark_C",
         "name": "Dark-Ion Fuel",
         "duration": 3,
         "producedIn": "converter",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "powerRequirements": [
             100,
             400
@@ -239,11 +287,16 @@ export const RECIPES = {
             }
         ]
     },
-    "dark-matter-residue": { // Recipe_DarkEnergy_C
+    "dark-matter-residue": {
+        "className": "Recipe_DarkEnergy_C",
         "name": "Dark Matter Residue",
         "duration": 6,
         "producedIn": "converter",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 9
+        },
         "powerRequirements": [
             100,
             400
@@ -261,11 +314,16 @@ export const RECIPES = {
             }
         ]
     },
-    "excited-photonic-matter": { // Recipe_QuantumEnergy_C
+    "excited-photonic-matter": {
+        "className": "Recipe_QuantumEnergy_C",
         "name": "Excited Photonic Matter",
         "duration": 3,
         "producedIn": "converter",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 9
+        },
         "powerRequirements": [
             100,
             400
@@ -278,11 +336,16 @@ export const RECIPES = {
             }
         ]
     },
-    "dark-matter-crystal": { // Recipe_DarkMatter_C
+    "dark-matter-crystal": {
+        "className": "Recipe_DarkMatter_C",
         "name": "Dark Matter Crystal",
         "duration": 2,
         "producedIn": "particle-accelerator",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 9
+        },
         "powerRequirements": [
             500,
             1500
@@ -304,11 +367,16 @@ export const RECIPES = {
             }
         ]
     },
-    "superposition-oscillator": { // Recipe_SuperpositionOscillator_C
+    "superposition-oscillator": {
+        "className": "Recipe_SuperpositionOscillator_C",
         "name": "Superposition Oscillator",
         "duration": 12,
         "producedIn": "quantum-encoder",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 9
+        },
         "powerRequirements": [
             0,
             2000
@@ -342,11 +410,16 @@ export const RECIPES = {
             }
         ]
     },
-    "neural-quantum-processor": { // Recipe_TemporalProcessor_C
+    "neural-quantum-processor": {
+        "className": "Recipe_TemporalProcessor_C",
         "name": "Neural-Quantum Processor",
         "duration": 20,
         "producedIn": "quantum-encoder",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 9
+        },
         "powerRequirements": [
             0,
             2000
@@ -380,11 +453,16 @@ export const RECIPES = {
             }
         ]
     },
-    "ai-expansion-server": { // Recipe_SpaceElevatorPart_12_C
+    "ai-expansion-server": {
+        "className": "Recipe_SpaceElevatorPart_12_C",
         "name": "AI Expansion Server",
         "duration": 15,
         "producedIn": "quantum-encoder",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 9
+        },
         "powerRequirements": [
             0,
             2000
@@ -418,11 +496,16 @@ export const RECIPES = {
             }
         ]
     },
-    "ionized-fuel": { // Recipe_IonizedFuel_C
+    "ionized-fuel": {
+        "className": "Recipe_IonizedFuel_C",
         "name": "Ionized Fuel",
         "duration": 24,
         "producedIn": "refinery",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 9
+        },
         "inputs": [
             {
                 "item": "rocket-fuel",
@@ -444,11 +527,16 @@ export const RECIPES = {
             }
         ]
     },
-    "packaged-ionized-fuel": { // Recipe_PackagedIonizedFuel_C
+    "packaged-ionized-fuel": {
+        "className": "Recipe_PackagedIonizedFuel_C",
         "name": "Packaged Ionized Fuel",
         "duration": 3,
         "producedIn": "packager",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 9
+        },
         "inputs": [
             {
                 "item": "ionized-fuel",
@@ -466,11 +554,16 @@ export const RECIPES = {
             }
         ]
     },
-    "unpackage-ionized-fuel": { // Recipe_UnpackageIonizedFuel_C
+    "unpackage-ionized-fuel": {
+        "className": "Recipe_UnpackageIonizedFuel_C",
         "name": "Unpackage Ionized Fuel",
         "duration": 3,
         "producedIn": "packager",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 9
+        },
         "inputs": [
             {
                 "item": "packaged-ionized-fuel",
@@ -488,11 +581,15 @@ export const RECIPES = {
             }
         ]
     },
-    "turbo-diamonds": { // Recipe_Alternate_Diamond_Turbo_C
+    "turbo-diamonds": {
+        "className": "Recipe_Alternate_Diamond_Turbo_C",
         "name": "Turbo Diamonds",
         "duration": 3,
         "producedIn": "particle-accelerator",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "powerRequirements": [
             250,
             750
@@ -514,11 +611,16 @@ export const RECIPES = {
             }
         ]
     },
-    "sam-fluctuator": { // Recipe_SAMFluctuator_C
+    "sam-fluctuator": {
+        "className": "Recipe_SAMFluctuator_C",
         "name": "SAM Fluctuator",
         "duration": 6,
         "producedIn": "manufacturer",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "alien-technology"
+        },
         "inputs": [
             {
                 "item": "reanimated-sam",
@@ -540,11 +642,16 @@ export const RECIPES = {
             }
         ]
     },
-    "ficsite-trigon": { // Recipe_FicsiteMesh_C
+    "ficsite-trigon": {
+        "className": "Recipe_FicsiteMesh_C",
         "name": "Ficsite Trigon",
         "duration": 6,
         "producedIn": "constructor",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 9
+        },
         "inputs": [
             {
                 "item": "ficsite-ingot",
@@ -558,11 +665,16 @@ export const RECIPES = {
             }
         ]
     },
-    "ficsite-ingot-iron": { // Recipe_FicsiteIngot_Iron_C
+    "ficsite-ingot-iron": {
+        "className": "Recipe_FicsiteIngot_Iron_C",
         "name": "Ficsite Ingot (Iron)",
         "duration": 6,
         "producedIn": "converter",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 9
+        },
         "powerRequirements": [
             100,
             400
@@ -584,11 +696,16 @@ export const RECIPES = {
             }
         ]
     },
-    "time-crystal": { // Recipe_TimeCrystal_C
+    "time-crystal": {
+        "className": "Recipe_TimeCrystal_C",
         "name": "Time Crystal",
         "duration": 10,
         "producedIn": "converter",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 9
+        },
         "powerRequirements": [
             100,
             400
@@ -606,11 +723,16 @@ export const RECIPES = {
             }
         ]
     },
-    "diamonds": { // Recipe_Diamond_C
+    "diamonds": {
+        "className": "Recipe_Diamond_C",
         "name": "Diamonds",
         "duration": 2,
         "producedIn": "particle-accelerator",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 9
+        },
         "powerRequirements": [
             250,
             750
@@ -628,11 +750,16 @@ export const RECIPES = {
             }
         ]
     },
-    "reanimated-sam": { // Recipe_IngotSAM_C
+    "reanimated-sam": {
+        "className": "Recipe_IngotSAM_C",
         "name": "Reanimated SAM",
         "duration": 2,
         "producedIn": "constructor",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "alien-technology"
+        },
         "inputs": [
             {
                 "item": "sam",
@@ -646,11 +773,16 @@ export const RECIPES = {
             }
         ]
     },
-    "biochemical-sculptor": { // Recipe_SpaceElevatorPart_10_C
+    "biochemical-sculptor": {
+        "className": "Recipe_SpaceElevatorPart_10_C",
         "name": "Biochemical Sculptor",
         "duration": 120,
         "producedIn": "blender",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 9
+        },
         "powerRequirements": [
             500,
             1500
@@ -676,11 +808,16 @@ export const RECIPES = {
             }
         ]
     },
-    "ficsite-ingot-aluminum": { // Recipe_FicsiteIngot_AL_C
+    "ficsite-ingot-aluminum": {
+        "className": "Recipe_FicsiteIngot_AL_C",
         "name": "Ficsite Ingot (Aluminum)",
         "duration": 2,
         "producedIn": "converter",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 9
+        },
         "powerRequirements": [
             100,
             400
@@ -702,11 +839,16 @@ export const RECIPES = {
             }
         ]
     },
-    "ficsite-ingot-caterium": { // Recipe_FicsiteIngot_CAT_C
+    "ficsite-ingot-caterium": {
+        "className": "Recipe_FicsiteIngot_CAT_C",
         "name": "Ficsite Ingot (Caterium)",
         "duration": 4,
         "producedIn": "converter",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 9
+        },
         "powerRequirements": [
             100,
             400
@@ -728,11 +870,16 @@ export const RECIPES = {
             }
         ]
     },
-    "bauxite-caterium": { // Recipe_Bauxite_Caterium_C
+    "bauxite-caterium": {
+        "className": "Recipe_Bauxite_Caterium_C",
         "name": "Bauxite (Caterium)",
         "duration": 6,
         "producedIn": "converter",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "alien-technology"
+        },
         "powerRequirements": [
             100,
             400
@@ -754,11 +901,16 @@ export const RECIPES = {
             }
         ]
     },
-    "bauxite-copper": { // Recipe_Bauxite_Copper_C
+    "bauxite-copper": {
+        "className": "Recipe_Bauxite_Copper_C",
         "name": "Bauxite (Copper)",
         "duration": 6,
         "producedIn": "converter",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "alien-technology"
+        },
         "powerRequirements": [
             100,
             400
@@ -780,11 +932,16 @@ export const RECIPES = {
             }
         ]
     },
-    "caterium-ore-copper": { // Recipe_Caterium_Copper_C
+    "caterium-ore-copper": {
+        "className": "Recipe_Caterium_Copper_C",
         "name": "Caterium Ore (Copper)",
         "duration": 6,
         "producedIn": "converter",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "alien-technology"
+        },
         "powerRequirements": [
             100,
             400
@@ -806,11 +963,16 @@ export const RECIPES = {
             }
         ]
     },
-    "caterium-ore-quartz": { // Recipe_Caterium_Quartz_C
+    "caterium-ore-quartz": {
+        "className": "Recipe_Caterium_Quartz_C",
         "name": "Caterium Ore (Quartz)",
         "duration": 6,
         "producedIn": "converter",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "alien-technology"
+        },
         "powerRequirements": [
             100,
             400
@@ -832,11 +994,16 @@ export const RECIPES = {
             }
         ]
     },
-    "coal-iron": { // Recipe_Coal_Iron_C
+    "coal-iron": {
+        "className": "Recipe_Coal_Iron_C",
         "name": "Coal (Iron)",
         "duration": 6,
         "producedIn": "converter",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "alien-technology"
+        },
         "powerRequirements": [
             100,
             400
@@ -858,11 +1025,16 @@ export const RECIPES = {
             }
         ]
     },
-    "coal-limestone": { // Recipe_Coal_Limestone_C
+    "coal-limestone": {
+        "className": "Recipe_Coal_Limestone_C",
         "name": "Coal (Limestone)",
         "duration": 6,
         "producedIn": "converter",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "alien-technology"
+        },
         "powerRequirements": [
             100,
             400
@@ -884,11 +1056,16 @@ export const RECIPES = {
             }
         ]
     },
-    "copper-ore-quartz": { // Recipe_Copper_Quartz_C
+    "copper-ore-quartz": {
+        "className": "Recipe_Copper_Quartz_C",
         "name": "Copper Ore (Quartz)",
         "duration": 6,
         "producedIn": "converter",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "alien-technology"
+        },
         "powerRequirements": [
             100,
             400
@@ -910,11 +1087,16 @@ export const RECIPES = {
             }
         ]
     },
-    "copper-ore-sulfur": { // Recipe_Copper_Sulfur_C
+    "copper-ore-sulfur": {
+        "className": "Recipe_Copper_Sulfur_C",
         "name": "Copper Ore (Sulfur)",
         "duration": 6,
         "producedIn": "converter",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "alien-technology"
+        },
         "powerRequirements": [
             100,
             400
@@ -936,11 +1118,16 @@ export const RECIPES = {
             }
         ]
     },
-    "iron-ore-limestone": { // Recipe_Iron_Limestone_C
+    "iron-ore-limestone": {
+        "className": "Recipe_Iron_Limestone_C",
         "name": "Iron Ore (Limestone)",
         "duration": 6,
         "producedIn": "converter",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "alien-technology"
+        },
         "powerRequirements": [
             100,
             400
@@ -962,11 +1149,16 @@ export const RECIPES = {
             }
         ]
     },
-    "limestone-sulfur": { // Recipe_Limestone_Sulfur_C
+    "limestone-sulfur": {
+        "className": "Recipe_Limestone_Sulfur_C",
         "name": "Limestone (Sulfur)",
         "duration": 6,
         "producedIn": "converter",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "alien-technology"
+        },
         "powerRequirements": [
             100,
             400
@@ -988,11 +1180,16 @@ export const RECIPES = {
             }
         ]
     },
-    "nitrogen-gas-bauxite": { // Recipe_Nitrogen_Bauxite_C
+    "nitrogen-gas-bauxite": {
+        "className": "Recipe_Nitrogen_Bauxite_C",
         "name": "Nitrogen Gas (Bauxite)",
         "duration": 6,
         "producedIn": "converter",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "alien-technology"
+        },
         "powerRequirements": [
             100,
             400
@@ -1014,11 +1211,16 @@ export const RECIPES = {
             }
         ]
     },
-    "nitrogen-gas-caterium": { // Recipe_Nitrogen_Caterium_C
+    "nitrogen-gas-caterium": {
+        "className": "Recipe_Nitrogen_Caterium_C",
         "name": "Nitrogen Gas (Caterium)",
         "duration": 6,
         "producedIn": "converter",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "alien-technology"
+        },
         "powerRequirements": [
             100,
             400
@@ -1040,11 +1242,16 @@ export const RECIPES = {
             }
         ]
     },
-    "raw-quartz-bauxite": { // Recipe_Quartz_Bauxite_C
+    "raw-quartz-bauxite": {
+        "className": "Recipe_Quartz_Bauxite_C",
         "name": "Raw Quartz (Bauxite)",
         "duration": 6,
         "producedIn": "converter",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "alien-technology"
+        },
         "powerRequirements": [
             100,
             400
@@ -1066,11 +1273,16 @@ export const RECIPES = {
             }
         ]
     },
-    "raw-quartz-coal": { // Recipe_Quartz_Coal_C
+    "raw-quartz-coal": {
+        "className": "Recipe_Quartz_Coal_C",
         "name": "Raw Quartz (Coal)",
         "duration": 6,
         "producedIn": "converter",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "alien-technology"
+        },
         "powerRequirements": [
             100,
             400
@@ -1092,11 +1304,16 @@ export const RECIPES = {
             }
         ]
     },
-    "sulfur-coal": { // Recipe_Sulfur_Coal_C
+    "sulfur-coal": {
+        "className": "Recipe_Sulfur_Coal_C",
         "name": "Sulfur (Coal)",
         "duration": 6,
         "producedIn": "converter",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "alien-technology"
+        },
         "powerRequirements": [
             100,
             400
@@ -1118,11 +1335,16 @@ export const RECIPES = {
             }
         ]
     },
-    "sulfur-iron": { // Recipe_Sulfur_Iron_C
+    "sulfur-iron": {
+        "className": "Recipe_Sulfur_Iron_C",
         "name": "Sulfur (Iron)",
         "duration": 6,
         "producedIn": "converter",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "alien-technology"
+        },
         "powerRequirements": [
             100,
             400
@@ -1144,11 +1366,16 @@ export const RECIPES = {
             }
         ]
     },
-    "uranium-ore-bauxite": { // Recipe_Uranium_Bauxite_C
+    "uranium-ore-bauxite": {
+        "className": "Recipe_Uranium_Bauxite_C",
         "name": "Uranium Ore (Bauxite)",
         "duration": 6,
         "producedIn": "converter",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "alien-technology"
+        },
         "powerRequirements": [
             100,
             400
@@ -1170,11 +1397,16 @@ export const RECIPES = {
             }
         ]
     },
-    "turbofuel": { // Recipe_Alternate_Turbofuel_C
+    "turbofuel": {
+        "className": "Recipe_Alternate_Turbofuel_C",
         "name": "Turbofuel",
         "duration": 16,
         "producedIn": "refinery",
         "alternative": true,
+        "unlock": {
+            "kind": "mam",
+            "tree": "sulfur"
+        },
         "inputs": [
             {
                 "item": "fuel",
@@ -1192,11 +1424,16 @@ export const RECIPES = {
             }
         ]
     },
-    "packaged-turbofuel": { // Recipe_PackagedTurboFuel_C
+    "packaged-turbofuel": {
+        "className": "Recipe_PackagedTurboFuel_C",
         "name": "Packaged Turbofuel",
         "duration": 6,
         "producedIn": "packager",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "sulfur"
+        },
         "inputs": [
             {
                 "item": "turbofuel",
@@ -1214,11 +1451,16 @@ export const RECIPES = {
             }
         ]
     },
-    "unpackage-turbofuel": { // Recipe_UnpackageTurboFuel_C
+    "unpackage-turbofuel": {
+        "className": "Recipe_UnpackageTurboFuel_C",
         "name": "Unpackage Turbofuel",
         "duration": 6,
         "producedIn": "packager",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "sulfur"
+        },
         "inputs": [
             {
                 "item": "packaged-turbofuel",
@@ -1236,11 +1478,15 @@ export const RECIPES = {
             }
         ]
     },
-    "charcoal": { // Recipe_Alternate_Coal_1_C
+    "charcoal": {
+        "className": "Recipe_Alternate_Coal_1_C",
         "name": "Charcoal",
         "duration": 4,
         "producedIn": "constructor",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "wood",
@@ -1254,11 +1500,15 @@ export const RECIPES = {
             }
         ]
     },
-    "biocoal": { // Recipe_Alternate_Coal_2_C
+    "biocoal": {
+        "className": "Recipe_Alternate_Coal_2_C",
         "name": "Biocoal",
         "duration": 8,
         "producedIn": "constructor",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "biomass",
@@ -1272,11 +1522,16 @@ export const RECIPES = {
             }
         ]
     },
-    "compacted-coal": { // Recipe_Alternate_EnrichedCoal_C
+    "compacted-coal": {
+        "className": "Recipe_Alternate_EnrichedCoal_C",
         "name": "Compacted Coal",
         "duration": 12,
         "producedIn": "assembler",
         "alternative": true,
+        "unlock": {
+            "kind": "mam",
+            "tree": "sulfur"
+        },
         "inputs": [
             {
                 "item": "coal",
@@ -1294,11 +1549,16 @@ export const RECIPES = {
             }
         ]
     },
-    "circuit-board": { // Recipe_CircuitBoard_C
+    "circuit-board": {
+        "className": "Recipe_CircuitBoard_C",
         "name": "Circuit Board",
         "duration": 8,
         "producedIn": "assembler",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 5
+        },
         "inputs": [
             {
                 "item": "copper-sheet",
@@ -1316,11 +1576,16 @@ export const RECIPES = {
             }
         ]
     },
-    "fuel": { // Recipe_LiquidFuel_C
+    "fuel": {
+        "className": "Recipe_LiquidFuel_C",
         "name": "Fuel",
         "duration": 6,
         "producedIn": "refinery",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 5
+        },
         "inputs": [
             {
                 "item": "crude-oil",
@@ -1338,11 +1603,16 @@ export const RECIPES = {
             }
         ]
     },
-    "petroleum-coke": { // Recipe_PetroleumCoke_C
+    "petroleum-coke": {
+        "className": "Recipe_PetroleumCoke_C",
         "name": "Petroleum Coke",
         "duration": 6,
         "producedIn": "refinery",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 5
+        },
         "inputs": [
             {
                 "item": "heavy-oil-residue",
@@ -1356,11 +1626,16 @@ export const RECIPES = {
             }
         ]
     },
-    "plastic": { // Recipe_Plastic_C
+    "plastic": {
+        "className": "Recipe_Plastic_C",
         "name": "Plastic",
         "duration": 6,
         "producedIn": "refinery",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 5
+        },
         "inputs": [
             {
                 "item": "crude-oil",
@@ -1378,11 +1653,16 @@ export const RECIPES = {
             }
         ]
     },
-    "rubber": { // Recipe_Rubber_C
+    "rubber": {
+        "className": "Recipe_Rubber_C",
         "name": "Rubber",
         "duration": 6,
         "producedIn": "refinery",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 5
+        },
         "inputs": [
             {
                 "item": "crude-oil",
@@ -1400,11 +1680,16 @@ export const RECIPES = {
             }
         ]
     },
-    "residual-fuel": { // Recipe_ResidualFuel_C
+    "residual-fuel": {
+        "className": "Recipe_ResidualFuel_C",
         "name": "Residual Fuel",
         "duration": 6,
         "producedIn": "refinery",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 5
+        },
         "inputs": [
             {
                 "item": "heavy-oil-residue",
@@ -1418,11 +1703,16 @@ export const RECIPES = {
             }
         ]
     },
-    "residual-plastic": { // Recipe_ResidualPlastic_C
+    "residual-plastic": {
+        "className": "Recipe_ResidualPlastic_C",
         "name": "Residual Plastic",
         "duration": 6,
         "producedIn": "refinery",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 5
+        },
         "inputs": [
             {
                 "item": "polymer-resin",
@@ -1440,11 +1730,16 @@ export const RECIPES = {
             }
         ]
     },
-    "residual-rubber": { // Recipe_ResidualRubber_C
+    "residual-rubber": {
+        "className": "Recipe_ResidualRubber_C",
         "name": "Residual Rubber",
         "duration": 6,
         "producedIn": "refinery",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 5
+        },
         "inputs": [
             {
                 "item": "polymer-resin",
@@ -1462,11 +1757,15 @@ export const RECIPES = {
             }
         ]
     },
-    "pink-diamonds": { // Recipe_Alternate_Diamond_Pink_C
+    "pink-diamonds": {
+        "className": "Recipe_Alternate_Diamond_Pink_C",
         "name": "Pink Diamonds",
         "duration": 4,
         "producedIn": "converter",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "powerRequirements": [
             100,
             400
@@ -1488,11 +1787,15 @@ export const RECIPES = {
             }
         ]
     },
-    "petroleum-diamonds": { // Recipe_Alternate_Diamond_Petroleum_C
+    "petroleum-diamonds": {
+        "className": "Recipe_Alternate_Diamond_Petroleum_C",
         "name": "Petroleum Diamonds",
         "duration": 2,
         "producedIn": "particle-accelerator",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "powerRequirements": [
             250,
             750
@@ -1510,11 +1813,15 @@ export const RECIPES = {
             }
         ]
     },
-    "oil-based-diamonds": { // Recipe_Alternate_Diamond_OilBased_C
+    "oil-based-diamonds": {
+        "className": "Recipe_Alternate_Diamond_OilBased_C",
         "name": "Oil-Based Diamonds",
         "duration": 3,
         "producedIn": "particle-accelerator",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "powerRequirements": [
             250,
             750
@@ -1532,11 +1839,15 @@ export const RECIPES = {
             }
         ]
     },
-    "cloudy-diamonds": { // Recipe_Alternate_Diamond_Cloudy_C
+    "cloudy-diamonds": {
+        "className": "Recipe_Alternate_Diamond_Cloudy_C",
         "name": "Cloudy Diamonds",
         "duration": 3,
         "producedIn": "particle-accelerator",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "powerRequirements": [
             250,
             750
@@ -1558,11 +1869,15 @@ export const RECIPES = {
             }
         ]
     },
-    "dark-matter-trap": { // Recipe_Alternate_DarkMatter_Trap_C
+    "dark-matter-trap": {
+        "className": "Recipe_Alternate_DarkMatter_Trap_C",
         "name": "Dark Matter Trap",
         "duration": 2,
         "producedIn": "particle-accelerator",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "powerRequirements": [
             500,
             1500
@@ -1584,11 +1899,15 @@ export const RECIPES = {
             }
         ]
     },
-    "dark-matter-crystallization": { // Recipe_Alternate_DarkMatter_Crystallization_C
+    "dark-matter-crystallization": {
+        "className": "Recipe_Alternate_DarkMatter_Crystallization_C",
         "name": "Dark Matter Crystallization",
         "duration": 3,
         "producedIn": "particle-accelerator",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "powerRequirements": [
             500,
             1500
@@ -1606,11 +1925,15 @@ export const RECIPES = {
             }
         ]
     },
-    "wet-concrete": { // Recipe_Alternate_WetConcrete_C
+    "wet-concrete": {
+        "className": "Recipe_Alternate_WetConcrete_C",
         "name": "Wet Concrete",
         "duration": 3,
         "producedIn": "refinery",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "limestone",
@@ -1628,11 +1951,15 @@ export const RECIPES = {
             }
         ]
     },
-    "turbo-heavy-fuel": { // Recipe_Alternate_TurboHeavyFuel_C
+    "turbo-heavy-fuel": {
+        "className": "Recipe_Alternate_TurboHeavyFuel_C",
         "name": "Turbo Heavy Fuel",
         "duration": 8,
         "producedIn": "refinery",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "heavy-oil-residue",
@@ -1650,11 +1977,15 @@ export const RECIPES = {
             }
         ]
     },
-    "steel-rod": { // Recipe_Alternate_SteelRod_C
+    "steel-rod": {
+        "className": "Recipe_Alternate_SteelRod_C",
         "name": "Steel Rod",
         "duration": 5,
         "producedIn": "constructor",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "steel-ingot",
@@ -1668,11 +1999,16 @@ export const RECIPES = {
             }
         ]
     },
-    "steel-beam": { // Recipe_SteelBeam_C
+    "steel-beam": {
+        "className": "Recipe_SteelBeam_C",
         "name": "Steel Beam",
         "duration": 4,
         "producedIn": "constructor",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 3
+        },
         "inputs": [
             {
                 "item": "steel-ingot",
@@ -1686,11 +2022,16 @@ export const RECIPES = {
             }
         ]
     },
-    "steel-pipe": { // Recipe_SteelPipe_C
+    "steel-pipe": {
+        "className": "Recipe_SteelPipe_C",
         "name": "Steel Pipe",
         "duration": 6,
         "producedIn": "constructor",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 3
+        },
         "inputs": [
             {
                 "item": "steel-ingot",
@@ -1704,11 +2045,16 @@ export const RECIPES = {
             }
         ]
     },
-    "steel-ingot": { // Recipe_IngotSteel_C
+    "steel-ingot": {
+        "className": "Recipe_IngotSteel_C",
         "name": "Steel Ingot",
         "duration": 4,
         "producedIn": "foundry",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 3
+        },
         "inputs": [
             {
                 "item": "iron-ore",
@@ -1726,11 +2072,16 @@ export const RECIPES = {
             }
         ]
     },
-    "versatile-framework": { // Recipe_SpaceElevatorPart_2_C
+    "versatile-framework": {
+        "className": "Recipe_SpaceElevatorPart_2_C",
         "name": "Versatile Framework",
         "duration": 24,
         "producedIn": "assembler",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 3
+        },
         "inputs": [
             {
                 "item": "modular-frame",
@@ -1748,11 +2099,15 @@ export const RECIPES = {
             }
         ]
     },
-    "steel-canister": { // Recipe_Alternate_SteelCanister_C
+    "steel-canister": {
+        "className": "Recipe_Alternate_SteelCanister_C",
         "name": "Steel Canister",
         "duration": 6,
         "producedIn": "constructor",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "steel-ingot",
@@ -1766,11 +2121,16 @@ export const RECIPES = {
             }
         ]
     },
-    "empty-canister": { // Recipe_FluidCanister_C
+    "empty-canister": {
+        "className": "Recipe_FluidCanister_C",
         "name": "Empty Canister",
         "duration": 4,
         "producedIn": "constructor",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 5
+        },
         "inputs": [
             {
                 "item": "plastic",
@@ -1784,11 +2144,16 @@ export const RECIPES = {
             }
         ]
     },
-    "packaged-fuel": { // Recipe_Fuel_C
+    "packaged-fuel": {
+        "className": "Recipe_Fuel_C",
         "name": "Packaged Fuel",
         "duration": 3,
         "producedIn": "packager",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 5
+        },
         "inputs": [
             {
                 "item": "fuel",
@@ -1806,11 +2171,16 @@ export const RECIPES = {
             }
         ]
     },
-    "liquid-biofuel": { // Recipe_LiquidBiofuel_C
+    "liquid-biofuel": {
+        "className": "Recipe_LiquidBiofuel_C",
         "name": "Liquid Biofuel",
         "duration": 4,
         "producedIn": "refinery",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 5
+        },
         "inputs": [
             {
                 "item": "solid-biofuel",
@@ -1828,11 +2198,16 @@ export const RECIPES = {
             }
         ]
     },
-    "packaged-liquid-biofuel": { // Recipe_PackagedBiofuel_C
+    "packaged-liquid-biofuel": {
+        "className": "Recipe_PackagedBiofuel_C",
         "name": "Packaged Liquid Biofuel",
         "duration": 3,
         "producedIn": "packager",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 5
+        },
         "inputs": [
             {
                 "item": "liquid-biofuel",
@@ -1850,11 +2225,16 @@ export const RECIPES = {
             }
         ]
     },
-    "packaged-oil": { // Recipe_PackagedCrudeOil_C
+    "packaged-oil": {
+        "className": "Recipe_PackagedCrudeOil_C",
         "name": "Packaged Oil",
         "duration": 4,
         "producedIn": "packager",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 5
+        },
         "inputs": [
             {
                 "item": "crude-oil",
@@ -1872,11 +2252,16 @@ export const RECIPES = {
             }
         ]
     },
-    "packaged-heavy-oil-residue": { // Recipe_PackagedOilResidue_C
+    "packaged-heavy-oil-residue": {
+        "className": "Recipe_PackagedOilResidue_C",
         "name": "Packaged Heavy Oil Residue",
         "duration": 4,
         "producedIn": "packager",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 5
+        },
         "inputs": [
             {
                 "item": "heavy-oil-residue",
@@ -1894,11 +2279,16 @@ export const RECIPES = {
             }
         ]
     },
-    "packaged-water": { // Recipe_PackagedWater_C
+    "packaged-water": {
+        "className": "Recipe_PackagedWater_C",
         "name": "Packaged Water",
         "duration": 2,
         "producedIn": "packager",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 5
+        },
         "inputs": [
             {
                 "item": "water",
@@ -1916,11 +2306,16 @@ export const RECIPES = {
             }
         ]
     },
-    "unpackage-liquid-biofuel": { // Recipe_UnpackageBioFuel_C
+    "unpackage-liquid-biofuel": {
+        "className": "Recipe_UnpackageBioFuel_C",
         "name": "Unpackage Liquid Biofuel",
         "duration": 2,
         "producedIn": "packager",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 5
+        },
         "inputs": [
             {
                 "item": "packaged-liquid-biofuel",
@@ -1938,11 +2333,16 @@ export const RECIPES = {
             }
         ]
     },
-    "unpackage-fuel": { // Recipe_UnpackageFuel_C
+    "unpackage-fuel": {
+        "className": "Recipe_UnpackageFuel_C",
         "name": "Unpackage Fuel",
         "duration": 2,
         "producedIn": "packager",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 5
+        },
         "inputs": [
             {
                 "item": "packaged-fuel",
@@ -1960,11 +2360,16 @@ export const RECIPES = {
             }
         ]
     },
-    "unpackage-oil": { // Recipe_UnpackageOil_C
+    "unpackage-oil": {
+        "className": "Recipe_UnpackageOil_C",
         "name": "Unpackage Oil",
         "duration": 2,
         "producedIn": "packager",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 5
+        },
         "inputs": [
             {
                 "item": "packaged-oil",
@@ -1982,11 +2387,16 @@ export const RECIPES = {
             }
         ]
     },
-    "unpackage-heavy-oil-residue": { // Recipe_UnpackageOilResidue_C
+    "unpackage-heavy-oil-residue": {
+        "className": "Recipe_UnpackageOilResidue_C",
         "name": "Unpackage Heavy Oil Residue",
         "duration": 6,
         "producedIn": "packager",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 5
+        },
         "inputs": [
             {
                 "item": "packaged-heavy-oil-residue",
@@ -2004,11 +2414,16 @@ export const RECIPES = {
             }
         ]
     },
-    "unpackage-water": { // Recipe_UnpackageWater_C
+    "unpackage-water": {
+        "className": "Recipe_UnpackageWater_C",
         "name": "Unpackage Water",
         "duration": 1,
         "producedIn": "packager",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 5
+        },
         "inputs": [
             {
                 "item": "packaged-water",
@@ -2026,11 +2441,15 @@ export const RECIPES = {
             }
         ]
     },
-    "steamed-copper-sheet": { // Recipe_Alternate_SteamedCopperSheet_C
+    "steamed-copper-sheet": {
+        "className": "Recipe_Alternate_SteamedCopperSheet_C",
         "name": "Steamed Copper Sheet",
         "duration": 8,
         "producedIn": "refinery",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "copper-ingot",
@@ -2048,11 +2467,15 @@ export const RECIPES = {
             }
         ]
     },
-    "rubber-concrete": { // Recipe_Alternate_RubberConcrete_C
+    "rubber-concrete": {
+        "className": "Recipe_Alternate_RubberConcrete_C",
         "name": "Rubber Concrete",
         "duration": 6,
         "producedIn": "assembler",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "limestone",
@@ -2070,11 +2493,15 @@ export const RECIPES = {
             }
         ]
     },
-    "recycled-rubber": { // Recipe_Alternate_RecycledRubber_C
+    "recycled-rubber": {
+        "className": "Recipe_Alternate_RecycledRubber_C",
         "name": "Recycled Rubber",
         "duration": 12,
         "producedIn": "refinery",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "plastic",
@@ -2092,11 +2519,15 @@ export const RECIPES = {
             }
         ]
     },
-    "pure-quartz-crystal": { // Recipe_Alternate_PureQuartzCrystal_C
+    "pure-quartz-crystal": {
+        "className": "Recipe_Alternate_PureQuartzCrystal_C",
         "name": "Pure Quartz Crystal",
         "duration": 8,
         "producedIn": "refinery",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "raw-quartz",
@@ -2114,11 +2545,16 @@ export const RECIPES = {
             }
         ]
     },
-    "quartz-crystal": { // Recipe_QuartzCrystal_C
+    "quartz-crystal": {
+        "className": "Recipe_QuartzCrystal_C",
         "name": "Quartz Crystal",
         "duration": 8,
         "producedIn": "constructor",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "quartz"
+        },
         "inputs": [
             {
                 "item": "raw-quartz",
@@ -2132,11 +2568,15 @@ export const RECIPES = {
             }
         ]
     },
-    "pure-iron-ingot": { // Recipe_Alternate_PureIronIngot_C
+    "pure-iron-ingot": {
+        "className": "Recipe_Alternate_PureIronIngot_C",
         "name": "Pure Iron Ingot",
         "duration": 12,
         "producedIn": "refinery",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "iron-ore",
@@ -2154,11 +2594,15 @@ export const RECIPES = {
             }
         ]
     },
-    "pure-copper-ingot": { // Recipe_Alternate_PureCopperIngot_C
+    "pure-copper-ingot": {
+        "className": "Recipe_Alternate_PureCopperIngot_C",
         "name": "Pure Copper Ingot",
         "duration": 24,
         "producedIn": "refinery",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "copper-ore",
@@ -2176,11 +2620,15 @@ export const RECIPES = {
             }
         ]
     },
-    "pure-caterium-ingot": { // Recipe_Alternate_PureCateriumIngot_C
+    "pure-caterium-ingot": {
+        "className": "Recipe_Alternate_PureCateriumIngot_C",
         "name": "Pure Caterium Ingot",
         "duration": 5,
         "producedIn": "refinery",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "caterium-ore",
@@ -2198,11 +2646,15 @@ export const RECIPES = {
             }
         ]
     },
-    "pure-aluminum-ingot": { // Recipe_PureAluminumIngot_C
+    "pure-aluminum-ingot": {
+        "className": "Recipe_PureAluminumIngot_C",
         "name": "Pure Aluminum Ingot",
         "duration": 2,
         "producedIn": "smelter",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "aluminum-scrap",
@@ -2216,11 +2668,16 @@ export const RECIPES = {
             }
         ]
     },
-    "aluminum-casing": { // Recipe_AluminumCasing_C
+    "aluminum-casing": {
+        "className": "Recipe_AluminumCasing_C",
         "name": "Aluminum Casing",
         "duration": 2,
         "producedIn": "constructor",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 7
+        },
         "inputs": [
             {
                 "item": "aluminum-ingot",
@@ -2234,11 +2691,16 @@ export const RECIPES = {
             }
         ]
     },
-    "alclad-aluminum-sheet": { // Recipe_AluminumSheet_C
+    "alclad-aluminum-sheet": {
+        "className": "Recipe_AluminumSheet_C",
         "name": "Alclad Aluminum Sheet",
         "duration": 6,
         "producedIn": "assembler",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 7
+        },
         "inputs": [
             {
                 "item": "aluminum-ingot",
@@ -2256,11 +2718,16 @@ export const RECIPES = {
             }
         ]
     },
-    "alumina-solution": { // Recipe_AluminaSolution_C
+    "alumina-solution": {
+        "className": "Recipe_AluminaSolution_C",
         "name": "Alumina Solution",
         "duration": 6,
         "producedIn": "refinery",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 7
+        },
         "inputs": [
             {
                 "item": "bauxite",
@@ -2282,11 +2749,16 @@ export const RECIPES = {
             }
         ]
     },
-    "aluminum-scrap": { // Recipe_AluminumScrap_C
+    "aluminum-scrap": {
+        "className": "Recipe_AluminumScrap_C",
         "name": "Aluminum Scrap",
         "duration": 1,
         "producedIn": "refinery",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 7
+        },
         "inputs": [
             {
                 "item": "alumina-solution",
@@ -2308,11 +2780,16 @@ export const RECIPES = {
             }
         ]
     },
-    "packaged-alumina-solution": { // Recipe_PackagedAlumina_C
+    "packaged-alumina-solution": {
+        "className": "Recipe_PackagedAlumina_C",
         "name": "Packaged Alumina Solution",
         "duration": 1,
         "producedIn": "packager",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 7
+        },
         "inputs": [
             {
                 "item": "alumina-solution",
@@ -2330,11 +2807,16 @@ export const RECIPES = {
             }
         ]
     },
-    "aluminum-ingot": { // Recipe_IngotAluminum_C
+    "aluminum-ingot": {
+        "className": "Recipe_IngotAluminum_C",
         "name": "Aluminum Ingot",
         "duration": 4,
         "producedIn": "foundry",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 7
+        },
         "inputs": [
             {
                 "item": "aluminum-scrap",
@@ -2352,11 +2834,16 @@ export const RECIPES = {
             }
         ]
     },
-    "silica": { // Recipe_Silica_C
+    "silica": {
+        "className": "Recipe_Silica_C",
         "name": "Silica",
         "duration": 8,
         "producedIn": "constructor",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "quartz"
+        },
         "inputs": [
             {
                 "item": "raw-quartz",
@@ -2370,11 +2857,16 @@ export const RECIPES = {
             }
         ]
     },
-    "crystal-oscillator": { // Recipe_CrystalOscillator_C
+    "crystal-oscillator": {
+        "className": "Recipe_CrystalOscillator_C",
         "name": "Crystal Oscillator",
         "duration": 120,
         "producedIn": "manufacturer",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "quartz"
+        },
         "inputs": [
             {
                 "item": "quartz-crystal",
@@ -2396,11 +2888,16 @@ export const RECIPES = {
             }
         ]
     },
-    "unpackage-alumina-solution": { // Recipe_UnpackageAlumina_C
+    "unpackage-alumina-solution": {
+        "className": "Recipe_UnpackageAlumina_C",
         "name": "Unpackage Alumina Solution",
         "duration": 1,
         "producedIn": "packager",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 7
+        },
         "inputs": [
             {
                 "item": "packaged-alumina-solution",
@@ -2418,11 +2915,15 @@ export const RECIPES = {
             }
         ]
     },
-    "polymer-resin": { // Recipe_Alternate_PolymerResin_C
+    "polymer-resin": {
+        "className": "Recipe_Alternate_PolymerResin_C",
         "name": "Polymer Resin",
         "duration": 6,
         "producedIn": "refinery",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "crude-oil",
@@ -2440,11 +2941,15 @@ export const RECIPES = {
             }
         ]
     },
-    "plastic-smart-plating": { // Recipe_Alternate_PlasticSmartPlating_C
+    "plastic-smart-plating": {
+        "className": "Recipe_Alternate_PlasticSmartPlating_C",
         "name": "Plastic Smart Plating",
         "duration": 24,
         "producedIn": "manufacturer",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "reinforced-iron-plate",
@@ -2466,11 +2971,15 @@ export const RECIPES = {
             }
         ]
     },
-    "automated-speed-wiring": { // Recipe_Alternate_HighSpeedWiring_C
+    "automated-speed-wiring": {
+        "className": "Recipe_Alternate_HighSpeedWiring_C",
         "name": "Automated Speed Wiring",
         "duration": 32,
         "producedIn": "manufacturer",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "stator",
@@ -2492,11 +3001,16 @@ export const RECIPES = {
             }
         ]
     },
-    "encased-industrial-beam": { // Recipe_EncasedIndustrialBeam_C
+    "encased-industrial-beam": {
+        "className": "Recipe_EncasedIndustrialBeam_C",
         "name": "Encased Industrial Beam",
         "duration": 10,
         "producedIn": "assembler",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 4
+        },
         "inputs": [
             {
                 "item": "steel-beam",
@@ -2514,11 +3028,16 @@ export const RECIPES = {
             }
         ]
     },
-    "motor": { // Recipe_Motor_C
+    "motor": {
+        "className": "Recipe_Motor_C",
         "name": "Motor",
         "duration": 12,
         "producedIn": "assembler",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 4
+        },
         "inputs": [
             {
                 "item": "rotor",
@@ -2536,11 +3055,16 @@ export const RECIPES = {
             }
         ]
     },
-    "stator": { // Recipe_Stator_C
+    "stator": {
+        "className": "Recipe_Stator_C",
         "name": "Stator",
         "duration": 12,
         "producedIn": "assembler",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 4
+        },
         "inputs": [
             {
                 "item": "steel-pipe",
@@ -2558,11 +3082,16 @@ export const RECIPES = {
             }
         ]
     },
-    "automated-wiring": { // Recipe_SpaceElevatorPart_3_C
+    "automated-wiring": {
+        "className": "Recipe_SpaceElevatorPart_3_C",
         "name": "Automated Wiring",
         "duration": 24,
         "producedIn": "assembler",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 4
+        },
         "inputs": [
             {
                 "item": "stator",
@@ -2580,11 +3109,16 @@ export const RECIPES = {
             }
         ]
     },
-    "ai-limiter": { // Recipe_AILimiter_C
+    "ai-limiter": {
+        "className": "Recipe_AILimiter_C",
         "name": "AI Limiter",
         "duration": 12,
         "producedIn": "assembler",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "caterium"
+        },
         "inputs": [
             {
                 "item": "copper-sheet",
@@ -2602,11 +3136,15 @@ export const RECIPES = {
             }
         ]
     },
-    "heavy-oil-residue": { // Recipe_Alternate_HeavyOilResidue_C
+    "heavy-oil-residue": {
+        "className": "Recipe_Alternate_HeavyOilResidue_C",
         "name": "Heavy Oil Residue",
         "duration": 6,
         "producedIn": "refinery",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "crude-oil",
@@ -2624,11 +3162,15 @@ export const RECIPES = {
             }
         ]
     },
-    "heavy-flexible-frame": { // Recipe_Alternate_HeavyFlexibleFrame_C
+    "heavy-flexible-frame": {
+        "className": "Recipe_Alternate_HeavyFlexibleFrame_C",
         "name": "Heavy Flexible Frame",
         "duration": 16,
         "producedIn": "manufacturer",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "modular-frame",
@@ -2654,11 +3196,16 @@ export const RECIPES = {
             }
         ]
     },
-    "computer": { // Recipe_Computer_C
+    "computer": {
+        "className": "Recipe_Computer_C",
         "name": "Computer",
         "duration": 24,
         "producedIn": "manufacturer",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 5
+        },
         "inputs": [
             {
                 "item": "circuit-board",
@@ -2680,11 +3227,16 @@ export const RECIPES = {
             }
         ]
     },
-    "heavy-modular-frame": { // Recipe_ModularFrameHeavy_C
+    "heavy-modular-frame": {
+        "className": "Recipe_ModularFrameHeavy_C",
         "name": "Heavy Modular Frame",
         "duration": 30,
         "producedIn": "manufacturer",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 5
+        },
         "inputs": [
             {
                 "item": "modular-frame",
@@ -2710,11 +3262,16 @@ export const RECIPES = {
             }
         ]
     },
-    "modular-engine": { // Recipe_SpaceElevatorPart_4_C
+    "modular-engine": {
+        "className": "Recipe_SpaceElevatorPart_4_C",
         "name": "Modular Engine",
         "duration": 60,
         "producedIn": "manufacturer",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 5
+        },
         "inputs": [
             {
                 "item": "motor",
@@ -2736,11 +3293,16 @@ export const RECIPES = {
             }
         ]
     },
-    "adaptive-control-unit": { // Recipe_SpaceElevatorPart_5_C
+    "adaptive-control-unit": {
+        "className": "Recipe_SpaceElevatorPart_5_C",
         "name": "Adaptive Control Unit",
         "duration": 60,
         "producedIn": "manufacturer",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 5
+        },
         "inputs": [
             {
                 "item": "automated-wiring",
@@ -2766,11 +3328,15 @@ export const RECIPES = {
             }
         ]
     },
-    "fused-wire": { // Recipe_Alternate_FusedWire_C
+    "fused-wire": {
+        "className": "Recipe_Alternate_FusedWire_C",
         "name": "Fused Wire",
         "duration": 20,
         "producedIn": "assembler",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "copper-ingot",
@@ -2788,11 +3354,15 @@ export const RECIPES = {
             }
         ]
     },
-    "flexible-framework": { // Recipe_Alternate_FlexibleFramework_C
+    "flexible-framework": {
+        "className": "Recipe_Alternate_FlexibleFramework_C",
         "name": "Flexible Framework",
         "duration": 16,
         "producedIn": "manufacturer",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "modular-frame",
@@ -2814,11 +3384,15 @@ export const RECIPES = {
             }
         ]
     },
-    "electrode-circuit-board": { // Recipe_Alternate_ElectrodeCircuitBoard_C
+    "electrode-circuit-board": {
+        "className": "Recipe_Alternate_ElectrodeCircuitBoard_C",
         "name": "Electrode Circuit Board",
         "duration": 12,
         "producedIn": "assembler",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "rubber",
@@ -2836,11 +3410,15 @@ export const RECIPES = {
             }
         ]
     },
-    "electrode-aluminum-scrap": { // Recipe_Alternate_ElectroAluminumScrap_C
+    "electrode-aluminum-scrap": {
+        "className": "Recipe_Alternate_ElectroAluminumScrap_C",
         "name": "Electrode Aluminum Scrap",
         "duration": 4,
         "producedIn": "refinery",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "alumina-solution",
@@ -2862,11 +3440,15 @@ export const RECIPES = {
             }
         ]
     },
-    "diluted-packaged-fuel": { // Recipe_Alternate_DilutedPackagedFuel_C
+    "diluted-packaged-fuel": {
+        "className": "Recipe_Alternate_DilutedPackagedFuel_C",
         "name": "Diluted Packaged Fuel",
         "duration": 2,
         "producedIn": "refinery",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "heavy-oil-residue",
@@ -2884,11 +3466,15 @@ export const RECIPES = {
             }
         ]
     },
-    "copper-rotor": { // Recipe_Alternate_CopperRotor_C
+    "copper-rotor": {
+        "className": "Recipe_Alternate_CopperRotor_C",
         "name": "Copper Rotor",
         "duration": 16,
         "producedIn": "assembler",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "copper-sheet",
@@ -2906,11 +3492,16 @@ export const RECIPES = {
             }
         ]
     },
-    "modular-frame": { // Recipe_ModularFrame_C
+    "modular-frame": {
+        "className": "Recipe_ModularFrame_C",
         "name": "Modular Frame",
         "duration": 60,
         "producedIn": "assembler",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 2
+        },
         "inputs": [
             {
                 "item": "reinforced-iron-plate",
@@ -2928,11 +3519,16 @@ export const RECIPES = {
             }
         ]
     },
-    "rotor": { // Recipe_Rotor_C
+    "rotor": {
+        "className": "Recipe_Rotor_C",
         "name": "Rotor",
         "duration": 15,
         "producedIn": "assembler",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 2
+        },
         "inputs": [
             {
                 "item": "iron-rod",
@@ -2950,11 +3546,16 @@ export const RECIPES = {
             }
         ]
     },
-    "copper-sheet": { // Recipe_CopperSheet_C
+    "copper-sheet": {
+        "className": "Recipe_CopperSheet_C",
         "name": "Copper Sheet",
         "duration": 6,
         "producedIn": "constructor",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 2
+        },
         "inputs": [
             {
                 "item": "copper-ingot",
@@ -2968,11 +3569,16 @@ export const RECIPES = {
             }
         ]
     },
-    "smart-plating": { // Recipe_SpaceElevatorPart_1_C
+    "smart-plating": {
+        "className": "Recipe_SpaceElevatorPart_1_C",
         "name": "Smart Plating",
         "duration": 30,
         "producedIn": "assembler",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 2
+        },
         "inputs": [
             {
                 "item": "reinforced-iron-plate",
@@ -2990,11 +3596,15 @@ export const RECIPES = {
             }
         ]
     },
-    "copper-alloy-ingot": { // Recipe_Alternate_CopperAlloyIngot_C
+    "copper-alloy-ingot": {
+        "className": "Recipe_Alternate_CopperAlloyIngot_C",
         "name": "Copper Alloy Ingot",
         "duration": 6,
         "producedIn": "foundry",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "copper-ore",
@@ -3012,11 +3622,15 @@ export const RECIPES = {
             }
         ]
     },
-    "coke-steel-ingot": { // Recipe_Alternate_CokeSteelIngot_C
+    "coke-steel-ingot": {
+        "className": "Recipe_Alternate_CokeSteelIngot_C",
         "name": "Coke Steel Ingot",
         "duration": 12,
         "producedIn": "foundry",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "iron-ore",
@@ -3034,11 +3648,15 @@ export const RECIPES = {
             }
         ]
     },
-    "coated-iron-plate": { // Recipe_Alternate_CoatedIronPlate_C
+    "coated-iron-plate": {
+        "className": "Recipe_Alternate_CoatedIronPlate_C",
         "name": "Coated Iron Plate",
         "duration": 8,
         "producedIn": "assembler",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "iron-ingot",
@@ -3056,11 +3674,15 @@ export const RECIPES = {
             }
         ]
     },
-    "coated-iron-canister": { // Recipe_Alternate_CoatedIronCanister_C
+    "coated-iron-canister": {
+        "className": "Recipe_Alternate_CoatedIronCanister_C",
         "name": "Coated Iron Canister",
         "duration": 4,
         "producedIn": "assembler",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "iron-plate",
@@ -3078,11 +3700,15 @@ export const RECIPES = {
             }
         ]
     },
-    "coated-cable": { // Recipe_Alternate_CoatedCable_C
+    "coated-cable": {
+        "className": "Recipe_Alternate_CoatedCable_C",
         "name": "Coated Cable",
         "duration": 8,
         "producedIn": "refinery",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "wire",
@@ -3100,11 +3726,15 @@ export const RECIPES = {
             }
         ]
     },
-    "bolted-frame": { // Recipe_Alternate_BoltedFrame_C
+    "bolted-frame": {
+        "className": "Recipe_Alternate_BoltedFrame_C",
         "name": "Bolted Frame",
         "duration": 24,
         "producedIn": "assembler",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "reinforced-iron-plate",
@@ -3122,11 +3752,15 @@ export const RECIPES = {
             }
         ]
     },
-    "adhered-iron-plate": { // Recipe_Alternate_AdheredIronPlate_C
+    "adhered-iron-plate": {
+        "className": "Recipe_Alternate_AdheredIronPlate_C",
         "name": "Adhered Iron Plate",
         "duration": 16,
         "producedIn": "assembler",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "iron-plate",
@@ -3144,11 +3778,15 @@ export const RECIPES = {
             }
         ]
     },
-    "turbo-pressure-motor": { // Recipe_Alternate_TurboPressureMotor_C
+    "turbo-pressure-motor": {
+        "className": "Recipe_Alternate_TurboPressureMotor_C",
         "name": "Turbo Pressure Motor",
         "duration": 32,
         "producedIn": "manufacturer",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "motor",
@@ -3174,11 +3812,16 @@ export const RECIPES = {
             }
         ]
     },
-    "encased-plutonium-cell": { // Recipe_PlutoniumCell_C
+    "encased-plutonium-cell": {
+        "className": "Recipe_PlutoniumCell_C",
         "name": "Encased Plutonium Cell",
         "duration": 12,
         "producedIn": "assembler",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 8
+        },
         "inputs": [
             {
                 "item": "plutonium-pellet",
@@ -3196,11 +3839,16 @@ export const RECIPES = {
             }
         ]
     },
-    "pressure-conversion-cube": { // Recipe_PressureConversionCube_C
+    "pressure-conversion-cube": {
+        "className": "Recipe_PressureConversionCube_C",
         "name": "Pressure Conversion Cube",
         "duration": 60,
         "producedIn": "assembler",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 8
+        },
         "inputs": [
             {
                 "item": "fused-modular-frame",
@@ -3218,11 +3866,16 @@ export const RECIPES = {
             }
         ]
     },
-    "nitric-acid": { // Recipe_NitricAcid_C
+    "nitric-acid": {
+        "className": "Recipe_NitricAcid_C",
         "name": "Nitric Acid",
         "duration": 6,
         "producedIn": "blender",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 8
+        },
         "inputs": [
             {
                 "item": "nitrogen-gas",
@@ -3244,11 +3897,16 @@ export const RECIPES = {
             }
         ]
     },
-    "non-fissile-uranium": { // Recipe_NonFissileUranium_C
+    "non-fissile-uranium": {
+        "className": "Recipe_NonFissileUranium_C",
         "name": "Non-Fissile Uranium",
         "duration": 24,
         "producedIn": "blender",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 8
+        },
         "inputs": [
             {
                 "item": "uranium-waste",
@@ -3278,11 +3936,16 @@ export const RECIPES = {
             }
         ]
     },
-    "copper-powder": { // Recipe_CopperDust_C
+    "copper-powder": {
+        "className": "Recipe_CopperDust_C",
         "name": "Copper Powder",
         "duration": 6,
         "producedIn": "constructor",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 8
+        },
         "inputs": [
             {
                 "item": "copper-ingot",
@@ -3296,11 +3959,16 @@ export const RECIPES = {
             }
         ]
     },
-    "plutonium-pellet": { // Recipe_Plutonium_C
+    "plutonium-pellet": {
+        "className": "Recipe_Plutonium_C",
         "name": "Plutonium Pellet",
         "duration": 60,
         "producedIn": "particle-accelerator",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 8
+        },
         "powerRequirements": [
             250,
             750
@@ -3322,11 +3990,16 @@ export const RECIPES = {
             }
         ]
     },
-    "plutonium-fuel-rod": { // Recipe_PlutoniumFuelRod_C
+    "plutonium-fuel-rod": {
+        "className": "Recipe_PlutoniumFuelRod_C",
         "name": "Plutonium Fuel Rod",
         "duration": 240,
         "producedIn": "manufacturer",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 8
+        },
         "inputs": [
             {
                 "item": "encased-plutonium-cell",
@@ -3352,11 +4025,16 @@ export const RECIPES = {
             }
         ]
     },
-    "packaged-nitric-acid": { // Recipe_PackagedNitricAcid_C
+    "packaged-nitric-acid": {
+        "className": "Recipe_PackagedNitricAcid_C",
         "name": "Packaged Nitric Acid",
         "duration": 2,
         "producedIn": "packager",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 8
+        },
         "inputs": [
             {
                 "item": "nitric-acid",
@@ -3374,11 +4052,16 @@ export const RECIPES = {
             }
         ]
     },
-    "nuclear-pasta": { // Recipe_SpaceElevatorPart_9_C
+    "nuclear-pasta": {
+        "className": "Recipe_SpaceElevatorPart_9_C",
         "name": "Nuclear Pasta",
         "duration": 120,
         "producedIn": "particle-accelerator",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 8
+        },
         "powerRequirements": [
             500,
             1500
@@ -3400,11 +4083,16 @@ export const RECIPES = {
             }
         ]
     },
-    "unpackage-nitric-acid": { // Recipe_UnpackageNitricAcid_C
+    "unpackage-nitric-acid": {
+        "className": "Recipe_UnpackageNitricAcid_C",
         "name": "Unpackage Nitric Acid",
         "duration": 3,
         "producedIn": "packager",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 8
+        },
         "inputs": [
             {
                 "item": "packaged-nitric-acid",
@@ -3422,11 +4110,15 @@ export const RECIPES = {
             }
         ]
     },
-    "turbo-blend-fuel": { // Recipe_Alternate_TurboBlendFuel_C
+    "turbo-blend-fuel": {
+        "className": "Recipe_Alternate_TurboBlendFuel_C",
         "name": "Turbo Blend Fuel",
         "duration": 8,
         "producedIn": "blender",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "fuel",
@@ -3452,11 +4144,16 @@ export const RECIPES = {
             }
         ]
     },
-    "encased-uranium-cell": { // Recipe_UraniumCell_C
+    "encased-uranium-cell": {
+        "className": "Recipe_UraniumCell_C",
         "name": "Encased Uranium Cell",
         "duration": 12,
         "producedIn": "blender",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 8
+        },
         "inputs": [
             {
                 "item": "uranium",
@@ -3482,11 +4179,16 @@ export const RECIPES = {
             }
         ]
     },
-    "cooling-system": { // Recipe_CoolingSystem_C
+    "cooling-system": {
+        "className": "Recipe_CoolingSystem_C",
         "name": "Cooling System",
         "duration": 10,
         "producedIn": "blender",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 8
+        },
         "inputs": [
             {
                 "item": "heat-sink",
@@ -3512,11 +4214,16 @@ export const RECIPES = {
             }
         ]
     },
-    "battery": { // Recipe_Battery_C
+    "battery": {
+        "className": "Recipe_Battery_C",
         "name": "Battery",
         "duration": 3,
         "producedIn": "blender",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 7
+        },
         "inputs": [
             {
                 "item": "sulfuric-acid",
@@ -3542,11 +4249,16 @@ export const RECIPES = {
             }
         ]
     },
-    "supercomputer": { // Recipe_ComputerSuper_C
+    "supercomputer": {
+        "className": "Recipe_ComputerSuper_C",
         "name": "Supercomputer",
         "duration": 32,
         "producedIn": "manufacturer",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 7
+        },
         "inputs": [
             {
                 "item": "computer",
@@ -3572,11 +4284,16 @@ export const RECIPES = {
             }
         ]
     },
-    "radio-control-unit": { // Recipe_RadioControlUnit_C
+    "radio-control-unit": {
+        "className": "Recipe_RadioControlUnit_C",
         "name": "Radio Control Unit",
         "duration": 48,
         "producedIn": "manufacturer",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 7
+        },
         "inputs": [
             {
                 "item": "aluminum-casing",
@@ -3598,11 +4315,16 @@ export const RECIPES = {
             }
         ]
     },
-    "sulfuric-acid": { // Recipe_SulfuricAcid_C
+    "sulfuric-acid": {
+        "className": "Recipe_SulfuricAcid_C",
         "name": "Sulfuric Acid",
         "duration": 6,
         "producedIn": "refinery",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 7
+        },
         "inputs": [
             {
                 "item": "sulfur",
@@ -3620,11 +4342,16 @@ export const RECIPES = {
             }
         ]
     },
-    "packaged-sulfuric-acid": { // Recipe_PackagedSulfuricAcid_C
+    "packaged-sulfuric-acid": {
+        "className": "Recipe_PackagedSulfuricAcid_C",
         "name": "Packaged Sulfuric Acid",
         "duration": 3,
         "producedIn": "packager",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 7
+        },
         "inputs": [
             {
                 "item": "sulfuric-acid",
@@ -3642,11 +4369,16 @@ export const RECIPES = {
             }
         ]
     },
-    "assembly-director-system": { // Recipe_SpaceElevatorPart_7_C
+    "assembly-director-system": {
+        "className": "Recipe_SpaceElevatorPart_7_C",
         "name": "Assembly Director System",
         "duration": 80,
         "producedIn": "assembler",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 7
+        },
         "inputs": [
             {
                 "item": "adaptive-control-unit",
@@ -3664,11 +4396,16 @@ export const RECIPES = {
             }
         ]
     },
-    "high-speed-connector": { // Recipe_HighSpeedConnector_C
+    "high-speed-connector": {
+        "className": "Recipe_HighSpeedConnector_C",
         "name": "High-Speed Connector",
         "duration": 16,
         "producedIn": "manufacturer",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "caterium"
+        },
         "inputs": [
             {
                 "item": "quickwire",
@@ -3690,11 +4427,16 @@ export const RECIPES = {
             }
         ]
     },
-    "unpackage-sulfuric-acid": { // Recipe_UnpackageSulfuricAcid_C
+    "unpackage-sulfuric-acid": {
+        "className": "Recipe_UnpackageSulfuricAcid_C",
         "name": "Unpackage Sulfuric Acid",
         "duration": 1,
         "producedIn": "packager",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 7
+        },
         "inputs": [
             {
                 "item": "packaged-sulfuric-acid",
@@ -3712,11 +4454,15 @@ export const RECIPES = {
             }
         ]
     },
-    "super-state-computer": { // Recipe_Alternate_SuperStateComputer_C
+    "super-state-computer": {
+        "className": "Recipe_Alternate_SuperStateComputer_C",
         "name": "Super-State Computer",
         "duration": 25,
         "producedIn": "manufacturer",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "computer",
@@ -3742,11 +4488,16 @@ export const RECIPES = {
             }
         ]
     },
-    "electromagnetic-control-rod": { // Recipe_ElectromagneticControlRod_C
+    "electromagnetic-control-rod": {
+        "className": "Recipe_ElectromagneticControlRod_C",
         "name": "Electromagnetic Control Rod",
         "duration": 30,
         "producedIn": "assembler",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 8
+        },
         "inputs": [
             {
                 "item": "stator",
@@ -3764,11 +4515,16 @@ export const RECIPES = {
             }
         ]
     },
-    "uranium-fuel-rod": { // Recipe_NuclearFuelRod_C
+    "uranium-fuel-rod": {
+        "className": "Recipe_NuclearFuelRod_C",
         "name": "Uranium Fuel Rod",
         "duration": 150,
         "producedIn": "manufacturer",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 8
+        },
         "inputs": [
             {
                 "item": "encased-uranium-cell",
@@ -3790,11 +4546,16 @@ export const RECIPES = {
             }
         ]
     },
-    "magnetic-field-generator": { // Recipe_SpaceElevatorPart_6_C
+    "magnetic-field-generator": {
+        "className": "Recipe_SpaceElevatorPart_6_C",
         "name": "Magnetic Field Generator",
         "duration": 120,
         "producedIn": "assembler",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 8
+        },
         "inputs": [
             {
                 "item": "versatile-framework",
@@ -3812,11 +4573,15 @@ export const RECIPES = {
             }
         ]
     },
-    "sloppy-alumina": { // Recipe_Alternate_SloppyAlumina_C
+    "sloppy-alumina": {
+        "className": "Recipe_Alternate_SloppyAlumina_C",
         "name": "Sloppy Alumina",
         "duration": 3,
         "producedIn": "refinery",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "bauxite",
@@ -3834,11 +4599,15 @@ export const RECIPES = {
             }
         ]
     },
-    "radio-control-system": { // Recipe_Alternate_RadioControlSystem_C
+    "radio-control-system": {
+        "className": "Recipe_Alternate_RadioControlSystem_C",
         "name": "Radio Control System",
         "duration": 40,
         "producedIn": "manufacturer",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "crystal-oscillator",
@@ -3864,11 +4633,15 @@ export const RECIPES = {
             }
         ]
     },
-    "plutonium-fuel-unit": { // Recipe_Alternate_PlutoniumFuelUnit_C
+    "plutonium-fuel-unit": {
+        "className": "Recipe_Alternate_PlutoniumFuelUnit_C",
         "name": "Plutonium Fuel Unit",
         "duration": 120,
         "producedIn": "assembler",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "encased-plutonium-cell",
@@ -3886,11 +4659,15 @@ export const RECIPES = {
             }
         ]
     },
-    "oc-supercomputer": { // Recipe_Alternate_OCSupercomputer_C
+    "oc-supercomputer": {
+        "className": "Recipe_Alternate_OCSupercomputer_C",
         "name": "OC Supercomputer",
         "duration": 20,
         "producedIn": "assembler",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "radio-control-unit",
@@ -3908,11 +4685,16 @@ export const RECIPES = {
             }
         ]
     },
-    "heat-sink": { // Recipe_HeatSink_C
+    "heat-sink": {
+        "className": "Recipe_HeatSink_C",
         "name": "Heat Sink",
         "duration": 8,
         "producedIn": "assembler",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 8
+        },
         "inputs": [
             {
                 "item": "alclad-aluminum-sheet",
@@ -3930,11 +4712,16 @@ export const RECIPES = {
             }
         ]
     },
-    "fused-modular-frame": { // Recipe_FusedModularFrame_C
+    "fused-modular-frame": {
+        "className": "Recipe_FusedModularFrame_C",
         "name": "Fused Modular Frame",
         "duration": 40,
         "producedIn": "blender",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 8
+        },
         "inputs": [
             {
                 "item": "heavy-modular-frame",
@@ -3956,11 +4743,16 @@ export const RECIPES = {
             }
         ]
     },
-    "empty-fluid-tank": { // Recipe_GasTank_C
+    "empty-fluid-tank": {
+        "className": "Recipe_GasTank_C",
         "name": "Empty Fluid Tank",
         "duration": 1,
         "producedIn": "constructor",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 8
+        },
         "inputs": [
             {
                 "item": "aluminum-ingot",
@@ -3974,11 +4766,16 @@ export const RECIPES = {
             }
         ]
     },
-    "packaged-nitrogen-gas": { // Recipe_PackagedNitrogen_C
+    "packaged-nitrogen-gas": {
+        "className": "Recipe_PackagedNitrogen_C",
         "name": "Packaged Nitrogen Gas",
         "duration": 1,
         "producedIn": "packager",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 8
+        },
         "inputs": [
             {
                 "item": "nitrogen-gas",
@@ -3996,11 +4793,16 @@ export const RECIPES = {
             }
         ]
     },
-    "unpackage-nitrogen-gas": { // Recipe_UnpackageNitrogen_C
+    "unpackage-nitrogen-gas": {
+        "className": "Recipe_UnpackageNitrogen_C",
         "name": "Unpackage Nitrogen Gas",
         "duration": 1,
         "producedIn": "packager",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 8
+        },
         "inputs": [
             {
                 "item": "packaged-nitrogen-gas",
@@ -4018,11 +4820,15 @@ export const RECIPES = {
             }
         ]
     },
-    "instant-scrap": { // Recipe_Alternate_InstantScrap_C
+    "instant-scrap": {
+        "className": "Recipe_Alternate_InstantScrap_C",
         "name": "Instant Scrap",
         "duration": 6,
         "producedIn": "blender",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "bauxite",
@@ -4052,11 +4858,15 @@ export const RECIPES = {
             }
         ]
     },
-    "instant-plutonium-cell": { // Recipe_Alternate_InstantPlutoniumCell_C
+    "instant-plutonium-cell": {
+        "className": "Recipe_Alternate_InstantPlutoniumCell_C",
         "name": "Instant Plutonium Cell",
         "duration": 120,
         "producedIn": "particle-accelerator",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "powerRequirements": [
             250,
             750
@@ -4078,11 +4888,15 @@ export const RECIPES = {
             }
         ]
     },
-    "heat-fused-frame": { // Recipe_Alternate_HeatFusedFrame_C
+    "heat-fused-frame": {
+        "className": "Recipe_Alternate_HeatFusedFrame_C",
         "name": "Heat-Fused Frame",
         "duration": 20,
         "producedIn": "blender",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "heavy-modular-frame",
@@ -4108,11 +4922,15 @@ export const RECIPES = {
             }
         ]
     },
-    "fertile-uranium": { // Recipe_Alternate_FertileUranium_C
+    "fertile-uranium": {
+        "className": "Recipe_Alternate_FertileUranium_C",
         "name": "Fertile Uranium",
         "duration": 12,
         "producedIn": "blender",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "uranium",
@@ -4142,11 +4960,15 @@ export const RECIPES = {
             }
         ]
     },
-    "electric-motor": { // Recipe_Alternate_ElectricMotor_C
+    "electric-motor": {
+        "className": "Recipe_Alternate_ElectricMotor_C",
         "name": "Electric Motor",
         "duration": 16,
         "producedIn": "assembler",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "electromagnetic-control-rod",
@@ -4164,11 +4986,15 @@ export const RECIPES = {
             }
         ]
     },
-    "diluted-fuel": { // Recipe_Alternate_DilutedFuel_C
+    "diluted-fuel": {
+        "className": "Recipe_Alternate_DilutedFuel_C",
         "name": "Diluted Fuel",
         "duration": 6,
         "producedIn": "blender",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "heavy-oil-residue",
@@ -4186,11 +5012,15 @@ export const RECIPES = {
             }
         ]
     },
-    "cooling-device": { // Recipe_Alternate_CoolingDevice_C
+    "cooling-device": {
+        "className": "Recipe_Alternate_CoolingDevice_C",
         "name": "Cooling Device",
         "duration": 24,
         "producedIn": "blender",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "heat-sink",
@@ -4212,11 +5042,15 @@ export const RECIPES = {
             }
         ]
     },
-    "classic-battery": { // Recipe_Alternate_ClassicBattery_C
+    "classic-battery": {
+        "className": "Recipe_Alternate_ClassicBattery_C",
         "name": "Classic Battery",
         "duration": 8,
         "producedIn": "manufacturer",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "sulfur",
@@ -4242,11 +5076,15 @@ export const RECIPES = {
             }
         ]
     },
-    "automated-miner": { // Recipe_Alternate_AutomatedMiner_C
+    "automated-miner": {
+        "className": "Recipe_Alternate_AutomatedMiner_C",
         "name": "Automated Miner",
         "duration": 60,
         "producedIn": "assembler",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "steel-pipe",
@@ -4264,11 +5102,15 @@ export const RECIPES = {
             }
         ]
     },
-    "alclad-casing": { // Recipe_Alternate_AlcladCasing_C
+    "alclad-casing": {
+        "className": "Recipe_Alternate_AlcladCasing_C",
         "name": "Alclad Casing",
         "duration": 8,
         "producedIn": "assembler",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "aluminum-ingot",
@@ -4286,11 +5128,15 @@ export const RECIPES = {
             }
         ]
     },
-    "molded-steel-pipe": { // Recipe_Alternate_SteelPipe_Molded_C
+    "molded-steel-pipe": {
+        "className": "Recipe_Alternate_SteelPipe_Molded_C",
         "name": "Molded Steel Pipe",
         "duration": 6,
         "producedIn": "foundry",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "steel-ingot",
@@ -4308,11 +5154,15 @@ export const RECIPES = {
             }
         ]
     },
-    "iron-pipe": { // Recipe_Alternate_SteelPipe_Iron_C
+    "iron-pipe": {
+        "className": "Recipe_Alternate_SteelPipe_Iron_C",
         "name": "Iron Pipe",
         "duration": 12,
         "producedIn": "constructor",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "iron-ingot",
@@ -4326,11 +5176,15 @@ export const RECIPES = {
             }
         ]
     },
-    "steel-cast-plate": { // Recipe_Alternate_SteelCastedPlate_C
+    "steel-cast-plate": {
+        "className": "Recipe_Alternate_SteelCastedPlate_C",
         "name": "Steel Cast Plate",
         "duration": 4,
         "producedIn": "foundry",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "iron-ingot",
@@ -4348,11 +5202,15 @@ export const RECIPES = {
             }
         ]
     },
-    "molded-beam": { // Recipe_Alternate_SteelBeam_Molded_C
+    "molded-beam": {
+        "className": "Recipe_Alternate_SteelBeam_Molded_C",
         "name": "Molded Beam",
         "duration": 12,
         "producedIn": "foundry",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "steel-ingot",
@@ -4370,11 +5228,15 @@ export const RECIPES = {
             }
         ]
     },
-    "aluminum-beam": { // Recipe_Alternate_SteelBeam_Aluminum_C
+    "aluminum-beam": {
+        "className": "Recipe_Alternate_SteelBeam_Aluminum_C",
         "name": "Aluminum Beam",
         "duration": 8,
         "producedIn": "constructor",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "aluminum-ingot",
@@ -4388,11 +5250,15 @@ export const RECIPES = {
             }
         ]
     },
-    "aluminum-rod": { // Recipe_Alternate_AluminumRod_C
+    "aluminum-rod": {
+        "className": "Recipe_Alternate_AluminumRod_C",
         "name": "Aluminum Rod",
         "duration": 8,
         "producedIn": "constructor",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "aluminum-ingot",
@@ -4406,11 +5272,15 @@ export const RECIPES = {
             }
         ]
     },
-    "plastic-ai-limiter": { // Recipe_Alternate_AILimiter_Plastic_C
+    "plastic-ai-limiter": {
+        "className": "Recipe_Alternate_AILimiter_Plastic_C",
         "name": "Plastic AI Limiter",
         "duration": 15,
         "producedIn": "assembler",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "quickwire",
@@ -4428,11 +5298,15 @@ export const RECIPES = {
             }
         ]
     },
-    "distilled-silica": { // Recipe_Alternate_Silica_Distilled_C
+    "distilled-silica": {
+        "className": "Recipe_Alternate_Silica_Distilled_C",
         "name": "Distilled Silica",
         "duration": 6,
         "producedIn": "blender",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "dissolved-silica",
@@ -4458,11 +5332,15 @@ export const RECIPES = {
             }
         ]
     },
-    "quartz-purification": { // Recipe_Alternate_Quartz_Purified_C
+    "quartz-purification": {
+        "className": "Recipe_Alternate_Quartz_Purified_C",
         "name": "Quartz Purification",
         "duration": 12,
         "producedIn": "refinery",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "raw-quartz",
@@ -4484,11 +5362,15 @@ export const RECIPES = {
             }
         ]
     },
-    "fused-quartz-crystal": { // Recipe_Alternate_Quartz_Fused_C
+    "fused-quartz-crystal": {
+        "className": "Recipe_Alternate_Quartz_Fused_C",
         "name": "Fused Quartz Crystal",
         "duration": 20,
         "producedIn": "foundry",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "raw-quartz",
@@ -4506,11 +5388,15 @@ export const RECIPES = {
             }
         ]
     },
-    "leached-iron-ingot": { // Recipe_Alternate_IronIngot_Leached_C
+    "leached-iron-ingot": {
+        "className": "Recipe_Alternate_IronIngot_Leached_C",
         "name": "Leached Iron ingot",
         "duration": 6,
         "producedIn": "refinery",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "iron-ore",
@@ -4528,11 +5414,15 @@ export const RECIPES = {
             }
         ]
     },
-    "basic-iron-ingot": { // Recipe_Alternate_IronIngot_Basic_C
+    "basic-iron-ingot": {
+        "className": "Recipe_Alternate_IronIngot_Basic_C",
         "name": "Basic Iron Ingot",
         "duration": 12,
         "producedIn": "foundry",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "iron-ore",
@@ -4550,11 +5440,15 @@ export const RECIPES = {
             }
         ]
     },
-    "tempered-copper-ingot": { // Recipe_Alternate_CopperIngot_Tempered_C
+    "tempered-copper-ingot": {
+        "className": "Recipe_Alternate_CopperIngot_Tempered_C",
         "name": "Tempered Copper Ingot",
         "duration": 12,
         "producedIn": "foundry",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "copper-ore",
@@ -4572,11 +5466,15 @@ export const RECIPES = {
             }
         ]
     },
-    "leached-copper-ingot": { // Recipe_Alternate_CopperIngot_Leached_C
+    "leached-copper-ingot": {
+        "className": "Recipe_Alternate_CopperIngot_Leached_C",
         "name": "Leached Copper Ingot",
         "duration": 12,
         "producedIn": "refinery",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "copper-ore",
@@ -4594,11 +5492,15 @@ export const RECIPES = {
             }
         ]
     },
-    "tempered-caterium-ingot": { // Recipe_Alternate_CateriumIngot_Tempered_C
+    "tempered-caterium-ingot": {
+        "className": "Recipe_Alternate_CateriumIngot_Tempered_C",
         "name": "Tempered Caterium Ingot",
         "duration": 8,
         "producedIn": "foundry",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "caterium-ore",
@@ -4616,11 +5518,15 @@ export const RECIPES = {
             }
         ]
     },
-    "leached-caterium-ingot": { // Recipe_Alternate_CateriumIngot_Leached_C
+    "leached-caterium-ingot": {
+        "className": "Recipe_Alternate_CateriumIngot_Leached_C",
         "name": "Leached Caterium Ingot",
         "duration": 10,
         "producedIn": "refinery",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "caterium-ore",
@@ -4638,11 +5544,15 @@ export const RECIPES = {
             }
         ]
     },
-    "caterium-wire": { // Recipe_Alternate_Wire_2_C
+    "caterium-wire": {
+        "className": "Recipe_Alternate_Wire_2_C",
         "name": "Caterium Wire",
         "duration": 4,
         "producedIn": "constructor",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "caterium-ingot",
@@ -4656,11 +5566,15 @@ export const RECIPES = {
             }
         ]
     },
-    "iron-wire": { // Recipe_Alternate_Wire_1_C
+    "iron-wire": {
+        "className": "Recipe_Alternate_Wire_1_C",
         "name": "Iron Wire",
         "duration": 24,
         "producedIn": "constructor",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "iron-ingot",
@@ -4674,11 +5588,15 @@ export const RECIPES = {
             }
         ]
     },
-    "infused-uranium-cell": { // Recipe_Alternate_UraniumCell_1_C
+    "infused-uranium-cell": {
+        "className": "Recipe_Alternate_UraniumCell_1_C",
         "name": "Infused Uranium Cell",
         "duration": 12,
         "producedIn": "manufacturer",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "uranium",
@@ -4704,11 +5622,16 @@ export const RECIPES = {
             }
         ]
     },
-    "caterium-ingot": { // Recipe_IngotCaterium_C
+    "caterium-ingot": {
+        "className": "Recipe_IngotCaterium_C",
         "name": "Caterium Ingot",
         "duration": 4,
         "producedIn": "smelter",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "caterium"
+        },
         "inputs": [
             {
                 "item": "caterium-ore",
@@ -4722,11 +5645,15 @@ export const RECIPES = {
             }
         ]
     },
-    "turbo-electric-motor": { // Recipe_Alternate_TurboMotor_1_C
+    "turbo-electric-motor": {
+        "className": "Recipe_Alternate_TurboMotor_1_C",
         "name": "Turbo Electric Motor",
         "duration": 64,
         "producedIn": "manufacturer",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "motor",
@@ -4752,11 +5679,16 @@ export const RECIPES = {
             }
         ]
     },
-    "turbo-motor": { // Recipe_MotorTurbo_C
+    "turbo-motor": {
+        "className": "Recipe_MotorTurbo_C",
         "name": "Turbo Motor",
         "duration": 32,
         "producedIn": "manufacturer",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 8
+        },
         "inputs": [
             {
                 "item": "cooling-system",
@@ -4782,11 +5714,16 @@ export const RECIPES = {
             }
         ]
     },
-    "thermal-propulsion-rocket": { // Recipe_SpaceElevatorPart_8_C
+    "thermal-propulsion-rocket": {
+        "className": "Recipe_SpaceElevatorPart_8_C",
         "name": "Thermal Propulsion Rocket",
         "duration": 120,
         "producedIn": "manufacturer",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 8
+        },
         "inputs": [
             {
                 "item": "modular-engine",
@@ -4812,11 +5749,15 @@ export const RECIPES = {
             }
         ]
     },
-    "quickwire-stator": { // Recipe_Alternate_Stator_C
+    "quickwire-stator": {
+        "className": "Recipe_Alternate_Stator_C",
         "name": "Quickwire Stator",
         "duration": 15,
         "producedIn": "assembler",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "steel-pipe",
@@ -4834,11 +5775,15 @@ export const RECIPES = {
             }
         ]
     },
-    "cheap-silica": { // Recipe_Alternate_Silica_C
+    "cheap-silica": {
+        "className": "Recipe_Alternate_Silica_C",
         "name": "Cheap Silica",
         "duration": 8,
         "producedIn": "assembler",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "raw-quartz",
@@ -4856,11 +5801,15 @@ export const RECIPES = {
             }
         ]
     },
-    "steel-screw": { // Recipe_Alternate_Screw_2_C
+    "steel-screw": {
+        "className": "Recipe_Alternate_Screw_2_C",
         "name": "Steel Screw",
         "duration": 12,
         "producedIn": "constructor",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "steel-beam",
@@ -4874,11 +5823,15 @@ export const RECIPES = {
             }
         ]
     },
-    "cast-screw": { // Recipe_Alternate_Screw_C
+    "cast-screw": {
+        "className": "Recipe_Alternate_Screw_C",
         "name": "Cast Screw",
         "duration": 24,
         "producedIn": "constructor",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "iron-ingot",
@@ -4892,11 +5845,15 @@ export const RECIPES = {
             }
         ]
     },
-    "steel-rotor": { // Recipe_Alternate_Rotor_C
+    "steel-rotor": {
+        "className": "Recipe_Alternate_Rotor_C",
         "name": "Steel Rotor",
         "duration": 12,
         "producedIn": "assembler",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "steel-pipe",
@@ -4914,11 +5871,15 @@ export const RECIPES = {
             }
         ]
     },
-    "encased-industrial-pipe": { // Recipe_Alternate_EncasedIndustrialBeam_C
+    "encased-industrial-pipe": {
+        "className": "Recipe_Alternate_EncasedIndustrialBeam_C",
         "name": "Encased Industrial Pipe",
         "duration": 15,
         "producedIn": "assembler",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "steel-pipe",
@@ -4936,11 +5897,15 @@ export const RECIPES = {
             }
         ]
     },
-    "stitched-iron-plate": { // Recipe_Alternate_ReinforcedIronPlate_2_C
+    "stitched-iron-plate": {
+        "className": "Recipe_Alternate_ReinforcedIronPlate_2_C",
         "name": "Stitched Iron Plate",
         "duration": 32,
         "producedIn": "assembler",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "iron-plate",
@@ -4958,11 +5923,15 @@ export const RECIPES = {
             }
         ]
     },
-    "bolted-iron-plate": { // Recipe_Alternate_ReinforcedIronPlate_1_C
+    "bolted-iron-plate": {
+        "className": "Recipe_Alternate_ReinforcedIronPlate_1_C",
         "name": "Bolted Iron Plate",
         "duration": 12,
         "producedIn": "assembler",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "iron-plate",
@@ -4980,11 +5949,15 @@ export const RECIPES = {
             }
         ]
     },
-    "radio-connection-unit": { // Recipe_Alternate_RadioControlUnit_1_C
+    "radio-connection-unit": {
+        "className": "Recipe_Alternate_RadioControlUnit_1_C",
         "name": "Radio Connection Unit",
         "duration": 16,
         "producedIn": "manufacturer",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "heat-sink",
@@ -5006,11 +5979,15 @@ export const RECIPES = {
             }
         ]
     },
-    "fused-quickwire": { // Recipe_Alternate_Quickwire_C
+    "fused-quickwire": {
+        "className": "Recipe_Alternate_Quickwire_C",
         "name": "Fused Quickwire",
         "duration": 8,
         "producedIn": "assembler",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "caterium-ingot",
@@ -5028,11 +6005,15 @@ export const RECIPES = {
             }
         ]
     },
-    "recycled-plastic": { // Recipe_Alternate_Plastic_1_C
+    "recycled-plastic": {
+        "className": "Recipe_Alternate_Plastic_1_C",
         "name": "Recycled Plastic",
         "duration": 12,
         "producedIn": "refinery",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "rubber",
@@ -5050,11 +6031,15 @@ export const RECIPES = {
             }
         ]
     },
-    "uranium-fuel-unit": { // Recipe_Alternate_NuclearFuelRod_1_C
+    "uranium-fuel-unit": {
+        "className": "Recipe_Alternate_NuclearFuelRod_1_C",
         "name": "Uranium Fuel Unit",
         "duration": 300,
         "producedIn": "manufacturer",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "encased-uranium-cell",
@@ -5080,11 +6065,15 @@ export const RECIPES = {
             }
         ]
     },
-    "rigor-motor": { // Recipe_Alternate_Motor_1_C
+    "rigor-motor": {
+        "className": "Recipe_Alternate_Motor_1_C",
         "name": "Rigor Motor",
         "duration": 48,
         "producedIn": "manufacturer",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "rotor",
@@ -5106,11 +6095,15 @@ export const RECIPES = {
             }
         ]
     },
-    "steeled-frame": { // Recipe_Alternate_ModularFrame_C
+    "steeled-frame": {
+        "className": "Recipe_Alternate_ModularFrame_C",
         "name": "Steeled Frame",
         "duration": 60,
         "producedIn": "assembler",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "reinforced-iron-plate",
@@ -5128,11 +6121,15 @@ export const RECIPES = {
             }
         ]
     },
-    "compacted-steel-ingot": { // Recipe_Alternate_IngotSteel_2_C
+    "compacted-steel-ingot": {
+        "className": "Recipe_Alternate_IngotSteel_2_C",
         "name": "Compacted Steel Ingot",
         "duration": 24,
         "producedIn": "foundry",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "iron-ore",
@@ -5150,11 +6147,15 @@ export const RECIPES = {
             }
         ]
     },
-    "solid-steel-ingot": { // Recipe_Alternate_IngotSteel_1_C
+    "solid-steel-ingot": {
+        "className": "Recipe_Alternate_IngotSteel_1_C",
         "name": "Solid Steel Ingot",
         "duration": 3,
         "producedIn": "foundry",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "iron-ingot",
@@ -5172,11 +6173,15 @@ export const RECIPES = {
             }
         ]
     },
-    "iron-alloy-ingot": { // Recipe_Alternate_IngotIron_C
+    "iron-alloy-ingot": {
+        "className": "Recipe_Alternate_IngotIron_C",
         "name": "Iron Alloy Ingot",
         "duration": 12,
         "producedIn": "foundry",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "iron-ore",
@@ -5194,11 +6199,15 @@ export const RECIPES = {
             }
         ]
     },
-    "silicon-high-speed-connector": { // Recipe_Alternate_HighSpeedConnector_C
+    "silicon-high-speed-connector": {
+        "className": "Recipe_Alternate_HighSpeedConnector_C",
         "name": "Silicon High-Speed Connector",
         "duration": 40,
         "producedIn": "manufacturer",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "quickwire",
@@ -5220,11 +6229,15 @@ export const RECIPES = {
             }
         ]
     },
-    "heavy-encased-frame": { // Recipe_Alternate_ModularFrameHeavy_C
+    "heavy-encased-frame": {
+        "className": "Recipe_Alternate_ModularFrameHeavy_C",
         "name": "Heavy Encased Frame",
         "duration": 64,
         "producedIn": "manufacturer",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "modular-frame",
@@ -5250,11 +6263,15 @@ export const RECIPES = {
             }
         ]
     },
-    "heat-exchanger": { // Recipe_Alternate_HeatSink_1_C
+    "heat-exchanger": {
+        "className": "Recipe_Alternate_HeatSink_1_C",
         "name": "Heat Exchanger",
         "duration": 6,
         "producedIn": "assembler",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "aluminum-casing",
@@ -5272,11 +6289,15 @@ export const RECIPES = {
             }
         ]
     },
-    "fine-black-powder": { // Recipe_Alternate_Gunpowder_1_C
+    "fine-black-powder": {
+        "className": "Recipe_Alternate_Gunpowder_1_C",
         "name": "Fine Black Powder",
         "duration": 8,
         "producedIn": "assembler",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "sulfur",
@@ -5294,11 +6315,15 @@ export const RECIPES = {
             }
         ]
     },
-    "electromagnetic-connection-rod": { // Recipe_Alternate_ElectromagneticControlRod_1_C
+    "electromagnetic-connection-rod": {
+        "className": "Recipe_Alternate_ElectromagneticControlRod_1_C",
         "name": "Electromagnetic Connection Rod",
         "duration": 15,
         "producedIn": "assembler",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "stator",
@@ -5316,11 +6341,15 @@ export const RECIPES = {
             }
         ]
     },
-    "insulated-crystal-oscillator": { // Recipe_Alternate_CrystalOscillator_C
+    "insulated-crystal-oscillator": {
+        "className": "Recipe_Alternate_CrystalOscillator_C",
         "name": "Insulated Crystal Oscillator",
         "duration": 32,
         "producedIn": "manufacturer",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "quartz-crystal",
@@ -5342,11 +6371,15 @@ export const RECIPES = {
             }
         ]
     },
-    "fine-concrete": { // Recipe_Alternate_Concrete_C
+    "fine-concrete": {
+        "className": "Recipe_Alternate_Concrete_C",
         "name": "Fine Concrete",
         "duration": 12,
         "producedIn": "assembler",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "silica",
@@ -5364,11 +6397,15 @@ export const RECIPES = {
             }
         ]
     },
-    "crystal-computer": { // Recipe_Alternate_Computer_2_C
+    "crystal-computer": {
+        "className": "Recipe_Alternate_Computer_2_C",
         "name": "Crystal Computer",
         "duration": 36,
         "producedIn": "assembler",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "circuit-board",
@@ -5386,11 +6423,15 @@ export const RECIPES = {
             }
         ]
     },
-    "caterium-computer": { // Recipe_Alternate_Computer_1_C
+    "caterium-computer": {
+        "className": "Recipe_Alternate_Computer_1_C",
         "name": "Caterium Computer",
         "duration": 16,
         "producedIn": "manufacturer",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "circuit-board",
@@ -5412,11 +6453,15 @@ export const RECIPES = {
             }
         ]
     },
-    "caterium-circuit-board": { // Recipe_Alternate_CircuitBoard_2_C
+    "caterium-circuit-board": {
+        "className": "Recipe_Alternate_CircuitBoard_2_C",
         "name": "Caterium Circuit Board",
         "duration": 48,
         "producedIn": "assembler",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "plastic",
@@ -5434,11 +6479,15 @@ export const RECIPES = {
             }
         ]
     },
-    "silicon-circuit-board": { // Recipe_Alternate_CircuitBoard_1_C
+    "silicon-circuit-board": {
+        "className": "Recipe_Alternate_CircuitBoard_1_C",
         "name": "Silicon Circuit Board",
         "duration": 24,
         "producedIn": "assembler",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "copper-sheet",
@@ -5456,11 +6505,15 @@ export const RECIPES = {
             }
         ]
     },
-    "quickwire-cable": { // Recipe_Alternate_Cable_2_C
+    "quickwire-cable": {
+        "className": "Recipe_Alternate_Cable_2_C",
         "name": "Quickwire Cable",
         "duration": 24,
         "producedIn": "assembler",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "quickwire",
@@ -5478,11 +6531,15 @@ export const RECIPES = {
             }
         ]
     },
-    "insulated-cable": { // Recipe_Alternate_Cable_1_C
+    "insulated-cable": {
+        "className": "Recipe_Alternate_Cable_1_C",
         "name": "Insulated Cable",
         "duration": 12,
         "producedIn": "assembler",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "wire",
@@ -5500,11 +6557,16 @@ export const RECIPES = {
             }
         ]
     },
-    "ficsonium": { // Recipe_Ficsonium_C
+    "ficsonium": {
+        "className": "Recipe_Ficsonium_C",
         "name": "Ficsonium",
         "duration": 6,
         "producedIn": "particle-accelerator",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 9
+        },
         "powerRequirements": [
             500,
             1500
@@ -5530,11 +6592,16 @@ export const RECIPES = {
             }
         ]
     },
-    "ficsonium-fuel-rod": { // Recipe_FicsoniumFuelRod_C
+    "ficsonium-fuel-rod": {
+        "className": "Recipe_FicsoniumFuelRod_C",
         "name": "Ficsonium Fuel Rod",
         "duration": 24,
         "producedIn": "quantum-encoder",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 9
+        },
         "powerRequirements": [
             0,
             2000
@@ -5568,11 +6635,16 @@ export const RECIPES = {
             }
         ]
     },
-    "singularity-cell": { // Recipe_SingularityCell_C
+    "singularity-cell": {
+        "className": "Recipe_SingularityCell_C",
         "name": "Singularity Cell",
         "duration": 60,
         "producedIn": "manufacturer",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 9
+        },
         "powerRequirements": [
             0,
             0
@@ -5602,11 +6674,16 @@ export const RECIPES = {
             }
         ]
     },
-    "ballistic-warp-drive": { // Recipe_SpaceElevatorPart_11_C
+    "ballistic-warp-drive": {
+        "className": "Recipe_SpaceElevatorPart_11_C",
         "name": "Ballistic Warp Drive",
         "duration": 60,
         "producedIn": "manufacturer",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 9
+        },
         "powerRequirements": [
             500,
             1500
@@ -5636,11 +6713,16 @@ export const RECIPES = {
             }
         ]
     },
-    "iodine-infused-filter": { // Recipe_FilterHazmat_C
+    "iodine-infused-filter": {
+        "className": "Recipe_FilterHazmat_C",
         "name": "Iodine-Infused Filter",
         "duration": 16,
         "producedIn": "manufacturer",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "quartz"
+        },
         "inputs": [
             {
                 "item": "gas-filter",
@@ -5662,11 +6744,16 @@ export const RECIPES = {
             }
         ]
     },
-    "quickwire": { // Recipe_Quickwire_C
+    "quickwire": {
+        "className": "Recipe_Quickwire_C",
         "name": "Quickwire",
         "duration": 5,
         "producedIn": "constructor",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "caterium"
+        },
         "inputs": [
             {
                 "item": "caterium-ingot",
@@ -5680,11 +6767,16 @@ export const RECIPES = {
             }
         ]
     },
-    "solid-biofuel": { // Recipe_Biofuel_C
+    "solid-biofuel": {
+        "className": "Recipe_Biofuel_C",
         "name": "Solid Biofuel",
         "duration": 4,
         "producedIn": "constructor",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 2
+        },
         "inputs": [
             {
                 "item": "biomass",
@@ -5698,11 +6790,16 @@ export const RECIPES = {
             }
         ]
     },
-    "hog-protein": { // Recipe_Protein_Hog_C
+    "hog-protein": {
+        "className": "Recipe_Protein_Hog_C",
         "name": "Hog Protein",
         "duration": 3,
         "producedIn": "constructor",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "alien-organisms"
+        },
         "inputs": [
             {
                 "item": "hog-remains",
@@ -5716,11 +6813,16 @@ export const RECIPES = {
             }
         ]
     },
-    "spitter-protein": { // Recipe_Protein_Spitter_C
+    "spitter-protein": {
+        "className": "Recipe_Protein_Spitter_C",
         "name": "Spitter Protein",
         "duration": 3,
         "producedIn": "constructor",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "alien-organisms"
+        },
         "inputs": [
             {
                 "item": "spitter-remains",
@@ -5734,11 +6836,16 @@ export const RECIPES = {
             }
         ]
     },
-    "biomass-mycelia": { // Recipe_Biomass_Mycelia_C
+    "biomass-mycelia": {
+        "className": "Recipe_Biomass_Mycelia_C",
         "name": "Biomass (Mycelia)",
         "duration": 4,
         "producedIn": "constructor",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "mycelia"
+        },
         "inputs": [
             {
                 "item": "mycelia",
@@ -5752,11 +6859,16 @@ export const RECIPES = {
             }
         ]
     },
-    "power-shard-1": { // Recipe_PowerCrystalShard_1_C
+    "power-shard-1": {
+        "className": "Recipe_PowerCrystalShard_1_C",
         "name": "Power Shard (1)",
         "duration": 8,
         "producedIn": "constructor",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "power-slugs"
+        },
         "inputs": [
             {
                 "item": "blue-power-slug",
@@ -5770,11 +6882,16 @@ export const RECIPES = {
             }
         ]
     },
-    "black-powder": { // Recipe_Gunpowder_C
+    "black-powder": {
+        "className": "Recipe_Gunpowder_C",
         "name": "Black Powder",
         "duration": 4,
         "producedIn": "assembler",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "sulfur"
+        },
         "inputs": [
             {
                 "item": "coal",
@@ -5792,11 +6909,16 @@ export const RECIPES = {
             }
         ]
     },
-    "alien-power-matrix": { // Recipe_AlienPowerFuel_C
+    "alien-power-matrix": {
+        "className": "Recipe_AlienPowerFuel_C",
         "name": "Alien Power Matrix",
         "duration": 24,
         "producedIn": "quantum-encoder",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "alien-technology"
+        },
         "powerRequirements": [
             0,
             2000
@@ -5830,11 +6952,16 @@ export const RECIPES = {
             }
         ]
     },
-    "stinger-protein": { // Recipe_Protein_Stinger_C
+    "stinger-protein": {
+        "className": "Recipe_Protein_Stinger_C",
         "name": "Stinger Protein",
         "duration": 3,
         "producedIn": "constructor",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "alien-organisms"
+        },
         "inputs": [
             {
                 "item": "stinger-remains",
@@ -5848,11 +6975,16 @@ export const RECIPES = {
             }
         ]
     },
-    "hatcher-protein": { // Recipe_Protein_Crab_C
+    "hatcher-protein": {
+        "className": "Recipe_Protein_Crab_C",
         "name": "Hatcher Protein",
         "duration": 3,
         "producedIn": "constructor",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "alien-organisms"
+        },
         "inputs": [
             {
                 "item": "hatcher-remains",
@@ -5866,11 +6998,16 @@ export const RECIPES = {
             }
         ]
     },
-    "alien-dna-capsule": { // Recipe_AlienDNACapsule_C
+    "alien-dna-capsule": {
+        "className": "Recipe_AlienDNACapsule_C",
         "name": "Alien DNA Capsule",
         "duration": 6,
         "producedIn": "constructor",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "alien-organisms"
+        },
         "inputs": [
             {
                 "item": "alien-protein",
@@ -5884,11 +7021,16 @@ export const RECIPES = {
             }
         ]
     },
-    "biomass-alien-protein": { // Recipe_Biomass_AlienProtein_C
+    "biomass-alien-protein": {
+        "className": "Recipe_Biomass_AlienProtein_C",
         "name": "Biomass (Alien Protein)",
         "duration": 4,
         "producedIn": "constructor",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "alien-organisms"
+        },
         "inputs": [
             {
                 "item": "alien-protein",
@@ -5902,11 +7044,16 @@ export const RECIPES = {
             }
         ]
     },
-    "iron-rebar": { // Recipe_SpikedRebar_C
+    "iron-rebar": {
+        "className": "Recipe_SpikedRebar_C",
         "name": "Iron Rebar",
         "duration": 4,
         "producedIn": "constructor",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 2
+        },
         "inputs": [
             {
                 "item": "iron-rod",
@@ -5920,11 +7067,16 @@ export const RECIPES = {
             }
         ]
     },
-    "homing-rifle-ammo": { // Recipe_CartridgeSmart_C
+    "homing-rifle-ammo": {
+        "className": "Recipe_CartridgeSmart_C",
         "name": "Homing Rifle Ammo",
         "duration": 24,
         "producedIn": "assembler",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "caterium"
+        },
         "inputs": [
             {
                 "item": "rifle-ammo",
@@ -5942,11 +7094,16 @@ export const RECIPES = {
             }
         ]
     },
-    "stun-rebar": { // Recipe_Rebar_Stunshot_C
+    "stun-rebar": {
+        "className": "Recipe_Rebar_Stunshot_C",
         "name": "Stun Rebar",
         "duration": 6,
         "producedIn": "assembler",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "quartz"
+        },
         "inputs": [
             {
                 "item": "iron-rebar",
@@ -5964,11 +7121,16 @@ export const RECIPES = {
             }
         ]
     },
-    "gas-filter": { // Recipe_FilterGasMask_C
+    "gas-filter": {
+        "className": "Recipe_FilterGasMask_C",
         "name": "Gas Filter",
         "duration": 8,
         "producedIn": "manufacturer",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "mycelia"
+        },
         "inputs": [
             {
                 "item": "fabric",
@@ -5990,11 +7152,16 @@ export const RECIPES = {
             }
         ]
     },
-    "gas-nobelisk": { // Recipe_NobeliskGas_C
+    "gas-nobelisk": {
+        "className": "Recipe_NobeliskGas_C",
         "name": "Gas Nobelisk",
         "duration": 12,
         "producedIn": "assembler",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "alien-organisms"
+        },
         "inputs": [
             {
                 "item": "nobelisk",
@@ -6012,11 +7179,15 @@ export const RECIPES = {
             }
         ]
     },
-    "polyester-fabric": { // Recipe_Alternate_PolyesterFabric_C
+    "polyester-fabric": {
+        "className": "Recipe_Alternate_PolyesterFabric_C",
         "name": "Polyester Fabric",
         "duration": 2,
         "producedIn": "refinery",
         "alternative": true,
+        "unlock": {
+            "kind": "hard-drive"
+        },
         "inputs": [
             {
                 "item": "polymer-resin",
@@ -6034,11 +7205,16 @@ export const RECIPES = {
             }
         ]
     },
-    "fabric": { // Recipe_Fabric_C
+    "fabric": {
+        "className": "Recipe_Fabric_C",
         "name": "Fabric",
         "duration": 4,
         "producedIn": "assembler",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "mycelia"
+        },
         "inputs": [
             {
                 "item": "mycelia",
@@ -6056,11 +7232,16 @@ export const RECIPES = {
             }
         ]
     },
-    "synthetic-power-shard": { // Recipe_SyntheticPowerShard_C
+    "synthetic-power-shard": {
+        "className": "Recipe_SyntheticPowerShard_C",
         "name": "Synthetic Power Shard",
         "duration": 12,
         "producedIn": "quantum-encoder",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "alien-technology"
+        },
         "powerRequirements": [
             0,
             2000
@@ -6094,11 +7275,16 @@ export const RECIPES = {
             }
         ]
     },
-    "power-shard-5": { // Recipe_PowerCrystalShard_3_C
+    "power-shard-5": {
+        "className": "Recipe_PowerCrystalShard_3_C",
         "name": "Power Shard (5)",
         "duration": 24,
         "producedIn": "constructor",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "power-slugs"
+        },
         "inputs": [
             {
                 "item": "purple-power-slug",
@@ -6112,11 +7298,16 @@ export const RECIPES = {
             }
         ]
     },
-    "power-shard-2": { // Recipe_PowerCrystalShard_2_C
+    "power-shard-2": {
+        "className": "Recipe_PowerCrystalShard_2_C",
         "name": "Power Shard (2)",
         "duration": 12,
         "producedIn": "constructor",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "power-slugs"
+        },
         "inputs": [
             {
                 "item": "yellow-power-slug",
@@ -6130,11 +7321,16 @@ export const RECIPES = {
             }
         ]
     },
-    "pulse-nobelisk": { // Recipe_NobeliskShockwave_C
+    "pulse-nobelisk": {
+        "className": "Recipe_NobeliskShockwave_C",
         "name": "Pulse Nobelisk",
         "duration": 60,
         "producedIn": "assembler",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "quartz"
+        },
         "inputs": [
             {
                 "item": "nobelisk",
@@ -6152,11 +7348,16 @@ export const RECIPES = {
             }
         ]
     },
-    "shatter-rebar": { // Recipe_Rebar_Spreadshot_C
+    "shatter-rebar": {
+        "className": "Recipe_Rebar_Spreadshot_C",
         "name": "Shatter Rebar",
         "duration": 12,
         "producedIn": "assembler",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "quartz"
+        },
         "inputs": [
             {
                 "item": "iron-rebar",
@@ -6174,11 +7375,16 @@ export const RECIPES = {
             }
         ]
     },
-    "turbo-rifle-ammo-packaged": { // Recipe_CartridgeChaos_Packaged_C
+    "turbo-rifle-ammo-packaged": {
+        "className": "Recipe_CartridgeChaos_Packaged_C",
         "name": "Turbo Rifle Ammo",
         "duration": 12,
         "producedIn": "manufacturer",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "sulfur"
+        },
         "inputs": [
             {
                 "item": "rifle-ammo",
@@ -6200,11 +7406,16 @@ export const RECIPES = {
             }
         ]
     },
-    "turbo-rifle-ammo": { // Recipe_CartridgeChaos_C
+    "turbo-rifle-ammo": {
+        "className": "Recipe_CartridgeChaos_C",
         "name": "Turbo Rifle Ammo",
         "duration": 12,
         "producedIn": "blender",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "sulfur"
+        },
         "inputs": [
             {
                 "item": "rifle-ammo",
@@ -6226,11 +7437,16 @@ export const RECIPES = {
             }
         ]
     },
-    "nuke-nobelisk": { // Recipe_NobeliskNuke_C
+    "nuke-nobelisk": {
+        "className": "Recipe_NobeliskNuke_C",
         "name": "Nuke Nobelisk",
         "duration": 120,
         "producedIn": "manufacturer",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "sulfur"
+        },
         "inputs": [
             {
                 "item": "nobelisk",
@@ -6256,11 +7472,16 @@ export const RECIPES = {
             }
         ]
     },
-    "rifle-ammo": { // Recipe_Cartridge_C
+    "rifle-ammo": {
+        "className": "Recipe_Cartridge_C",
         "name": "Rifle Ammo",
         "duration": 12,
         "producedIn": "assembler",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "sulfur"
+        },
         "inputs": [
             {
                 "item": "copper-sheet",
@@ -6278,11 +7499,16 @@ export const RECIPES = {
             }
         ]
     },
-    "explosive-rebar": { // Recipe_Rebar_Explosive_C
+    "explosive-rebar": {
+        "className": "Recipe_Rebar_Explosive_C",
         "name": "Explosive Rebar",
         "duration": 12,
         "producedIn": "manufacturer",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "sulfur"
+        },
         "inputs": [
             {
                 "item": "iron-rebar",
@@ -6304,11 +7530,16 @@ export const RECIPES = {
             }
         ]
     },
-    "cluster-nobelisk": { // Recipe_NobeliskCluster_C
+    "cluster-nobelisk": {
+        "className": "Recipe_NobeliskCluster_C",
         "name": "Cluster Nobelisk",
         "duration": 24,
         "producedIn": "assembler",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "sulfur"
+        },
         "inputs": [
             {
                 "item": "nobelisk",
@@ -6326,11 +7557,16 @@ export const RECIPES = {
             }
         ]
     },
-    "nobelisk": { // Recipe_Nobelisk_C
+    "nobelisk": {
+        "className": "Recipe_Nobelisk_C",
         "name": "Nobelisk",
         "duration": 6,
         "producedIn": "assembler",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "sulfur"
+        },
         "inputs": [
             {
                 "item": "black-powder",
@@ -6348,11 +7584,16 @@ export const RECIPES = {
             }
         ]
     },
-    "smokeless-powder": { // Recipe_GunpowderMK2_C
+    "smokeless-powder": {
+        "className": "Recipe_GunpowderMK2_C",
         "name": "Smokeless Powder",
         "duration": 6,
         "producedIn": "refinery",
         "alternative": false,
+        "unlock": {
+            "kind": "mam",
+            "tree": "sulfur"
+        },
         "inputs": [
             {
                 "item": "black-powder",
@@ -6370,11 +7611,16 @@ export const RECIPES = {
             }
         ]
     },
-    "biomass-leaves": { // Recipe_Biomass_Leaves_C
+    "biomass-leaves": {
+        "className": "Recipe_Biomass_Leaves_C",
         "name": "Biomass (Leaves)",
         "duration": 5,
         "producedIn": "constructor",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 0
+        },
         "inputs": [
             {
                 "item": "leaves",
@@ -6388,11 +7634,16 @@ export const RECIPES = {
             }
         ]
     },
-    "biomass-wood": { // Recipe_Biomass_Wood_C
+    "biomass-wood": {
+        "className": "Recipe_Biomass_Wood_C",
         "name": "Biomass (Wood)",
         "duration": 4,
         "producedIn": "constructor",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 0
+        },
         "inputs": [
             {
                 "item": "wood",
@@ -6406,11 +7657,16 @@ export const RECIPES = {
             }
         ]
     },
-    "reinforced-iron-plate": { // Recipe_IronPlateReinforced_C
+    "reinforced-iron-plate": {
+        "className": "Recipe_IronPlateReinforced_C",
         "name": "Reinforced Iron Plate",
         "duration": 12,
         "producedIn": "assembler",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 0
+        },
         "inputs": [
             {
                 "item": "iron-plate",
@@ -6428,11 +7684,16 @@ export const RECIPES = {
             }
         ]
     },
-    "concrete": { // Recipe_Concrete_C
+    "concrete": {
+        "className": "Recipe_Concrete_C",
         "name": "Concrete",
         "duration": 4,
         "producedIn": "constructor",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 0
+        },
         "inputs": [
             {
                 "item": "limestone",
@@ -6446,11 +7707,16 @@ export const RECIPES = {
             }
         ]
     },
-    "screw": { // Recipe_Screw_C
+    "screw": {
+        "className": "Recipe_Screw_C",
         "name": "Screw",
         "duration": 6,
         "producedIn": "constructor",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 0
+        },
         "inputs": [
             {
                 "item": "iron-rod",
@@ -6464,11 +7730,16 @@ export const RECIPES = {
             }
         ]
     },
-    "cable": { // Recipe_Cable_C
+    "cable": {
+        "className": "Recipe_Cable_C",
         "name": "Cable",
         "duration": 2,
         "producedIn": "constructor",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 0
+        },
         "inputs": [
             {
                 "item": "wire",
@@ -6482,11 +7753,16 @@ export const RECIPES = {
             }
         ]
     },
-    "wire": { // Recipe_Wire_C
+    "wire": {
+        "className": "Recipe_Wire_C",
         "name": "Wire",
         "duration": 4,
         "producedIn": "constructor",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 0
+        },
         "inputs": [
             {
                 "item": "copper-ingot",
@@ -6500,11 +7776,16 @@ export const RECIPES = {
             }
         ]
     },
-    "copper-ingot": { // Recipe_IngotCopper_C
+    "copper-ingot": {
+        "className": "Recipe_IngotCopper_C",
         "name": "Copper Ingot",
         "duration": 2,
         "producedIn": "smelter",
         "alternative": false,
+        "unlock": {
+            "kind": "milestone",
+            "tier": 0
+        },
         "inputs": [
             {
                 "item": "copper-ore",
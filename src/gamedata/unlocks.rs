@@ -0,0 +1,110 @@
+use std::collections::BTreeSet;
+
+use serde::{Deserialize, Serialize};
+
+use super::RecipeKind;
+
+
+/// How a recipe becomes available in the game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
+pub enum Unlock {
+    /// Unlocked by a HUB milestone of the given tier. Tier 0 is the onboarding.
+    Milestone { tier: u8 },
+    /// Unlocked by researching something in the MAM.
+    Mam { tree: MamTree },
+    /// Alternate recipe unlocked by analyzing a hard drive.
+    HardDrive,
+    /// Seasonal FICSMAS research.
+    Ficsmas,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum MamTree {
+    AlienOrganisms,
+    AlienTechnology,
+    Caterium,
+    Mycelia,
+    Nutrients,
+    PowerSlugs,
+    Quartz,
+    Sulfur,
+}
+
+/// Restricts recipes to those available at a specific point of the progression. Every `None`
+/// field means "no restriction", so the default filter allows all recipes.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct RecipeFilter {
+    /// Highest tier with all milestones unlocked.
+    pub max_tier: Option<u8>,
+    /// MAM trees that have been researched.
+    pub mam_trees: Option<BTreeSet<MamTree>>,
+    /// Alternate recipes unlocked via hard drives.
+    pub alternates: Option<BTreeSet<RecipeKind>>,
+    /// Whether FICSMAS recipes are available.
+    pub ficsmas: Option<bool>,
+}
+
+impl RecipeFilter {
+    pub fn allows(&self, recipe: RecipeKind) -> bool {
+        match recipe.info().unlock {
+            Unlock::Milestone { tier } => self.max_tier.is_none_or(|max| tier <= max),
+            Unlock::Mam { tree } => self.mam_trees.as_ref().is_none_or(|t| t.contains(&tree)),
+            Unlock::HardDrive => self.alternates.as_ref().is_none_or(|a| a.contains(&recipe)),
+            Unlock::Ficsmas => self.ficsmas.unwrap_or(true),
+        }
+    }
+
+    /// Returns all recipes allowed by this filter.
+    pub fn allowed_recipes(&self) -> impl '_ + Iterator<Item = RecipeKind> {
+        (0..).map_while(|id| RecipeKind::try_from(id).ok()).filter(|&r| self.allows(r))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn allows() {
+        let tier = |max| RecipeFilter { max_tier: Some(max), ..Default::default() };
+        let mam = |trees: &[MamTree]| RecipeFilter {
+            mam_trees: Some(trees.iter().copied().collect()),
+            ..Default::default()
+        };
+        let alternates = |recipes: &[RecipeKind]| RecipeFilter {
+            alternates: Some(recipes.iter().copied().collect()),
+            ..Default::default()
+        };
+
+        let cases = [
+            // No restriction.
+            (RecipeFilter::default(), RecipeKind::IronPlate, true),
+            (RecipeFilter::default(), RecipeKind::CircuitBoard, true),
+            (RecipeFilter::default(), RecipeKind::AiLimiter, true),
+            (RecipeFilter::default(), RecipeKind::PureIronIngot, true),
+            // Milestones up to and including the tier.
+            (tier(0), RecipeKind::IronPlate, true),
+            (tier(4), RecipeKind::CircuitBoard, false),
+            (tier(5), RecipeKind::CircuitBoard, true),
+            (tier(0), RecipeKind::AiLimiter, true),
+            (tier(0), RecipeKind::PureIronIngot, true),
+            // Only researched MAM trees.
+            (mam(&[]), RecipeKind::AiLimiter, false),
+            (mam(&[MamTree::Quartz]), RecipeKind::AiLimiter, false),
+            (mam(&[MamTree::Quartz, MamTree::Caterium]), RecipeKind::AiLimiter, true),
+            (mam(&[]), RecipeKind::IronPlate, true),
+            // Only unlocked alternates.
+            (alternates(&[]), RecipeKind::PureIronIngot, false),
+            (alternates(&[RecipeKind::PureIronIngot]), RecipeKind::PureIronIngot, true),
+            (alternates(&[RecipeKind::PureIronIngot]), RecipeKind::IronWire, false),
+            (alternates(&[]), RecipeKind::IronIngot, true),
+        ];
+        for (filter, recipe, expected) in cases {
+            assert_eq!(filter.allows(recipe), expected, "{recipe:?} with {filter:?}");
+        }
+    }
+}
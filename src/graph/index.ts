@@ -3,11 +3,12 @@ import Ajv, { JTDDataType } from "ajv/dist/jtd";
 import { NODE_TYPES } from "../nodes";
 import { immerable } from "immer";
 import { type GraphNode } from "./node";
-import { ItemId, RecipeId, RECIPES, RESOURCE_ITEMS } from "../gamedata";
-import { RecipeGraphNode } from "./recipe";
+import { dataPacksGeneration, ItemId, RecipeId, RECIPES, RESOURCE_ITEMS } from "../gamedata";
+import { PackRecipeGraphNode, RecipeGraphNode, RecipeLikeGraphNode } from "./recipe";
 import { SourceGraphNode } from "./source";
-import { SplitterGraphNode } from "./splitter";
-import { MergerGraphNode } from "./merger";
+import { SmartSplitterGraphNode, SplitFilter, SplitterGraphNode } from "./splitter";
+import { MergerGraphNode, PriorityMergerGraphNode } from "./merger";
+import { edge_flows } from "../../pkg/satisfactory_planner";
 
 
 export type GraphNodeId = number & { readonly __tag: unique symbol };
@@ -31,6 +32,11 @@ export class GraphHandle {
 }
 
 
+// Immer replaces the graph on every change, so the flows are simulated once per change (or when
+// data packs are loaded). Keyed by the target handle of each edge.
+const incomingRates = new WeakMap<Graph, { packs: number; rates: Map<string, number> }>();
+const handleKey = (handle: GraphHandle) => `${handle.node}:${handle.handle}`;
+
 export class Graph {
     [immerable] = true;
 
@@ -49,24 +55,27 @@ export class Graph {
         return this.nodes.has(id);
     }
 
-    isValidConnection(source: GraphHandle, target: GraphHandle): boolean {
-        const getItem = (handle: GraphHandle): ItemId | undefined => {
-            return this.dfs(handle, (handle, node) => {
-                const item = node.match({
-                    recipe: (node) => node.entry(handle.handle).item,
-                    merger: () => undefined,
-                    splitter: () => undefined,
-                    source: (node) => node.item,
-                });
-                return item
-                    ? ["stop", item]
-                    : ["continue", node.neighbors()];
+    /**
+     * The item passing through `handle`. For splitters and mergers, that's the item of the first
+     * recipe or source found through their connections, if any.
+     */
+    handleItem(handle: GraphHandle): ItemId | undefined {
+        return this.dfs(handle, (handle, node) => {
+            const item = node.match({
+                recipe: (node) => node.entry(handle.handle)?.item,
+                merger: () => undefined,
+                splitter: () => undefined,
+                source: (node) => node.item,
             });
-        };
-
+            return item
+                ? ["stop", item]
+                : ["continue", node.neighbors()];
+        });
+    }
 
-        const sourceItem = getItem(source);
-        const targetItem = getItem(target);
+    isValidConnection(source: GraphHandle, target: GraphHandle): boolean {
+        const sourceItem = this.handleItem(source);
+        const targetItem = this.handleItem(target);
         return sourceItem === undefined
             || targetItem === undefined
             || sourceItem === targetItem;
@@ -85,14 +94,14 @@ export class Graph {
             // cannot define a clear expectation, as it just has a "total expectation",
             // that has to be covered by the sum of inputs. For those mergers, we instead
             // show the diff on their output.
-            if (node.type() === "merger" && node.incomingEdges.size > 1) {
+            if (node instanceof MergerGraphNode && node.incomingEdges.size > 1) {
                 out = undefined;
                 return ["stop", undefined];
             }
 
             // Recipe nodes add to the total sum, but we do not continue on their outgoing edges.
-            if (node instanceof RecipeGraphNode) {
-                out! += node.entry(handle.handle).totalRate;
+            if (node instanceof RecipeLikeGraphNode) {
+                out! += node.entry(handle.handle)?.totalRate ?? 0;
                 return ["continue", []];
             }
 
@@ -102,36 +111,26 @@ export class Graph {
         return out;
     }
 
+    /**
+     * Rate flowing into `handle`, as simulated by `edge_flows`. Unlike summing up the nominal rates
+     * upstream, this also knows how splitters divide their input.
+     */
     incomingRate(handle: GraphHandle): number | undefined {
-        const otherSide = this.node(handle.node).incomingEdges.get(handle.handle);
-        if (!otherSide) {
-            return undefined;
+        const packs = dataPacksGeneration();
+        let cached = incomingRates.get(this);
+        if (cached?.packs !== packs) {
+            const json = this.toJSON();
+            const ids = [...this.nodes.keys()];
+            const input = JSON.stringify({ state: { graph: json }, version: 0 });
+            const flows: number[] = JSON.parse(edge_flows(input)).edges;
+            const rates = new Map(json.edges.map((e, i) => [
+                handleKey(new GraphHandle(ids[e.target.node], e.target.handle)),
+                flows[i],
+            ]));
+            cached = { packs, rates };
+            incomingRates.set(this, cached);
         }
-
-        // DFS
-        let out: number | undefined = 0;
-        this.dfs(otherSide, (handle, node) => {
-            // Once we reach a splitter with multiple outputs, we cannot proceed. The splitter
-            // cannot define a clear output for that handle, as its input can be split differently.
-            if (node.type() === "splitter" && node.outgoingEdges.size > 1) {
-                out = undefined;
-                return ["stop", undefined];
-            }
-
-            // Recipe nodes add to the total sum, but we do not continue on their outgoing edges.
-            if (node instanceof RecipeGraphNode) {
-                out! += node.entry(handle.handle).totalRate;
-                return ["continue", []];
-            }
-            if (node instanceof SourceGraphNode) {
-                out! += node.rate;
-                return ["continue", []];
-            }
-
-            return ["continue", node.incomingEdges.values()];
-        });
-
-        return out;
+        return cached.rates.get(handleKey(handle));
     }
 
     dfs<T>(
@@ -258,11 +257,30 @@ export class Graph {
                     const n = new RecipeGraphNode(jsonNode.recipe, pos);
                     n.buildingsCount = jsonNode.buildingsCount;
                     n.overclock = jsonNode.overclock;
+                    n.somersloops = jsonNode.somersloops ?? 0;
+                    return n;
+                },
+                "packRecipe": jsonNode => {
+                    const n = new PackRecipeGraphNode(jsonNode.recipe, pos);
+                    n.buildingsCount = jsonNode.buildingsCount;
+                    n.overclock = jsonNode.overclock;
+                    n.somersloops = jsonNode.somersloops ?? 0;
                     return n;
                 },
                 "source": jsonNode => new SourceGraphNode(jsonNode.item, jsonNode.rate, pos),
                 "splitter": () => new SplitterGraphNode(pos),
                 "merger": () => new MergerGraphNode(pos),
+                "smartSplitter": jsonNode => {
+                    const n = new SmartSplitterGraphNode(pos);
+                    const [a, b, c] = jsonNode.filters as SplitFilter[][];
+                    n.filters = [a, b, c];
+                    return n;
+                },
+                "priorityMerger": jsonNode => {
+                    const n = new PriorityMergerGraphNode(pos);
+                    n.priority = jsonNode.priority.map(h => h as GraphHandleId);
+                    return n;
+                },
             }));
         }
 
@@ -304,6 +322,20 @@ const graphSchema = {
                             buildingsCount: { type: "uint32" },
                             overclock: { type: "float32" },
                         },
+                        optionalProperties: {
+                            somersloops: { type: "uint8" },
+                        },
+                    },
+                    "packRecipe": {
+                        properties: {
+                            pos: { ref: "pos" },
+                            recipe: { type: "string" },
+                            buildingsCount: { type: "uint32" },
+                            overclock: { type: "float32" },
+                        },
+                        optionalProperties: {
+                            somersloops: { type: "uint8" },
+                        },
                     },
                     "source": {
                         properties: {
@@ -322,6 +354,18 @@ const graphSchema = {
                             pos: { ref: "pos" },
                         },
                     },
+                    "smartSplitter": {
+                        properties: {
+                            pos: { ref: "pos" },
+                            filters: { elements: { elements: { type: "string" } } },
+                        },
+                    },
+                    "priorityMerger": {
+                        properties: {
+                            pos: { ref: "pos" },
+                            priority: { elements: { type: "uint8" } },
+                        },
+                    },
                 },
             },
         },
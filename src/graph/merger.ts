@@ -20,7 +20,7 @@ export const mergerHandlePos = (handle: GraphHandleId): Position => {
 export class MergerGraphNode extends GraphNode {
     [immerable] = true;
 
-    type() { return "merger" as const; }
+    type(): "merger" | "priorityMerger" { return "merger"; }
 
     inputs(): GraphHandleId[] {
         return MERGER_INPUTS;
@@ -35,8 +35,22 @@ export class MergerGraphNode extends GraphNode {
     toJSON(): GraphJson["nodes"][number] {
         const { incomingEdges: _0, outgoingEdges: _1, ...rest } = this;
         return {
-            type: this.type(),
+            type: "merger",
             ...rest,
         };
     }
 }
+
+/** Merger taking items from its inputs by priority, with the same handles as a merger. */
+export class PriorityMergerGraphNode extends MergerGraphNode {
+    [immerable] = true;
+
+    /** Input handles, highest priority first. */
+    priority: GraphHandleId[] = [...MERGER_INPUTS];
+
+    type() { return "priorityMerger" as const; }
+
+    toJSON(): GraphJson["nodes"][number] {
+        return { type: this.type(), pos: this.pos, priority: this.priority };
+    }
+}
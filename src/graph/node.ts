@@ -1,7 +1,7 @@
 import { XYPosition } from "@xyflow/react";
 import { match, unreachable } from "../util";
 import { immerable } from "immer";
-import { type RecipeGraphNode } from "./recipe";
+import { type RecipeLikeGraphNode } from "./recipe";
 import { type MergerGraphNode } from "./merger";
 import { type SplitterGraphNode } from "./splitter";
 import { type SourceGraphNode } from "./source";
@@ -39,18 +39,24 @@ export abstract class GraphNode {
     }
 
     match<A, B, C, D>(cases: {
-        recipe: (node: RecipeGraphNode, type: "recipe") => A;
-        merger: (node: MergerGraphNode, type: "merger") => B;
-        splitter: (node: SplitterGraphNode, type: "splitter") => C;
+        recipe: (node: RecipeLikeGraphNode, type: "recipe" | "packRecipe") => A;
+        merger: (node: MergerGraphNode, type: "merger" | "priorityMerger") => B;
+        splitter: (node: SplitterGraphNode, type: "splitter" | "smartSplitter") => C;
         source: (node: SourceGraphNode, type: "source") => D;
     }): A | B | C | D {
         // I would like to use `instanceof` checks here, which would make the casts unnecessary,
         // but for that I would need to import the classes, which results in circular imports.
         const ty = this.type();
         return match<NodeTypes, A | B | C | D>(ty, {
-            "recipe": () => cases.recipe(this as unknown as RecipeGraphNode, "recipe"),
+            "recipe": () => cases.recipe(this as unknown as RecipeLikeGraphNode, "recipe"),
+            "packRecipe": () =>
+                cases.recipe(this as unknown as RecipeLikeGraphNode, "packRecipe"),
             "merger": () => cases.merger(this as unknown as MergerGraphNode, "merger"),
             "splitter": () => cases.splitter(this as unknown as SplitterGraphNode, "splitter"),
+            "smartSplitter": () =>
+                cases.splitter(this as unknown as SplitterGraphNode, "smartSplitter"),
+            "priorityMerger": () =>
+                cases.merger(this as unknown as MergerGraphNode, "priorityMerger"),
             "source": () => cases.source(this as unknown as SourceGraphNode, "source"),
         }, unreachable);
     }
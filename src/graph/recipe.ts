@@ -1,8 +1,8 @@
 import { Position, XYPosition } from "@xyflow/react";
 import { immerable } from "immer";
 import { GraphHandle, GraphHandleId, GraphJson } from ".";
-import { IoEntry, Recipe, RecipeId, RECIPES } from "../gamedata";
-import { match, notNullish } from "../util";
+import { IoEntry, packRecipe, Recipe, RecipeId, RecipeLike, RECIPES } from "../gamedata";
+import { match } from "../util";
 import { GraphNode } from "./node";
 
 
@@ -25,20 +25,17 @@ type ExtendedIoEntry = IoEntry & {
     connectedTo: GraphHandle | null;
 };
 
-export class RecipeGraphNode extends GraphNode {
+/** Node running a recipe, either a built-in one or one from a data pack. */
+export abstract class RecipeLikeGraphNode extends GraphNode {
     [immerable] = true;
 
-    recipeId: RecipeId;
     buildingsCount: number = 1;
     overclock: number = 1;
+    /** Somersloops in each building. */
+    somersloops: number = 0;
 
-    type() { return "recipe" as const; }
-
-    constructor(recipe: RecipeId, pos: XYPosition) {
-        super(pos);
-        this.recipeId = recipe;
-    }
-
+    abstract type(): "recipe" | "packRecipe";
+    abstract recipe(): RecipeLike;
 
     inputs(): GraphHandleId[] {
         return this.recipe().inputs.map((_, idx) => recipeHandleIdFor(idx, "input"));
@@ -50,23 +47,24 @@ export class RecipeGraphNode extends GraphNode {
     multiplier(): number {
         return this.buildingsCount * this.overclock;
     }
-    recipe(): Recipe {
-        return RECIPES[this.recipeId];
-    }
 
     inputEntries(): ExtendedIoEntry[] {
-        return this.inputs().map(handle => this.entry(handle));
+        return this.inputs().map(handle => this.entry(handle)).filter(e => e !== undefined);
     }
     outputEntries(): ExtendedIoEntry[] {
-        return this.outputs().map(handle => this.entry(handle));
+        return this.outputs().map(handle => this.entry(handle)).filter(e => e !== undefined);
     }
-    entry(handle: GraphHandleId): ExtendedIoEntry {
+    /** The input or output at `handle`, if the recipe is known and has one there. */
+    entry(handle: GraphHandleId): ExtendedIoEntry | undefined {
         const recipe = this.recipe();
         const [xputs, edges, idx] = handle < 4
             ? [recipe.inputs, this.incomingEdges, handle]
             : [recipe.outputs, this.outgoingEdges, handle - 4];
 
-        const entry = notNullish(xputs[idx]);
+        const entry = xputs[idx];
+        if (!entry) {
+            return undefined;
+        }
         const rate = entry.amount / recipe.duration * 60;
         return {
             ...entry,
@@ -76,13 +74,94 @@ export class RecipeGraphNode extends GraphNode {
             connectedTo: edges.get(handle) ?? null,
         };
     }
+}
+
+export class RecipeGraphNode extends RecipeLikeGraphNode {
+    [immerable] = true;
+
+    recipeId: RecipeId;
+
+    type() { return "recipe" as const; }
+
+    constructor(recipe: RecipeId, pos: XYPosition) {
+        super(pos);
+        this.recipeId = recipe;
+    }
+
+    recipe(): Recipe {
+        return RECIPES[this.recipeId];
+    }
+
+    toJSON(): GraphJson["nodes"][number] {
+        const {
+            incomingEdges: _0,
+            outgoingEdges: _1,
+            recipeId: _2,
+            somersloops,
+            ...rest
+        } = this;
+        return {
+            type: this.type(),
+            recipe: this.recipeId,
+            ...rest,
+            ...(somersloops > 0 && { somersloops }),
+        };
+    }
+}
+
+/**
+ * Recipe that only exists in a data pack, referred to by its ID in the pack. While the pack is not
+ * loaded (see `loadDataPacks`), the recipe is unknown and all handles are accepted, so that plans
+ * using it still load.
+ */
+export class PackRecipeGraphNode extends RecipeLikeGraphNode {
+    [immerable] = true;
+
+    recipeId: string;
+
+    type() { return "packRecipe" as const; }
+
+    constructor(recipe: string, pos: XYPosition) {
+        super(pos);
+        this.recipeId = recipe;
+    }
+
+    recipe(): RecipeLike {
+        return packRecipe(this.recipeId) ?? {
+            name: `${this.recipeId} (data pack not loaded)`,
+            duration: 60,
+            inputs: [],
+            outputs: [],
+        };
+    }
+    isKnown(): boolean {
+        return packRecipe(this.recipeId) !== undefined;
+    }
+
+    inputs(): GraphHandleId[] {
+        return this.isKnown()
+            ? super.inputs()
+            : [0, 1, 2, 3].map(idx => recipeHandleIdFor(idx, "input"));
+    }
+    outputs(): GraphHandleId[] {
+        return this.isKnown()
+            ? super.outputs()
+            : [0, 1, 2, 3].map(idx => recipeHandleIdFor(idx, "output"));
+    }
 
     toJSON(): GraphJson["nodes"][number] {
-        const { incomingEdges: _0, outgoingEdges: _1, recipeId: _2, ...rest } = this;
+        const {
+            incomingEdges: _0,
+            outgoingEdges: _1,
+            recipeId: _2,
+            somersloops,
+            ...rest
+        } = this;
         return {
             type: this.type(),
             recipe: this.recipeId,
             ...rest,
+            ...(somersloops > 0 && { somersloops }),
         };
     }
 }
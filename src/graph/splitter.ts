@@ -1,6 +1,7 @@
 import { immerable } from "immer";
 import { Position } from "@xyflow/react";
 import { GraphHandleId, GraphJson } from ".";
+import { ItemId } from "../gamedata";
 import { match, unreachable } from "../util";
 import { GraphNode } from "./node";
 
@@ -20,7 +21,7 @@ export const splitterHandlePos = (handle: GraphHandleId): Position => {
 export class SplitterGraphNode extends GraphNode {
     [immerable] = true;
 
-    type() { return "splitter" as const; }
+    type(): "splitter" | "smartSplitter" { return "splitter"; }
 
     inputs(): GraphHandleId[] {
         return SPLITTER_INPUTS;
@@ -32,8 +33,25 @@ export class SplitterGraphNode extends GraphNode {
     toJSON(): GraphJson["nodes"][number] {
         const { incomingEdges: _0, outgoingEdges: _1, ...rest } = this;
         return {
-            type: this.type(),
+            type: "splitter",
             ...rest,
         };
     }
 }
+
+/** Which items a smart splitter sends to an output, see `state::SplitFilter` in Rust. */
+export type SplitFilter = ItemId | "any" | "anyUndefined" | "overflow";
+
+/** Smart or programmable splitter, with the same handles as a splitter. */
+export class SmartSplitterGraphNode extends SplitterGraphNode {
+    [immerable] = true;
+
+    /** Filters of the outputs 1, 2 and 3. An output without filters gets nothing. */
+    filters: [SplitFilter[], SplitFilter[], SplitFilter[]] = [[], [], []];
+
+    type() { return "smartSplitter" as const; }
+
+    toJSON(): GraphJson["nodes"][number] {
+        return { type: this.type(), pos: this.pos, filters: this.filters };
+    }
+}
@@ -0,0 +1,476 @@
+//! Automatic layout of a graph, assigning a position to every node.
+//!
+//! This is a layered ("Sugiyama-style") layout where items flow from left to right:
+//!
+//! 1. Cycles are broken by temporarily reversing some edges.
+//! 2. Every node is assigned a layer (column). Sources start on the left, final products end up
+//!    in the rightmost column. Mergers are moved right next to the node they feed.
+//! 3. Edges spanning multiple layers are split by dummy nodes. Then, the nodes of each layer are
+//!    reordered with barycenter sweeps to reduce edge crossings.
+//! 4. Columns are placed next to each other and nodes within a column are moved towards the
+//!    average height of their neighbors, without changing their order.
+//!
+//! All positions are snapped to the 25 unit grid, and the top left node is at (0, 0).
+
+use std::cmp::{max, Ordering};
+
+use crate::state::{Graph, Node, Pos};
+
+
+const GRID: i32 = 25;
+
+/// Horizontal gap between two columns.
+const COLUMN_GAP: i32 = 100;
+
+/// Vertical gap between two nodes in the same column.
+const ROW_GAP: i32 = 50;
+
+/// Number of down/up sweeps for crossing reduction and for vertical positioning.
+const ORDER_SWEEPS: usize = 12;
+const POSITION_SWEEPS: usize = 8;
+
+
+/// Assigns new positions to all nodes of `graph`. Nodes and edges are otherwise unchanged.
+pub fn auto_layout(graph: &mut Graph) {
+    if graph.nodes.is_empty() {
+        return;
+    }
+
+    let mut edges = graph.edges.iter()
+        .map(|e| (e.source.node as usize, e.target.node as usize))
+        .filter(|(s, t)| s != t)
+        .collect::<Vec<_>>();
+    edges.sort_unstable();
+    edges.dedup();
+    break_cycles(graph.nodes.len(), &mut edges);
+
+    let layer_of = assign_layers(&graph.nodes, &edges);
+    let mut layers = Layers::new(&graph.nodes, &edges, &layer_of);
+    layers.reduce_crossings();
+
+    let xs = layers.column_xs(&graph.nodes);
+    let ys = layers.node_ys(&graph.nodes);
+    for (i, node) in graph.nodes.iter_mut().enumerate() {
+        *node.pos_mut() = Pos { x: xs[layer_of[i]], y: ys[i] };
+    }
+}
+
+/// Approximate size of the node when rendered in the frontend.
+fn node_size(node: &Node) -> (i32, i32) {
+    match node {
+        Node::Recipe { recipe, .. } => {
+            let info = recipe.info();
+            let rows = max(info.inputs.len(), info.outputs.len()) as i32;
+            (250, 56 + rows * 25)
+        }
+        // Recipes have at most 4 rows.
+        Node::PackRecipe { .. } => (250, 56 + 4 * 25),
+        // The rate label is shown to the right of the icon.
+        Node::Source { .. } => (75, 25),
+        _ => (25, 25),
+    }
+}
+
+/// Reverses edges such that the graph becomes acyclic. Back edges of a depth first search starting
+/// at the nodes without incoming edges are reversed.
+fn break_cycles(num_nodes: usize, edges: &mut Vec<(usize, usize)>) {
+    #[derive(Clone, Copy, PartialEq)]
+    enum Mark { New, Active, Done }
+
+    let mut out_edges = vec![Vec::new(); num_nodes];
+    let mut has_incoming = vec![false; num_nodes];
+    for (i, &(s, t)) in edges.iter().enumerate() {
+        out_edges[s].push(i);
+        has_incoming[t] = true;
+    }
+
+    let mut marks = vec![Mark::New; num_nodes];
+    let mut reverse = vec![false; edges.len()];
+    let roots = (0..num_nodes).filter(|&n| !has_incoming[n]).chain(0..num_nodes);
+    for root in roots {
+        if marks[root] != Mark::New {
+            continue;
+        }
+
+        marks[root] = Mark::Active;
+        let mut stack = vec![(root, 0)];
+        while let Some(top) = stack.last_mut() {
+            let (node, next) = *top;
+            match out_edges[node].get(next) {
+                Some(&edge) => {
+                    top.1 += 1;
+                    let target = edges[edge].1;
+                    match marks[target] {
+                        Mark::New => {
+                            marks[target] = Mark::Active;
+                            stack.push((target, 0));
+                        }
+                        Mark::Active => reverse[edge] = true,
+                        Mark::Done => {}
+                    }
+                }
+                None => {
+                    marks[node] = Mark::Done;
+                    stack.pop();
+                }
+            }
+        }
+    }
+
+    for (edge, reverse) in edges.iter_mut().zip(reverse) {
+        if reverse {
+            *edge = (edge.1, edge.0);
+        }
+    }
+    edges.sort_unstable();
+    edges.dedup();
+}
+
+/// Assigns a layer to each node. `edges` must be acyclic. Every edge points from a lower to a
+/// higher layer.
+fn assign_layers(nodes: &[Node], edges: &[(usize, usize)]) -> Vec<usize> {
+    let n = nodes.len();
+    let mut preds = vec![Vec::new(); n];
+    let mut succs = vec![Vec::new(); n];
+    for &(s, t) in edges {
+        succs[s].push(t);
+        preds[t].push(s);
+    }
+
+    // Topological order via Kahn's algorithm.
+    let mut in_degree = preds.iter().map(|p| p.len()).collect::<Vec<_>>();
+    let mut order = (0..n).filter(|&i| in_degree[i] == 0).collect::<Vec<_>>();
+    let mut i = 0;
+    while i < order.len() {
+        for &t in &succs[order[i]] {
+            in_degree[t] -= 1;
+            if in_degree[t] == 0 {
+                order.push(t);
+            }
+        }
+        i += 1;
+    }
+
+    // Longest path from any node without predecessors.
+    let mut layers = vec![0; n];
+    for &node in &order {
+        for &t in &succs[node] {
+            layers[t] = max(layers[t], layers[node] + 1);
+        }
+    }
+
+    // Final products go to the last column.
+    let last = layers.iter().copied().max().unwrap_or(0);
+    for node in 0..n {
+        if succs[node].is_empty() && !preds[node].is_empty() {
+            layers[node] = last;
+        }
+    }
+
+    // Nodes that don't need to be left are moved right towards their successors: mergers, and
+    // all nodes without predecessors except sources.
+    for &node in order.iter().rev() {
+        let pull_right = match nodes[node] {
+            Node::Merger { .. } | Node::PriorityMerger { .. } => true,
+            Node::Source { .. } => false,
+            _ => preds[node].is_empty(),
+        };
+        if let Some(min_succ) = succs[node].iter().map(|&s| layers[s]).min() {
+            if pull_right {
+                layers[node] = min_succ - 1;
+            }
+        }
+    }
+
+    layers
+}
+
+/// The layered graph including dummy nodes. Indices `0..num_real` are the real nodes, dummy
+/// nodes are appended after that.
+struct Layers {
+    num_real: usize,
+    /// Node indices of each layer, in order from top to bottom.
+    order: Vec<Vec<usize>>,
+    /// Index within its layer for each node.
+    rank: Vec<usize>,
+    /// Neighbors in the previous and next layer for each node.
+    up: Vec<Vec<usize>>,
+    down: Vec<Vec<usize>>,
+}
+
+impl Layers {
+    fn new(nodes: &[Node], edges: &[(usize, usize)], layer_of: &[usize]) -> Self {
+        let num_real = nodes.len();
+        let num_layers = layer_of.iter().copied().max().unwrap_or(0) + 1;
+        let mut up = vec![Vec::new(); num_real];
+        let mut down = vec![Vec::new(); num_real];
+
+        // Sort key for the initial order: the current y position. This keeps the user's order if
+        // it's already good. Dummy nodes use the position of their edge's source.
+        let mut initial_y = nodes.iter().map(|n| n.pos().y).collect::<Vec<_>>();
+        let mut layer_of = layer_of.to_vec();
+        for &(s, t) in edges {
+            let mut prev = s;
+            for layer in layer_of[s] + 1..layer_of[t] {
+                let dummy = up.len();
+                up.push(vec![prev]);
+                down.push(vec![]);
+                down[prev].push(dummy);
+                layer_of.push(layer);
+                initial_y.push(initial_y[s]);
+                prev = dummy;
+            }
+            down[prev].push(t);
+            up[t].push(prev);
+        }
+
+        let mut order = vec![Vec::new(); num_layers];
+        for node in 0..up.len() {
+            order[layer_of[node]].push(node);
+        }
+        for layer in &mut order {
+            layer.sort_by_key(|&n| initial_y[n]);
+        }
+
+        let mut out = Self { num_real, order, rank: vec![0; up.len()], up, down };
+        out.update_ranks();
+        out
+    }
+
+    fn update_ranks(&mut self) {
+        for layer in &self.order {
+            for (i, &node) in layer.iter().enumerate() {
+                self.rank[node] = i;
+            }
+        }
+    }
+
+    /// Reorders nodes within layers to reduce the number of edge crossings, using the barycenter
+    /// heuristic. The best order seen is kept.
+    fn reduce_crossings(&mut self) {
+        let mut best = self.order.clone();
+        let mut best_crossings = self.crossings();
+        for sweep in 0..ORDER_SWEEPS {
+            let downwards = sweep % 2 == 0;
+            for i in 0..self.order.len() {
+                let layer = if downwards { i } else { self.order.len() - 1 - i };
+                let neighbors = if downwards { &self.up } else { &self.down };
+                let mut keyed = self.order[layer].iter()
+                    .map(|&node| {
+                        let ns = &neighbors[node];
+                        let key = if ns.is_empty() {
+                            self.rank[node] as f64
+                        } else {
+                            ns.iter().map(|&n| self.rank[n] as f64).sum::<f64>() / ns.len() as f64
+                        };
+                        (key, node)
+                    })
+                    .collect::<Vec<_>>();
+                keyed.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+                self.order[layer] = keyed.into_iter().map(|(_, node)| node).collect();
+                self.update_ranks();
+            }
+
+            let crossings = self.crossings();
+            if crossings < best_crossings {
+                best_crossings = crossings;
+                best = self.order.clone();
+            }
+        }
+
+        self.order = best;
+        self.update_ranks();
+    }
+
+    /// Number of edge crossings between all pairs of adjacent layers.
+    fn crossings(&self) -> usize {
+        let mut out = 0;
+        for layer in &self.order {
+            let mut edges = layer.iter()
+                .flat_map(|&s| self.down[s].iter().map(move |&t| (s, t)))
+                .map(|(s, t)| (self.rank[s], self.rank[t]))
+                .collect::<Vec<_>>();
+            edges.sort_unstable();
+            for (i, a) in edges.iter().enumerate() {
+                out += edges[i + 1..].iter().filter(|b| b.0 > a.0 && b.1 < a.1).count();
+            }
+        }
+        out
+    }
+
+    /// The x coordinate of each column.
+    fn column_xs(&self, nodes: &[Node]) -> Vec<i32> {
+        let mut out = Vec::with_capacity(self.order.len());
+        let mut x = 0;
+        for layer in &self.order {
+            out.push(x);
+            let width = layer.iter()
+                .filter(|&&n| n < self.num_real)
+                .map(|&n| node_size(&nodes[n]).0)
+                .max()
+                .unwrap_or(0);
+            x += snap_up(width + COLUMN_GAP);
+        }
+        out
+    }
+
+    /// The y coordinate for each real node.
+    fn node_ys(&self, nodes: &[Node]) -> Vec<i32> {
+        let height = |n: usize| if n < self.num_real { node_size(&nodes[n]).1 as f64 } else { 0.0 };
+
+        // Start with all columns stacked from the top.
+        let mut ys = vec![0.0; self.rank.len()];
+        for layer in &self.order {
+            let mut y = 0.0;
+            for &node in layer {
+                ys[node] = y;
+                y += height(node) + ROW_GAP as f64;
+            }
+        }
+
+        let center = |ys: &[f64], n: usize| ys[n] + height(n) / 2.0;
+        for sweep in 0..POSITION_SWEEPS {
+            let downwards = sweep % 2 == 0;
+            for i in 0..self.order.len() {
+                let layer = &self.order[if downwards { i } else { self.order.len() - 1 - i }];
+                let neighbors = if downwards { &self.up } else { &self.down };
+
+                // Where each node would like to be, then resolve overlaps by once pushing nodes
+                // down and once pushing them up. Both results keep the order and spacing, and so
+                // does their average.
+                let desired = layer.iter()
+                    .map(|&node| match neighbors[node].len() {
+                        0 => ys[node],
+                        len => {
+                            let sum = neighbors[node].iter().map(|&n| center(&ys, n)).sum::<f64>();
+                            sum / len as f64 - height(node) / 2.0
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                let mut pushed_down = desired.clone();
+                for j in 1..layer.len() {
+                    let min = pushed_down[j - 1] + height(layer[j - 1]) + ROW_GAP as f64;
+                    pushed_down[j] = pushed_down[j].max(min);
+                }
+                let mut pushed_up = desired;
+                for j in (0..layer.len().saturating_sub(1)).rev() {
+                    let max = pushed_up[j + 1] - height(layer[j]) - ROW_GAP as f64;
+                    pushed_up[j] = pushed_up[j].min(max);
+                }
+                for (j, &node) in layer.iter().enumerate() {
+                    ys[node] = (pushed_down[j] + pushed_up[j]) / 2.0;
+                }
+            }
+        }
+
+        let min = (0..self.num_real).map(|n| ys[n]).fold(f64::INFINITY, f64::min);
+        (0..self.num_real).map(|n| snap(ys[n] - min)).collect()
+    }
+}
+
+fn snap(v: f64) -> i32 {
+    (v / GRID as f64).round() as i32 * GRID
+}
+
+fn snap_up(v: i32) -> i32 {
+    (v + GRID - 1) / GRID * GRID
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::state::Input;
+    use super::*;
+
+    fn layout(json: &str) -> Graph {
+        let json = format!(r#"{{"state":{{"graph":{json}}},"version":0}}"#);
+        let mut graph = serde_json::from_str::<Input>(&json).unwrap().state.graph;
+        auto_layout(&mut graph);
+        graph
+    }
+
+    fn check_grid_and_overlaps(graph: &Graph) {
+        let rects = graph.nodes.iter().map(|n| (*n.pos(), node_size(n))).collect::<Vec<_>>();
+        for (i, (a, (aw, ah))) in rects.iter().enumerate() {
+            assert_eq!(a.x % GRID, 0);
+            assert_eq!(a.y % GRID, 0);
+            for (b, (bw, bh)) in &rects[i + 1..] {
+                let overlap = a.x < b.x + bw && b.x < a.x + aw && a.y < b.y + bh && b.y < a.y + ah;
+                assert!(!overlap, "nodes overlap");
+            }
+        }
+    }
+
+    #[test]
+    fn flows_left_to_right() {
+        // Two sources -> merger -> recipe -> splitter -> two recipes.
+        let g = layout(r#"{
+            "nodes": [
+                {"type":"source","pos":{"x":500,"y":0},"item":"iron-ore","rate":30},
+                {"type":"source","pos":{"x":0,"y":900},"item":"iron-ore","rate":30},
+                {"type":"merger","pos":{"x":-300,"y":100}},
+                {"type":"recipe","recipe":"iron-ingot","pos":{"x":0,"y":0},"buildingsCount":2,"overclock":1},
+                {"type":"splitter","pos":{"x":0,"y":0}},
+                {"type":"recipe","recipe":"iron-plate","pos":{"x":0,"y":0},"buildingsCount":1,"overclock":1},
+                {"type":"recipe","recipe":"iron-rod","pos":{"x":0,"y":0},"buildingsCount":2,"overclock":1}
+            ],
+            "edges": [
+                {"source":{"node":0,"handle":0},"target":{"node":2,"handle":0}},
+                {"source":{"node":1,"handle":0},"target":{"node":2,"handle":1}},
+                {"source":{"node":2,"handle":3},"target":{"node":3,"handle":0}},
+                {"source":{"node":3,"handle":4},"target":{"node":4,"handle":0}},
+                {"source":{"node":4,"handle":1},"target":{"node":5,"handle":0}},
+                {"source":{"node":4,"handle":2},"target":{"node":6,"handle":0}}
+            ]
+        }"#);
+
+        for e in &g.edges {
+            assert!(g.node(e.source.node).pos().x < g.node(e.target.node).pos().x);
+        }
+        assert_eq!(g.nodes.iter().map(|n| n.pos().x).min(), Some(0));
+        assert_eq!(g.nodes.iter().map(|n| n.pos().y).min(), Some(0));
+        assert_eq!(g.nodes[5].pos().x, g.nodes[6].pos().x);
+        check_grid_and_overlaps(&g);
+    }
+
+    #[test]
+    fn removes_crossings() {
+        // Two independent chains, initially ordered such that their edges cross.
+        let g = layout(r#"{
+            "nodes": [
+                {"type":"source","pos":{"x":0,"y":0},"item":"iron-ore","rate":30},
+                {"type":"source","pos":{"x":0,"y":200},"item":"copper-ore","rate":30},
+                {"type":"recipe","recipe":"copper-ingot","pos":{"x":300,"y":0},"buildingsCount":1,"overclock":1},
+                {"type":"recipe","recipe":"iron-ingot","pos":{"x":300,"y":200},"buildingsCount":1,"overclock":1}
+            ],
+            "edges": [
+                {"source":{"node":0,"handle":0},"target":{"node":3,"handle":0}},
+                {"source":{"node":1,"handle":0},"target":{"node":2,"handle":0}}
+            ]
+        }"#);
+
+        let above = |a: usize, b: usize| g.nodes[a].pos().y < g.nodes[b].pos().y;
+        assert_eq!(above(0, 1), above(3, 2));
+        check_grid_and_overlaps(&g);
+    }
+
+    #[test]
+    fn cycles() {
+        // Plastic -> Recycled Rubber -> Recycled Plastic -> back to rubber.
+        let g = layout(r#"{
+            "nodes": [
+                {"type":"source","pos":{"x":0,"y":0},"item":"water","rate":60},
+                {"type":"recipe","recipe":"recycled-rubber","pos":{"x":0,"y":0},"buildingsCount":1,"overclock":1},
+                {"type":"recipe","recipe":"recycled-plastic","pos":{"x":0,"y":0},"buildingsCount":1,"overclock":1},
+                {"type":"splitter","pos":{"x":0,"y":0}}
+            ],
+            "edges": [
+                {"source":{"node":0,"handle":0},"target":{"node":1,"handle":1}},
+                {"source":{"node":1,"handle":4},"target":{"node":3,"handle":0}},
+                {"source":{"node":3,"handle":1},"target":{"node":2,"handle":0}},
+                {"source":{"node":2,"handle":4},"target":{"node":1,"handle":0}}
+            ]
+        }"#);
+        check_grid_and_overlaps(&g);
+    }
+}
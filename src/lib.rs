@@ -1,3 +1,5 @@
+use std::{cell::RefCell, rc::Rc};
+
 use base64::Engine;
 
 use wasm_bindgen::prelude::*;
@@ -21,10 +23,21 @@ macro_rules! log {
     ($($t:tt)*) => (println!($($t)*));
 }
 
-mod gamedata;
-mod state;
+pub mod analysis;
+pub mod gamedata;
+pub mod satisfactory_tools;
+pub mod save;
+pub mod state;
+mod balancer;
 mod digest;
+mod layout;
+mod export;
 
+thread_local! {
+    /// Game data the plans are analyzed with, see `load_data_packs`.
+    static GAME_DATA: RefCell<Rc<gamedata::GameData>> =
+        RefCell::new(Rc::new(gamedata::GameData::builtin()));
+}
 
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
 pub fn compress_state(json: &str) -> String {
@@ -35,17 +48,426 @@ pub fn compress_state(json: &str) -> String {
     base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(&new)
 }
 
+/// Decodes a digest from `compress_state`. Returns `{ input, warnings }` as JSON, like
+/// `migrate_state`, as the digest may use recipes that were removed from the game.
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
 pub fn decompress_state(digest: &str) -> String {
     set_panic_hook();
 
-    let binary = base64::engine::general_purpose::URL_SAFE_NO_PAD.decode(&digest)
+    let binary = base64::engine::general_purpose::URL_SAFE_NO_PAD.decode(digest)
         .expect("invalid base64");
-    let state = digest::decode(&binary).expect("invalid digest");
-    serde_json::to_string(&state).expect("Failed to serialize")
+    let (input, warnings) = digest::decode(&binary).expect("invalid digest");
+    serde_json::json!({ "input": input, "warnings": warnings }).to_string()
+}
+
+/// Replaces recipes that were removed from the game in the given state JSON (same as
+/// `compress_state`). Returns `{ input, warnings }` as JSON.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub fn migrate_state(json: &str) -> String {
+    set_panic_hook();
+
+    let mut input = serde_json::from_str::<serde_json::Value>(json).expect("Failed to deserialize");
+    let warnings = state::migrate(&mut input);
+    serde_json::json!({ "input": input, "warnings": warnings }).to_string()
+}
+
+/// Assigns new positions to all nodes of the given state (same JSON as `compress_state`) and
+/// returns the resulting state as JSON.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub fn auto_layout(json: &str) -> String {
+    set_panic_hook();
+
+    let mut input = serde_json::from_str::<state::Input>(json).expect("Failed to deserialize");
+    layout::auto_layout(&mut input.state.graph);
+    serde_json::to_string(&input).expect("Failed to serialize")
+}
+
+/// Renders the given state (same JSON as `compress_state`) as Graphviz DOT.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub fn export_dot(json: &str) -> String {
+    set_panic_hook();
+
+    let input = serde_json::from_str::<state::Input>(json).expect("Failed to deserialize");
+    export::to_dot(&input.state.graph, &game_data())
+}
+
+/// Renders the given state (same JSON as `compress_state`) as Mermaid flowchart.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub fn export_mermaid(json: &str) -> String {
+    set_panic_hook();
+
+    let input = serde_json::from_str::<state::Input>(json).expect("Failed to deserialize");
+    export::to_mermaid(&input.state.graph, &game_data())
+}
+
+/// Renders the given state (same JSON as `compress_state`) as standalone SVG image.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub fn export_svg(json: &str) -> String {
+    set_panic_hook();
+
+    let input = serde_json::from_str::<state::Input>(json).expect("Failed to deserialize");
+    export::to_svg(&input.state.graph, &game_data())
+}
+
+/// Renders a production summary of the given state (same JSON as `compress_state`) as CSV, or as
+/// Markdown if `markdown` is true.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub fn export_summary(json: &str, markdown: bool) -> String {
+    set_panic_hook();
+
+    let input = serde_json::from_str::<state::Input>(json).expect("Failed to deserialize");
+    if markdown {
+        export::summary_markdown(&input.state.graph, &game_data())
+    } else {
+        export::summary_csv(&input.state.graph, &game_data())
+    }
+}
+
+/// Calculates all buildings and construction items needed for the given state (same JSON as
+/// `compress_state`). Returns the `analysis::BillOfMaterials` as JSON.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub fn bill_of_materials(json: &str) -> String {
+    set_panic_hook();
+
+    let input = serde_json::from_str::<state::Input>(json).expect("Failed to deserialize");
+    let bom = analysis::bill_of_materials(&input.state.graph, &game_data());
+    serde_json::to_string(&bom).expect("Failed to serialize")
+}
+
+/// Returns the IDs of all recipes that produce the item with the given ID, as JSON array.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub fn recipes_producing(item: &str) -> Result<String, String> {
+    set_panic_hook();
+
+    let item = parse_item_id(item)?;
+    Ok(serde_json::to_string(item.produced_by()).expect("Failed to serialize"))
+}
+
+/// Returns the IDs of all recipes that consume the item with the given ID, as JSON array.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub fn recipes_consuming(item: &str) -> Result<String, String> {
+    set_panic_hook();
+
+    let item = parse_item_id(item)?;
+    Ok(serde_json::to_string(item.consumed_by()).expect("Failed to serialize"))
+}
+
+/// Returns the recipes producing and consuming every item in one go, as JSON object
+/// `{ producers, consumers }` mapping item IDs to arrays of recipe IDs. Items without any recipe
+/// are left out.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub fn recipe_index() -> String {
+    set_panic_hook();
+
+    let items = (0..).map_while(|id| gamedata::ItemKind::try_from(id).ok()).collect::<Vec<_>>();
+    let index = |recipes: fn(gamedata::ItemKind) -> &'static [gamedata::RecipeKind]| {
+        items.iter()
+            .filter(|&&item| !recipes(item).is_empty())
+            .map(|&item| (item, recipes(item)))
+            .collect::<std::collections::BTreeMap<_, _>>()
+    };
+    serde_json::json!({
+        "producers": index(gamedata::ItemKind::produced_by),
+        "consumers": index(gamedata::ItemKind::consumed_by),
+    }).to_string()
+}
+
+/// Calculates raw resources, buildings and power for producing 1 unit/min of the given item. The
+/// selection is a JSON object mapping item IDs to recipe IDs and may be empty. `filter` is a JSON
+/// `gamedata::RecipeFilter` restricting the default recipes. Returns the `analysis::Breakdown` as
+/// JSON.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub fn raw_breakdown(item: &str, selection: &str, filter: &str) -> Result<String, String> {
+    set_panic_hook();
+
+    let item = parse_item_id(item)?;
+    let selection = parse_selection(selection, filter)?;
+    let breakdown = analysis::breakdown(item, &selection).map_err(|e| e.to_string())?;
+    Ok(serde_json::to_string(&breakdown).expect("Failed to serialize"))
+}
+
+/// Calculates the most of the given item that can be made from the raw resources in `budget`, a
+/// JSON object mapping resource IDs to rates per minute. `filter` is a JSON
+/// `gamedata::RecipeFilter` restricting the recipes. Returns the `analysis::MaxOutput` as JSON.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub fn max_output(item: &str, budget: &str, filter: &str) -> Result<String, String> {
+    set_panic_hook();
+
+    let item = parse_item_id(item)?;
+    let budget = serde_json::from_str(budget).map_err(|e| e.to_string())?;
+    let filter = serde_json::from_str(filter).map_err(|e| e.to_string())?;
+    let result = analysis::max_output(item, &budget, &filter)?;
+    Ok(serde_json::to_string(&result).expect("Failed to serialize"))
+}
+
+/// Finds the plans for producing the given item that trade raw resources, power and building
+/// count against each other, none worse than another in all three. `filter` is a JSON
+/// `gamedata::RecipeFilter` restricting the recipes. Returns the `analysis::ParetoFrontier` as
+/// JSON.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub fn pareto_frontier(item: &str, filter: &str) -> Result<String, String> {
+    set_panic_hook();
+
+    let item = parse_item_id(item)?;
+    let filter = serde_json::from_str(filter).map_err(|e| e.to_string())?;
+    let frontier = analysis::pareto_frontier(item, &filter)?;
+    Ok(serde_json::to_string(&frontier).expect("Failed to serialize"))
+}
+
+/// Compares all recipes producing the given item by the cost of their full production chain. The
+/// selection and filter configure the recipes for intermediate items (see `raw_breakdown`),
+/// `rank_by` is one of `raw`, `buildings` or `power`. Returns JSON, or a Markdown table if
+/// `markdown` is true.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub fn compare_recipes(
+    item: &str,
+    selection: &str,
+    filter: &str,
+    rank_by: &str,
+    markdown: bool,
+) -> Result<String, String> {
+    set_panic_hook();
+
+    let item = parse_item_id(item)?;
+    let selection = parse_selection(selection, filter)?;
+    let rank_by = serde_json::from_value(rank_by.into())
+        .map_err(|_| format!("invalid rank_by '{rank_by}'"))?;
+    let comparison = analysis::compare_recipes(item, &selection, rank_by);
+    if markdown {
+        Ok(comparison.to_markdown())
+    } else {
+        Ok(serde_json::to_string(&comparison).expect("Failed to serialize"))
+    }
+}
+
+/// Simulates how items flow through the given state (same JSON as `compress_state`), including
+/// how splitters divide and mergers combine them. Returns the `analysis::Flows` as JSON.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub fn edge_flows(json: &str) -> String {
+    set_panic_hook();
+
+    let input = serde_json::from_str::<state::Input>(json).expect("Failed to deserialize");
+    let flows = analysis::flows(&input.state.graph, &game_data());
+    serde_json::to_string(&flows).expect("Failed to serialize")
+}
+
+/// Finds what limits the end products of the given state (same JSON as `compress_state`) and
+/// which changes would unlock more output. `tiers` is a JSON `analysis::ConveyorTiers` all
+/// connections are assumed to be built with, or `null` for the highest tiers. Returns the
+/// `analysis::Bottlenecks` as JSON.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub fn bottlenecks(json: &str, tiers: &str) -> Result<String, String> {
+    set_panic_hook();
+
+    let input = parse_input(json)?;
+    let tiers = serde_json::from_str::<Option<analysis::ConveyorTiers>>(tiers)
+        .map_err(|e| e.to_string())?
+        .unwrap_or_default();
+    let bottlenecks = analysis::bottlenecks(&input.state.graph, &game_data(), tiers);
+    Ok(serde_json::to_string(&bottlenecks).expect("Failed to serialize"))
+}
+
+/// Computes the edits balancing the given state (same JSON as `compress_state`) while keeping
+/// the rate of its end products. `options` is a JSON `analysis::RebalanceOptions`. Returns
+/// `{ rebalance, input }` as JSON, with the `analysis::Rebalance` and the state with all edits
+/// applied.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub fn rebalance(json: &str, options: &str) -> Result<String, String> {
+    set_panic_hook();
+
+    let mut input = parse_input(json)?;
+    let options = serde_json::from_str(options).map_err(|e| e.to_string())?;
+    let rebalance = analysis::rebalance(&input.state.graph, &game_data(), options);
+    analysis::apply_edits(&mut input.state.graph, &rebalance.edits);
+    Ok(serde_json::json!({ "rebalance": rebalance, "input": input }).to_string())
+}
+
+/// Like `rebalance`, but rounding every recipe node to whole buildings at one of the clocks given
+/// in `options`, a JSON `analysis::IntegerOptions`. Returns `{ plan, input }` as JSON, with the
+/// `analysis::IntegerPlan` and the state with all edits applied.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub fn round_per_node(json: &str, options: &str) -> Result<String, String> {
+    set_panic_hook();
+
+    let mut input = parse_input(json)?;
+    let options = serde_json::from_str(options).map_err(|e| e.to_string())?;
+    let plan = analysis::round_per_node(&input.state.graph, &game_data(), &options)?;
+    analysis::apply_edits(&mut input.state.graph, &plan.edits);
+    Ok(serde_json::json!({ "plan": plan, "input": input }).to_string())
+}
+
+/// Like `round_per_node`, but searches for the plan with the fewest buildings or power shards,
+/// which may have buildings at different clocks in one node. Returns the same JSON.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub fn optimize_integer(json: &str, options: &str) -> Result<String, String> {
+    set_panic_hook();
+
+    let mut input = parse_input(json)?;
+    let options = serde_json::from_str(options).map_err(|e| e.to_string())?;
+    let plan = analysis::optimize_integer(&input.state.graph, &game_data(), &options)?;
+    analysis::apply_edits(&mut input.state.graph, &plan.edits);
+    Ok(serde_json::json!({ "plan": plan, "input": input }).to_string())
+}
+
+/// Counts the power shards and Somersloops needed for the given state (same JSON as
+/// `compress_state`). `owned` is a JSON `analysis::Amplifiers` or `null`; if given, needing more
+/// than that is reported as problem. Returns the `analysis::AmplifierUsage` as JSON.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub fn amplifier_usage(json: &str, owned: &str) -> Result<String, String> {
+    set_panic_hook();
+
+    let input = parse_input(json)?;
+    let owned = serde_json::from_str(owned).map_err(|e| e.to_string())?;
+    let usage = analysis::amplifier_usage(&input.state.graph, &game_data(), owned);
+    Ok(serde_json::to_string(&usage).expect("Failed to serialize"))
+}
+
+/// Calculates ways to produce `rate` items per minute of the given item with the given recipe,
+/// fewest buildings first. Returns the `analysis::ClockOption`s as JSON array.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub fn clock_options(recipe: &str, item: &str, rate: f64) -> Result<String, String> {
+    set_panic_hook();
+
+    let recipe = serde_json::from_value(recipe.into()).map_err(|e| e.to_string())?;
+    let item = parse_item_id(item)?;
+    let options = analysis::clock_options(recipe, item, rate)?;
+    Ok(serde_json::to_string(&options).expect("Failed to serialize"))
+}
+
+/// Rewrites all recipe nodes of the given state (same JSON as `compress_state`) to the fewest
+/// buildings at a uniform clock of at most `max_clock` (e.g. 1.0 or 2.5). Returns
+/// `{ input, warnings }` as JSON.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub fn normalize_clocks(json: &str, max_clock: f64) -> Result<String, String> {
+    set_panic_hook();
+
+    let mut input = parse_input(json)?;
+    let max_clock = state::Overclock::try_from(max_clock)?;
+    let warnings = analysis::normalize_clocks(&mut input.state.graph, max_clock);
+    Ok(serde_json::json!({ "input": input, "warnings": warnings }).to_string())
+}
+
+/// Returns the IDs of all recipes allowed by the given JSON `gamedata::RecipeFilter`, as JSON array.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub fn allowed_recipes(filter: &str) -> Result<String, String> {
+    set_panic_hook();
+
+    let filter = serde_json::from_str::<gamedata::RecipeFilter>(filter)
+        .map_err(|e| e.to_string())?;
+    Ok(serde_json::to_string(&filter.allowed_recipes().collect::<Vec<_>>())
+        .expect("Failed to serialize"))
+}
+
+/// Builds a network of splitters and mergers dividing one input in the ratio of the given JSON
+/// array of numbers, e.g. `[1, 2]` or rates like `[7.5, 22.5]`. Returns the
+/// `balancer::RatioNetwork` as JSON.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub fn ratio_network(ratios: &str) -> Result<String, String> {
+    set_panic_hook();
+
+    let rates = serde_json::from_str::<Vec<f64>>(ratios).map_err(|e| e.to_string())?;
+    let ratios = balancer::ratios_from_rates(&rates)?;
+    let network = balancer::ratio_network(&ratios)?;
+    Ok(serde_json::to_string(&network).expect("Failed to serialize"))
+}
+
+/// Imports the factory from a Satisfactory save file. Returns the `save::SaveImport` as JSON, with
+/// a state that can be passed to `compress_state`.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub fn import_save(data: &[u8]) -> Result<String, String> {
+    set_panic_hook();
+
+    let import = save::import(data)?;
+    Ok(serde_json::to_string(&import).expect("Failed to serialize"))
+}
+
+/// Builds a plan from a production request of the Satisfactory Tools planner. Returns the
+/// `satisfactory_tools::ToolsImport` as JSON.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub fn import_satisfactory_tools(json: &str) -> Result<String, String> {
+    set_panic_hook();
+
+    let data = serde_json::from_str::<satisfactory_tools::ProductionData>(json)
+        .map_err(|e| e.to_string())?;
+    let import = satisfactory_tools::import(&data);
+    Ok(serde_json::to_string(&import).expect("Failed to serialize"))
+}
+
+/// Converts the given state (same JSON as `compress_state`) to a production request of the
+/// Satisfactory Tools planner.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub fn export_satisfactory_tools(json: &str) -> String {
+    set_panic_hook();
+
+    let input = serde_json::from_str::<state::Input>(json).expect("Failed to deserialize");
+    let data = satisfactory_tools::export(&input.state.graph);
+    serde_json::to_string(&data).expect("Failed to serialize")
+}
+
+/// Applies the given JSON array of `gamedata::DataPack`s in order on top of the built-in game data.
+/// All following calls analyze plans with the result, so that pack recipes are simulated. Returns
+/// the resulting `gamedata::GameData` as JSON.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+pub fn load_data_packs(packs: &str) -> Result<String, String> {
+    set_panic_hook();
+
+    let packs = serde_json::from_str::<Vec<gamedata::DataPack>>(packs)
+        .map_err(|e| e.to_string())?;
+    let data = gamedata::GameData::with_packs(&packs)?;
+    let json = serde_json::to_string(&data).expect("Failed to serialize");
+    GAME_DATA.with(|current| *current.borrow_mut() = Rc::new(data));
+    Ok(json)
+}
+
+fn parse_selection(selection: &str, filter: &str) -> Result<analysis::RecipeSelection, String> {
+    let selection = serde_json::from_str::<analysis::RecipeSelection>(selection)
+        .map_err(|e| e.to_string())?;
+    let filter = serde_json::from_str(filter).map_err(|e| e.to_string())?;
+    Ok(selection.restrict(filter))
+}
+
+fn parse_item_id(id: &str) -> Result<gamedata::ItemKind, String> {
+    serde_json::from_value(id.into()).map_err(|_| format!("invalid item ID '{id}'"))
+}
+
+fn parse_input(json: &str) -> Result<state::Input, String> {
+    serde_json::from_str(json).map_err(|e| e.to_string())
+}
+
+fn game_data() -> Rc<gamedata::GameData> {
+    GAME_DATA.with(|data| data.borrow().clone())
 }
 
 fn set_panic_hook() {
     #[cfg(feature = "console_error_panic_hook")]
     console_error_panic_hook::set_once();
 }
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid_json_is_an_error() {
+        assert!(raw_breakdown("iron-plate", "{", "{}").is_err());
+        assert!(raw_breakdown("no-such-item", "{}", "{}").is_err());
+        assert!(compare_recipes("iron-plate", "{}", "{}", "speed", false).is_err());
+        assert!(allowed_recipes(r#"{"maxTier": "one"}"#).is_err());
+        assert!(bottlenecks("{}", "null").is_err());
+        assert!(rebalance("{}", "{}").is_err());
+        assert!(amplifier_usage("{}", "null").is_err());
+        assert!(raw_breakdown("iron-plate", "{}", "{}").is_ok());
+    }
+
+    #[test]
+    fn recipe_index_matches_lookups() {
+        let index = serde_json::from_str::<serde_json::Value>(&recipe_index()).unwrap();
+        let producers = &index["producers"]["iron-plate"];
+        assert_eq!(producers.to_string(), recipes_producing("iron-plate").unwrap());
+        let consumers = &index["consumers"]["iron-plate"];
+        assert_eq!(consumers.to_string(), recipes_consuming("iron-plate").unwrap());
+        assert!(index["producers"].get("ai-expansion-server").is_some());
+        assert!(index["consumers"].get("ai-expansion-server").is_none());
+    }
+}
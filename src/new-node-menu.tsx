@@ -6,7 +6,7 @@ export const NEW_NODE_MENU_WIDTH = 250;
 export const NEW_NODE_MENU_HEIGHT = 320;
 
 export const calcNewNodeMenuPos = (
-    e: React.MouseEvent | MouseEvent,
+    e: { clientX: number; clientY: number },
     bounds: DOMRect,
 ): NewNodeMenuPos => {
     // Set just one property per dimension. The menu defaults to opening to the right bottom of
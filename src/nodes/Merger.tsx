@@ -1,7 +1,12 @@
 import { type Node, Handle, NodeProps } from "@xyflow/react";
+import { LuChevronDown, LuChevronUp } from "react-icons/lu";
 
-import { CombinerNode, handleCss, RateDiff, totalRateCss } from "./util";
-import { MERGER_INPUTS, MERGER_OUTPUTS, MergerGraphNode, mergerHandlePos } from "../graph/merger";
+import {
+    CombinerNode, handleCss, RateDiff, settingsButtonCss, settingsPopoverCss, totalRateCss,
+} from "./util";
+import {
+    MERGER_INPUTS, MERGER_OUTPUTS, MergerGraphNode, mergerHandlePos, PriorityMergerGraphNode,
+} from "../graph/merger";
 import { toFlowHandleId } from "../util";
 import { useStore } from "../store";
 import { NodeData } from ".";
@@ -9,7 +14,7 @@ import { GraphHandle } from "../graph";
 
 
 export type MergerNodeData = NodeData<MergerGraphNode>;
-export type MergerNode = Node<MergerNodeData, "merger">;
+export type MergerNode = Node<MergerNodeData, "merger" | "priorityMerger">;
 
 export const MergerNode = ({ selected, data: { node, id } }: NodeProps<MergerNode>) => {
     const graph = useStore(state => state.graph);
@@ -61,5 +66,51 @@ export const MergerNode = ({ selected, data: { node, id } }: NodeProps<MergerNod
                 />
             </div>
         )}
+        {selected && node instanceof PriorityMergerGraphNode && (
+            <PrioritySettings id={id} node={node} />
+        )}
     </CombinerNode>;
 };
+
+/** Lets the user reorder the inputs of a priority merger. */
+const PrioritySettings = ({ id, node }: NodeData<PriorityMergerGraphNode>) => {
+    const setData = useStore(state => state.setPriorityMergerNodeData);
+    const swap = (i: number, j: number) => {
+        const priority = [...node.priority];
+        [priority[i], priority[j]] = [priority[j], priority[i]];
+        setData(id, { priority });
+    };
+
+    return <div css={{
+        ...settingsPopoverCss,
+        position: "absolute",
+        bottom: "calc(100% + 12px)",
+        left: "50%",
+        transform: "translateX(-50%)",
+        fontSize: 12,
+        display: "flex",
+        flexDirection: "column",
+        gap: 4,
+    }}>
+        <div css={{ fontWeight: "bold", whiteSpace: "nowrap" }}>Highest priority first</div>
+        {node.priority.map((h, i) => (
+            <div key={h} css={{ display: "flex", alignItems: "center", gap: 4 }}>
+                <span css={{ flex: 1, textTransform: "capitalize" }}>
+                    {i + 1}. {mergerHandlePos(h)}
+                </span>
+                <button
+                    title="Higher priority"
+                    disabled={i === 0}
+                    css={settingsButtonCss}
+                    onClick={() => swap(i, i - 1)}
+                ><LuChevronUp /></button>
+                <button
+                    title="Lower priority"
+                    disabled={i === node.priority.length - 1}
+                    css={settingsButtonCss}
+                    onClick={() => swap(i, i + 1)}
+                ><LuChevronDown /></button>
+            </div>
+        ))}
+    </div>;
+};
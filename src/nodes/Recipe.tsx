@@ -1,18 +1,19 @@
 import { type Node, Handle, NodeProps, Position } from "@xyflow/react";
-import { ItemId, ITEMS } from "../gamedata";
+import { ItemId, itemName, ITEMS } from "../gamedata";
 import { useState } from "react";
 import { itemIcon, toFlowHandleId } from "../util";
 import { handleCss, rateCss, RateDiff, settingsPopoverCss, totalRateCss } from "./util";
 import { useStore } from "../store";
 import { LuMinus, LuPlus } from "react-icons/lu";
 import { useShallow } from "zustand/shallow";
-import { RecipeGraphNode, recipeHandleIdFor } from "../graph/recipe";
+import { RecipeGraphNode, recipeHandleIdFor, RecipeLikeGraphNode } from "../graph/recipe";
 import { GraphHandle } from "../graph";
 import { NodeData } from ".";
+import { useSettings } from "../settings";
 
 
-export type RecipeNodeData = NodeData<RecipeGraphNode>;
-export type RecipeNode = Node<RecipeNodeData, "recipe">;
+export type RecipeNodeData = NodeData<RecipeLikeGraphNode>;
+export type RecipeNode = Node<RecipeNodeData, "recipe" | "packRecipe">;
 
 export const RecipeNode = ({ data: { node, id }, selected }: NodeProps<RecipeNode>) => {
     const { graph, setRecipeNodeData } = useStore(useShallow(state => ({
@@ -20,6 +21,8 @@ export const RecipeNode = ({ data: { node, id }, selected }: NodeProps<RecipeNod
         setRecipeNodeData: state.setRecipeNodeData,
     })));
     const updateData = (update: Partial<RecipeGraphNode>) => setRecipeNodeData(id, update);
+    // Pack recipes are only known once their data pack is loaded.
+    useSettings(state => state.dataPacks);
 
     // Work around the zombie child problem. When deleting nodes, for some reason, the node is
     // still rendered once, but with `graph` alerady not containing it. This usually leads to
@@ -161,10 +164,10 @@ const IoEntry = ({
                 ...handleCss,
             }}
         />
-        <img
+        {itemId in ITEMS && <img
             src={itemIcon(itemId)}
             css={{ height: "100%"}}
-        />
+        />}
         <div css={{
             ...rateCss,
             minWidth: 20,
@@ -181,7 +184,7 @@ const IoEntry = ({
             WebkitLineClamp: 2,
             textOverflow: "ellipsis",
             fontStretch: "90%",
-        }}>{ITEMS[itemId].name}</div>
+        }}>{itemName(itemId)}</div>
     </div>
 );
 
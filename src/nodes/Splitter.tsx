@@ -1,14 +1,19 @@
 import { type Node, Handle, NodeProps } from "@xyflow/react";
+import { LuX } from "react-icons/lu";
 
-import { CombinerNode, handleCss } from "./util";
-import { SPLITTER_INPUTS, SPLITTER_OUTPUTS, SplitterGraphNode, splitterHandlePos } from "../graph/splitter";
+import { CombinerNode, handleCss, settingsButtonCss, settingsPopoverCss } from "./util";
+import {
+    SmartSplitterGraphNode, SPLITTER_INPUTS, SPLITTER_OUTPUTS, SplitFilter, SplitterGraphNode,
+    splitterHandlePos,
+} from "../graph/splitter";
 import { toFlowHandleId } from "../util";
 import { useStore } from "../store";
+import { ItemId, itemName, ITEMS } from "../gamedata";
 import { NodeData } from ".";
 
 
 export type SplitterNodeData = NodeData<SplitterGraphNode>;
-export type SplitterNode = Node<SplitterNodeData, "splitter">;
+export type SplitterNode = Node<SplitterNodeData, "splitter" | "smartSplitter">;
 
 export const SplitterNode = ({ selected, data: { node, id } }: NodeProps<SplitterNode>) => {
     const graph = useStore(state => state.graph);
@@ -35,5 +40,75 @@ export const SplitterNode = ({ selected, data: { node, id } }: NodeProps<Splitte
                 />
             ))
         )}
+        {selected && node instanceof SmartSplitterGraphNode && (
+            <FilterSettings id={id} node={node} />
+        )}
     </CombinerNode>;
 };
+
+const SPECIAL_FILTERS: [SplitFilter, string][] = [
+    ["any", "Any"],
+    ["anyUndefined", "Any undefined"],
+    ["overflow", "Overflow"],
+];
+
+const filterName = (filter: SplitFilter): string => (
+    SPECIAL_FILTERS.find(([f]) => f === filter)?.[1] ?? itemName(filter as ItemId)
+);
+
+/** Lets the user pick the filters of each output of a smart splitter. */
+const FilterSettings = ({ id, node }: NodeData<SmartSplitterGraphNode>) => {
+    const setData = useStore(state => state.setSmartSplitterNodeData);
+    const setFilters = (output: number, filters: SplitFilter[]) => {
+        const all = [...node.filters] as SmartSplitterGraphNode["filters"];
+        all[output] = filters;
+        setData(id, { filters: all });
+    };
+
+    return <div css={{
+        ...settingsPopoverCss,
+        position: "absolute",
+        bottom: "calc(100% + 12px)",
+        left: "50%",
+        transform: "translateX(-50%)",
+        fontSize: 12,
+        width: 220,
+        display: "flex",
+        flexDirection: "column",
+        gap: 6,
+    }}>
+        {node.filters.map((filters, output) => (
+            <div key={output}>
+                <div css={{ fontWeight: "bold", textTransform: "capitalize" }}>
+                    {splitterHandlePos(SPLITTER_OUTPUTS[output])}
+                </div>
+                <div css={{ display: "flex", flexWrap: "wrap", gap: 4, margin: "2px 0" }}>
+                    {filters.length === 0 && <span css={{ color: "#777" }}>Nothing</span>}
+                    {filters.map(filter => (
+                        <button
+                            key={filter}
+                            title="Remove"
+                            css={settingsButtonCss}
+                            onClick={() => setFilters(output, filters.filter(f => f !== filter))}
+                        >
+                            {filterName(filter)} <LuX />
+                        </button>
+                    ))}
+                </div>
+                <select
+                    value=""
+                    onChange={e => setFilters(output, [...filters, e.target.value as SplitFilter])}
+                    css={{ width: "100%" }}
+                >
+                    <option value="" disabled>Add filter…</option>
+                    {SPECIAL_FILTERS.filter(([f]) => !filters.includes(f)).map(([f, name]) => (
+                        <option key={f} value={f}>{name}</option>
+                    ))}
+                    {(Object.keys(ITEMS) as ItemId[])
+                        .filter(item => !filters.includes(item))
+                        .map(item => <option key={item} value={item}>{ITEMS[item].name}</option>)}
+                </select>
+            </div>
+        ))}
+    </div>;
+};
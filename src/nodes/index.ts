@@ -17,7 +17,11 @@ export type NodeData<T extends GraphNode> = {
 
 export const NODE_TYPES = {
     "recipe": RecipeNode,
+    "packRecipe": RecipeNode,
     "splitter": SplitterNode,
     "merger": MergerNode,
     "source": SourceNode,
+    // Shown like their plain counterparts, with settings for filters or priorities when selected.
+    "smartSplitter": SplitterNode,
+    "priorityMerger": MergerNode,
 } satisfies NodeTypes;
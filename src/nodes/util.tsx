@@ -46,6 +46,17 @@ export const settingsPopoverCss = {
     padding: 4,
 };
 
+export const settingsButtonCss = {
+    background: "#f2f2f2",
+    border: "1px solid #ddd",
+    borderRadius: 4,
+    cursor: "pointer",
+    "&:hover:not([disabled])": {
+        background: "#e0e0e0",
+        borderColor: "#bbb",
+    },
+} as const;
+
 type RateDiffProps = {
     expected: number | undefined;
     actual: number;
@@ -0,0 +1,437 @@
+//! Import and export of the production request JSON used by the Satisfactory Tools production
+//! planner (satisfactorytools.com).
+//!
+//! That format does not describe a factory, but a request to their solver: target products,
+//! allowed alternate recipes, blocked recipes and resource limits, all keyed by the game's class
+//! names. Exporting derives such a request from the products and recipes of a plan. Importing
+//! solves the request with our own recipe breakdown and builds a plan from the result, with one
+//! recipe node per recipe and splitters and mergers wherever an item has several producers or
+//! consumers.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    analysis::{breakdown, RecipeSelection},
+    gamedata::{Building, ItemKind, RecipeFilter, RecipeKind, SourceItemKind},
+    layout,
+    state::{
+        amplification, Edge, Graph, GraphHandle, HandleId, Input, Node, NodeId, Overclock, Pos,
+        State,
+    },
+};
+
+
+/// A saved production tab of Satisfactory Tools.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct ProductionData {
+    #[serde(default)]
+    pub metadata: Metadata,
+    pub request: Request,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Metadata {
+    pub name: Option<String>,
+    pub icon: Option<String>,
+    pub schema_version: u32,
+    pub game_version: String,
+}
+
+impl Default for Metadata {
+    fn default() -> Self {
+        Self { name: None, icon: None, schema_version: 1, game_version: "1".into() }
+    }
+}
+
+/// All items, recipes and buildings are referenced by class name, e.g. `Desc_IronPlate_C`.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Request {
+    pub allowed_alternate_recipes: Vec<String>,
+    pub blocked_recipes: Vec<String>,
+    pub blocked_machines: Vec<String>,
+    pub blocked_resources: Vec<String>,
+    pub sinkable_resources: Vec<String>,
+    pub production: Vec<ProductionItem>,
+    pub input: Vec<InputItem>,
+    /// Maximum amount per minute for each raw resource.
+    pub resource_max: BTreeMap<String, f64>,
+    pub resource_weight: BTreeMap<String, f64>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductionItem {
+    pub item: Option<String>,
+    /// `perMinute` for a fixed rate, `max` to maximize the output.
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub amount: f64,
+    /// Relative weight for `max` targets, in percent.
+    #[serde(default = "default_ratio")]
+    pub ratio: f64,
+}
+
+fn default_ratio() -> f64 {
+    100.0
+}
+
+/// An item that is provided from outside.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InputItem {
+    pub item: Option<String>,
+    pub amount: f64,
+}
+
+/// Result of `import`.
+#[derive(Serialize)]
+pub struct ToolsImport {
+    pub input: Input,
+    /// Parts of the request that could not be represented, e.g. unknown class names.
+    pub warnings: Vec<String>,
+}
+
+/// Creates a request for the net output of all recipes in `graph`, allowing exactly the
+/// alternate recipes used. Raw resources supplied by source nodes become resource limits.
+pub fn export(graph: &Graph) -> ProductionData {
+    let mut balance = BTreeMap::<ItemKind, f64>::new();
+    let mut main_outputs = BTreeSet::new();
+    let mut alternates = BTreeSet::new();
+    let mut resource_max = BTreeMap::new();
+    for node in &graph.nodes {
+        match node {
+            Node::Recipe { recipe, buildings_count, overclock, somersloops, .. } => {
+                let info = recipe.info();
+                let factor = info.crafts_per_minute()
+                    * buildings_count.get() as f64
+                    * f64::from(*overclock);
+                let amplified = factor * amplification(info.building, *somersloops);
+                for input in info.inputs {
+                    *balance.entry(input.item).or_default() -= input.amount * factor;
+                }
+                for output in info.outputs {
+                    *balance.entry(output.item).or_default() += output.amount * amplified;
+                }
+                main_outputs.insert(info.outputs[0].item);
+                if info.alternative {
+                    alternates.insert(*recipe);
+                }
+            }
+            Node::Source { item, rate, .. } => {
+                let item = ItemKind::from(*item);
+                *resource_max.entry(item.class_name().to_owned()).or_default() += *rate as f64;
+            }
+            _ => {}
+        }
+    }
+
+    let production = balance.into_iter()
+        .filter(|(item, surplus)| main_outputs.contains(item) && *surplus > 1e-6)
+        .map(|(item, surplus)| ProductionItem {
+            item: Some(item.class_name().to_owned()),
+            kind: "perMinute".into(),
+            amount: (surplus * 1e4).round() / 1e4,
+            ratio: 100.0,
+        })
+        .collect();
+
+    ProductionData {
+        metadata: Metadata::default(),
+        request: Request {
+            allowed_alternate_recipes: alternates.iter()
+                .map(|r| r.class_name().to_owned())
+                .collect(),
+            production,
+            resource_max,
+            ..Request::default()
+        },
+    }
+}
+
+/// Solves the request and builds a plan for it. Only `perMinute` targets are supported.
+pub fn import(data: &ProductionData) -> ToolsImport {
+    let request = &data.request;
+    let mut warnings = Vec::new();
+    let mut unknown = |kind: &str, name: &str| warnings.push(format!("unknown {kind} '{name}'"));
+
+    let mut alternates = BTreeSet::new();
+    for name in &request.allowed_alternate_recipes {
+        match RecipeKind::from_class_name(name) {
+            Some(recipe) => { alternates.insert(recipe); }
+            None => unknown("recipe", name),
+        }
+    }
+    let mut blocked = BTreeSet::new();
+    for name in &request.blocked_recipes {
+        match RecipeKind::from_class_name(name) {
+            Some(recipe) => { blocked.insert(recipe); }
+            None => unknown("recipe", name),
+        }
+    }
+    let mut blocked_machines = BTreeSet::new();
+    for name in &request.blocked_machines {
+        // Machines are referenced by their descriptor, not the buildable class.
+        match Building::from_class_name(&name.replacen("Desc_", "Build_", 1)) {
+            Some(building) => { blocked_machines.insert(building); }
+            None => unknown("machine", name),
+        }
+    }
+
+    let filter = RecipeFilter { alternates: Some(alternates), ..RecipeFilter::default() };
+    let is_blocked = |r: RecipeKind| {
+        blocked.contains(&r) || blocked_machines.contains(&r.info().building)
+    };
+    let mut selection = RecipeSelection::new().restrict(filter.clone());
+    for item in (0..).map_while(|i| ItemKind::try_from(i).ok()) {
+        let Some(default) = selection.default_for(item).filter(|&r| is_blocked(r)) else {
+            continue;
+        };
+        let replacement = item.produced_by().iter().copied()
+            .find(|&r| r.info().outputs[0].item == item && filter.allows(r) && !is_blocked(r));
+        match replacement {
+            Some(recipe) => selection.set(item, recipe),
+            None => warnings.push(format!(
+                "no unblocked recipe for {}, using {}",
+                item.info().name,
+                default.info().name,
+            )),
+        }
+    }
+
+    // ----- Solve
+    let mut recipes = BTreeMap::<RecipeKind, f64>::new();
+    let mut raw = BTreeMap::<SourceItemKind, f64>::new();
+    for target in &request.production {
+        let Some(name) = &target.item else { continue };
+        let Some(item) = ItemKind::from_class_name(name) else {
+            warnings.push(format!("unknown item '{name}'"));
+            continue;
+        };
+        if target.kind != "perMinute" {
+            warnings.push(format!("{}: target type '{}' is not supported", item.info().name, target.kind));
+            continue;
+        }
+        let b = match breakdown(item, &selection) {
+            Ok(b) => b,
+            Err(cycle) => {
+                warnings.push(format!("{}: {cycle}", item.info().name));
+                continue;
+            }
+        };
+        for (recipe, buildings) in b.recipes {
+            *recipes.entry(recipe).or_default() += buildings * target.amount;
+        }
+        for (source, rate) in b.raw {
+            *raw.entry(source).or_default() += rate * target.amount;
+        }
+        for (other, rate) in b.unresolved {
+            warnings.push(format!(
+                "{}: {} of {} per minute cannot be produced",
+                item.info().name,
+                rate * target.amount,
+                other.info().name,
+            ));
+        }
+    }
+
+    for (&source, &rate) in &raw {
+        let name = ItemKind::from(source).class_name();
+        if let Some(&max) = request.resource_max.get(name) {
+            if rate > max + 1e-6 {
+                let name = ItemKind::from(source).info().name;
+                warnings.push(format!("{name}: needs {rate} per minute, but only {max} are allowed"));
+            }
+        }
+    }
+    if !request.input.is_empty() {
+        warnings.push("input items are not supported and were ignored".into());
+    }
+
+    // ----- Build plan
+    let pos = Pos { x: 0, y: 0 };
+    let mut nodes = Vec::new();
+    for (&recipe, &buildings) in &recipes {
+        let count = (buildings - 1e-6).ceil().max(1.0);
+        let overclock = Overclock::try_from((buildings / count).max(0.01))
+            .expect("overclock in range");
+        nodes.push(Node::Recipe {
+            pos,
+            recipe,
+            buildings_count: (count as u32).try_into().unwrap(),
+            overclock,
+            somersloops: 0,
+        });
+    }
+    for (&item, &rate) in &raw {
+        nodes.push(Node::Source { pos, item, rate: (rate - 1e-6).ceil() as u32 });
+    }
+
+    let mut graph = Graph { nodes, edges: vec![] };
+    let items = graph.nodes.iter()
+        .flat_map(|n| match n {
+            Node::Recipe { recipe, .. } => recipe.info().inputs.iter().map(|i| i.item).collect(),
+            _ => vec![],
+        })
+        .collect::<BTreeSet<_>>();
+    for item in items {
+        let mut producers = Vec::new();
+        let mut consumers = Vec::new();
+        for (id, node) in graph.nodes.iter().enumerate() {
+            let handle = |handle: usize| GraphHandle { node: id as NodeId, handle: handle as HandleId };
+            match node {
+                Node::Recipe { recipe, .. } => {
+                    let info = recipe.info();
+                    let outputs = info.outputs.iter().position(|o| o.item == item);
+                    producers.extend(outputs.map(|i| handle(4 + i)));
+                    let inputs = info.inputs.iter().position(|i| i.item == item);
+                    consumers.extend(inputs.map(handle));
+                }
+                Node::Source { item: source, .. } if ItemKind::from(*source) == item => {
+                    producers.push(handle(0));
+                }
+                _ => {}
+            }
+        }
+        if !producers.is_empty() {
+            connect(&mut graph, producers, consumers);
+        }
+    }
+
+    layout::auto_layout(&mut graph);
+    ToolsImport {
+        input: Input { state: State { graph }, version: 0, data_packs: vec![] },
+        warnings,
+    }
+}
+
+/// Connects all `producers` to all `consumers` by merging them into one flow and splitting that
+/// again, chaining mergers and splitters if there are more than three of either.
+fn connect(graph: &mut Graph, producers: Vec<GraphHandle>, consumers: Vec<GraphHandle>) {
+    let add = |graph: &mut Graph, node: Node| {
+        graph.nodes.push(node);
+        (graph.nodes.len() - 1) as NodeId
+    };
+    let edge = |graph: &mut Graph, source, target| graph.edges.push(Edge { source, target });
+    let pos = Pos { x: 0, y: 0 };
+
+    let mut producers = producers.into_iter();
+    let mut flow = producers.next().unwrap();
+    let rest = producers.collect::<Vec<_>>();
+    for chunk in rest.chunks(2) {
+        let merger = add(graph, Node::Merger { pos });
+        edge(graph, flow, GraphHandle { node: merger, handle: 0 });
+        for (i, &producer) in chunk.iter().enumerate() {
+            edge(graph, producer, GraphHandle { node: merger, handle: 1 + i as HandleId });
+        }
+        flow = GraphHandle { node: merger, handle: 3 };
+    }
+
+    let mut consumers = &consumers[..];
+    while consumers.len() > 1 {
+        let splitter = add(graph, Node::Splitter { pos });
+        edge(graph, flow, GraphHandle { node: splitter, handle: 0 });
+        let direct = if consumers.len() > 3 { 2 } else { consumers.len() };
+        for (i, &consumer) in consumers[..direct].iter().enumerate() {
+            edge(graph, GraphHandle { node: splitter, handle: 1 + i as HandleId }, consumer);
+        }
+        consumers = &consumers[direct..];
+        flow = GraphHandle { node: splitter, handle: 3 };
+    }
+    if let [consumer] = consumers {
+        edge(graph, flow, *consumer);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::export::tests::{amplified, example};
+    use super::*;
+
+    fn request(json: &str) -> ProductionData {
+        serde_json::from_str(&format!(r#"{{"request":{json}}}"#)).unwrap()
+    }
+
+    fn recipes(graph: &Graph) -> Vec<(RecipeKind, u32, f64)> {
+        graph.nodes.iter()
+            .filter_map(|n| match n {
+                Node::Recipe { recipe, buildings_count, overclock, .. } => {
+                    Some((*recipe, buildings_count.get(), f64::from(*overclock)))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn export_example() {
+        let data = export(&example());
+        let json = serde_json::to_value(&data).unwrap();
+        assert_eq!(json["metadata"]["schemaVersion"], 1);
+        assert_eq!(json["request"]["production"], serde_json::json!([
+            {"item": "Desc_IronPlate_C", "type": "perMinute", "amount": 60.0, "ratio": 100.0},
+            {"item": "Desc_IronRod_C", "type": "perMinute", "amount": 30.0, "ratio": 100.0},
+        ]));
+        assert_eq!(json["request"]["resourceMax"], serde_json::json!({"Desc_OreIron_C": 90.0}));
+        assert_eq!(json["request"]["allowedAlternateRecipes"], serde_json::json!([]));
+        assert_eq!(json["request"]["blockedRecipes"], serde_json::json!([]));
+
+        let json = serde_json::to_value(export(&amplified())).unwrap();
+        assert_eq!(json["request"]["production"][0], serde_json::json!(
+            {"item": "Desc_IronPlate_C", "type": "perMinute", "amount": 120.0, "ratio": 100.0}
+        ));
+    }
+
+    #[test]
+    fn import_reinforced_plates() {
+        let data = request(r#"{
+            "production": [{"item": "Desc_IronPlateReinforced_C", "type": "perMinute", "amount": 60, "ratio": 100}],
+            "resourceMax": {"Desc_OreIron_C": 600}
+        }"#);
+        let result = import(&data);
+        let graph = &result.input.state.graph;
+        assert_eq!(recipes(graph), [
+            (RecipeKind::IronPlate, 18, 1.0),
+            (RecipeKind::IronRod, 12, 1.0),
+            (RecipeKind::IronIngot, 24, 1.0),
+            (RecipeKind::ReinforcedIronPlate, 12, 1.0),
+            (RecipeKind::Screw, 18, 1.0),
+        ]);
+        // Source, one splitter for the ingots, and a direct connection for everything else.
+        assert_eq!(graph.nodes.len(), 7);
+        assert_eq!(graph.edges.len(), 7);
+        assert_eq!(graph.nodes.iter().filter(|n| n.is_splitter()).count(), 1);
+        assert_eq!(result.warnings, ["Iron Ore: needs 720 per minute, but only 600 are allowed"]);
+    }
+
+    #[test]
+    fn import_alternates_and_blocked() {
+        let data = request(r#"{
+            "allowedAlternateRecipes": ["Recipe_Alternate_PureIronIngot_C", "Recipe_Nonsense_C"],
+            "blockedRecipes": ["Recipe_IngotIron_C"],
+            "production": [{"item": "Desc_IronIngot_C", "type": "perMinute", "amount": 97.5}]
+        }"#);
+        let result = import(&data);
+        let graph = &result.input.state.graph;
+        assert_eq!(recipes(graph), [(RecipeKind::PureIronIngot, 2, 0.75)]);
+        assert_eq!(result.warnings, ["unknown recipe 'Recipe_Nonsense_C'"]);
+        // Iron ore and water sources
+        assert_eq!(graph.nodes.len(), 3);
+        assert_eq!(graph.edges.len(), 2);
+    }
+
+    #[test]
+    fn chains() {
+        let source = |node| GraphHandle { node, handle: 0 };
+        let mut graph = Graph { nodes: vec![], edges: vec![] };
+        connect(&mut graph, (0..4).map(source).collect(), (10..15).map(source).collect());
+        // Two mergers for four producers, two splitters for five consumers.
+        assert_eq!(graph.nodes.iter().filter(|n| n.is_splitter()).count(), 2);
+        assert_eq!(graph.nodes.len(), 4);
+        assert_eq!(graph.edges.len(), (3 + 2) + (3 + 4));
+    }
+}
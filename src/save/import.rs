@@ -0,0 +1,417 @@
+//! Turning the objects of a save file into a plan.
+//!
+//! Every manufacturing building becomes one recipe node, and conveyor splitters and mergers
+//! become splitter and merger nodes, smart and priority ones with their sort rules and input
+//! priorities. Belts, lifts and pipes are not nodes: they are followed through their connection
+//! components to find which building connects to which. Everything else (miners, storage,
+//! power, ...) is ignored.
+
+use std::collections::{HashMap, HashSet};
+
+use serde::Serialize;
+
+use crate::{
+    gamedata::{Building, ItemKind, RecipeKind},
+    layout,
+    state::{
+        Edge, Graph, GraphHandle, HandleId, Input, InputPriority, Node, NodeId, Overclock, Pos,
+        SplitFilter, SplitRule, State,
+    },
+};
+use super::parse::{short_name, Object, Value};
+
+
+/// A plan reconstructed from a save file.
+#[derive(Serialize)]
+pub struct SaveImport {
+    pub input: Input,
+    /// Things that could not be imported, e.g. buildings without a recipe.
+    pub warnings: Vec<String>,
+}
+
+enum Kind {
+    Building(Building),
+    Merger,
+    Splitter,
+    PriorityMerger,
+    /// Smart and programmable splitters.
+    SmartSplitter,
+    /// Belts, lifts, pipes and pipe junctions, which just pass items through.
+    Conduit,
+}
+
+fn classify(class_name: &str) -> Option<Kind> {
+    if let Some(building) = Building::from_class_name(class_name) {
+        return Some(Kind::Building(building));
+    }
+    match class_name {
+        "Build_ConveyorAttachmentMerger_C" => Some(Kind::Merger),
+        "Build_ConveyorAttachmentSplitter_C" => Some(Kind::Splitter),
+        "Build_ConveyorAttachmentMergerPriority_C" => Some(Kind::PriorityMerger),
+        "Build_ConveyorAttachmentSplitterSmart_C"
+            | "Build_ConveyorAttachmentSplitterProgrammable_C" => Some(Kind::SmartSplitter),
+        _ if ["Build_ConveyorBelt", "Build_ConveyorLift", "Build_Pipeline"]
+            .iter()
+            .any(|prefix| class_name.starts_with(prefix)) => Some(Kind::Conduit),
+        _ => None,
+    }
+}
+
+pub(super) fn to_plan(objects: &[Object]) -> Result<SaveImport, String> {
+    let mut warnings = Vec::new();
+    let mut nodes = Vec::new();
+    let mut node_of_actor = HashMap::<&str, NodeId>::new();
+    let mut conduits = HashMap::<&str, Vec<&str>>::new();
+
+    // ----- Nodes
+    for object in objects.iter().filter(|o| o.parent.is_none()) {
+        let class_name = object.short_class_name();
+        let node = match classify(class_name) {
+            Some(Kind::Building(building)) => {
+                let Some(node) = recipe_node(object, building, &mut warnings) else { continue };
+                node
+            }
+            Some(Kind::Merger) => Node::Merger { pos: Pos { x: 0, y: 0 } },
+            Some(Kind::Splitter) => Node::Splitter { pos: Pos { x: 0, y: 0 } },
+            Some(Kind::PriorityMerger) => match input_priority(object, &mut warnings) {
+                Some(priority) => Node::PriorityMerger { pos: Pos { x: 0, y: 0 }, priority },
+                None => Node::Merger { pos: Pos { x: 0, y: 0 } },
+            },
+            Some(Kind::SmartSplitter) => Node::SmartSplitter {
+                pos: Pos { x: 0, y: 0 },
+                filters: split_filters(object, &mut warnings),
+            },
+            Some(Kind::Conduit) => {
+                conduits.insert(&object.path, Vec::new());
+                continue;
+            }
+            None => continue,
+        };
+
+        let id = NodeId::try_from(nodes.len()).map_err(|_| "too many buildings in save")?;
+        node_of_actor.insert(&object.path, id);
+        nodes.push(node);
+    }
+
+    // ----- Connection components
+    let mut connected = HashMap::<&str, Vec<&str>>::new();
+    let mut owner = HashMap::<&str, &str>::new();
+    let mut node_connectors = Vec::new();
+    for object in objects {
+        let Some(parent) = object.parent.as_deref() else { continue };
+        owner.insert(&object.path, parent);
+        if let Some(Value::Object(other)) = object.property("mConnectedComponent") {
+            connected.entry(&object.path).or_default().push(other);
+            connected.entry(other).or_default().push(&object.path);
+        }
+        if let Some(connectors) = conduits.get_mut(parent) {
+            connectors.push(&object.path);
+        } else if let Some(&node) = node_of_actor.get(parent) {
+            node_connectors.push((node, object.path.as_str()));
+        }
+    }
+
+    // ----- Edges: follow every output connector through conduits to input connectors.
+    let mut edges = Vec::new();
+    let mut used = HashSet::<(NodeId, HandleId)>::new();
+    for &(source, connector) in &node_connectors {
+        let name = short_name(connector);
+        if !name.contains("Output") {
+            continue;
+        }
+        let Some(source_handle) = output_handle(&nodes[source as usize], name) else {
+            warnings.push(format!("{connector}: unknown output"));
+            continue;
+        };
+        let item = match &nodes[source as usize] {
+            Node::Recipe { recipe, .. } => Some(recipe.info().outputs[source_handle as usize - 4].item),
+            _ => None,
+        };
+
+        let mut stack = vec![connector];
+        let mut visited = vec![connector];
+        while let Some(current) = stack.pop() {
+            for &other in connected.get(current).into_iter().flatten() {
+                if visited.contains(&other) {
+                    continue;
+                }
+                visited.push(other);
+                let Some(&parent) = owner.get(other) else { continue };
+                if let Some(connectors) = conduits.get(parent) {
+                    stack.extend(connectors.iter().copied().filter(|&c| c != other));
+                    visited.extend(connectors.iter().copied());
+                } else if let Some(&target) = node_of_actor.get(parent) {
+                    let name = short_name(other);
+                    if !name.contains("Input") {
+                        continue;
+                    }
+                    let is_used = |h| used.contains(&(target, h));
+                    let Some(target_handle) = input_handle(&nodes[target as usize], name, item, is_used) else {
+                        warnings.push(format!("{connector} → {other}: no matching input, skipped"));
+                        continue;
+                    };
+                    if used.contains(&(source, source_handle)) {
+                        warnings.push(format!("{connector}: connected to multiple inputs"));
+                        continue;
+                    }
+                    used.insert((source, source_handle));
+                    used.insert((target, target_handle));
+                    edges.push(Edge {
+                        source: GraphHandle { node: source, handle: source_handle },
+                        target: GraphHandle { node: target, handle: target_handle },
+                    });
+                }
+            }
+        }
+    }
+
+    let mut graph = Graph { nodes, edges };
+    layout::auto_layout(&mut graph);
+    Ok(SaveImport {
+        input: Input { state: State { graph }, version: 0, data_packs: vec![] },
+        warnings,
+    })
+}
+
+fn recipe_node(
+    object: &Object,
+    building: Building,
+    warnings: &mut Vec<String>,
+) -> Option<Node> {
+    let recipe = match object.property("mCurrentRecipe") {
+        Some(Value::Object(path)) if !path.is_empty() => RecipeKind::from_class_name(short_name(path)),
+        _ => {
+            warnings.push(format!("{}: no recipe set, skipped", object.path));
+            return None;
+        }
+    };
+    let Some(recipe) = recipe.filter(|r| r.info().building == building) else {
+        warnings.push(format!("{}: unknown recipe, skipped", object.path));
+        return None;
+    };
+
+    let float = |name| match object.property(name) {
+        Some(Value::Float(v)) => *v,
+        _ => 1.0,
+    };
+    let potential = float("mCurrentPotential");
+    let overclock = Overclock::try_from(potential).unwrap_or_else(|_| {
+        warnings.push(format!("{}: invalid clock speed {potential}", object.path));
+        Overclock(1_000_000)
+    });
+    let boost = float("mCurrentProductionBoost");
+    let slots = building.somersloop_slots();
+    let somersloops = ((boost - 1.0) * slots as f64).round().max(0.0);
+    let somersloops = if somersloops > slots as f64 {
+        warnings.push(format!(
+            "{}: production boost {boost} needs more than {slots} Somersloops, set to {slots}",
+            object.path,
+        ));
+        slots
+    } else {
+        somersloops as u8
+    };
+
+    Some(Node::Recipe {
+        pos: Pos { x: 0, y: 0 },
+        recipe,
+        buildings_count: 1.try_into().unwrap(),
+        overclock,
+        somersloops,
+    })
+}
+
+/// Filters of a smart splitter from its sort rules, each sending an item class to one output:
+/// `OutputIndex` 0 to 2 for the connectors `Output1` to `Output3`.
+fn split_filters(object: &Object, warnings: &mut Vec<String>) -> [Vec<SplitFilter>; 3] {
+    let Some(Value::Array(rules)) = object.property("mSortRules") else {
+        warnings.push(format!("{}: no sort rules found, set to any", object.path));
+        let any = || vec![SplitFilter::Rule(SplitRule::Any)];
+        return [any(), any(), any()];
+    };
+
+    let mut filters = <[Vec<SplitFilter>; 3]>::default();
+    for rule in rules {
+        let Value::Struct(properties) = rule else { continue };
+        let field = |name| properties.iter().find(|p| p.name == name).map(|p| &p.value);
+        let (Some(Value::Object(class)), Some(Value::Int(output))) =
+            (field("ItemClass"), field("OutputIndex")) else { continue };
+        let filter = match short_name(class) {
+            "Desc_None_C" => continue,
+            "Desc_Wildcard_C" => SplitFilter::Rule(SplitRule::Any),
+            "Desc_AnyUndefined_C" => SplitFilter::Rule(SplitRule::AnyUndefined),
+            "Desc_Overflow_C" => SplitFilter::Rule(SplitRule::Overflow),
+            name => match ItemKind::from_class_name(name) {
+                Some(item) => SplitFilter::Item(item),
+                None => {
+                    warnings.push(format!("{}: unknown item {name} in filter", object.path));
+                    continue;
+                }
+            },
+        };
+        match usize::try_from(*output).ok().and_then(|o| filters.get_mut(o)) {
+            Some(filters) if !filters.contains(&filter) => filters.push(filter),
+            Some(_) => {}
+            None => warnings.push(format!("{}: filter for invalid output {output}", object.path)),
+        }
+    }
+    filters
+}
+
+/// Order of the inputs of a priority merger from `mPriorities`, one value per connector `Input1`
+/// to `Input3`. Like in the game's menu, lower values are served first. Returns `None` if all
+/// inputs have the same priority, which works like a plain merger.
+fn input_priority(object: &Object, warnings: &mut Vec<String>) -> Option<InputPriority> {
+    let Some(Value::Array(values)) = object.property("mPriorities") else { return None };
+    let mut values = values.iter()
+        .map(|v| match v {
+            Value::Int(v) => *v,
+            _ => 0,
+        })
+        .collect::<Vec<_>>();
+    values.resize(3, 0);
+    if values.iter().all(|&v| v == values[0]) {
+        return None;
+    }
+
+    if (0..3).any(|a| (0..a).any(|b| values[a] == values[b])) {
+        warnings.push(format!("{}: inputs with equal priority ordered by input", object.path));
+    }
+    let mut order: [HandleId; 3] = [0, 1, 2];
+    order.sort_by_key(|&h| values[h as usize]);
+    InputPriority::try_from(order).ok()
+}
+
+/// Number at the end of a connector name, e.g. 2 for `Output2` and 0 for `PipeInputFactory`.
+fn connector_index(name: &str) -> usize {
+    let digits = name.trim_start_matches(|c: char| !c.is_ascii_digit());
+    digits.parse().unwrap_or(0)
+}
+
+fn output_handle(node: &Node, name: &str) -> Option<HandleId> {
+    let idx = connector_index(name);
+    match node {
+        Node::Recipe { recipe, .. } => {
+            let is_pipe = name.starts_with("Pipe");
+            let (i, _) = recipe.info().outputs.iter().enumerate()
+                .filter(|(_, o)| o.item.info().form.is_fluid() == is_pipe)
+                .nth(idx)?;
+            Some(4 + i as HandleId)
+        }
+        Node::Splitter { .. } | Node::SmartSplitter { .. } => {
+            (1..=3).contains(&idx).then_some(idx as HandleId)
+        }
+        Node::Merger { .. } | Node::PriorityMerger { .. } => Some(3),
+        Node::Source { .. } => Some(0),
+        // Saves only have built-in recipes.
+        Node::PackRecipe { .. } => None,
+    }
+}
+
+/// Finds the handle for an input connector. In the game, all belt (or pipe) inputs of a building
+/// accept any item, so for recipes we prefer the input matching `item`, if known.
+fn input_handle(
+    node: &Node,
+    name: &str,
+    item: Option<ItemKind>,
+    is_used: impl Fn(HandleId) -> bool,
+) -> Option<HandleId> {
+    let idx = connector_index(name);
+    match node {
+        Node::Recipe { recipe, .. } => {
+            let is_pipe = name.starts_with("Pipe");
+            let candidates = recipe.info().inputs.iter().enumerate()
+                .filter(|(_, i)| i.item.info().form.is_fluid() == is_pipe)
+                .map(|(h, i)| (h as HandleId, i.item))
+                .filter(|&(h, _)| !is_used(h))
+                .collect::<Vec<_>>();
+            let by_item = candidates.iter().find(|(_, i)| Some(*i) == item);
+            let by_index = candidates.get(idx).or(candidates.first());
+            by_item.or(by_index).map(|(h, _)| *h)
+        }
+        Node::Merger { .. } | Node::PriorityMerger { .. } => {
+            (1..=3).contains(&idx).then(|| idx as HandleId - 1)
+        }
+        Node::Splitter { .. } | Node::SmartSplitter { .. } => Some(0),
+        Node::Source { .. } | Node::PackRecipe { .. } => None,
+    }
+    .filter(|&h| !is_used(h))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::save::{import, writer::{small_factory, write}};
+    use super::*;
+
+    const FIXTURE: &str = "src/save/fixtures/small-factory.sav";
+
+    /// Regenerates the fixture with `cargo test -- --ignored write_fixture`.
+    #[test]
+    #[ignore]
+    fn write_fixture() {
+        std::fs::write(FIXTURE, write(&small_factory(), 512)).unwrap();
+    }
+
+    #[test]
+    fn small_factory_fixture() {
+        let import = import(include_bytes!("fixtures/small-factory.sav")).unwrap();
+        let graph = &import.input.state.graph;
+
+        let recipes = graph.nodes.iter()
+            .map(|n| match n {
+                Node::Recipe { recipe, overclock, somersloops, .. } => {
+                    Some((recipe.info().name, f64::from(*overclock), *somersloops))
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(recipes, [
+            Some(("Iron Ingot", 1.5, 0)),
+            None,
+            Some(("Iron Plate", 1.0, 1)),
+            Some(("Iron Rod", 1.0, 1)),
+            None,
+            None,
+        ]);
+        assert!(graph.node(1).is_splitter());
+
+        let edges = graph.edges.iter()
+            .map(|e| ((e.source.node, e.source.handle), (e.target.node, e.target.handle)))
+            .collect::<Vec<_>>();
+        assert_eq!(edges, [((0, 4), (1, 0)), ((1, 1), (2, 0)), ((1, 3), (3, 0))]);
+
+        assert_eq!(import.warnings.len(), 2, "{:?}", import.warnings);
+        assert!(import.warnings[0].contains("Build_ConstructorMk1_C_2: production boost 3"));
+        assert!(import.warnings[1].contains("Build_ConstructorMk1_C_3: no recipe set"));
+
+        let Node::SmartSplitter { filters, .. } = &graph.nodes[4] else { panic!() };
+        assert_eq!(filters, &[
+            vec![SplitFilter::Item(ItemKind::IronPlate), SplitFilter::Item(ItemKind::IronRod)],
+            vec![],
+            vec![SplitFilter::Rule(SplitRule::Overflow)],
+        ]);
+        let Node::PriorityMerger { priority, .. } = &graph.nodes[5] else { panic!() };
+        assert_eq!(<[HandleId; 3]>::from(*priority), [1, 2, 0]);
+
+        // The clamped Somersloops make it a valid plan.
+        let digest = crate::digest::encode(&import.input);
+        assert!(crate::digest::decode(&digest).is_ok());
+    }
+
+    #[test]
+    fn input_matching_item() {
+        let node = Node::Recipe {
+            pos: Pos { x: 0, y: 0 },
+            recipe: RecipeKind::from_class_name("Recipe_Screw_C").unwrap(),
+            buildings_count: 1.try_into().unwrap(),
+            overclock: Overclock(1_000_000),
+            somersloops: 0,
+        };
+        assert_eq!(output_handle(&node, "Output0"), Some(4));
+        assert_eq!(output_handle(&node, "PipeOutputFactory"), None);
+        assert_eq!(input_handle(&node, "Input0", None, |_| false), Some(0));
+        assert_eq!(input_handle(&node, "Input0", None, |_| true), None);
+        assert_eq!(input_handle(&node, "Input0", Some(ItemKind::Wire), |_| false), Some(0));
+    }
+}
@@ -0,0 +1,25 @@
+//! Importing Satisfactory save files (`.sav`) into a plan.
+//!
+//! A save file consists of an uncompressed header followed by zlib-compressed chunks, which
+//! together form the body with all levels and their objects. Only the parts needed to
+//! reconstruct the factory are decoded: the class, path and parent of every object and its simple
+//! properties, including arrays of them. Only saves of game version 1.0 and newer are supported.
+
+mod import;
+mod parse;
+mod reader;
+#[cfg(test)]
+mod writer;
+
+pub use self::{
+    import::SaveImport,
+    parse::{parse, Header, Object, Property, SaveFile, Value},
+};
+
+
+/// Parses a save file and converts its manufacturing buildings, splitters, mergers and the
+/// conveyor and pipe connections between them into a plan.
+pub fn import(data: &[u8]) -> Result<SaveImport, String> {
+    let save = parse(data)?;
+    import::to_plan(&save.objects)
+}
@@ -0,0 +1,405 @@
+//! Parsing the container format and object serialization of save files.
+
+use miniz_oxide::inflate::decompress_to_vec_zlib;
+
+use super::reader::Reader;
+
+
+/// Tag at the start of every compressed body chunk.
+pub(super) const PACKAGE_FILE_TAG: u32 = 0x9E2A83C1;
+
+/// Archive header marker of chunks written by game version 1.0 and newer. Older saves have 0
+/// there and no compression algorithm byte.
+pub(super) const ARCHIVE_V2: u32 = 0x22222222;
+
+const ZLIB: u8 = 3;
+
+/// Oldest save header version we can read (game version 1.0).
+pub(super) const MIN_HEADER_VERSION: i32 = 13;
+
+#[derive(Debug, Clone)]
+pub struct Header {
+    pub header_version: i32,
+    pub save_version: i32,
+    pub build_version: i32,
+    pub map_name: String,
+    pub session_name: String,
+    pub play_duration_seconds: i32,
+}
+
+#[derive(Debug, Clone)]
+pub struct SaveFile {
+    pub header: Header,
+    pub objects: Vec<Object>,
+}
+
+/// A serialized actor or component.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Object {
+    /// Full class path, e.g. `/Game/FactoryGame/Buildable/Factory/ConstructorMk1/Build_ConstructorMk1.Build_ConstructorMk1_C`.
+    pub class_name: String,
+    /// Unique path of this object, e.g. `Persistent_Level:PersistentLevel.Build_ConstructorMk1_C_42`.
+    pub path: String,
+    /// For components, the path of the actor they belong to.
+    pub parent: Option<String>,
+    /// For actors, the world position in cm.
+    pub position: Option<[f32; 3]>,
+    pub properties: Vec<Property>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Property {
+    pub name: String,
+    pub index: i32,
+    pub value: Value,
+}
+
+/// Property value. Only simple types and arrays of them are decoded, everything else is skipped.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    Str(String),
+    /// Path of the referenced object.
+    Object(String),
+    /// Array of ints, object references or structs.
+    Array(Vec<Value>),
+    /// Element of an array of structs, with its properties.
+    Struct(Vec<Property>),
+    Other,
+}
+
+impl Object {
+    /// Class name without the package path, e.g. `Build_ConstructorMk1_C`.
+    pub fn short_class_name(&self) -> &str {
+        short_name(&self.class_name)
+    }
+
+    pub fn property(&self, name: &str) -> Option<&Value> {
+        self.properties.iter().find(|p| p.name == name).map(|p| &p.value)
+    }
+}
+
+/// Returns the part after the last `.`, which for class and asset paths is the name.
+pub(super) fn short_name(path: &str) -> &str {
+    path.rsplit('.').next().unwrap_or(path)
+}
+
+/// Parses the full save file.
+pub fn parse(data: &[u8]) -> Result<SaveFile, String> {
+    let mut r = Reader::new(data);
+    let header = parse_header(&mut r)?;
+    let body = decompress_body(&mut r)?;
+    let objects = parse_body(&body)?;
+    Ok(SaveFile { header, objects })
+}
+
+fn parse_header(r: &mut Reader) -> Result<Header, String> {
+    let header_version = r.i32()?;
+    if header_version < MIN_HEADER_VERSION {
+        return Err(format!(
+            "save header version {header_version} is too old, only saves of game version 1.0 \
+                and newer are supported",
+        ));
+    }
+    let save_version = r.i32()?;
+    let build_version = r.i32()?;
+    if header_version >= 14 {
+        let _save_name = r.string()?;
+    }
+    let map_name = r.string()?;
+    let _map_options = r.string()?;
+    let session_name = r.string()?;
+    let play_duration_seconds = r.i32()?;
+    let _save_date_time = r.i64()?;
+    let _session_visibility = r.u8()?;
+    let _editor_object_version = r.i32()?;
+    let _mod_metadata = r.string()?;
+    let _is_modded_save = r.i32()?;
+    let _save_identifier = r.string()?;
+    let _is_partitioned_world = r.i32()?;
+    let _save_data_hash = r.bytes(20)?;
+    let _is_creative_mode_enabled = r.i32()?;
+
+    Ok(Header {
+        header_version,
+        save_version,
+        build_version,
+        map_name,
+        session_name,
+        play_duration_seconds,
+    })
+}
+
+/// Decompresses and concatenates all body chunks until the end of the file.
+fn decompress_body(r: &mut Reader) -> Result<Vec<u8>, String> {
+    let mut out = Vec::new();
+    while !r.is_empty() {
+        if r.u32()? != PACKAGE_FILE_TAG {
+            return Err("invalid chunk header".into());
+        }
+        let v2 = r.u32()? == ARCHIVE_V2;
+        let _max_chunk_size = r.i64()?;
+        if v2 {
+            let algorithm = r.u8()?;
+            if algorithm != ZLIB {
+                return Err(format!("unsupported compression algorithm {algorithm}"));
+            }
+        }
+        let compressed_size = r.len64()?;
+        let uncompressed_size = r.len64()?;
+        let _ = (r.i64()?, r.i64()?); // Sizes are repeated
+
+        let chunk = decompress_to_vec_zlib(r.bytes(compressed_size)?)
+            .map_err(|e| format!("failed to decompress chunk: {e:?}"))?;
+        if chunk.len() != uncompressed_size {
+            return Err("chunk has unexpected uncompressed size".into());
+        }
+        out.extend_from_slice(&chunk);
+    }
+    Ok(out)
+}
+
+/// Parses the uncompressed body: partitioning grids, then all sublevels followed by the
+/// persistent level.
+fn parse_body(body: &[u8]) -> Result<Vec<Object>, String> {
+    let mut r = Reader::new(body);
+    let len = r.len64()?;
+    let mut r = r.sub(len)?;
+
+    let grid_count = r.len32()?;
+    for _ in 0..grid_count {
+        let _name = r.string()?;
+        let _cell_size = r.u32()?;
+        let _grid_hash = r.u32()?;
+        let cell_count = r.len32()?;
+        for _ in 0..cell_count {
+            let _cell_name = r.string()?;
+            let _cell_hash = r.u32()?;
+        }
+    }
+
+    let mut objects = Vec::new();
+    let sublevel_count = r.len32()?;
+    for i in 0..=sublevel_count {
+        let is_persistent = i == sublevel_count;
+        if !is_persistent {
+            let _level_name = r.string()?;
+        }
+        parse_level(&mut r, &mut objects)?;
+    }
+
+    Ok(objects)
+}
+
+fn parse_level(r: &mut Reader, objects: &mut Vec<Object>) -> Result<(), String> {
+    let headers_len = r.len64()?;
+    let mut headers = r.sub(headers_len)?;
+    let count = headers.len32()?;
+    let first = objects.len();
+    for _ in 0..count {
+        objects.push(parse_object_header(&mut headers)?);
+    }
+
+    let objects_len = r.len64()?;
+    let mut data = r.sub(objects_len)?;
+    let data_count = data.len32()?;
+    if data_count != count {
+        return Err(format!("level has {count} object headers, but {data_count} objects"));
+    }
+    for object in &mut objects[first..] {
+        let _object_version = data.i32()?;
+        let _should_migrate = data.i32()?;
+        let len = data.len32()?;
+        let mut object_data = data.sub(len)?;
+        if object.position.is_some() {
+            let _parent = read_object_ref(&mut object_data)?;
+            let component_count = object_data.len32()?;
+            for _ in 0..component_count {
+                read_object_ref(&mut object_data)?;
+            }
+        }
+        object.properties = parse_properties(&mut object_data)?;
+        // Class specific data follows, which we ignore.
+    }
+
+    let collectables = r.len32()?;
+    for _ in 0..collectables {
+        read_object_ref(r)?;
+    }
+
+    Ok(())
+}
+
+fn parse_object_header(r: &mut Reader) -> Result<Object, String> {
+    let is_actor = match r.i32()? {
+        0 => false,
+        1 => true,
+        other => return Err(format!("invalid object type {other}")),
+    };
+    let class_name = r.string()?;
+    let path = read_object_ref(r)?;
+
+    let mut out = Object { class_name, path, parent: None, position: None, properties: vec![] };
+    if is_actor {
+        let _need_transform = r.i32()?;
+        let _rotation = [r.f32()?, r.f32()?, r.f32()?, r.f32()?];
+        out.position = Some([r.f32()?, r.f32()?, r.f32()?]);
+        let _scale = [r.f32()?, r.f32()?, r.f32()?];
+        let _was_placed_in_level = r.i32()?;
+    } else {
+        out.parent = Some(r.string()?);
+    }
+    Ok(out)
+}
+
+/// Object reference: level name and path name. Returns only the path name.
+fn read_object_ref(r: &mut Reader) -> Result<String, String> {
+    let _level = r.string()?;
+    r.string()
+}
+
+fn parse_properties(r: &mut Reader) -> Result<Vec<Property>, String> {
+    let mut out = Vec::new();
+    loop {
+        let name = r.string()?;
+        if name == "None" || name.is_empty() {
+            return Ok(out);
+        }
+        let ty = r.string()?;
+        let size = r.len32()?;
+        let index = r.i32()?;
+
+        let value = match ty.as_str() {
+            "BoolProperty" => {
+                let v = r.u8()? != 0;
+                skip_guid(r)?;
+                Value::Bool(v)
+            }
+            "ByteProperty" => {
+                let enum_name = r.string()?;
+                skip_guid(r)?;
+                if enum_name == "None" {
+                    Value::Int(r.u8()?.into())
+                } else {
+                    Value::Str(r.string()?)
+                }
+            }
+            "EnumProperty" => {
+                let _enum_name = r.string()?;
+                skip_guid(r)?;
+                Value::Str(r.string()?)
+            }
+            "IntProperty" => { skip_guid(r)?; Value::Int(r.i32()?.into()) }
+            "UInt32Property" => { skip_guid(r)?; Value::Int(r.u32()?.into()) }
+            "Int64Property" => { skip_guid(r)?; Value::Int(r.i64()?) }
+            "FloatProperty" => { skip_guid(r)?; Value::Float(r.f32()?.into()) }
+            "DoubleProperty" => { skip_guid(r)?; Value::Float(r.f64()?) }
+            "StrProperty" | "NameProperty" => { skip_guid(r)?; Value::Str(r.string()?) }
+            "ObjectProperty" => { skip_guid(r)?; Value::Object(read_object_ref(r)?) }
+            "StructProperty" => {
+                let _struct_name = r.string()?;
+                r.skip(16)?;
+                skip_guid(r)?;
+                r.skip(size)?;
+                Value::Other
+            }
+            "ArrayProperty" => {
+                let inner_type = r.string()?;
+                skip_guid(r)?;
+                // The array is self-contained, so if it can't be decoded, it's simply skipped.
+                parse_array(r.sub(size)?, &inner_type).unwrap_or(Value::Other)
+            }
+            "SetProperty" => {
+                let _inner_type = r.string()?;
+                skip_guid(r)?;
+                r.skip(size)?;
+                Value::Other
+            }
+            "MapProperty" => {
+                let _key_type = r.string()?;
+                let _value_type = r.string()?;
+                skip_guid(r)?;
+                r.skip(size)?;
+                Value::Other
+            }
+            _ => {
+                skip_guid(r)?;
+                r.skip(size)?;
+                Value::Other
+            }
+        };
+        out.push(Property { name, index, value });
+    }
+}
+
+fn parse_array(mut r: Reader, inner_type: &str) -> Result<Value, String> {
+    let count = r.len32()?;
+    let elements = match inner_type {
+        "IntProperty" => (0..count)
+            .map(|_| r.i32().map(|v| Value::Int(v.into())))
+            .collect::<Result<_, _>>()?,
+        "ObjectProperty" => (0..count)
+            .map(|_| read_object_ref(&mut r).map(Value::Object))
+            .collect::<Result<_, _>>()?,
+        "StructProperty" => {
+            // One tag for all elements. Structs with a binary layout (e.g. vectors) don't parse
+            // as properties and make the whole array fail.
+            let _name = r.string()?;
+            let _ty = r.string()?;
+            let _size = r.len32()?;
+            let _index = r.i32()?;
+            let _struct_name = r.string()?;
+            r.skip(16)?;
+            skip_guid(&mut r)?;
+            (0..count)
+                .map(|_| parse_properties(&mut r).map(Value::Struct))
+                .collect::<Result<_, _>>()?
+        }
+        _ => return Ok(Value::Other),
+    };
+    Ok(Value::Array(elements))
+}
+
+fn skip_guid(r: &mut Reader) -> Result<(), String> {
+    if r.u8()? != 0 {
+        r.skip(16)?;
+    }
+    Ok(())
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::save::writer::{small_factory, write};
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let objects = small_factory();
+        let save = parse(&write(&objects, 128 * 1024)).unwrap();
+        assert_eq!(save.header.header_version, MIN_HEADER_VERSION + 1);
+        assert_eq!(save.header.session_name, "Test session");
+        assert_eq!(save.objects, objects);
+    }
+
+    #[test]
+    fn multiple_chunks() {
+        let objects = small_factory();
+        let data = write(&objects, 256);
+        let chunks = data.windows(4).filter(|w| *w == PACKAGE_FILE_TAG.to_le_bytes()).count();
+        assert!(chunks > 10);
+        assert_eq!(parse(&data).unwrap().objects, objects);
+    }
+
+    #[test]
+    fn errors() {
+        let mut data = write(&small_factory(), 1024);
+        data.truncate(data.len() - 1);
+        assert!(parse(&data).is_err());
+
+        data[..4].copy_from_slice(&12i32.to_le_bytes());
+        assert!(parse(&data).unwrap_err().contains("too old"));
+    }
+}
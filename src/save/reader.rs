@@ -0,0 +1,115 @@
+//! Little endian reader for the primitive types used in save files.
+
+pub(super) struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    pub(super) fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    pub(super) fn is_empty(&self) -> bool {
+        self.pos >= self.data.len()
+    }
+
+    pub(super) fn bytes(&mut self, len: usize) -> Result<&'a [u8], String> {
+        let end = self.pos.checked_add(len).filter(|&end| end <= self.data.len())
+            .ok_or_else(|| format!("unexpected end of data at offset {}", self.pos))?;
+        let out = &self.data[self.pos..end];
+        self.pos = end;
+        Ok(out)
+    }
+
+    pub(super) fn skip(&mut self, len: usize) -> Result<(), String> {
+        self.bytes(len).map(|_| ())
+    }
+
+    /// Returns a reader for the next `len` bytes and advances this reader past them.
+    pub(super) fn sub(&mut self, len: usize) -> Result<Reader<'a>, String> {
+        self.bytes(len).map(Reader::new)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], String> {
+        Ok(self.bytes(N)?.try_into().unwrap())
+    }
+
+    pub(super) fn u8(&mut self) -> Result<u8, String> {
+        Ok(self.array::<1>()?[0])
+    }
+
+    pub(super) fn i32(&mut self) -> Result<i32, String> {
+        self.array().map(i32::from_le_bytes)
+    }
+
+    pub(super) fn u32(&mut self) -> Result<u32, String> {
+        self.array().map(u32::from_le_bytes)
+    }
+
+    pub(super) fn i64(&mut self) -> Result<i64, String> {
+        self.array().map(i64::from_le_bytes)
+    }
+
+    pub(super) fn f32(&mut self) -> Result<f32, String> {
+        self.array().map(f32::from_le_bytes)
+    }
+
+    pub(super) fn f64(&mut self) -> Result<f64, String> {
+        self.array().map(f64::from_le_bytes)
+    }
+
+    /// A length that is stored as `i32` or `i64` and must not be negative.
+    pub(super) fn len32(&mut self) -> Result<usize, String> {
+        let len = self.i32()?;
+        usize::try_from(len).map_err(|_| format!("invalid length {len}"))
+    }
+
+    pub(super) fn len64(&mut self) -> Result<usize, String> {
+        let len = self.i64()?;
+        usize::try_from(len).map_err(|_| format!("invalid length {len}"))
+    }
+
+    /// Unreal `FString`: an `i32` length including the null terminator, followed by Latin-1 (if
+    /// positive) or UTF-16 (if negative) characters.
+    pub(super) fn string(&mut self) -> Result<String, String> {
+        let len = self.i32()?;
+        let out = match len {
+            0 => String::new(),
+            1.. => {
+                let bytes = self.bytes(len as usize)?;
+                bytes[..bytes.len() - 1].iter().map(|&b| b as char).collect()
+            }
+            _ => {
+                let units = len.unsigned_abs() as usize;
+                let bytes = self.bytes(units * 2)?;
+                let units = bytes.chunks_exact(2)
+                    .map(|c| u16::from_le_bytes([c[0], c[1]]))
+                    .take(units - 1);
+                char::decode_utf16(units).map(|c| c.unwrap_or('\u{fffd}')).collect()
+            }
+        };
+        Ok(out)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strings() {
+        let data = [
+            0, 0, 0, 0,
+            4, 0, 0, 0, b'a', b'b', 0xe4, 0,
+            -3i8 as u8, 0xff, 0xff, 0xff, 0x3d, 0xd8, 0x80, 0xde, 0, 0,
+        ];
+        let mut r = Reader::new(&data);
+        assert_eq!(r.string().unwrap(), "");
+        assert_eq!(r.string().unwrap(), "abä");
+        assert_eq!(r.string().unwrap(), "🚀");
+        assert!(r.is_empty());
+        assert!(r.u8().is_err());
+    }
+}
@@ -0,0 +1,296 @@
+//! Writer for minimal save files, mirroring the parser. Used to create test fixtures.
+
+use miniz_oxide::deflate::compress_to_vec_zlib;
+
+use super::parse::{Object, Property, Value, ARCHIVE_V2, MIN_HEADER_VERSION, PACKAGE_FILE_TAG};
+
+
+#[derive(Default)]
+struct Writer(Vec<u8>);
+
+impl Writer {
+    fn u8(&mut self, v: u8) { self.0.push(v); }
+    fn i32(&mut self, v: i32) { self.0.extend_from_slice(&v.to_le_bytes()); }
+    fn u32(&mut self, v: u32) { self.0.extend_from_slice(&v.to_le_bytes()); }
+    fn i64(&mut self, v: i64) { self.0.extend_from_slice(&v.to_le_bytes()); }
+    fn f32(&mut self, v: f32) { self.0.extend_from_slice(&v.to_le_bytes()); }
+
+    fn string(&mut self, s: &str) {
+        if s.is_empty() {
+            self.i32(0);
+        } else if s.chars().all(|c| (c as u32) < 0x100) {
+            self.i32(s.len() as i32 + 1);
+            self.0.extend(s.chars().map(|c| c as u8));
+            self.u8(0);
+        } else {
+            let units = s.encode_utf16().collect::<Vec<_>>();
+            self.i32(-(units.len() as i32 + 1));
+            for unit in units.into_iter().chain([0]) {
+                self.0.extend_from_slice(&unit.to_le_bytes());
+            }
+        }
+    }
+
+    fn object_ref(&mut self, path: &str) {
+        self.string("Persistent_Level");
+        self.string(path);
+    }
+
+    /// Writes `f` prefixed by its length as `i64`.
+    fn sized64(&mut self, f: impl FnOnce(&mut Self)) {
+        let mut inner = Self::default();
+        f(&mut inner);
+        self.i64(inner.0.len() as i64);
+        self.0.extend(inner.0);
+    }
+}
+
+/// Serializes the objects as save file with the body split into chunks of at most `max_chunk`
+/// bytes.
+pub(super) fn write(objects: &[Object], max_chunk: usize) -> Vec<u8> {
+    let mut w = Writer::default();
+    w.i32(MIN_HEADER_VERSION + 1);
+    w.i32(46);
+    w.i32(365306);
+    w.string("Test");
+    w.string("Persistent_Level");
+    w.string("?startloc=Grass Fields");
+    w.string("Test session");
+    w.i32(3600);
+    w.i64(638_000_000_000_000_000);
+    w.u8(0);
+    w.i32(1);
+    w.string("");
+    w.i32(0);
+    w.string("test");
+    w.i32(1);
+    w.0.extend([0; 20]);
+    w.i32(0);
+
+    let mut body = Writer::default();
+    body.sized64(|b| {
+        b.i32(0); // Grids
+        b.i32(0); // Sublevels
+        b.sized64(|h| {
+            h.i32(objects.len() as i32);
+            for object in objects {
+                write_object_header(h, object);
+            }
+        });
+        b.sized64(|d| {
+            d.i32(objects.len() as i32);
+            for object in objects {
+                d.i32(46);
+                d.i32(0);
+                let mut data = Writer::default();
+                if object.position.is_some() {
+                    data.object_ref("");
+                    data.i32(0);
+                }
+                for property in &object.properties {
+                    write_property(&mut data, &property.name, &property.value);
+                }
+                data.string("None");
+                data.i32(0);
+                d.i32(data.0.len() as i32);
+                d.0.extend(data.0);
+            }
+        });
+        b.i32(0); // Collectables
+    });
+
+    for chunk in body.0.chunks(max_chunk) {
+        let compressed = compress_to_vec_zlib(chunk, 6);
+        w.u32(PACKAGE_FILE_TAG);
+        w.u32(ARCHIVE_V2);
+        w.i64(max_chunk as i64);
+        w.u8(3);
+        for _ in 0..2 {
+            w.i64(compressed.len() as i64);
+            w.i64(chunk.len() as i64);
+        }
+        w.0.extend(compressed);
+    }
+    w.0
+}
+
+fn write_object_header(w: &mut Writer, object: &Object) {
+    w.i32(object.position.is_some() as i32);
+    w.string(&object.class_name);
+    w.object_ref(&object.path);
+    match (object.position, &object.parent) {
+        (Some(position), _) => {
+            w.i32(1);
+            for v in [0.0, 0.0, 0.0, 1.0] {
+                w.f32(v);
+            }
+            for v in position {
+                w.f32(v);
+            }
+            for _ in 0..3 {
+                w.f32(1.0);
+            }
+            w.i32(0);
+        }
+        (None, parent) => w.string(parent.as_deref().unwrap_or_default()),
+    }
+}
+
+fn write_property(w: &mut Writer, name: &str, value: &Value) {
+    let mut data = Writer::default();
+    let ty = match value {
+        Value::Bool(_) => "BoolProperty",
+        Value::Int(v) => { data.i32(*v as i32); "IntProperty" }
+        Value::Float(v) => { data.f32(*v as f32); "FloatProperty" }
+        Value::Str(v) => { data.string(v); "StrProperty" }
+        Value::Object(v) => { data.object_ref(v); "ObjectProperty" }
+        Value::Array(elements) => { write_array(&mut data, name, elements); "ArrayProperty" }
+        Value::Struct(_) | Value::Other => unimplemented!(),
+    };
+    w.string(name);
+    w.string(ty);
+    w.i32(data.0.len() as i32);
+    w.i32(0);
+    match value {
+        Value::Bool(v) => w.u8(*v as u8),
+        Value::Array(elements) => w.string(array_type(elements)),
+        _ => {}
+    }
+    w.u8(0); // No GUID
+    w.0.extend(data.0);
+}
+
+fn array_type(elements: &[Value]) -> &'static str {
+    match elements.first() {
+        Some(Value::Object(_)) => "ObjectProperty",
+        Some(Value::Struct(_)) => "StructProperty",
+        _ => "IntProperty",
+    }
+}
+
+fn write_array(w: &mut Writer, name: &str, elements: &[Value]) {
+    w.i32(elements.len() as i32);
+    let mut data = Writer::default();
+    for element in elements {
+        match element {
+            Value::Int(v) => data.i32(*v as i32),
+            Value::Object(v) => data.object_ref(v),
+            Value::Struct(properties) => {
+                for property in properties {
+                    write_property(&mut data, &property.name, &property.value);
+                }
+                data.string("None");
+            }
+            _ => unimplemented!(),
+        }
+    }
+    if array_type(elements) == "StructProperty" {
+        w.string(name);
+        w.string("StructProperty");
+        w.i32(data.0.len() as i32);
+        w.i32(0);
+        w.string("SplitterSortRule");
+        w.0.extend([0; 16]);
+        w.u8(0); // No GUID
+    }
+    w.0.extend(data.0);
+}
+
+fn actor(class_name: &str, n: u32, properties: Vec<Property>) -> Object {
+    Object {
+        class_name: format!("/Game/FactoryGame/Buildable/{class_name}.{class_name}"),
+        path: format!("Persistent_Level:PersistentLevel.{class_name}_{n}"),
+        parent: None,
+        position: Some([n as f32 * 800.0, 0.0, 0.0]),
+        properties,
+    }
+}
+
+fn property(name: &str, value: Value) -> Property {
+    Property { name: name.into(), index: 0, value }
+}
+
+fn recipe(class_name: &str) -> Property {
+    let path = format!("/Game/FactoryGame/Recipes/{class_name}.{class_name}");
+    property("mCurrentRecipe", Value::Object(path))
+}
+
+/// Adds the connection components `a` and `b` of two actors, connected to each other if both
+/// are given.
+fn link(objects: &mut Vec<Object>, a: (&Object, &str), b: Option<(&Object, &str)>) {
+    let component = |(actor, name): (&Object, &str)| Object {
+        class_name: "/Script/FactoryGame.FGFactoryConnectionComponent".into(),
+        path: format!("{}.{name}", actor.path),
+        parent: Some(actor.path.clone()),
+        position: None,
+        properties: vec![],
+    };
+    let mut a = component(a);
+    if let Some(b) = b {
+        let mut b = component(b);
+        a.properties.push(property("mConnectedComponent", Value::Object(b.path.clone())));
+        b.properties.push(property("mConnectedComponent", Value::Object(a.path.clone())));
+        objects.push(b);
+    }
+    objects.push(a);
+}
+
+/// Sort rule of a smart splitter sending the item class to the output.
+fn sort_rule(item_class: &str, output: i64) -> Value {
+    let path = format!("/Game/FactoryGame/Resource/{item_class}.{item_class}");
+    Value::Struct(vec![
+        property("ItemClass", Value::Object(path)),
+        property("OutputIndex", Value::Int(output)),
+    ])
+}
+
+/// A smelter feeding two constructors through a splitter, belts and a lift, plus an idle
+/// constructor, which is skipped, and an unconnected smart splitter and priority merger.
+pub(super) fn small_factory() -> Vec<Object> {
+    let smelter = actor("Build_SmelterMk1_C", 1, vec![
+        recipe("Recipe_IngotIron_C"),
+        property("mCurrentPotential", Value::Float(1.5)),
+    ]);
+    let splitter = actor("Build_ConveyorAttachmentSplitter_C", 1, vec![]);
+    let plate = actor("Build_ConstructorMk1_C", 1, vec![
+        recipe("Recipe_IronPlate_C"),
+        property("mCurrentProductionBoost", Value::Float(2.0)),
+    ]);
+    // A boost the building can't have, as only one Somersloop fits.
+    let rod = actor("Build_ConstructorMk1_C", 2, vec![
+        recipe("Recipe_IronRod_C"),
+        property("mCurrentProductionBoost", Value::Float(3.0)),
+    ]);
+    let idle = actor("Build_ConstructorMk1_C", 3, vec![]);
+    let smart = actor("Build_ConveyorAttachmentSplitterSmart_C", 1, vec![
+        property("mSortRules", Value::Array(vec![
+            sort_rule("Desc_IronPlate_C", 0),
+            sort_rule("Desc_None_C", 1),
+            sort_rule("Desc_Overflow_C", 2),
+            sort_rule("Desc_IronRod_C", 0),
+        ])),
+    ]);
+    let priority = actor("Build_ConveyorAttachmentMergerPriority_C", 1, vec![
+        property("mPriorities", Value::Array(vec![Value::Int(2), Value::Int(0), Value::Int(1)])),
+    ]);
+    let belts = (1..=4)
+        .map(|n| actor("Build_ConveyorBeltMk2_C", n, vec![]))
+        .collect::<Vec<_>>();
+    let lift = actor("Build_ConveyorLiftMk2_C", 1, vec![]);
+
+    let mut objects = vec![];
+    link(&mut objects, (&smelter, "Output0"), Some((&belts[0], "ConveyorAny0")));
+    link(&mut objects, (&belts[0], "ConveyorAny1"), Some((&splitter, "Input1")));
+    link(&mut objects, (&splitter, "Output1"), Some((&belts[1], "ConveyorAny0")));
+    link(&mut objects, (&belts[1], "ConveyorAny1"), Some((&plate, "Input0")));
+    link(&mut objects, (&splitter, "Output3"), Some((&belts[2], "ConveyorAny0")));
+    link(&mut objects, (&belts[2], "ConveyorAny1"), Some((&lift, "ConveyorAny0")));
+    link(&mut objects, (&lift, "ConveyorAny1"), Some((&belts[3], "ConveyorAny0")));
+    link(&mut objects, (&belts[3], "ConveyorAny1"), Some((&rod, "Input0")));
+    link(&mut objects, (&splitter, "Output2"), None);
+    link(&mut objects, (&plate, "Output0"), None);
+
+    let actors = [smelter, splitter, plate, rod, idle, smart, priority, lift].into_iter()
+        .chain(belts);
+    actors.chain(objects).collect()
+}
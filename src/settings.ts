@@ -0,0 +1,43 @@
+import { create } from "zustand";
+import { persist } from "zustand/middleware";
+
+import { loadDataPacks, RecipeFilter } from "./gamedata";
+
+
+/**
+ * Settings of the player rather than of a plan, so they are neither part of the undo history nor
+ * of shared links.
+ */
+export type Settings = {
+    /** Progression used to hide recipes that aren't unlocked yet. */
+    recipeFilter: RecipeFilter;
+    setRecipeFilter: (filter: RecipeFilter) => void;
+    /** Data packs applied on top of the built-in game data, in order. */
+    dataPacks: unknown[];
+    /** Loads `packs` with `loadDataPacks` and keeps them. Throws if a pack is invalid. */
+    setDataPacks: (packs: unknown[]) => void;
+};
+
+export const useSettings = create<Settings>()(
+    persist(
+        set => ({
+            recipeFilter: {},
+            setRecipeFilter: (recipeFilter) => set({ recipeFilter }),
+            dataPacks: [],
+            setDataPacks: (dataPacks) => {
+                loadDataPacks(dataPacks);
+                set({ dataPacks });
+            },
+        }),
+        {
+            name: "satisfactory-planner-settings",
+            onRehydrateStorage: () => state => {
+                try {
+                    loadDataPacks(state?.dataPacks ?? []);
+                } catch (e) {
+                    console.error("Failed to load data packs:", e);
+                }
+            },
+        },
+    ),
+);
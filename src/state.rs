@@ -1,15 +1,22 @@
-use std::num::NonZeroU32;
+use std::{collections::BTreeMap, num::NonZeroU32};
 
 use serde::{Deserialize, Serialize};
 
-use crate::gamedata::{RecipeKind, SourceItemKind};
+use crate::gamedata::{
+    self, Building, GameData, ItemKind, Migration, PackItemForm, PackRef, RecipeKind,
+    SourceItemKind,
+};
 
 
 #[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Input {
     pub state: State,
     #[allow(dead_code)]
     pub version: u32,
+    /// Data packs this plan was made with, in the order they were applied.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub data_packs: Vec<PackRef>,
 }
 
 #[derive(Deserialize, Serialize)]
@@ -17,7 +24,7 @@ pub struct State {
     pub graph: Graph,
 }
 
-#[derive(Deserialize, Serialize)]
+#[derive(Clone, Deserialize, Serialize)]
 pub struct Graph {
     pub nodes: Vec<Node>,
     pub edges: Vec<Edge>,
@@ -27,6 +34,45 @@ impl Graph {
     pub fn node(&self, id: NodeId) -> &Node {
         &self.nodes[id as usize]
     }
+
+    /// Returns the item flowing along `edge`, or `None` if it cannot be determined (e.g. for
+    /// splitters and mergers only connected to each other, or items only known to data packs).
+    pub fn edge_item(&self, edge: &Edge, data: &GameData) -> Option<ItemKind> {
+        let mut visited = vec![false; self.nodes.len()];
+        let mut stack = vec![edge.source, edge.target];
+        while let Some(handle) = stack.pop() {
+            let node = self.node(handle.node);
+            match node {
+                Node::Recipe { .. } | Node::PackRecipe { .. } => {
+                    let Some(recipe) = node.recipe(data) else { continue };
+                    let entry = match handle.handle {
+                        h @ 0..=3 => recipe.inputs.get(h as usize),
+                        h => recipe.outputs.get(h as usize - 4),
+                    };
+                    if let Some(entry) = entry {
+                        return entry.item;
+                    }
+                }
+                Node::Source { item, .. } => return Some((*item).into()),
+                Node::Merger { .. }
+                | Node::Splitter { .. }
+                | Node::SmartSplitter { .. }
+                | Node::PriorityMerger { .. } => {
+                    if !std::mem::replace(&mut visited[handle.node as usize], true) {
+                        for e in self.edges.iter() {
+                            if e.source.node == handle.node {
+                                stack.push(e.target);
+                            }
+                            if e.target.node == handle.node {
+                                stack.push(e.source);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        None
+    }
 }
 
 pub type NodeId = u16;
@@ -53,6 +99,20 @@ pub enum Node {
         recipe: RecipeKind,
         buildings_count: NonZeroU32,
         overclock: Overclock,
+        /// Somersloops in each of the buildings, see `Building::somersloop_slots`.
+        #[serde(default, skip_serializing_if = "is_zero")]
+        somersloops: u8,
+    },
+    /// Recipe that only exists in one of the plan's data packs, referred to by its ID in the pack
+    /// (see `gamedata::GameData::recipe`). Has the same handles as `Recipe`.
+    #[serde(rename_all = "camelCase")]
+    PackRecipe {
+        pos: Pos,
+        recipe: String,
+        buildings_count: NonZeroU32,
+        overclock: Overclock,
+        #[serde(default, skip_serializing_if = "is_zero")]
+        somersloops: u8,
     },
     Merger {
         pos: Pos,
@@ -65,20 +125,227 @@ pub enum Node {
         item: SourceItemKind,
         rate: u32,
     },
+    /// Smart or programmable splitter, with the same handles as `Splitter`.
+    SmartSplitter {
+        pos: Pos,
+        /// Filters of the outputs 1, 2 and 3. An output without filters gets nothing.
+        filters: [Vec<SplitFilter>; 3],
+    },
+    /// Merger taking items from its inputs by priority, with the same handles as `Merger`.
+    PriorityMerger {
+        pos: Pos,
+        priority: InputPriority,
+    },
+}
+
+/// Which items a smart splitter sends to an output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum SplitFilter {
+    Rule(SplitRule),
+    Item(ItemKind),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SplitRule {
+    Any,
+    /// Items not matched by an item filter of another output.
+    AnyUndefined,
+    /// Items the other outputs cannot take.
+    Overflow,
+}
+
+/// Input handles of a priority merger, highest priority first.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(try_from = "[HandleId; 3]", into = "[HandleId; 3]")]
+pub struct InputPriority([HandleId; 3]);
+
+impl InputPriority {
+    pub const DEFAULT: Self = Self([0, 1, 2]);
+
+    /// All orders, used for compact encoding.
+    pub const ALL: [Self; 6] = [
+        Self([0, 1, 2]),
+        Self([0, 2, 1]),
+        Self([1, 0, 2]),
+        Self([1, 2, 0]),
+        Self([2, 0, 1]),
+        Self([2, 1, 0]),
+    ];
+
+    pub fn handles(self) -> [HandleId; 3] {
+        self.0
+    }
+}
+
+impl From<InputPriority> for [HandleId; 3] {
+    fn from(value: InputPriority) -> Self {
+        value.0
+    }
+}
+
+impl TryFrom<[HandleId; 3]> for InputPriority {
+    type Error = &'static str;
+
+    fn try_from(value: [HandleId; 3]) -> Result<Self, Self::Error> {
+        Self::ALL.into_iter()
+            .find(|p| p.0 == value)
+            .ok_or("priority must be an order of the input handles 0, 1 and 2")
+    }
 }
 
 impl Node {
     pub fn pos(&self) -> &Pos {
         match self {
             Node::Recipe { pos, .. } => pos,
+            Node::PackRecipe { pos, .. } => pos,
+            Node::Merger { pos, .. } => pos,
+            Node::Splitter { pos, .. } => pos,
+            Node::Source { pos, .. } => pos,
+            Node::SmartSplitter { pos, .. } => pos,
+            Node::PriorityMerger { pos, .. } => pos,
+        }
+    }
+
+    pub fn pos_mut(&mut self) -> &mut Pos {
+        match self {
+            Node::Recipe { pos, .. } => pos,
+            Node::PackRecipe { pos, .. } => pos,
             Node::Merger { pos, .. } => pos,
             Node::Splitter { pos, .. } => pos,
             Node::Source { pos, .. } => pos,
+            Node::SmartSplitter { pos, .. } => pos,
+            Node::PriorityMerger { pos, .. } => pos,
         }
     }
 
+    /// Whether this is any kind of splitter.
+    pub fn is_splitter(&self) -> bool {
+        matches!(self, Self::Splitter { .. } | Self::SmartSplitter { .. })
+    }
+
+    /// Whether this is any kind of merger.
+    pub fn is_merger(&self) -> bool {
+        matches!(self, Self::Merger { .. } | Self::PriorityMerger { .. })
+    }
+
     pub fn is_split_merge(&self) -> bool {
-        matches!(self, Self::Splitter { .. } | Self::Merger { .. })
+        self.is_splitter() || self.is_merger()
+    }
+
+    /// The recipe of a recipe node, with pack recipes looked up in `data`. `None` for other nodes
+    /// and for pack recipes that `data` doesn't have.
+    pub fn recipe<'a>(&'a self, data: &'a GameData) -> Option<NodeRecipe<'a>> {
+        match self {
+            Node::Recipe { recipe, buildings_count, overclock, somersloops, .. } => {
+                let info = recipe.info();
+                let io = |entries: &[gamedata::IoEntry]| entries.iter()
+                    .map(|e| NodeIo {
+                        item: Some(e.item),
+                        name: e.item.info().name,
+                        amount: e.amount,
+                        fluid: e.item.info().form.is_fluid(),
+                    })
+                    .collect();
+                Some(NodeRecipe {
+                    name: info.name,
+                    building: Some(info.building),
+                    building_name: info.building.name(),
+                    crafts_per_minute: info.crafts_per_minute(),
+                    inputs: io(info.inputs),
+                    outputs: io(info.outputs),
+                    buildings_count: *buildings_count,
+                    overclock: *overclock,
+                    somersloops: *somersloops,
+                })
+            }
+            Node::PackRecipe { recipe, buildings_count, overclock, somersloops, .. } => {
+                let entry = data.recipe(recipe)?;
+                let io = |entries: &'a [gamedata::PackIoEntry]| entries.iter()
+                    .map(|e| {
+                        let item = data.item(&e.item);
+                        NodeIo {
+                            item: item.and_then(|i| i.builtin),
+                            name: item.map_or(&e.item, |i| &i.name),
+                            amount: e.amount,
+                            fluid: item.is_some_and(|i| i.form != PackItemForm::Solid),
+                        }
+                    })
+                    .collect();
+                let building = entry.builtin_building();
+                Some(NodeRecipe {
+                    name: &entry.name,
+                    building,
+                    building_name: building.map_or(&entry.building, |b| b.name()),
+                    crafts_per_minute: 60.0 / entry.duration,
+                    inputs: io(&entry.inputs),
+                    outputs: io(&entry.outputs),
+                    buildings_count: *buildings_count,
+                    overclock: *overclock,
+                    somersloops: *somersloops,
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Buildings and clock of a recipe node, built-in or from a data pack.
+    pub fn clock(&self) -> Option<(NonZeroU32, Overclock)> {
+        match self {
+            Node::Recipe { buildings_count, overclock, .. }
+            | Node::PackRecipe { buildings_count, overclock, .. } => {
+                Some((*buildings_count, *overclock))
+            }
+            _ => None,
+        }
+    }
+
+    /// Like `clock`, but mutable.
+    pub fn clock_mut(&mut self) -> Option<(&mut NonZeroU32, &mut Overclock)> {
+        match self {
+            Node::Recipe { buildings_count, overclock, .. }
+            | Node::PackRecipe { buildings_count, overclock, .. } => {
+                Some((buildings_count, overclock))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// The recipe of a `Recipe` or `PackRecipe` node, see `Node::recipe`.
+pub struct NodeRecipe<'a> {
+    pub name: &'a str,
+    /// `None` for buildings added by mods.
+    pub building: Option<Building>,
+    pub building_name: &'a str,
+    /// Crafts per minute of one building at 100%.
+    pub crafts_per_minute: f64,
+    pub inputs: Vec<NodeIo<'a>>,
+    pub outputs: Vec<NodeIo<'a>>,
+    pub buildings_count: NonZeroU32,
+    pub overclock: Overclock,
+    pub somersloops: u8,
+}
+
+/// An input or output of a `NodeRecipe`, with amounts per craft.
+pub struct NodeIo<'a> {
+    /// `None` for items only known to data packs.
+    pub item: Option<ItemKind>,
+    pub name: &'a str,
+    pub amount: f64,
+    pub fluid: bool,
+}
+
+impl NodeRecipe<'_> {
+    /// Crafts per minute of all buildings of the node together, at their clock.
+    pub fn crafts_per_minute_total(&self) -> f64 {
+        self.buildings_count.get() as f64 * f64::from(self.overclock) * self.crafts_per_minute
+    }
+
+    /// Factor by which the Somersloops multiply the output. Buildings added by mods have no slots.
+    pub fn amplification(&self) -> f64 {
+        self.building.map_or(1.0, |b| amplification(b, self.somersloops))
     }
 }
 
@@ -88,6 +355,18 @@ pub struct Pos {
     pub y: i32,
 }
 
+/// Factor by which `somersloops` in one building multiply its output.
+pub fn amplification(building: Building, somersloops: u8) -> f64 {
+    match building.somersloop_slots() {
+        0 => 1.0,
+        slots => 1.0 + somersloops as f64 / slots as f64,
+    }
+}
+
+fn is_zero(v: &u8) -> bool {
+    *v == 0
+}
+
 /// Satisfactory overclock values can be between 1% and 250% with 4 decimal digits of precision.
 /// `f32` cannot correctly represent all values, so we use an int instead that's 10_000 times the
 /// percent value = 1_000_000 times the value. The value inside JSON is from 0.01 to 2.5.
@@ -95,9 +374,16 @@ pub struct Pos {
 #[serde(try_from = "f64", into = "f64")]
 pub struct Overclock(pub u32);
 
-impl Into<f64> for Overclock {
-    fn into(self) -> f64 {
-        (self.0 as f64) / 1_000_000.0
+impl From<Overclock> for f64 {
+    fn from(value: Overclock) -> Self {
+        (value.0 as f64) / 1_000_000.0
+    }
+}
+
+impl Overclock {
+    /// Power shards needed for one building at this clock: one per 50% step above 100%.
+    pub fn power_shards(self) -> u32 {
+        self.0.saturating_sub(1_000_000).div_ceil(500_000)
     }
 }
 
@@ -105,10 +391,110 @@ impl TryFrom<f64> for Overclock {
     type Error = &'static str;
 
     fn try_from(value: f64) -> Result<Self, Self::Error> {
-        if !(value >= 0.01 && value <= 2.5) {
+        if !(0.01..=2.5).contains(&value) {
             return Err("overclock value out of range");
         }
 
         Ok(Self((value * 1_000_000.0).round() as u32))
     }
 }
+
+/// Replaces recipes that no longer exist in the game by their successors, in a plan given as JSON
+/// value (as it would not deserialize otherwise). Their edges move to the successors' handles.
+/// Returns a warning for each replaced recipe and each edge that had to be removed.
+pub fn migrate(input: &mut serde_json::Value) -> Vec<String> {
+    migrate_with(input, gamedata::resolve_id)
+}
+
+/// Like `migrate`, with `resolve` instead of `gamedata::resolve_id` for recipe IDs.
+fn migrate_with<'m>(
+    input: &mut serde_json::Value,
+    resolve: impl Fn(&str) -> Option<(RecipeKind, Option<&'m Migration>)>,
+) -> Vec<String> {
+    let mut warnings = Vec::new();
+    let mut migrated = BTreeMap::new();
+    let nodes = input.pointer_mut("/state/graph/nodes").and_then(|n| n.as_array_mut());
+    for (i, node) in nodes.into_iter().flatten().enumerate() {
+        // Pack recipes keep the IDs of their pack.
+        if node.get("type").and_then(|t| t.as_str()) != Some("recipe") {
+            continue;
+        }
+        let Some(recipe) = node.get_mut("recipe") else { continue };
+        let Some((new, migration)) = recipe.as_str().and_then(&resolve) else { continue };
+        if let Some(migration) = migration {
+            *recipe = serde_json::to_value(new).unwrap();
+            warnings.push(migration.warning());
+            migrated.insert(i as u64, migration);
+        }
+    }
+
+    let edges = input.pointer_mut("/state/graph/edges").and_then(|e| e.as_array_mut());
+    if let Some(edges) = edges.filter(|_| !migrated.is_empty()) {
+        edges.retain_mut(|edge| ["source", "target"].into_iter().all(|side| {
+            let Some(handle) = edge.get_mut(side) else { return true };
+            let node = handle.get("node").and_then(|n| n.as_u64());
+            let Some(migration) = node.and_then(|n| migrated.get(&n)) else { return true };
+            // Invalid handles are left for deserialization to report.
+            let old = handle.get("handle")
+                .and_then(|h| h.as_u64())
+                .and_then(|h| HandleId::try_from(h).ok());
+            let Some(old) = old else { return true };
+            match migration.handle(old) {
+                Ok(new) => {
+                    handle["handle"] = new.into();
+                    true
+                }
+                Err(warning) => {
+                    warnings.push(warning);
+                    false
+                }
+            }
+        }));
+    }
+    warnings
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn migrate_edges() {
+        let mut input = serde_json::json!({"state":{"graph":{
+            "nodes": [
+                {"type":"source","pos":{"x":0,"y":0},"item":"copper-ore","rate":30},
+                {"type":"source","pos":{"x":0,"y":0},"item":"iron-ore","rate":90},
+                {"type":"recipe","recipe":"iron-alloy-ingot-legacy","pos":{"x":0,"y":0},
+                    "buildingsCount":1,"overclock":1},
+                {"type":"merger","pos":{"x":0,"y":0}}
+            ],
+            "edges": [
+                {"source":{"node":0,"handle":0},"target":{"node":2,"handle":0}},
+                {"source":{"node":1,"handle":0},"target":{"node":2,"handle":1}},
+                {"source":{"node":2,"handle":4},"target":{"node":3,"handle":0}}
+            ]
+        }},"version":0});
+        let migration = Migration {
+            stable_id: 9000,
+            old_id: "iron-alloy-ingot-legacy",
+            successor: RecipeKind::IronIngot,
+            note: "removed",
+            inputs: &[ItemKind::CopperOre, ItemKind::IronOre],
+            outputs: &[ItemKind::IronIngot],
+        };
+        let warnings = migrate_with(&mut input, |id| match id == migration.old_id {
+            true => Some((migration.successor, Some(&migration))),
+            false => gamedata::resolve_id(id),
+        });
+        assert_eq!(warnings.len(), 2, "{warnings:?}");
+
+        let input = serde_json::from_value::<Input>(input).unwrap();
+        let graph = input.state.graph;
+        assert!(matches!(graph.nodes[2], Node::Recipe { recipe: RecipeKind::IronIngot, .. }));
+        let edges = graph.edges.iter()
+            .map(|e| (e.source.node, e.source.handle, e.target.node, e.target.handle))
+            .collect::<Vec<_>>();
+        assert_eq!(edges, [(1, 0, 2, 0), (2, 4, 3, 0)]);
+    }
+}
@@ -3,13 +3,15 @@ import { immer } from "zustand/middleware/immer";
 import { XYPosition } from "@xyflow/react";
 import { temporal } from 'zundo';
 
-import { Graph, GraphHandle, GraphJson, GraphNodeId } from "./graph";
+import { Graph, GraphHandle, GraphHandleId, GraphJson, GraphNodeId } from "./graph";
 import { GraphNode } from "./graph/node";
 import { bug } from "./util";
 import { SourceGraphNode } from "./graph/source";
-import { RecipeGraphNode } from "./graph/recipe";
+import { RecipeGraphNode, RecipeLikeGraphNode } from "./graph/recipe";
+import { SmartSplitterGraphNode } from "./graph/splitter";
+import { PriorityMergerGraphNode } from "./graph/merger";
 import { persist, PersistStorage } from "zustand/middleware";
-import { compress_state, decompress_state } from "../pkg/satisfactory_planner";
+import { compress_state, decompress_state, migrate_state } from "../pkg/satisfactory_planner";
 import equal from "fast-deep-equal";
 
 
@@ -19,12 +21,16 @@ export type State = {
 
 type Actions = {
     addNode: (node: GraphNode) => void;
+    /** Adds `node` and connects its `handle` to `to`, as one undo step. */
+    addConnectedNode: (node: GraphNode, handle: GraphHandleId, to: GraphHandle) => void;
     removeNode: (id: GraphNodeId) => void;
     addEdge: (source: GraphHandle, target: GraphHandle) => void;
     removeEdge: (source: GraphHandle, target: GraphHandle) => void;
     updateNodePos: (id: GraphNodeId, pos: XYPosition) => void;
     setRecipeNodeData: (node: GraphNodeId, data: Partial<RecipeGraphNode>) => void;
     setSourceNodeData: (node: GraphNodeId, data: Partial<SourceGraphNode>) => void;
+    setSmartSplitterNodeData: (node: GraphNodeId, data: Partial<SmartSplitterGraphNode>) => void;
+    setPriorityMergerNodeData: (node: GraphNodeId, data: Partial<PriorityMergerGraphNode>) => void;
 };
 
 const initialState: State = {
@@ -37,6 +43,15 @@ const stateInit = immer<State & Actions>(set => ({
     addNode: (node) => set(state => {
         state.graph.addNode(node);
     }),
+    addConnectedNode: (node, handle, to) => set(state => {
+        const id = state.graph.addNode(node);
+        const own = new GraphHandle(id, handle);
+        if (node.inputs().includes(handle)) {
+            state.graph.addEdge(to, own);
+        } else {
+            state.graph.addEdge(own, to);
+        }
+    }),
     removeNode: (id) => set(state => {
         state.graph.removeNode(id);
     }),
@@ -52,12 +67,15 @@ const stateInit = immer<State & Actions>(set => ({
 
     setRecipeNodeData: (nodeId, data) => set(state => {
         const node = state.graph.node(nodeId);
-        if (!(node instanceof RecipeGraphNode)) {
-            return bug("node is not a source node");
+        if (!(node instanceof RecipeLikeGraphNode)) {
+            return bug("node is not a recipe node");
+        }
+        if (node instanceof RecipeGraphNode) {
+            node.recipeId = data.recipeId ?? node.recipeId;
         }
-        node.recipeId = data.recipeId ?? node.recipeId;
         node.buildingsCount = data.buildingsCount ?? node.buildingsCount;
         node.overclock = data.overclock ?? node.overclock;
+        node.somersloops = data.somersloops ?? node.somersloops;
     }),
     setSourceNodeData: (nodeId, data) => set(state => {
         const node = state.graph.node(nodeId);
@@ -67,6 +85,20 @@ const stateInit = immer<State & Actions>(set => ({
         node.item = data.item ?? node.item;
         node.rate = data.rate ?? node.rate;
     }),
+    setSmartSplitterNodeData: (nodeId, data) => set(state => {
+        const node = state.graph.node(nodeId);
+        if (!(node instanceof SmartSplitterGraphNode)) {
+            return bug("node is not a smart splitter node");
+        }
+        node.filters = data.filters ?? node.filters;
+    }),
+    setPriorityMergerNodeData: (nodeId, data) => set(state => {
+        const node = state.graph.node(nodeId);
+        if (!(node instanceof PriorityMergerGraphNode)) {
+            return bug("node is not a priority merger node");
+        }
+        node.priority = data.priority ?? node.priority;
+    }),
 }));
 
 
@@ -75,6 +107,8 @@ type PersistedState = {
     state: {
         graph: GraphJson;
     };
+    /** Data packs the plan was made with, in the order they were applied. */
+    dataPacks?: { id: string; version: string }[];
 };
 
 /**
@@ -144,6 +178,15 @@ const isStateEqual = (a: PersistedState, b: PersistedState) => {
 };
 
 
+/**
+ * Warnings from loading the plan, e.g. about recipes that were removed from the game. Not part of
+ * the plan, so kept apart from `useStore`.
+ */
+export const useLoadWarnings = create<{ warnings: string[]; dismiss: () => void }>()(set => ({
+    warnings: [],
+    dismiss: () => set({ warnings: [] }),
+}));
+
 // Custom storage engine to do custom serialization/deserialization.
 const storage: PersistStorage<State & Actions> = {
     getItem: name => {
@@ -151,11 +194,11 @@ const storage: PersistStorage<State & Actions> = {
         if (!str) {
             return null;
         }
-        const json = JSON.parse(str);
-        const digest = compress_state(str);
+        const { input: json, warnings } = JSON.parse(migrate_state(str));
+        useLoadWarnings.setState({ warnings });
+        const digest = compress_state(JSON.stringify(json));
         console.log("Digest: ", digest);
-        const roundtrip = decompress_state(digest);
-        const rtstate = JSON.parse(roundtrip);
+        const { input: rtstate } = JSON.parse(decompress_state(digest));
 
         // Check roundtrip
         if (!isStateEqual(json, rtstate)) {